@@ -0,0 +1,36 @@
+pub mod palette;
+pub mod state;
+
+use crate::app::AppState;
+use crate::backend::SharedPdfBackend;
+use crate::extension::Extension;
+use crate::input::{AppInputEvent, InputHookResult};
+pub use palette::AnnotationsPaletteProvider;
+pub use state::{AnnotationsCommandPort, AnnotationsState, AnnotationsUiSnapshot};
+
+pub struct AnnotationsExtension;
+
+impl Extension for AnnotationsExtension {
+    type State = AnnotationsState;
+
+    fn init_state() -> Self::State {
+        AnnotationsState::default()
+    }
+
+    fn handle_input(
+        state: &mut Self::State,
+        event: AppInputEvent,
+        app: &mut AppState,
+    ) -> InputHookResult {
+        let _ = (event, app);
+        // The flash only marks where a jump landed; the next key press dismisses it and
+        // still goes on to its usual binding.
+        state.clear_flash();
+        InputHookResult::Ignored
+    }
+
+    fn on_document_reloaded(state: &mut Self::State, app: &mut AppState, pdf: SharedPdfBackend) {
+        let _ = (app, pdf);
+        state.on_document_reloaded();
+    }
+}
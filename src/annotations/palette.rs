@@ -0,0 +1,269 @@
+use crate::backend::{PageLabels, PdfDateTime};
+use crate::command::Command;
+use crate::error::AppResult;
+use crate::input::shortcut::format_shortcut_key;
+use crate::palette::{
+    PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PalettePayload,
+    PalettePostAction, PaletteProvider, PaletteSearchText, PaletteSubmitEffect, PaletteTextPart,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotationPaletteEntry {
+    pub page: usize,
+    pub subtype: String,
+    pub author: Option<String>,
+    pub date: Option<PdfDateTime>,
+    pub contents: Option<String>,
+}
+
+pub struct AnnotationsPaletteProvider;
+
+impl PaletteProvider for AnnotationsPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::Annotations
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Annotations".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::FilterCandidates
+    }
+
+    fn initial_selected_candidate(
+        &self,
+        ctx: &PaletteContext<'_>,
+        candidates: &[PaletteCandidate],
+    ) -> Option<usize> {
+        // Entries are in page order, so this lands on the first annotation at or after the
+        // current page instead of sending the reader back to page one.
+        let entries = &ctx.extensions.annotations.entries;
+        candidates.iter().position(|candidate| {
+            decode_payload(&candidate.payload)
+                .and_then(|index| entries.get(index))
+                .is_some_and(|entry| entry.page >= ctx.app.current_page)
+        })
+    }
+
+    fn list(&self, ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok(ctx
+            .extensions
+            .annotations
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| annotation_candidate(index, entry, &ctx.app.page_labels))
+            .collect())
+    }
+
+    fn on_submit(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        let Some(index) = selected.and_then(|candidate| decode_payload(&candidate.payload)) else {
+            return Ok(PaletteSubmitEffect::Close);
+        };
+
+        Ok(PaletteSubmitEffect::Dispatch {
+            command: Command::AnnotationGoto { index },
+            history_record: None,
+            next: PalettePostAction::Close,
+        })
+    }
+
+    fn assistive_text(
+        &self,
+        ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        if ctx.extensions.annotations.entries.is_empty() {
+            return Some("No annotations in this document".to_string());
+        }
+
+        let enter = format_shortcut_key(crate::input::shortcut::ShortcutKey::key(
+            crossterm::event::KeyCode::Enter,
+        ));
+        Some(format!("{enter} jump to annotation"))
+    }
+}
+
+fn annotation_candidate(
+    index: usize,
+    entry: &AnnotationPaletteEntry,
+    labels: &PageLabels,
+) -> PaletteCandidate {
+    let kind = subtype_label(&entry.subtype);
+    let contents = entry
+        .contents
+        .as_deref()
+        .map(|contents| contents.split_whitespace().collect::<Vec<_>>().join(" "));
+    let page = page_text(entry.page, labels);
+
+    let mut left = vec![
+        PaletteTextPart::secondary(kind),
+        PaletteTextPart::primary("  "),
+    ];
+    left.push(match &contents {
+        Some(contents) => PaletteTextPart::primary(contents.clone()),
+        None => PaletteTextPart::secondary("(no comment)"),
+    });
+
+    let mut details = Vec::new();
+    if let Some(author) = &entry.author {
+        details.push(author.clone());
+    }
+    if let Some(date) = entry.date {
+        details.push(format!(
+            "{:04}-{:02}-{:02}",
+            date.year, date.month, date.day
+        ));
+    }
+    details.push(page.clone());
+
+    let mut search_texts = vec![
+        PaletteSearchText::new(kind),
+        PaletteSearchText::new(page),
+        PaletteSearchText::new(format!("page {}", entry.page + 1)),
+    ];
+    search_texts.extend(
+        [contents, entry.author.clone()]
+            .into_iter()
+            .flatten()
+            .map(PaletteSearchText::new),
+    );
+
+    PaletteCandidate {
+        id: format!("annotation-{index}"),
+        left,
+        right: vec![PaletteTextPart::secondary(details.join(" · "))],
+        search_texts,
+        payload: PalettePayload::Opaque(index.to_string()),
+    }
+}
+
+/// Reader-facing names for the subtypes whose PDF names are not self-explanatory.
+fn subtype_label(subtype: &str) -> &str {
+    match subtype {
+        "Text" => "Note",
+        "FreeText" => "Free text",
+        "StrikeOut" => "Strikeout",
+        "PolyLine" => "Polyline",
+        "FileAttachment" => "File",
+        other => other,
+    }
+}
+
+fn page_text(page: usize, labels: &PageLabels) -> String {
+    match labels.distinct(page) {
+        Some(label) => format!("p.{label}"),
+        None => format!("p.{}", page + 1),
+    }
+}
+
+fn decode_payload(payload: &PalettePayload) -> Option<usize> {
+    let PalettePayload::Opaque(payload) = payload else {
+        return None;
+    };
+    payload.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::{PageLabels, PdfDateTime};
+    use crate::command::Command;
+    use crate::extension::ExtensionUiSnapshot;
+    use crate::palette::{
+        PaletteAppSnapshot, PaletteContext, PaletteKind, PaletteProvider, PaletteSubmitEffect,
+    };
+
+    use super::{AnnotationPaletteEntry, AnnotationsPaletteProvider};
+
+    fn annotations_snapshot() -> ExtensionUiSnapshot {
+        ExtensionUiSnapshot {
+            annotations: crate::annotations::AnnotationsUiSnapshot {
+                entries: vec![
+                    AnnotationPaletteEntry {
+                        page: 0,
+                        subtype: "Highlight".to_string(),
+                        author: None,
+                        date: None,
+                        contents: None,
+                    },
+                    AnnotationPaletteEntry {
+                        page: 2,
+                        subtype: "Text".to_string(),
+                        author: Some("Ada".to_string()),
+                        date: Some(PdfDateTime {
+                            year: 2024,
+                            month: 5,
+                            day: 1,
+                            hour: 9,
+                            minute: 30,
+                            second: 0,
+                            utc_offset_minutes: None,
+                        }),
+                        contents: Some("Check\nthis figure".to_string()),
+                    },
+                ]
+                .into(),
+            },
+            ..ExtensionUiSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn list_shows_kind_contents_and_details_and_submit_jumps_to_the_selection() {
+        let provider = AnnotationsPaletteProvider;
+        let extensions = annotations_snapshot();
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot {
+                current_page: 1,
+                page_labels: PageLabels::new(["i", "ii", "iii"].map(str::to_string).to_vec()),
+                ..PaletteAppSnapshot::default()
+            },
+            extensions: &extensions,
+            kind: PaletteKind::Annotations,
+            input: "",
+            open_payload: None,
+        };
+
+        let items = provider.list(&ctx).expect("annotations list should build");
+        assert_eq!(items[0].left[2].text, "(no comment)");
+        assert_eq!(items[1].left[0].text, "Note");
+        assert_eq!(items[1].left[2].text, "Check this figure");
+        assert_eq!(items[1].right[0].text, "Ada · 2024-05-01 · p.iii");
+        assert_eq!(provider.initial_selected_candidate(&ctx, &items), Some(1));
+
+        let effect = provider
+            .on_submit(&ctx, Some(&items[1]))
+            .expect("submit should succeed");
+        assert!(matches!(
+            effect,
+            PaletteSubmitEffect::Dispatch {
+                command: Command::AnnotationGoto { index: 1 },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn empty_documents_explain_why_the_list_is_empty() {
+        let provider = AnnotationsPaletteProvider;
+        let extensions = ExtensionUiSnapshot::default();
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot::default(),
+            extensions: &extensions,
+            kind: PaletteKind::Annotations,
+            input: "",
+            open_payload: None,
+        };
+
+        assert!(provider.list(&ctx).expect("list should build").is_empty());
+        assert_eq!(
+            provider.assistive_text(&ctx, None).as_deref(),
+            Some("No annotations in this document")
+        );
+    }
+}
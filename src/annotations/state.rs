@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use crate::app::{AppState, NoticeAction, PaletteRequest};
+use crate::backend::{PdfBackend, PdfRect, SharedPdfBackend};
+use crate::command::CommandOutcome;
+use crate::error::{AppError, AppResult};
+use crate::highlight::{HighlightSource, HighlightSpan, HighlightStyle};
+use crate::palette::PaletteKind;
+
+use super::palette::AnnotationPaletteEntry;
+
+struct AnnotationsCache {
+    doc_id: u64,
+    entries: Arc<[AnnotationPaletteEntry]>,
+    /// Page-space bounds for each entry, by the same index.
+    rects: Vec<PdfRect>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AnnotationFlash {
+    page: usize,
+    rect: PdfRect,
+}
+
+#[derive(Default)]
+pub struct AnnotationsState {
+    cache: Option<AnnotationsCache>,
+    flash: Option<AnnotationFlash>,
+}
+
+pub struct AnnotationsCommandPort<'a> {
+    state: &'a mut AnnotationsState,
+}
+
+impl<'a> AnnotationsCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut AnnotationsState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn open_palette(&mut self, pdf: SharedPdfBackend) -> AppResult<PaletteRequest> {
+        self.state.open_palette(pdf)
+    }
+
+    pub(crate) fn goto(
+        &mut self,
+        app: &mut AppState,
+        page_count: usize,
+        index: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.goto(app, page_count, index)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnnotationsUiSnapshot {
+    pub entries: Arc<[AnnotationPaletteEntry]>,
+}
+
+impl AnnotationsState {
+    pub fn open_palette(&mut self, pdf: SharedPdfBackend) -> AppResult<PaletteRequest> {
+        self.ensure_loaded(pdf.as_ref())?;
+        Ok(PaletteRequest::Open {
+            kind: PaletteKind::Annotations,
+            payload: None,
+        })
+    }
+
+    /// Moves to the annotation's page and flashes its bounds until the next key press.
+    pub fn goto(
+        &mut self,
+        app: &mut AppState,
+        page_count: usize,
+        index: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let Some((page, rect)) = self.cache.as_ref().and_then(|cache| {
+            let entry = cache.entries.get(index)?;
+            Some((entry.page, *cache.rects.get(index)?))
+        }) else {
+            return Err(AppError::invalid_argument(
+                "annotation index is out of range",
+            ));
+        };
+        if page >= page_count {
+            return Err(AppError::page_out_of_range(
+                page.saturating_add(1),
+                page_count,
+            ));
+        }
+
+        app.current_page = app.normalize_page_for_layout(page, page_count);
+        self.flash = Some(AnnotationFlash { page, rect });
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    pub fn clear_flash(&mut self) {
+        self.flash = None;
+    }
+
+    pub fn highlight_spans_for_visible_pages(
+        &self,
+        visible_pages: [Option<usize>; 2],
+    ) -> Vec<HighlightSpan> {
+        self.flash
+            .filter(|flash| visible_pages.contains(&Some(flash.page)))
+            .map(|flash| HighlightSpan {
+                source: HighlightSource::Annotation,
+                page: flash.page,
+                rects: vec![flash.rect],
+                style: HighlightStyle::ANNOTATION_FLASH,
+            })
+            .into_iter()
+            .collect()
+    }
+
+    pub fn palette_entries(&self) -> Arc<[AnnotationPaletteEntry]> {
+        self.cache
+            .as_ref()
+            .map(|cache| Arc::clone(&cache.entries))
+            .unwrap_or_else(|| Arc::from([]))
+    }
+
+    pub fn ui_snapshot(&self) -> AnnotationsUiSnapshot {
+        AnnotationsUiSnapshot {
+            entries: self.palette_entries(),
+        }
+    }
+
+    pub fn on_document_reloaded(&mut self) {
+        *self = Self::default();
+    }
+
+    fn ensure_loaded(&mut self, pdf: &dyn PdfBackend) -> AppResult<()> {
+        if self
+            .cache
+            .as_ref()
+            .is_some_and(|cache| cache.doc_id == pdf.doc_id())
+        {
+            return Ok(());
+        }
+
+        let mut entries = Vec::new();
+        let mut rects = Vec::new();
+        for page in 0..pdf.page_count() {
+            for annotation in pdf.extract_annotations(page)? {
+                rects.push(annotation.rect);
+                entries.push(AnnotationPaletteEntry {
+                    page,
+                    subtype: annotation.subtype,
+                    author: annotation.author,
+                    date: annotation.date,
+                    contents: annotation.contents,
+                });
+            }
+        }
+        self.cache = Some(AnnotationsCache {
+            doc_id: pdf.doc_id(),
+            entries: Arc::from(entries),
+            rects,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::app::AppState;
+    use crate::backend::test_support::TextStubPdf;
+    use crate::backend::{PdfAnnotation, PdfRect, SharedPdfBackend};
+    use crate::command::CommandOutcome;
+    use crate::error::AppError;
+    use crate::highlight::HighlightSource;
+
+    use super::AnnotationsState;
+
+    fn rect() -> PdfRect {
+        PdfRect {
+            x0: 10.0,
+            y0: 20.0,
+            x1: 30.0,
+            y1: 25.0,
+        }
+    }
+
+    #[test]
+    fn goto_moves_to_the_page_and_flashes_until_cleared() {
+        let pdf = Arc::new(
+            TextStubPdf::blank(3)
+                .with_path("annotated.pdf")
+                .with_annotations(
+                    2,
+                    vec![PdfAnnotation {
+                        subtype: "Highlight".to_string(),
+                        author: Some("Ada".to_string()),
+                        date: None,
+                        contents: Some("typo".to_string()),
+                        rect: rect(),
+                    }],
+                ),
+        ) as SharedPdfBackend;
+        let mut app = AppState::default();
+        let mut state = AnnotationsState::default();
+        state
+            .open_palette(Arc::clone(&pdf))
+            .expect("palette should open");
+        assert_eq!(state.palette_entries().len(), 1);
+        assert_eq!(state.palette_entries()[0].page, 2);
+
+        let (outcome, _) = state.goto(&mut app, 3, 0).expect("goto should succeed");
+        assert_eq!(outcome, CommandOutcome::Applied);
+        assert_eq!(app.current_page, 2);
+
+        let spans = state.highlight_spans_for_visible_pages([Some(2), None]);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].source, HighlightSource::Annotation);
+        assert_eq!(spans[0].rects, vec![rect()]);
+        assert!(
+            state
+                .highlight_spans_for_visible_pages([Some(0), Some(1)])
+                .is_empty()
+        );
+
+        state.clear_flash();
+        assert!(
+            state
+                .highlight_spans_for_visible_pages([Some(2), None])
+                .is_empty()
+        );
+        assert!(matches!(
+            state.goto(&mut app, 3, 1),
+            Err(AppError::InvalidArgument(_))
+        ));
+    }
+}
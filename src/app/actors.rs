@@ -14,7 +14,7 @@ use super::loop_effects::LoopEffects;
 use super::loop_runtime::LoopStep;
 use super::nav::NavTracker;
 use super::render_ops::CurrentTaskContext;
-use super::state::AppState;
+use super::state::{AppState, PageViewMode};
 use super::terminal_session::TerminalSurface;
 use super::view_ops::{
     RenderFramePlan, compute_current_scale_for_state, current_viewport_for_session,
@@ -178,6 +178,10 @@ impl RenderActor {
         render_worker: &mut RenderWorker,
         step: &LoopStep,
     ) {
+        // Text view draws extracted text, so rendering pages would only burn cycles.
+        if state.view_mode == PageViewMode::Text {
+            return;
+        }
         render.ensure_current_task_enqueued(
             state,
             pdf,
@@ -185,13 +189,13 @@ impl RenderActor {
             render_worker,
             CurrentTaskContext {
                 current_scale: step.current_scale,
-                required: step.required,
-                current_interest_keys: step.current_interest_keys,
+                required: step.required.clone(),
+                current_interest_keys: step.current_interest_keys.clone(),
                 current_cached: step.current_cached,
                 preview_tasks: step.initial_preview_tasks.clone(),
             },
         );
-        render.dispatch_prefetch_if_due(state, self, render_worker, step.prefetch_dispatch);
+        render.dispatch_prefetch_if_due(state, self, render_worker, step.prefetch_dispatch.clone());
     }
 
     pub(super) fn handle_render_complete<S>(
@@ -219,13 +223,14 @@ impl RenderActor {
             ctx.pdf,
             visible_pages,
             current_scale,
+            viewport,
             self.generation() == 0,
         );
         let pan = PanOffset {
             cells_x: state.pan_x,
             cells_y: state.pan_y,
         };
-        let enable_crop = state.zoom > 1.0;
+        let enable_crop = state.crops_to_viewport();
         let interactive = ctx
             .input_actor
             .is_interactive(ctx.prefetch_pause_after_input);
@@ -330,6 +335,10 @@ impl UiActor {
 
         if self.needs_redraw() {
             let palette_view = interaction.palette_view();
+            let extension_render = interaction
+                .extensions
+                .host
+                .render_snapshot(step.visible_pages.existing_pages());
             let mut status_bar_segments = interaction.extensions.host.status_bar_segments(state);
             if let Some(pending_sequence) = interaction.pending_sequence_status() {
                 status_bar_segments.push(pending_sequence);
@@ -347,11 +356,10 @@ impl UiActor {
                     current_scale: step.current_scale,
                     initial_preview: step.initial_preview.clone(),
                     presenter_key: step.presenter_key,
-                    highlight_overlay: interaction
-                        .extensions
-                        .host
-                        .render_snapshot(step.visible_pages.existing_pages())
-                        .highlight_overlay,
+                    highlight_overlay: extension_render.highlight_overlay,
+                    link_hints: extension_render.link_hints,
+                    document_info: extension_render.document_info,
+                    document_title: extension_render.document_title,
                     generation: render_generation,
                     nav_streak,
                 },
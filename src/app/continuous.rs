@@ -0,0 +1,377 @@
+use ratatui::layout::Rect;
+
+use crate::backend::PdfBackend;
+use crate::presenter::{PanOffset, PresenterHorizontalAlign, Viewport};
+
+use super::constants::DEFAULT_PAGE_SIZE_PT;
+use super::scale::resolved_cell_size_px;
+
+/// Blank rows between consecutive pages.
+const PAGE_GAP_ROWS: i64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ContinuousPageSize {
+    pub(crate) width_px: u32,
+    pub(crate) height_px: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ContinuousLayoutRequest {
+    pub(crate) viewport: Viewport,
+    pub(crate) cell_px: Option<(u16, u16)>,
+    pub(crate) page_count: usize,
+    /// Page the vertical pan is measured from, normally `current_page`.
+    pub(crate) anchor_page: usize,
+    /// Horizontal pan in cells, and the viewport's top row relative to the anchor page's top
+    /// edge. Negative rows reach into the pages above.
+    pub(crate) pan: PanOffset,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ContinuousPageClip {
+    pub(crate) page: usize,
+    pub(crate) crop_x: u32,
+    pub(crate) crop_y: u32,
+    pub(crate) crop_width: u32,
+    pub(crate) crop_height: u32,
+    pub(crate) area: Rect,
+    pub(crate) horizontal_align: PresenterHorizontalAlign,
+}
+
+impl ContinuousPageClip {
+    /// Presenter pan identifying this crop, so each scroll position encodes separately.
+    pub(crate) fn presenter_pan(&self, cell_px: Option<(u16, u16)>) -> PanOffset {
+        let (cell_width_px, cell_height_px) = resolved_cell_size_px(cell_px);
+        PanOffset {
+            cells_x: cells_i32(self.crop_x / u32::from(cell_width_px)),
+            cells_y: cells_i32(self.crop_y / u32::from(cell_height_px)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ContinuousLayout {
+    /// Page covering the most viewport rows; ties go to the upper page.
+    pub(crate) dominant_page: usize,
+    /// Clamped pan with the vertical offset rebased onto `dominant_page`.
+    pub(crate) pan: PanOffset,
+    /// Visible page regions from top to bottom.
+    pub(crate) clips: Vec<ContinuousPageClip>,
+}
+
+impl ContinuousLayout {
+    pub(crate) fn pages(&self) -> impl Iterator<Item = usize> + '_ {
+        self.clips.iter().map(|clip| clip.page)
+    }
+}
+
+/// Lays out the document scrolled to `pan` below the top of `anchor_page`, at `scale`.
+pub(crate) fn layout_for_document(
+    pdf: &dyn PdfBackend,
+    anchor_page: usize,
+    pan: PanOffset,
+    viewport: Viewport,
+    cell_px: Option<(u16, u16)>,
+    scale: f32,
+) -> ContinuousLayout {
+    layout(
+        ContinuousLayoutRequest {
+            viewport,
+            cell_px,
+            page_count: pdf.page_count(),
+            anchor_page,
+            pan,
+        },
+        |page| page_size(pdf, page, scale),
+    )
+}
+
+/// Stacks pages top to bottom, each starting on a fresh row, and clips them to the viewport.
+/// Scrolling stops once the last page's top reaches the viewport top, or its bottom reaches the
+/// viewport bottom when the page is taller than the viewport.
+pub(crate) fn layout(
+    request: ContinuousLayoutRequest,
+    page_size: impl Fn(usize) -> ContinuousPageSize,
+) -> ContinuousLayout {
+    if request.page_count == 0 {
+        return ContinuousLayout::default();
+    }
+
+    let (cell_width_px, cell_height_px) = resolved_cell_size_px(request.cell_px);
+    let cell_height = i64::from(cell_height_px);
+    let view_rows = i64::from(request.viewport.height.max(1));
+    let view_width_px = u32::from(request.viewport.width.max(1)) * u32::from(cell_width_px);
+    let rows = |page: usize| {
+        i64::from(page_size(page).height_px)
+            .saturating_add(cell_height - 1)
+            .saturating_div(cell_height)
+            .max(1)
+    };
+
+    let last_page = request.page_count - 1;
+    let mut top_page = request.anchor_page.min(last_page);
+    let mut offset = i64::from(request.pan.cells_y);
+    while offset < 0 && top_page > 0 {
+        top_page -= 1;
+        offset += rows(top_page) + PAGE_GAP_ROWS;
+    }
+    offset = offset.max(0);
+    while top_page < last_page && offset >= rows(top_page) + PAGE_GAP_ROWS {
+        offset -= rows(top_page) + PAGE_GAP_ROWS;
+        top_page += 1;
+    }
+    if top_page == last_page {
+        offset = offset.min((rows(last_page) - view_rows).max(0));
+    }
+
+    // (page, size, row of the page's top edge relative to the viewport top, page rows)
+    let mut visible = Vec::new();
+    let mut page_top = -offset;
+    let mut page = top_page;
+    while page_top < view_rows && page <= last_page {
+        let page_rows = rows(page);
+        if page_top + page_rows > 0 {
+            visible.push((page, page_size(page), page_top, page_rows));
+        }
+        page_top += page_rows + PAGE_GAP_ROWS;
+        page += 1;
+    }
+
+    let visible_rows =
+        |page_top: i64, page_rows: i64| (page_top + page_rows).min(view_rows) - page_top.max(0);
+    let (dominant_page, dominant_top) = visible
+        .iter()
+        .fold(
+            None,
+            |best: Option<(usize, i64, i64)>, &(page, _, top, rows)| {
+                let shown = visible_rows(top, rows);
+                match best {
+                    Some((_, _, best_shown)) if best_shown >= shown => best,
+                    _ => Some((page, top, shown)),
+                }
+            },
+        )
+        .map_or((top_page, -offset), |(page, top, _)| (page, top));
+
+    let canvas_width = visible
+        .iter()
+        .map(|(_, size, _, _)| size.width_px)
+        .max()
+        .unwrap_or(1);
+    let mut pan = PanOffset {
+        cells_x: request.pan.cells_x,
+        cells_y: 0,
+    };
+    let max_x = canvas_width.saturating_sub(view_width_px);
+    pan.clamp_to_pixel_bounds(max_x, 0, cell_width_px, cell_height_px);
+    let (origin_x, _) = pan.pixel_origin(max_x, 0, cell_width_px, cell_height_px);
+    pan.cells_y = cells_i32_signed(-dominant_top);
+
+    let clips = visible
+        .into_iter()
+        .filter_map(|(page, size, page_top, page_rows)| {
+            clip_page(ClipRequest {
+                page,
+                size,
+                page_top,
+                page_rows,
+                view_rows,
+                view_width_px,
+                canvas_width,
+                origin_x,
+                viewport: request.viewport,
+                cell_px: (cell_width_px, cell_height_px),
+            })
+        })
+        .collect();
+
+    ContinuousLayout {
+        dominant_page,
+        pan,
+        clips,
+    }
+}
+
+struct ClipRequest {
+    page: usize,
+    size: ContinuousPageSize,
+    page_top: i64,
+    page_rows: i64,
+    view_rows: i64,
+    view_width_px: u32,
+    canvas_width: u32,
+    origin_x: u32,
+    viewport: Viewport,
+    cell_px: (u16, u16),
+}
+
+fn clip_page(request: ClipRequest) -> Option<ContinuousPageClip> {
+    let (cell_width_px, cell_height_px) =
+        (u32::from(request.cell_px.0), u32::from(request.cell_px.1));
+    let first_row = request.page_top.max(0);
+    let end_row = (request.page_top + request.page_rows).min(request.view_rows);
+    let crop_y = u32::try_from(first_row - request.page_top)
+        .unwrap_or(0)
+        .saturating_mul(cell_height_px);
+    let crop_height = u32::try_from(end_row - first_row)
+        .unwrap_or(0)
+        .saturating_mul(cell_height_px)
+        .min(request.size.height_px.saturating_sub(crop_y));
+
+    // Pages narrower than the widest visible page are centered on a cell boundary so every
+    // horizontal crop starts on one too.
+    let page_x = (request.canvas_width.saturating_sub(request.size.width_px) / 2) / cell_width_px
+        * cell_width_px;
+    let x0 = request.origin_x.max(page_x);
+    let x1 = request
+        .origin_x
+        .saturating_add(request.view_width_px)
+        .min(page_x.saturating_add(request.size.width_px));
+    if x1 <= x0 || crop_height == 0 {
+        return None;
+    }
+
+    let (area_x, area_width, horizontal_align) = if request.canvas_width <= request.view_width_px {
+        (
+            request.viewport.x,
+            request.viewport.width,
+            PresenterHorizontalAlign::Center,
+        )
+    } else {
+        let column = ((x0 - request.origin_x) / cell_width_px) as u16;
+        let columns = (x1 - x0).div_ceil(cell_width_px) as u16;
+        (
+            request.viewport.x.saturating_add(column),
+            columns.min(request.viewport.width.saturating_sub(column)),
+            PresenterHorizontalAlign::Start,
+        )
+    };
+
+    Some(ContinuousPageClip {
+        page: request.page,
+        crop_x: x0 - page_x,
+        crop_y,
+        crop_width: x1 - x0,
+        crop_height,
+        area: Rect::new(
+            area_x,
+            request.viewport.y.saturating_add(first_row as u16),
+            area_width,
+            (end_row - first_row) as u16,
+        ),
+        horizontal_align,
+    })
+}
+
+fn page_size(pdf: &dyn PdfBackend, page: usize, scale: f32) -> ContinuousPageSize {
+    let (width_pt, height_pt) = pdf.page_dimensions(page).unwrap_or(DEFAULT_PAGE_SIZE_PT);
+    ContinuousPageSize {
+        width_px: scaled_px(width_pt, scale),
+        height_px: scaled_px(height_pt, scale),
+    }
+}
+
+fn scaled_px(points: f32, scale: f32) -> u32 {
+    if !points.is_finite() || !scale.is_finite() || points <= 0.0 || scale <= 0.0 {
+        return 1;
+    }
+    (points * scale).round().clamp(1.0, u32::MAX as f32) as u32
+}
+
+fn cells_i32(cells: u32) -> i32 {
+    i32::try_from(cells).unwrap_or(i32::MAX)
+}
+
+fn cells_i32_signed(cells: i64) -> i32 {
+    cells.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: ContinuousPageSize = ContinuousPageSize {
+        width_px: 80,
+        height_px: 95,
+    };
+
+    fn request(anchor_page: usize, cells_y: i32) -> ContinuousLayoutRequest {
+        ContinuousLayoutRequest {
+            viewport: Viewport {
+                x: 0,
+                y: 1,
+                width: 10,
+                height: 8,
+            },
+            cell_px: Some((10, 10)),
+            page_count: 3,
+            anchor_page,
+            pan: PanOffset {
+                cells_x: 0,
+                cells_y,
+            },
+        }
+    }
+
+    #[test]
+    fn scrolls_from_one_page_into_the_next() {
+        // Pages take 10 rows plus a gap row, so page 0 shows rows 6..10 and page 1 rows 0..3.
+        let layout = layout(request(0, 6), |_| PAGE);
+
+        assert_eq!(layout.pages().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(layout.clips[0].area, Rect::new(0, 1, 10, 4));
+        assert_eq!(
+            (layout.clips[0].crop_y, layout.clips[0].crop_height),
+            (60, 35)
+        );
+        assert_eq!(layout.clips[1].area, Rect::new(0, 6, 10, 3));
+        assert_eq!(
+            (layout.clips[1].crop_y, layout.clips[1].crop_height),
+            (0, 30)
+        );
+        assert_eq!(layout.dominant_page, 0);
+    }
+
+    #[test]
+    fn rebases_pan_onto_the_page_owning_most_rows() {
+        let layout = layout(request(0, 8), |_| PAGE);
+
+        assert_eq!(layout.dominant_page, 1);
+        assert_eq!(layout.pan.cells_y, -3);
+
+        let rebased = super::layout(request(1, -3), |_| PAGE);
+        assert_eq!(rebased.clips, layout.clips);
+        assert_eq!(rebased.dominant_page, 1);
+    }
+
+    #[test]
+    fn clamps_at_document_start_and_end() {
+        let top = layout(request(1, -40), |_| PAGE);
+        assert_eq!(top.pan.cells_y, 0);
+        assert_eq!(top.dominant_page, 0);
+
+        let bottom = layout(request(1, 40), |_| PAGE);
+        assert_eq!(bottom.dominant_page, 2);
+        assert_eq!(bottom.pan.cells_y, 2);
+        assert_eq!(bottom.clips.last().map(|clip| clip.area.bottom()), Some(9));
+    }
+
+    #[test]
+    fn pans_horizontally_across_pages_wider_than_the_viewport() {
+        let wide = ContinuousPageSize {
+            width_px: 150,
+            height_px: 95,
+        };
+        let mut request = request(0, 0);
+        request.pan.cells_x = 20;
+        let layout = layout(request, |_| wide);
+
+        assert_eq!(layout.pan.cells_x, 5);
+        assert_eq!(layout.clips[0].crop_x, 50);
+        assert_eq!(layout.clips[0].crop_width, 100);
+        assert_eq!(
+            layout.clips[0].horizontal_align,
+            PresenterHorizontalAlign::Start
+        );
+    }
+}
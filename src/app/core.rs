@@ -1,26 +1,39 @@
 use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use crate::config::Config;
 use crate::config::keymap::build_default_sequence_registry;
 use crate::config::{
-    AppOptions, AppOptionsResolver, CachePolicy, EventLoopPolicy, InputPolicy, RenderPolicy,
-    ResolvedAppOptions, ViewPolicy, WatchPolicy, load_default_app_options,
+    AppOptions, AppOptionsResolver, CachePolicy, ClipboardPolicy, EventLoopPolicy, InputPolicy,
+    LinksPolicy, RenderPolicy, ResolvedAppOptions, ViewPolicy, WatchPolicy, default_cache_dir,
+    load_default_app_options,
 };
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::extension::ExtensionHost;
 use crate::input::InputHistoryService;
 use crate::input::sequence::{DEFAULT_SEQUENCE_TIMEOUT, SequenceRegistry, SequenceResolver};
+use crate::marks::{MarkStore, marks_path};
 use crate::palette::{PaletteManager, PaletteRegistry};
 use crate::presenter::{ImagePresenter, PresenterKind, create_presenter_with_cache_limits};
+use crate::render::disk_cache::{
+    ClearedRenderCache, DiskRenderCache, RENDER_CACHE_DIR, clear_render_cache_dir,
+};
+use crate::search::text_index::{
+    ClearedTextIndex, TEXT_INDEX_DIR, TextIndexStore, clear_text_index_dir,
+};
 
+use super::reading_position::ReadingPositionSession;
 use super::runtime::RenderRuntime;
 use super::state::{AppState, CacheHandle, PaletteRequest};
+use super::view_ops::TextViewCache;
 
 pub struct RenderSubsystem {
     pub presenter: Box<dyn ImagePresenter>,
     pub runtime: RenderRuntime,
     pub viewer_has_image: bool,
     pub image_occluded_last_frame: bool,
+    pub(crate) text_view: TextViewCache,
 }
 
 impl RenderSubsystem {
@@ -30,6 +43,7 @@ impl RenderSubsystem {
             runtime,
             viewer_has_image: false,
             image_occluded_last_frame: false,
+            text_view: TextViewCache::default(),
         }
     }
 }
@@ -110,6 +124,10 @@ pub struct App {
     pub(crate) view_policy: ViewPolicy,
     pub(crate) event_loop_policy: EventLoopPolicy,
     pub(crate) watch_policy: WatchPolicy,
+    pub(crate) links_policy: LinksPolicy,
+    pub(crate) clipboard_policy: ClipboardPolicy,
+    pub(crate) cache_policy: CachePolicy,
+    pub(crate) reading_positions: Option<ReadingPositionSession>,
     run_options: RunOptions,
 }
 
@@ -187,9 +205,12 @@ impl App {
         let mut state = AppState {
             current_page: view.initial_page_index,
             page_layout_mode: view.initial_layout,
+            view_mode: view.initial_view,
+            fit_mode: view.fit,
             spread_direction: view.spread_direction,
             spread_cover_policy: view.spread_cover,
             zoom: view.initial_zoom,
+            status_title_visible: view.status_title,
             ..AppState::default()
         };
         state.caches.l1_rendered_pages = Some(CacheHandle {
@@ -201,14 +222,21 @@ impl App {
             });
         }
 
+        let mut interaction = InteractionSubsystem::with_input_policy(options.input);
+        interaction.extensions.host.configure_search(options.search);
+
         Ok(Self {
             state,
             render: RenderSubsystem::new(presenter, render_runtime_from_cache_policy(cache)),
-            interaction: InteractionSubsystem::with_input_policy(options.input),
+            interaction,
             render_policy: options.render,
             view_policy: view,
             event_loop_policy: options.event_loop,
             watch_policy: watch,
+            links_policy: options.links,
+            clipboard_policy: options.clipboard,
+            cache_policy: cache,
+            reading_positions: None,
             run_options: RunOptions {
                 watch: run_options.watch || watch.enabled,
             },
@@ -220,6 +248,44 @@ impl App {
         self.watch_policy.enabled = watch;
     }
 
+    /// Opens the on-disk render cache and search text index so that rendered pages and
+    /// extracted text outlive this process. Each is only opened when its budget is not zero.
+    ///
+    /// Both only save work: when they are disabled or their directory cannot be used, pages are
+    /// rendered and text is extracted as usual.
+    pub fn enable_disk_cache(&mut self) {
+        let text_budget_bytes = self.cache_policy.text_index_budget_bytes();
+        if text_budget_bytes > 0
+            && let Some(dir) = text_index_dir()
+            && let Ok(store) = TextIndexStore::open(dir, text_budget_bytes)
+        {
+            self.interaction
+                .extensions
+                .host
+                .attach_text_index(Arc::new(Mutex::new(store)));
+        }
+
+        let budget_bytes = self.cache_policy.l3_disk_budget_bytes();
+        if budget_bytes == 0 {
+            return;
+        }
+        let Some(dir) = render_cache_dir() else {
+            return;
+        };
+        self.render.runtime.l3_cache = DiskRenderCache::open(dir, budget_bytes).ok();
+    }
+
+    /// Keeps marks in the pvf state directory so they outlive the session and can be shared
+    /// between documents.
+    pub fn enable_persistent_marks(&mut self) {
+        if let Some(file) = marks_path() {
+            self.interaction
+                .extensions
+                .host
+                .attach_mark_store(MarkStore::new(file));
+        }
+    }
+
     pub(crate) fn enable_metrics_collection(&mut self) -> AppResult<()> {
         self.render.runtime.perf_stats.reset();
         self.render.presenter.initialize_headless_for_perf()?;
@@ -233,6 +299,34 @@ impl App {
     }
 }
 
+/// Directory of the on-disk render cache.
+pub fn render_cache_dir() -> Option<PathBuf> {
+    default_cache_dir().map(|dir| dir.join(RENDER_CACHE_DIR))
+}
+
+/// Deletes every page in the on-disk render cache and reports where and what was removed.
+pub fn clear_render_cache() -> AppResult<(PathBuf, ClearedRenderCache)> {
+    let dir = render_cache_dir().ok_or_else(|| {
+        AppError::invalid_argument("no cache directory: set XDG_CACHE_HOME or HOME")
+    })?;
+    let cleared = clear_render_cache_dir(&dir)?;
+    Ok((dir, cleared))
+}
+
+/// Directory of the persistent search text index.
+pub fn text_index_dir() -> Option<PathBuf> {
+    default_cache_dir().map(|dir| dir.join(TEXT_INDEX_DIR))
+}
+
+/// Deletes the extracted text of every indexed document and reports where and what was removed.
+pub fn clear_text_index() -> AppResult<(PathBuf, ClearedTextIndex)> {
+    let dir = text_index_dir().ok_or_else(|| {
+        AppError::invalid_argument("no cache directory: set XDG_CACHE_HOME or HOME")
+    })?;
+    let cleared = clear_text_index_dir(&dir)?;
+    Ok((dir, cleared))
+}
+
 fn render_runtime_from_cache_policy(cache: CachePolicy) -> RenderRuntime {
     RenderRuntime::with_l1_cache_limits(cache.l1_max_entries, cache.l1_memory_budget_bytes())
 }
@@ -241,7 +335,7 @@ fn render_runtime_from_cache_policy(cache: CachePolicy) -> RenderRuntime {
 mod tests {
     use std::time::Duration;
 
-    use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+    use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
     use crate::config::{CacheOptions, InputOptions, RenderOptions, ViewOptions, WatchOptions};
     use crate::presenter::PresenterKind;
 
@@ -331,8 +425,12 @@ mod tests {
                 initial_page: Some(3),
                 initial_zoom: Some(1.25),
                 initial_layout: Some(PageLayoutMode::Spread),
+                initial_view: Some(PageViewMode::Text),
+                fit: Some(FitMode::Height),
                 spread_direction: Some(SpreadDirection::Rtl),
                 spread_cover: Some(SpreadCoverPolicy::Cover),
+                status_title: Some(true),
+                remember_position: Some(false),
             },
             watch: WatchOptions {
                 enabled: Some(true),
@@ -374,8 +472,11 @@ mod tests {
         assert_eq!(app.state.current_page, 2);
         assert_eq!(app.state.zoom, 1.25);
         assert_eq!(app.state.page_layout_mode, PageLayoutMode::Spread);
+        assert_eq!(app.state.view_mode, PageViewMode::Text);
+        assert_eq!(app.state.fit_mode, FitMode::Height);
         assert_eq!(app.state.spread_direction, SpreadDirection::Rtl);
         assert_eq!(app.state.spread_cover_policy, SpreadCoverPolicy::Cover);
+        assert!(app.state.status_title_visible);
         assert!(app.run_options().watch);
         assert!(app.watch_policy.enabled);
         assert_eq!(app.watch_policy.poll_interval, Duration::from_millis(125));
@@ -7,14 +7,27 @@ use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
 use tokio::task::JoinHandle;
 use tokio::time::{self, Duration, Instant};
 
-use crate::backend::open_default_backend;
+use crate::backend::open_default_backend_with_password;
 use crate::event::DocumentReloadReason;
 use crate::event::DocumentReloadRequest;
 use crate::event::DocumentReloadResult;
 use crate::event::DomainEvent;
 
+use super::export_ops::ImageExport;
+use super::yank_ops::PagesYank;
+
 pub(crate) struct EventBusRuntime {
     tasks: Vec<JoinHandle<()>>,
+    file_watch: Option<FileWatch>,
+}
+
+/// The `--watch` poller, kept apart from the other tasks so it can follow the document to a new
+/// path.
+struct FileWatch {
+    path: PathBuf,
+    poll_interval: Duration,
+    settle_delay: Duration,
+    task: JoinHandle<()>,
 }
 
 impl EventBusRuntime {
@@ -24,7 +37,11 @@ impl EventBusRuntime {
         Self,
     ) {
         let (tx, rx) = unbounded_channel();
-        (tx, rx, Self { tasks: Vec::new() })
+        let runtime = Self {
+            tasks: Vec::new(),
+            file_watch: None,
+        };
+        (tx, rx, runtime)
     }
 
     pub(crate) fn spawn_headless() -> (
@@ -33,8 +50,11 @@ impl EventBusRuntime {
         Self,
     ) {
         let (tx, rx) = unbounded_channel();
-        let tasks = Vec::new();
-        (tx, rx, Self { tasks })
+        let runtime = Self {
+            tasks: Vec::new(),
+            file_watch: None,
+        };
+        (tx, rx, runtime)
     }
 
     pub(crate) fn start_input(&mut self, tx: UnboundedSender<DomainEvent>) {
@@ -48,16 +68,38 @@ impl EventBusRuntime {
         settle_delay: Duration,
         tx: UnboundedSender<DomainEvent>,
     ) {
-        self.push_task(spawn_file_watch_task(path, poll_interval, settle_delay, tx));
+        if let Some(watch) = self.file_watch.take() {
+            watch.task.abort();
+        }
+        let task = spawn_file_watch_task(path.clone(), poll_interval, settle_delay, tx);
+        self.file_watch = Some(FileWatch {
+            path,
+            poll_interval,
+            settle_delay,
+            task,
+        });
+    }
+
+    /// Moves a running file watch to `path` when the open document now lives there.
+    pub(crate) fn follow_file_watch(&mut self, path: &Path, tx: UnboundedSender<DomainEvent>) {
+        let Some(watch) = &self.file_watch else {
+            return;
+        };
+        if watch.path == path {
+            return;
+        }
+        let (poll_interval, settle_delay) = (watch.poll_interval, watch.settle_delay);
+        self.start_file_watch(path.to_path_buf(), poll_interval, settle_delay, tx);
     }
 
     pub(crate) fn start_document_reload(
         &mut self,
         path: PathBuf,
+        password: Option<String>,
         request: DocumentReloadRequest,
         tx: UnboundedSender<DomainEvent>,
     ) {
-        self.push_task(spawn_document_reload_task(path, request, tx));
+        self.push_task(spawn_document_reload_task(path, password, request, tx));
     }
 
     pub(crate) fn start_delayed_document_reload(
@@ -69,10 +111,25 @@ impl EventBusRuntime {
         self.push_task(spawn_delayed_document_reload_task(request, delay, tx));
     }
 
+    pub(crate) fn start_image_export(
+        &mut self,
+        export: ImageExport,
+        tx: UnboundedSender<DomainEvent>,
+    ) {
+        self.push_task(spawn_image_export_task(export, tx));
+    }
+
+    pub(crate) fn start_pages_yank(&mut self, yank: PagesYank, tx: UnboundedSender<DomainEvent>) {
+        self.push_task(spawn_pages_yank_task(yank, tx));
+    }
+
     pub(crate) fn shutdown(&mut self) {
         for task in self.tasks.drain(..) {
             task.abort();
         }
+        if let Some(watch) = self.file_watch.take() {
+            watch.task.abort();
+        }
     }
 
     fn push_task(&mut self, task: JoinHandle<()>) {
@@ -160,11 +217,13 @@ fn spawn_file_watch_task(
 
 fn spawn_document_reload_task(
     path: PathBuf,
+    password: Option<String>,
     request: DocumentReloadRequest,
     tx: UnboundedSender<DomainEvent>,
 ) -> JoinHandle<()> {
     tokio::task::spawn_blocking(move || {
-        let result = open_default_backend(&path).map_err(|err| err.to_string());
+        let result = open_default_backend_with_password(&path, password.as_deref())
+            .map_err(|err| err.to_string());
         let _ = tx.send(DomainEvent::DocumentReloaded(DocumentReloadResult {
             reason: request.reason,
             generation: request.generation,
@@ -173,6 +232,22 @@ fn spawn_document_reload_task(
     })
 }
 
+fn spawn_image_export_task(
+    export: ImageExport,
+    tx: UnboundedSender<DomainEvent>,
+) -> JoinHandle<()> {
+    tokio::task::spawn_blocking(move || {
+        let _ = tx.send(DomainEvent::ImageExported(export.run()));
+    })
+}
+
+fn spawn_pages_yank_task(yank: PagesYank, tx: UnboundedSender<DomainEvent>) -> JoinHandle<()> {
+    tokio::task::spawn_blocking(move || {
+        let (notice, osc52_text) = yank.run();
+        let _ = tx.send(DomainEvent::PagesYanked { notice, osc52_text });
+    })
+}
+
 fn spawn_delayed_document_reload_task(
     request: DocumentReloadRequest,
     delay: Duration,
@@ -191,7 +266,7 @@ mod tests {
 
     use tokio::time;
 
-    use crate::backend::test_support::{build_pdf, unique_temp_path};
+    use crate::backend::test_support::{build_encrypted_pdf, build_pdf, unique_temp_path};
     use crate::event::{DocumentReloadReason, DocumentReloadRequest, DomainEvent};
 
     use super::EventBusRuntime;
@@ -276,4 +351,40 @@ mod tests {
 
         fs::remove_file(&file).expect("test file should be removed");
     }
+
+    #[test]
+    fn document_reload_reuses_password_for_encrypted_pdf() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("tokio runtime should initialize");
+        let file = unique_temp_path("reload_encrypted.pdf");
+        fs::write(&file, build_encrypted_pdf("locked", "hunter2"))
+            .expect("test pdf should be written");
+
+        let result = runtime.block_on(async {
+            let (tx, mut rx, mut event_runtime) = EventBusRuntime::spawn_headless();
+            event_runtime.start_document_reload(
+                file.clone(),
+                Some("hunter2".to_string()),
+                DocumentReloadRequest::new(DocumentReloadReason::FileChanged),
+                tx,
+            );
+            let event = time::timeout(Duration::from_secs(5), rx.recv())
+                .await
+                .expect("reload should finish before timeout")
+                .expect("reload channel should stay open");
+            event_runtime.shutdown();
+            event
+        });
+
+        fs::remove_file(&file).expect("test file should be removed");
+        let DomainEvent::DocumentReloaded(reload) = result else {
+            panic!("reload task should emit a reload result");
+        };
+        let pdf = reload
+            .result
+            .expect("password should unlock the reloaded pdf");
+        assert_eq!(pdf.password(), Some("hunter2"));
+    }
 }
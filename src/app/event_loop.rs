@@ -91,6 +91,7 @@ impl App {
         let result = self
             .run_driver_loop(&mut runtime, &mut document, &mut driver)
             .await;
+        self.save_reading_position(document.pdf.as_ref());
         runtime.loop_event_runtime.shutdown();
         let restore_result = runtime.session.restore();
         match (result, restore_result) {
@@ -115,8 +116,11 @@ impl App {
     where
         S: TerminalSurface,
     {
+        self.restore_reading_position(pdf.as_ref());
         self.state.current_page = self.state.current_page.min(page_count - 1);
         self.state.normalize_current_page(page_count);
+        self.state.page_labels = pdf.page_labels();
+        self.render.runtime.attach_document(pdf.as_ref());
 
         let loop_started_at = Instant::now();
         let pending_redraw_interval = self.event_loop_policy.pending_redraw_interval;
@@ -132,8 +136,11 @@ impl App {
         prefetch_tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
         let mut redraw_tick = time::interval(pending_redraw_interval);
         redraw_tick.set_missed_tick_behavior(MissedTickBehavior::Skip);
-        let render_worker =
-            RenderWorker::spawn(Arc::clone(&pdf), self.render_policy.worker_threads);
+        let render_worker = RenderWorker::spawn(
+            Arc::clone(&pdf),
+            self.render_policy.worker_threads,
+            self.render.runtime.worker_l3_cache(),
+        );
         let viewport = Self::current_viewport(&session, self.state.debug_status_visible);
         let visible_pages = self.state.visible_page_slots(page_count);
         let tracked_scale =
@@ -323,6 +330,7 @@ impl App {
         prefetch_dispatch_budget: usize,
     ) -> LoopStep {
         let prefetch_viewport = Self::current_viewport(session, self.state.debug_status_visible);
+        self.sync_continuous_scroll(pdf, prefetch_viewport);
         let visible_pages = self.state.visible_page_slots(pdf.page_count());
         let current_scale =
             self.compute_current_scale(pdf, visible_pages.anchor_page, prefetch_viewport);
@@ -331,6 +339,7 @@ impl App {
             pdf,
             visible_pages,
             current_scale,
+            prefetch_viewport,
             render_generation == 0,
         );
         let overlay_stamp = self
@@ -353,12 +362,13 @@ impl App {
             },
         );
 
+        let initial_preview_tasks = current_view.preview_tasks(render_generation);
         LoopStep {
             current_scale: current_view.current_scale,
             visible_pages: current_view.visible_pages,
             required: current_view.required,
             current_interest_keys: current_view.current_interest_keys,
-            initial_preview_tasks: current_view.preview_tasks(render_generation),
+            initial_preview_tasks,
             prefetch_dispatch,
             initial_preview: current_view.initial_preview,
             presenter_key: current_view.presenter_key,
@@ -523,11 +533,16 @@ mod tests {
     use ratatui::layout::Size;
     use tokio::runtime::Builder;
     use tokio::sync::mpsc::unbounded_channel;
+    use tokio::time;
 
     use super::{WaitEvent, wait_next_event};
     use crate::app::App;
     use crate::app::core::InteractionSubsystem;
     use crate::app::loop_runtime::ActiveDocument;
+    use crate::app::reading_position::{
+        PinnedView, ReadingPosition, ReadingPositionSession, ReadingPositionStore,
+    };
+    use crate::app::scale::zoom_eq;
     use crate::app::terminal_session::{TerminalSession, TerminalSurface};
     use crate::app::{
         LoopDriver, LoopDriverDecision, LoopDriverHandle, LoopMetricsSnapshot, LoopObservation,
@@ -541,10 +556,13 @@ mod tests {
     use crate::config::Config;
     use crate::error::{AppError, AppResult};
     use crate::event::{
-        DocumentReloadReason, DocumentReloadRequest, DocumentReloadResult, DomainEvent,
+        AppEvent, DocumentReloadReason, DocumentReloadRequest, DocumentReloadResult, DomainEvent,
+        NavReason,
     };
     use crate::input::sequence::SequenceRegistry;
     use crate::input::shortcut::ShortcutKey;
+    use crate::marks::MarkStore;
+    use crate::marks::store::{Mark, document_key};
     use crate::presenter::PresenterKind;
     use crate::presenter::{
         ImagePresenter, PresenterBackgroundEvent, PresenterCaps, PresenterFeedback,
@@ -729,7 +747,7 @@ mod tests {
         let doc = PdfDoc::open(&file).expect("pdf should open");
         fs::remove_file(&file).expect("test pdf should be removed");
         let shared: SharedPdfBackend = Arc::new(doc);
-        RenderWorker::spawn(shared, 1)
+        RenderWorker::spawn(shared, 1, None)
     }
 
     fn test_pdf_backend() -> SharedPdfBackend {
@@ -761,6 +779,60 @@ mod tests {
         assert_eq!(restore_count.load(Ordering::Relaxed), 1);
     }
 
+    #[test]
+    fn run_loop_restores_and_saves_the_reading_position() {
+        let tokio_runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("tokio runtime should build");
+        let dir = unique_temp_path("reading_position_loop");
+        fs::create_dir_all(&dir).expect("test dir should be created");
+        let file = dir.join("book.pdf");
+        fs::write(&file, build_pdf(&["one", "two", "three"])).expect("test pdf should be created");
+        let pdf: SharedPdfBackend = Arc::new(PdfDoc::open(&file).expect("pdf should open"));
+        let store = ReadingPositionStore::new(dir.join("positions.json"));
+        let saved = ReadingPosition {
+            page: 2,
+            zoom: 2.0,
+            layout: crate::app::PageLayoutMode::Continuous,
+            ..ReadingPosition::of(&crate::app::AppState::default())
+        };
+        store
+            .save(&file, pdf.doc_id(), saved)
+            .expect("position should be saved");
+        let mut app =
+            App::new_with_config(PresenterKind::RatatuiImage, Config::default()).expect("app init");
+        app.reading_positions = Some(ReadingPositionSession::new(
+            store.clone(),
+            PinnedView {
+                zoom: true,
+                ..PinnedView::default()
+            },
+        ));
+
+        tokio_runtime
+            .block_on(app.run_loop(
+                Arc::clone(&pdf),
+                StubSession::new(80, 24),
+                super::LoopEventMode::Headless,
+                RestoreProbeDriver,
+            ))
+            .expect("loop should finish");
+
+        assert_eq!(app.state.current_page, 2);
+        assert_eq!(app.state.zoom, 1.0);
+        assert_eq!(
+            app.state.page_layout_mode,
+            crate::app::PageLayoutMode::Continuous
+        );
+        let resaved = store
+            .lookup(&file, pdf.doc_id())
+            .expect("position should be saved on exit");
+        assert_eq!((resaved.page, resaved.zoom), (2, 1.0));
+
+        fs::remove_dir_all(&dir).expect("test dir should be removed");
+    }
+
     fn failed_render_result(
         key: RenderedPageKey,
         class: WorkClass,
@@ -774,6 +846,7 @@ mod tests {
                 key.page,
                 crate::error::AppError::invalid_argument("render failed"),
             )),
+            from_l3: false,
             queue_wait: Duration::from_millis(1),
             elapsed: Duration::from_millis(2),
         }
@@ -1375,6 +1448,185 @@ mod tests {
         fs::remove_file(&file).expect("test file should be removed");
     }
 
+    #[test]
+    fn global_mark_in_another_file_opens_it_and_restores_the_mark() {
+        let tokio_runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("tokio runtime should build");
+        let _guard = tokio_runtime.enter();
+        let dir = unique_temp_path("global_mark_open");
+        fs::create_dir_all(&dir).expect("test dir should be created");
+        let first_file = dir.join("first.pdf");
+        let second_file = dir.join("second.pdf");
+        fs::write(&first_file, build_pdf(&["one", "two"])).expect("first pdf should be created");
+        fs::write(&second_file, build_pdf(&["a", "b", "c", "d"]))
+            .expect("second pdf should be created");
+        let store = MarkStore::new(dir.join("marks.json"));
+        store
+            .save(Mark {
+                name: 'A',
+                path: document_key(&second_file),
+                page: 2,
+                zoom: 1.5,
+                pan_x: 4,
+                pan_y: 9,
+            })
+            .expect("mark should be saved");
+
+        let first =
+            Arc::new(PdfDoc::open(&first_file).expect("first pdf should open")) as SharedPdfBackend;
+        let mut document = ActiveDocument::new(Arc::clone(&first));
+        let mut app =
+            App::new_with_config(PresenterKind::RatatuiImage, Config::default()).expect("app init");
+        app.interaction.extensions.host.attach_mark_store(store);
+        app.interaction
+            .prepare_extensions_for_document(Arc::clone(&first));
+        let (loop_event_tx, loop_event_rx, loop_event_runtime) =
+            crate::app::event_bus::EventBusRuntime::spawn_headless();
+        let mut runtime = app
+            .initialize_loop_runtime(
+                Arc::clone(&first),
+                first.page_count(),
+                StubSession::new(80, 24),
+                loop_event_tx,
+                loop_event_rx,
+                loop_event_runtime,
+            )
+            .expect("runtime should initialize");
+
+        app.handle_waited_event(
+            WaitEvent::Event(DomainEvent::Command(CommandRequest::new(
+                Command::GotoMark { name: Some('A') },
+                CommandInvocationSource::Binding,
+            ))),
+            &mut runtime,
+            &mut document,
+        )
+        .expect("mark jump should be handled");
+        assert!(runtime.reload_in_flight);
+
+        let mut recorded = None;
+        while recorded.is_none() {
+            let event = tokio_runtime
+                .block_on(time::timeout(
+                    Duration::from_secs(5),
+                    runtime.loop_event_rx.recv(),
+                ))
+                .expect("loop event should arrive")
+                .expect("loop channel should stay open");
+            if let DomainEvent::App(AppEvent::PageChanged { to, reason, .. }) = &event {
+                recorded = Some((*to, reason.clone()));
+            }
+            app.handle_waited_event(WaitEvent::Event(event), &mut runtime, &mut document)
+                .expect("loop event should be handled");
+        }
+
+        assert_eq!(document.path, second_file);
+        assert_eq!(runtime.page_count, 4);
+        assert_eq!(app.state.current_page, 2);
+        assert!(zoom_eq(app.state.zoom, 1.5));
+        assert_eq!((app.state.pan_x, app.state.pan_y), (4, 9));
+        assert_eq!(recorded, Some((2, NavReason::Mark { name: 'A' })));
+        fs::remove_dir_all(&dir).expect("test dir should be removed");
+    }
+
+    #[test]
+    fn file_watch_follows_a_global_mark_into_another_file() {
+        let tokio_runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("tokio runtime should build");
+        let _guard = tokio_runtime.enter();
+        let dir = unique_temp_path("global_mark_watch");
+        fs::create_dir_all(&dir).expect("test dir should be created");
+        let first_file = dir.join("first.pdf");
+        let second_file = dir.join("second.pdf");
+        fs::write(&first_file, build_pdf(&["one", "two"])).expect("first pdf should be created");
+        fs::write(&second_file, build_pdf(&["a", "b", "c"])).expect("second pdf should be created");
+        let store = MarkStore::new(dir.join("marks.json"));
+        store
+            .save(Mark {
+                name: 'B',
+                path: document_key(&second_file),
+                page: 1,
+                zoom: 1.0,
+                pan_x: 0,
+                pan_y: 0,
+            })
+            .expect("mark should be saved");
+
+        let first =
+            Arc::new(PdfDoc::open(&first_file).expect("first pdf should open")) as SharedPdfBackend;
+        let mut document = ActiveDocument::new(Arc::clone(&first));
+        let mut app =
+            App::new_with_config(PresenterKind::RatatuiImage, Config::default()).expect("app init");
+        app.interaction.extensions.host.attach_mark_store(store);
+        app.interaction
+            .prepare_extensions_for_document(Arc::clone(&first));
+        let (loop_event_tx, loop_event_rx, loop_event_runtime) =
+            crate::app::event_bus::EventBusRuntime::spawn_headless();
+        let mut runtime = app
+            .initialize_loop_runtime(
+                Arc::clone(&first),
+                first.page_count(),
+                StubSession::new(80, 24),
+                loop_event_tx,
+                loop_event_rx,
+                loop_event_runtime,
+            )
+            .expect("runtime should initialize");
+        runtime.loop_event_runtime.start_file_watch(
+            first_file.clone(),
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            runtime.loop_event_tx.clone(),
+        );
+
+        app.handle_waited_event(
+            WaitEvent::Event(DomainEvent::Command(CommandRequest::new(
+                Command::GotoMark { name: Some('B') },
+                CommandInvocationSource::Binding,
+            ))),
+            &mut runtime,
+            &mut document,
+        )
+        .expect("mark jump should be handled");
+        let mut page_changed = false;
+        while !page_changed {
+            let event = tokio_runtime
+                .block_on(time::timeout(
+                    Duration::from_secs(5),
+                    runtime.loop_event_rx.recv(),
+                ))
+                .expect("loop event should arrive")
+                .expect("loop channel should stay open");
+            page_changed = matches!(&event, DomainEvent::App(AppEvent::PageChanged { .. }));
+            app.handle_waited_event(WaitEvent::Event(event), &mut runtime, &mut document)
+                .expect("loop event should be handled");
+        }
+        assert_eq!(document.path, second_file);
+
+        // Let the moved watch take its baseline of the new file before it changes.
+        tokio_runtime.block_on(time::sleep(Duration::from_millis(50)));
+        fs::write(&second_file, build_pdf(&["a", "b", "c", "d", "e"]))
+            .expect("second pdf should change");
+        while runtime.page_count != 5 {
+            let event = tokio_runtime
+                .block_on(time::timeout(
+                    Duration::from_secs(5),
+                    runtime.loop_event_rx.recv(),
+                ))
+                .expect("watch should reload the changed file")
+                .expect("loop channel should stay open");
+            app.handle_waited_event(WaitEvent::Event(event), &mut runtime, &mut document)
+                .expect("loop event should be handled");
+        }
+
+        assert_eq!(document.path, second_file);
+        fs::remove_dir_all(&dir).expect("test dir should be removed");
+    }
+
     #[test]
     fn document_reload_success_clears_previous_reload_notice() {
         let tokio_runtime = Builder::new_current_thread()
@@ -0,0 +1,409 @@
+use std::fs::OpenOptions;
+use std::io::{self, BufWriter, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use image::{ImageError, ImageFormat, RgbaImage};
+
+use crate::backend::{PdfBackend, RgbaFrame, SharedPdfBackend};
+use crate::command::ExportScope;
+use crate::error::{AppError, AppResult};
+use crate::presenter::{PanOffset, Viewport};
+
+use super::core::App;
+use super::frame_ops::crop_frame_region;
+use super::scale::resolved_cell_size_px;
+use super::state::{NoticeAction, PageLayoutMode, notice_action_for_error};
+
+const DEFAULT_EXPORT_DPI: u32 = 150;
+const DEFAULT_EXPORT_PATH: &str = "{stem}-p{page}.png";
+const POINTS_PER_INCH: f32 = 72.0;
+
+/// Rectangle in the pixels of a page rendered at some scale, measured from its top-left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PixelRegion {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// What an export renders, worked out from the view when it was requested.
+enum ExportTarget {
+    /// Whole pages, left to right.
+    Pages(Vec<usize>),
+    /// The part `shown` of `page` as the viewer draws it at `screen` pixels.
+    Region {
+        page: usize,
+        shown: PixelRegion,
+        screen: (u32, u32),
+    },
+}
+
+/// An export that holds everything it needs, so it can render and write away from the UI thread.
+pub(super) struct ImageExport {
+    pdf: SharedPdfBackend,
+    target: ExportTarget,
+    dpi: u32,
+    path_template: String,
+}
+
+impl App {
+    /// Captures what `scope` covers on screen for an export at `dpi` (default 150) to the
+    /// `path` template (default `{stem}-p{page}.png`); [`ImageExport::run`] does the rest.
+    pub(super) fn plan_image_export(
+        &self,
+        pdf: SharedPdfBackend,
+        viewport: Option<Viewport>,
+        scope: ExportScope,
+        dpi: Option<u32>,
+        path: Option<&str>,
+    ) -> AppResult<ImageExport> {
+        let target = self.export_target(pdf.as_ref(), viewport, scope)?;
+        Ok(ImageExport {
+            pdf,
+            target,
+            dpi: dpi.unwrap_or(DEFAULT_EXPORT_DPI),
+            path_template: path.unwrap_or(DEFAULT_EXPORT_PATH).to_string(),
+        })
+    }
+
+    fn export_target(
+        &self,
+        pdf: &dyn PdfBackend,
+        viewport: Option<Viewport>,
+        scope: ExportScope,
+    ) -> AppResult<ExportTarget> {
+        let page = self.state.current_page;
+        match scope {
+            ExportScope::Page => Ok(ExportTarget::Pages(vec![page])),
+            ExportScope::Spread => Ok(self.visible_pages_target(pdf)),
+            ExportScope::View => {
+                let slots = self.state.visible_page_slots(pdf.page_count());
+                match self.state.page_presentation_for_slots(slots) {
+                    PageLayoutMode::Continuous => Err(AppError::unsupported(
+                        "exporting the view needs the single or spread layout",
+                    )),
+                    PageLayoutMode::Single if self.state.crops_to_viewport() => {
+                        let viewport = viewport.ok_or_else(|| {
+                            AppError::unsupported("the viewer has no area to export")
+                        })?;
+                        self.viewport_region_target(pdf, viewport)
+                    }
+                    // Whole pages fit on screen, and a zoomed spread is exported whole.
+                    _ => Ok(self.visible_pages_target(pdf)),
+                }
+            }
+        }
+    }
+
+    /// The pages on screen, in the order the spread shows them.
+    fn visible_pages_target(&self, pdf: &dyn PdfBackend) -> ExportTarget {
+        let slots = self.state.visible_page_slots(pdf.page_count());
+        ExportTarget::Pages(
+            [slots.left_page, slots.right_page]
+                .into_iter()
+                .flatten()
+                .collect(),
+        )
+    }
+
+    /// The part of the current page the viewer shows at the current zoom and pan.
+    fn viewport_region_target(
+        &self,
+        pdf: &dyn PdfBackend,
+        viewport: Viewport,
+    ) -> AppResult<ExportTarget> {
+        let page = self.state.current_page;
+        let (width_pt, height_pt) = pdf.page_dimensions(page)?;
+        let screen_scale = self.compute_current_scale(pdf, page, Some(viewport));
+        let screen = page_size_px(width_pt, height_pt, screen_scale);
+        let shown = shown_region(
+            screen,
+            viewport,
+            self.current_pan(),
+            self.render.presenter.capabilities().cell_px,
+        );
+        Ok(ExportTarget::Region {
+            page,
+            shown,
+            screen,
+        })
+    }
+}
+
+impl ImageExport {
+    /// Renders the export, writes the PNG and describes the outcome as a notice.
+    pub(super) fn run(self) -> NoticeAction {
+        match self.write() {
+            Ok((path, (width, height))) => NoticeAction::info(format!(
+                "exported {width}x{height} image to {}",
+                path.display()
+            )),
+            Err(err) => notice_action_for_error(err),
+        }
+    }
+
+    fn write(self) -> AppResult<(PathBuf, (u32, u32))> {
+        let scale = self.dpi as f32 / POINTS_PER_INCH;
+        let (frame, mut pages) = match self.target {
+            ExportTarget::Pages(pages) => {
+                let frames = pages
+                    .iter()
+                    .map(|page| self.pdf.render_page(*page, scale))
+                    .collect::<AppResult<Vec<_>>>()?;
+                (side_by_side(frames), pages)
+            }
+            ExportTarget::Region {
+                page,
+                shown,
+                screen,
+            } => {
+                let frame = self.pdf.render_page(page, scale)?;
+                let region = rescale_region(shown, screen, (frame.width, frame.height));
+                let frame =
+                    crop_frame_region(&frame, region.x, region.y, region.width, region.height);
+                (frame, vec![page])
+            }
+        };
+        pages.sort_unstable();
+        let path = expand_path_template(&self.path_template, self.pdf.path(), &pages, self.dpi);
+        let size = (frame.width, frame.height);
+        write_png(&path, frame)?;
+        Ok((path, size))
+    }
+}
+
+/// Pixel size of a page rendered at `scale`, rounded the way the backend sizes its frames.
+fn page_size_px(width_pt: f32, height_pt: f32, scale: f32) -> (u32, u32) {
+    (
+        ((width_pt * scale).floor() as u32).max(1),
+        ((height_pt * scale).floor() as u32).max(1),
+    )
+}
+
+/// The part of a page of `size` pixels that `viewport` shows at `pan`, as the presenter crops it.
+fn shown_region(
+    (width, height): (u32, u32),
+    viewport: Viewport,
+    mut pan: PanOffset,
+    cell_px: Option<(u16, u16)>,
+) -> PixelRegion {
+    let (cell_width_px, cell_height_px) = resolved_cell_size_px(cell_px);
+    let target_width = (viewport.width.max(1) as u32).saturating_mul(cell_width_px as u32);
+    let target_height = (viewport.height.max(1) as u32).saturating_mul(cell_height_px as u32);
+    let max_x = width.saturating_sub(target_width);
+    let max_y = height.saturating_sub(target_height);
+    pan.clamp_to_pixel_bounds(max_x, max_y, cell_width_px, cell_height_px);
+    let (x, y) = pan.pixel_origin(max_x, max_y, cell_width_px, cell_height_px);
+    PixelRegion {
+        x,
+        y,
+        width: target_width.min(width.saturating_sub(x)).max(1),
+        height: target_height.min(height.saturating_sub(y)).max(1),
+    }
+}
+
+/// Maps `region` of a page rendered at `from` pixels onto the same part of the page rendered at
+/// `to` pixels, growing it to whole pixels.
+fn rescale_region(region: PixelRegion, from: (u32, u32), to: (u32, u32)) -> PixelRegion {
+    let scale_x = to.0 as f32 / from.0.max(1) as f32;
+    let scale_y = to.1 as f32 / from.1.max(1) as f32;
+    let x = ((region.x as f32 * scale_x).floor() as u32).min(to.0.saturating_sub(1));
+    let y = ((region.y as f32 * scale_y).floor() as u32).min(to.1.saturating_sub(1));
+    let right = (((region.x + region.width) as f32 * scale_x).ceil() as u32).min(to.0);
+    let bottom = (((region.y + region.height) as f32 * scale_y).ceil() as u32).min(to.1);
+    PixelRegion {
+        x,
+        y,
+        width: right.saturating_sub(x).max(1),
+        height: bottom.saturating_sub(y).max(1),
+    }
+}
+
+/// Places `frames` left to right, top aligned; a shorter page leaves the area below it
+/// transparent.
+fn side_by_side(frames: Vec<RgbaFrame>) -> RgbaFrame {
+    if frames.len() == 1 {
+        return frames.into_iter().next().expect("one frame");
+    }
+
+    let width = frames.iter().map(|frame| frame.width).sum::<u32>();
+    let height = frames.iter().map(|frame| frame.height).max().unwrap_or(0);
+    let dst_stride = width as usize * 4;
+    let mut pixels = vec![0; dst_stride * height as usize];
+    let mut left = 0;
+    for frame in &frames {
+        let src_stride = frame.width as usize * 4;
+        for (row, src) in frame.pixels.chunks_exact(src_stride).enumerate() {
+            let start = row * dst_stride + left;
+            pixels[start..start + src_stride].copy_from_slice(src);
+        }
+        left += src_stride;
+    }
+    RgbaFrame {
+        width,
+        height,
+        pixels: pixels.into(),
+    }
+}
+
+/// Fills `{stem}` with the document's file stem, `{page}` with the 1-based page or page range
+/// and `{dpi}` with the export DPI.
+fn expand_path_template(template: &str, pdf_path: &Path, pages: &[usize], dpi: u32) -> PathBuf {
+    let stem = pdf_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "page".to_string());
+    let page = match (pages.first(), pages.last()) {
+        (Some(first), Some(last)) if first != last => format!("{}-{}", first + 1, last + 1),
+        (Some(first), _) => (first + 1).to_string(),
+        _ => String::new(),
+    };
+    PathBuf::from(
+        template
+            .replace("{stem}", &stem)
+            .replace("{page}", &page)
+            .replace("{dpi}", &dpi.to_string()),
+    )
+}
+
+/// Encodes `frame` as a PNG at `path`, refusing to replace an existing file.
+fn write_png(path: &Path, frame: RgbaFrame) -> AppResult<()> {
+    let context = format!("exporting image to {}", path.display());
+    let image = RgbaImage::from_raw(frame.width, frame.height, frame.into_pixels_vec()).ok_or(
+        AppError::invalid_argument("rgba frame pixels length does not match dimensions"),
+    )?;
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|source| {
+            if source.kind() == ErrorKind::AlreadyExists {
+                AppError::invalid_argument(format!("{} already exists", path.display()))
+            } else {
+                AppError::io_with_context(source, context.clone())
+            }
+        })?;
+    image
+        .write_to(&mut BufWriter::new(file), ImageFormat::Png)
+        .map_err(|err| {
+            let source = match err {
+                ImageError::IoError(source) => source,
+                other => io::Error::other(other),
+            };
+            AppError::io_with_context(source, context)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use crate::app::NoticeAction;
+    use crate::backend::test_support::{TextStubPdf, unique_temp_path};
+    use crate::backend::{RgbaFrame, SharedPdfBackend};
+    use crate::error::AppError;
+
+    use super::{
+        ExportTarget, ImageExport, PixelRegion, expand_path_template, page_size_px, rescale_region,
+        side_by_side, write_png,
+    };
+
+    fn solid(width: u32, height: u32, value: u8) -> RgbaFrame {
+        RgbaFrame {
+            width,
+            height,
+            pixels: vec![value; (width * height * 4) as usize].into(),
+        }
+    }
+
+    #[test]
+    fn path_template_names_stem_pages_and_dpi() {
+        let pdf = Path::new("/docs/report.pdf");
+
+        assert_eq!(
+            expand_path_template("{stem}-p{page}.png", pdf, &[2], 150),
+            PathBuf::from("report-p3.png")
+        );
+        assert_eq!(
+            expand_path_template("out/{stem}-{page}@{dpi}.png", pdf, &[2, 3], 300),
+            PathBuf::from("out/report-3-4@300.png")
+        );
+    }
+
+    #[test]
+    fn spread_pages_sit_side_by_side_top_aligned() {
+        let frame = side_by_side(vec![solid(2, 1, 10), solid(1, 2, 20)]);
+
+        assert_eq!((frame.width, frame.height), (3, 2));
+        let column = |x: usize, y: usize| frame.pixels[(y * 3 + x) * 4];
+        assert_eq!(
+            [column(0, 0), column(2, 0), column(0, 1), column(2, 1)],
+            [10, 20, 0, 20]
+        );
+    }
+
+    #[test]
+    fn viewport_region_keeps_its_place_on_the_page() {
+        let screen = page_size_px(100.0, 200.0, 2.0);
+        let export = page_size_px(100.0, 200.0, 5.0);
+        let shown = PixelRegion {
+            x: 40,
+            y: 100,
+            width: 80,
+            height: 50,
+        };
+
+        assert_eq!(
+            rescale_region(shown, screen, export),
+            PixelRegion {
+                x: 100,
+                y: 250,
+                width: 200,
+                height: 125,
+            }
+        );
+    }
+
+    #[test]
+    fn png_is_written_once() {
+        let dir = unique_temp_path("export");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let path = dir.join("page.png");
+
+        write_png(&path, solid(3, 2, 255)).expect("export should succeed");
+        let written = image::open(&path).expect("png should decode");
+        let again = write_png(&path, solid(3, 2, 255));
+        fs::remove_dir_all(&dir).expect("temp dir should be removed");
+
+        assert_eq!((written.width(), written.height()), (3, 2));
+        assert!(
+            matches!(again, Err(AppError::InvalidArgument(message)) if message.contains("already exists"))
+        );
+    }
+
+    #[test]
+    fn export_run_writes_the_pages_and_reports_the_file() {
+        let dir = unique_temp_path("export_run");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let export = ImageExport {
+            pdf: Arc::new(TextStubPdf::blank(4).with_path("/docs/report.pdf")) as SharedPdfBackend,
+            target: ExportTarget::Pages(vec![3, 2]),
+            dpi: 72,
+            path_template: dir.join("{stem}-{page}.png").display().to_string(),
+        };
+
+        let notice = export.run();
+        let written = dir.join("report-3-4.png");
+        let exists = written.exists();
+        fs::remove_dir_all(&dir).expect("temp dir should be removed");
+
+        assert_eq!(
+            notice,
+            NoticeAction::info(format!("exported 2x1 image to {}", written.display()))
+        );
+        assert!(exists);
+    }
+}
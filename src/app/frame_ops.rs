@@ -96,6 +96,21 @@ pub(crate) fn effective_pan_for_viewport(
     effective_pan
 }
 
+/// Top-left pixel of the region `crop_frame_for_viewport` copies for an already clamped `pan`.
+pub(crate) fn viewport_crop_origin(
+    frame: &RgbaFrame,
+    viewport: Viewport,
+    pan: PanOffset,
+    cell_px: Option<(u16, u16)>,
+) -> (u32, u32) {
+    let (cell_width_px, cell_height_px) = resolved_cell_size_px(cell_px);
+    let target_width = (viewport.width.max(1) as u32).saturating_mul(cell_width_px as u32);
+    let target_height = (viewport.height.max(1) as u32).saturating_mul(cell_height_px as u32);
+    let max_x = frame.width.saturating_sub(target_width);
+    let max_y = frame.height.saturating_sub(target_height);
+    pan.pixel_origin(max_x, max_y, cell_width_px, cell_height_px)
+}
+
 pub(crate) fn crop_frame_for_viewport(
     frame: &RgbaFrame,
     viewport: Viewport,
@@ -1,6 +1,8 @@
+use std::path::PathBuf;
+
 use crossterm::event::KeyEvent;
 
-use crate::backend::SharedPdfBackend;
+use crate::backend::{PdfBackend, SharedPdfBackend};
 use crate::command::{
     Command, CommandDispatchContext, CommandDispatchResult, CommandInvocationSource,
     CommandRequest, dispatch_with_view_policy, drain_background_events,
@@ -97,6 +99,11 @@ impl InteractionSubsystem {
         self.extensions.host.on_document_reloaded(state, pdf);
     }
 
+    /// File to open before the global mark `name` can be restored, if it is not `pdf`.
+    pub(crate) fn mark_document(&mut self, pdf: &dyn PdfBackend, name: char) -> Option<PathBuf> {
+        self.extensions.host.mark_document(pdf, name)
+    }
+
     pub(crate) fn sync_extensions_after_page_change(
         &mut self,
         pdf: SharedPdfBackend,
@@ -0,0 +1,98 @@
+use std::process::{Command, Stdio};
+
+use crate::clipboard::copy_to_clipboard;
+use crate::error::AppError;
+
+use super::core::App;
+use super::state::{NoticeAction, notice_action_for_error};
+
+impl App {
+    /// Hands `uri` to the configured `[links] opener`, or copies it when no opener is set or
+    /// the URI is not one the opener may be trusted with.
+    pub(super) fn follow_link_uri(&self, uri: &str) -> NoticeAction {
+        let opener = self
+            .links_policy
+            .opener
+            .as_deref()
+            .filter(|_| is_openable_uri(uri));
+        let Some(opener) = opener else {
+            return match copy_to_clipboard(uri, self.clipboard_policy.command.as_deref()) {
+                Ok(()) => NoticeAction::info(format!("copied link: {uri}")),
+                Err(err) => notice_action_for_error(err),
+            };
+        };
+
+        match spawn_opener(opener, uri) {
+            Ok(()) => NoticeAction::info(format!("opened link: {uri}")),
+            Err(err) => notice_action_for_error(err),
+        }
+    }
+}
+
+fn spawn_opener(opener: &str, uri: &str) -> Result<(), AppError> {
+    let Some(mut command) = opener_command(opener, uri) else {
+        return Err(AppError::invalid_argument("links.opener is empty"));
+    };
+    let mut child = command.spawn().map_err(|source| {
+        AppError::io_with_context(source, format!("running link opener `{opener}`"))
+    })?;
+    // Reap the opener in the background so finished children do not linger as zombies.
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Only web and mail links reach the opener. A PDF can carry any URI, and schemes such as
+/// `file:` or `javascript:`, or a value starting with `-` that the opener would read as a flag,
+/// must not be run on the reader's behalf.
+fn is_openable_uri(uri: &str) -> bool {
+    let Some((scheme, _)) = uri.split_once(':') else {
+        return false;
+    };
+    ["http", "https", "mailto"]
+        .iter()
+        .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+}
+
+fn opener_command(opener: &str, uri: &str) -> Option<Command> {
+    let mut parts = opener.split_whitespace();
+    let program = parts.next()?;
+    let mut command = Command::new(program);
+    command
+        .args(parts)
+        .arg(uri)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    Some(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_openable_uri, opener_command};
+
+    #[test]
+    fn opener_command_appends_uri_after_configured_arguments() {
+        let command = opener_command("firefox --new-tab", "https://example.com")
+            .expect("opener should parse");
+
+        assert_eq!(command.get_program(), "firefox");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec!["--new-tab", "https://example.com"]
+        );
+        assert!(opener_command("  ", "https://example.com").is_none());
+    }
+
+    #[test]
+    fn only_web_and_mail_uris_are_openable() {
+        assert!(is_openable_uri("https://example.com"));
+        assert!(is_openable_uri("HTTP://example.com"));
+        assert!(is_openable_uri("mailto:someone@example.com"));
+
+        assert!(!is_openable_uri("file:///etc/passwd"));
+        assert!(!is_openable_uri("javascript:alert(1)"));
+        assert!(!is_openable_uri("--help"));
+        assert!(!is_openable_uri("-x:https://example.com"));
+        assert!(!is_openable_uri("example.com"));
+    }
+}
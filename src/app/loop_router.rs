@@ -2,7 +2,10 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::backend::SharedPdfBackend;
-use crate::command::{Command, CommandLifecycleEffect, CommandOutcome, CommandRequest, PanAmount};
+use crate::command::{
+    Command, CommandInvocationSource, CommandLifecycleEffect, CommandOutcome, CommandRequest,
+    PanAmount,
+};
 use crate::error::{AppError, AppResult};
 use crate::event::{
     AppEvent, DocumentReloadReason, DocumentReloadRequest, DocumentReloadResult, DomainEvent,
@@ -18,7 +21,7 @@ use super::loop_effects::LoopEffects;
 use super::loop_runtime::{
     ActiveDocument, LoopControl, LoopRuntime, WaitEvent, terminate_process_now,
 };
-use super::state::{Mode, notice_action_for_error};
+use super::state::{Mode, NoticeAction, notice_action_for_error};
 use super::terminal_session::{TerminalSession, TerminalSurface};
 
 const FILE_RELOAD_RETRY_DELAYS: [Duration; 5] = [
@@ -156,6 +159,15 @@ impl App {
             WaitEvent::Event(DomainEvent::DocumentReloaded(result)) => {
                 self.handle_document_reload_result(runtime, document, result)?;
             }
+            WaitEvent::Event(DomainEvent::ImageExported(notice)) => {
+                self.state.apply_notice_action(notice);
+                self.request_redraw(runtime, RedrawReason::AppEvent);
+            }
+            WaitEvent::Event(DomainEvent::PagesYanked { notice, osc52_text }) => {
+                let notice = self.finish_pages_yank(notice, osc52_text);
+                self.state.apply_notice_action(notice);
+                self.request_redraw(runtime, RedrawReason::AppEvent);
+            }
             WaitEvent::Event(DomainEvent::Wake) => {}
             WaitEvent::Closed => return Ok(LoopControl::Break),
         }
@@ -234,6 +246,31 @@ impl App {
         i32::from((viewport.width.min(viewport.height) / 5).max(1))
     }
 
+    /// Shows the notice of a command the loop ran itself and reports it as executed.
+    fn finish_intercepted_command<S>(
+        &mut self,
+        runtime: &mut LoopRuntime<S>,
+        request: &CommandRequest,
+        notice: NoticeAction,
+    ) -> AppResult<LoopControl>
+    where
+        S: TerminalSession,
+    {
+        self.state.apply_notice_action(notice);
+        self.request_redraw(runtime, RedrawReason::Command);
+        if runtime
+            .loop_event_tx
+            .send(DomainEvent::App(AppEvent::CommandExecuted {
+                id: request.command.command_id(),
+                outcome: CommandOutcome::Applied,
+            }))
+            .is_err()
+        {
+            return Ok(LoopControl::Break);
+        }
+        Ok(LoopControl::Continue)
+    }
+
     fn handle_command_event<S>(
         &mut self,
         request: CommandRequest,
@@ -262,6 +299,67 @@ impl App {
             }
             return Ok(LoopControl::Continue);
         }
+        if let Command::GotoMark { name: Some(name) } = request.command
+            && let Some(path) = self.interaction.mark_document(document.pdf.as_ref(), name)
+        {
+            self.request_document_reload(
+                runtime,
+                document,
+                DocumentReloadRequest::new(DocumentReloadReason::Mark { name }).with_path(path),
+            );
+            return self.finish_intercepted_command(runtime, &request, NoticeAction::Clear);
+        }
+        if let Command::OpenLinkUri { uri } = &request.command {
+            let notice = self.follow_link_uri(uri);
+            return self.finish_intercepted_command(runtime, &request, notice);
+        }
+        let yank = match request.command {
+            Command::YankPage => Some(Ok(self.plan_visible_pages_yank(Arc::clone(&document.pdf)))),
+            Command::YankPages { first, last } => {
+                Some(self.plan_page_range_yank(Arc::clone(&document.pdf), first..=last))
+            }
+            _ => None,
+        };
+        if let Some(yank) = yank {
+            let notice = match yank {
+                Ok(yank) => {
+                    runtime
+                        .loop_event_runtime
+                        .start_pages_yank(yank, runtime.loop_event_tx.clone());
+                    NoticeAction::info("copying page text")
+                }
+                Err(err) => notice_action_for_error(err),
+            };
+            return self.finish_intercepted_command(runtime, &request, notice);
+        }
+        if let Command::ExportImage { scope, dpi, path } = &request.command {
+            let viewport =
+                Self::current_viewport(&runtime.session, self.state.debug_status_visible);
+            let notice = match self.plan_image_export(
+                Arc::clone(&document.pdf),
+                viewport,
+                *scope,
+                *dpi,
+                path.as_deref(),
+            ) {
+                Ok(export) => {
+                    runtime
+                        .loop_event_runtime
+                        .start_image_export(export, runtime.loop_event_tx.clone());
+                    NoticeAction::info(format!("exporting {} image", scope.as_str()))
+                }
+                Err(err) => notice_action_for_error(err),
+            };
+            return self.finish_intercepted_command(runtime, &request, notice);
+        }
+        if matches!(request.command, Command::VisualYank) {
+            let notice = self.yank_visual_selection();
+            return self.finish_intercepted_command(runtime, &request, notice);
+        }
+        if matches!(request.command, Command::ForgetPosition) {
+            let notice = self.forget_reading_position(document.pdf.as_ref());
+            return self.finish_intercepted_command(runtime, &request, notice);
+        }
         let state_before_command = self.state.clone();
         let previous_visible_pages = self
             .state
@@ -342,9 +440,14 @@ impl App {
             return;
         }
 
+        let (path, password) = match &request.path {
+            Some(path) => (path.clone(), None),
+            None => (document.path.clone(), document.password.clone()),
+        };
         runtime.reload_in_flight = true;
         runtime.loop_event_runtime.start_document_reload(
-            document.path.clone(),
+            path,
+            password,
             request,
             runtime.loop_event_tx.clone(),
         );
@@ -366,14 +469,24 @@ impl App {
 
         match reload.result {
             Ok(pdf) => {
-                if let Err(err) = self.apply_document_reload(runtime, document, pdf) {
+                if let DocumentReloadReason::Mark { name } = reload.reason {
+                    if let Err(err) = self.open_mark_document(runtime, document, pdf, name) {
+                        self.state
+                            .set_error_notice(format!("Could not open mark {name}: {err}"));
+                        self.request_redraw(runtime, RedrawReason::AppEvent);
+                    }
+                } else if let Err(err) = self.apply_document_reload(runtime, document, pdf) {
                     self.state
                         .set_error_notice(format!("Could not reload document: {err}"));
                     self.request_redraw(runtime, RedrawReason::AppEvent);
                 }
             }
             Err(message) => {
-                if matches!(reload.reason, DocumentReloadReason::Manual) {
+                if let DocumentReloadReason::Mark { name } = reload.reason {
+                    self.state
+                        .set_error_notice(format!("Could not open mark {name}: {message}"));
+                    self.request_redraw(runtime, RedrawReason::AppEvent);
+                } else if matches!(reload.reason, DocumentReloadReason::Manual) {
                     self.state
                         .set_error_notice(format!("Could not reload document: {message}"));
                     self.request_redraw(runtime, RedrawReason::AppEvent);
@@ -431,6 +544,35 @@ impl App {
         true
     }
 
+    /// Switches to the file of the global mark `name`, starting from its first page, and then
+    /// jumps to the mark as if it were in that file all along.
+    fn open_mark_document<S>(
+        &mut self,
+        runtime: &mut LoopRuntime<S>,
+        document: &mut ActiveDocument,
+        pdf: SharedPdfBackend,
+        name: char,
+    ) -> AppResult<()>
+    where
+        S: TerminalSurface,
+    {
+        if pdf.page_count() == 0 {
+            return Err(AppError::invalid_argument("pdf has no pages"));
+        }
+        self.save_reading_position(document.pdf.as_ref());
+        self.state.current_page = 0;
+        self.state.pan_x = 0;
+        self.state.pan_y = 0;
+        self.apply_document_reload(runtime, document, pdf)?;
+        let _ = runtime
+            .loop_event_tx
+            .send(DomainEvent::Command(CommandRequest::new(
+                Command::GotoMark { name: Some(name) },
+                CommandInvocationSource::Binding,
+            )));
+        Ok(())
+    }
+
     fn apply_document_reload<S>(
         &mut self,
         runtime: &mut LoopRuntime<S>,
@@ -446,18 +588,27 @@ impl App {
         let old_doc_id = document.pdf.doc_id();
         runtime.reload_retry_attempts = 0;
         document.replace(Arc::clone(&pdf));
+        runtime
+            .loop_event_runtime
+            .follow_file_watch(&document.path, runtime.loop_event_tx.clone());
         runtime.page_count = pdf.page_count();
         self.state.current_page = self.state.current_page.min(runtime.page_count - 1);
         self.state.normalize_current_page(runtime.page_count);
+        self.state.page_labels = pdf.page_labels();
         self.state.clear_reload_notice();
         self.state.clear_render_notice();
 
         self.render.runtime.l1_cache.remove_doc(old_doc_id);
+        self.render.runtime.attach_document(pdf.as_ref());
         self.render.presenter.reset_terminal_state();
         self.render.viewer_has_image = false;
         self.render.image_occluded_last_frame = false;
-        runtime.render_worker =
-            RenderWorker::spawn(Arc::clone(&pdf), self.render_policy.worker_threads);
+        self.render.text_view.clear();
+        runtime.render_worker = RenderWorker::spawn(
+            Arc::clone(&pdf),
+            self.render_policy.worker_threads,
+            self.render.runtime.worker_l3_cache(),
+        );
 
         let viewport = Self::current_viewport(&runtime.session, self.state.debug_status_visible);
         let visible_pages = self.state.visible_page_slots(runtime.page_count);
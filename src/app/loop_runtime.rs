@@ -41,16 +41,23 @@ pub(super) struct LoopRuntime<S> {
 pub(super) struct ActiveDocument {
     pub(super) pdf: SharedPdfBackend,
     pub(super) path: PathBuf,
+    pub(super) password: Option<String>,
 }
 
 impl ActiveDocument {
     pub(super) fn new(pdf: SharedPdfBackend) -> Self {
         let path = pdf.path().to_path_buf();
-        Self { pdf, path }
+        let password = pdf.password().map(str::to_string);
+        Self {
+            pdf,
+            path,
+            password,
+        }
     }
 
     pub(super) fn replace(&mut self, pdf: SharedPdfBackend) {
         self.path = pdf.path().to_path_buf();
+        self.password = pdf.password().map(str::to_string);
         self.pdf = pdf;
     }
 }
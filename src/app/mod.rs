@@ -1,30 +1,43 @@
 mod actors;
 mod constants;
+mod continuous;
 mod core;
 mod event_bus;
 mod event_loop;
+mod export_ops;
 mod frame_ops;
 mod input_ops;
+mod link_ops;
 mod loop_driver;
 mod loop_effects;
 mod loop_router;
 mod loop_runtime;
 mod nav;
+mod reading_position;
 mod render_ops;
 mod runtime;
 pub(crate) mod scale;
+mod selection_ops;
 mod state;
 pub(crate) mod terminal_session;
+mod unlock;
 mod view_ops;
+mod yank_ops;
 
 #[cfg(test)]
 mod tests;
 
-pub use core::{App, AppBuilder, RunOptions};
+pub use crate::render::disk_cache::ClearedRenderCache;
+pub use crate::search::text_index::ClearedTextIndex;
+pub use core::{
+    App, AppBuilder, RunOptions, clear_render_cache, clear_text_index, render_cache_dir,
+    text_index_dir,
+};
 pub use runtime::RenderRuntime;
 pub use state::{
-    AppState, CacheHandle, CacheRefs, Mode, Notice, NoticeAction, NoticeLevel, PageLayoutMode,
-    PaletteRequest, SpreadCoverPolicy, SpreadDirection, VisiblePageSlots, notice_action_for_error,
+    AppState, CacheHandle, CacheRefs, FitMode, Mode, Notice, NoticeAction, NoticeLevel,
+    PageLayoutMode, PageViewMode, PaletteRequest, SpreadCoverPolicy, SpreadDirection,
+    VisiblePageSlots, notice_action_for_error,
 };
 
 pub(crate) use loop_driver::{
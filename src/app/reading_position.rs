@@ -0,0 +1,480 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::PdfBackend;
+use crate::config::{ViewOptions, default_state_dir};
+use crate::error::{AppError, AppResult};
+
+use super::core::App;
+use super::scale::{ZOOM_MAX, ZOOM_MIN};
+use super::state::{
+    AppState, FitMode, NoticeAction, PageLayoutMode, SpreadCoverPolicy, SpreadDirection,
+    notice_action_for_error,
+};
+
+/// Name of the reading position file below the pvf state directory.
+pub const READING_POSITIONS_FILE: &str = "positions.json";
+/// Documents remembered at most; the ones closed longest ago are dropped first.
+const MAX_SAVED_POSITIONS: usize = 500;
+
+/// Where a document was left, restored when it is opened again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingPosition {
+    pub page: usize,
+    /// What the page was scaled to before zoom applied; `None` for positions saved before the
+    /// fit was remembered.
+    pub fit: Option<FitMode>,
+    pub zoom: f32,
+    pub pan_x: i32,
+    pub pan_y: i32,
+    pub layout: PageLayoutMode,
+    pub spread_direction: SpreadDirection,
+    pub spread_cover: SpreadCoverPolicy,
+}
+
+impl ReadingPosition {
+    pub fn of(state: &AppState) -> Self {
+        Self {
+            page: state.current_page,
+            fit: Some(state.fit_mode),
+            zoom: state.zoom,
+            pan_x: state.pan_x,
+            pan_y: state.pan_y,
+            layout: state.page_layout_mode,
+            spread_direction: state.spread_direction,
+            spread_cover: state.spread_cover_policy,
+        }
+    }
+
+    /// Moves `state` to this position, leaving pinned settings alone. Zoom is only restored
+    /// on top of the fit it was chosen for, and pan together with the page and zoom it was
+    /// measured at.
+    pub fn apply(self, state: &mut AppState, pinned: PinnedView) {
+        if !pinned.page {
+            state.current_page = self.page;
+        }
+        if !pinned.fit
+            && let Some(fit) = self.fit
+        {
+            state.fit_mode = fit;
+        }
+        let same_fit = self.fit.is_none_or(|fit| fit == state.fit_mode);
+        let zoom_restored = !pinned.zoom && same_fit && self.zoom.is_finite();
+        if zoom_restored {
+            state.zoom = self.zoom.clamp(ZOOM_MIN, ZOOM_MAX);
+        }
+        if !pinned.page && zoom_restored {
+            state.pan_x = self.pan_x;
+            state.pan_y = self.pan_y;
+        }
+        if !pinned.layout {
+            state.page_layout_mode = self.layout;
+        }
+        state.spread_direction = self.spread_direction;
+        state.spread_cover_policy = self.spread_cover;
+    }
+}
+
+/// View settings given explicitly on the command line, which a restored position must not
+/// override.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PinnedView {
+    pub page: bool,
+    pub fit: bool,
+    pub zoom: bool,
+    pub layout: bool,
+}
+
+impl PinnedView {
+    pub fn from_options(explicit: &ViewOptions) -> Self {
+        Self {
+            page: explicit.initial_page.is_some(),
+            fit: explicit.fit.is_some(),
+            zoom: explicit.initial_zoom.is_some(),
+            layout: explicit.initial_layout.is_some(),
+        }
+    }
+}
+
+/// Reading positions of recently closed documents, kept in one small JSON file.
+///
+/// Documents are matched by canonical path first and by `doc_id` when the file was moved. Every
+/// operation rereads the file and rewrites it whole, so pvf processes closing different documents
+/// keep each other's positions.
+#[derive(Debug, Clone)]
+pub struct ReadingPositionStore {
+    file: PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SavedPositions {
+    documents: Vec<SavedPosition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SavedPosition {
+    path: PathBuf,
+    doc_id: u64,
+    page: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fit: Option<String>,
+    zoom: f32,
+    pan_x: i32,
+    pan_y: i32,
+    layout: String,
+    spread_direction: String,
+    spread_cover: String,
+}
+
+impl SavedPosition {
+    fn new(path: PathBuf, doc_id: u64, position: ReadingPosition) -> Self {
+        Self {
+            path,
+            doc_id,
+            page: position.page,
+            fit: position.fit.map(|fit| fit.id().to_string()),
+            zoom: position.zoom,
+            pan_x: position.pan_x,
+            pan_y: position.pan_y,
+            layout: position.layout.id().to_string(),
+            spread_direction: position.spread_direction.id().to_string(),
+            spread_cover: position.spread_cover.id().to_string(),
+        }
+    }
+
+    fn position(&self) -> Option<ReadingPosition> {
+        let fit = match &self.fit {
+            Some(id) => Some(
+                [
+                    FitMode::Page,
+                    FitMode::Width,
+                    FitMode::Height,
+                    FitMode::Actual,
+                ]
+                .into_iter()
+                .find(|fit| fit.id() == id)?,
+            ),
+            None => None,
+        };
+        Some(ReadingPosition {
+            page: self.page,
+            fit,
+            zoom: self.zoom,
+            pan_x: self.pan_x,
+            pan_y: self.pan_y,
+            layout: [
+                PageLayoutMode::Single,
+                PageLayoutMode::Spread,
+                PageLayoutMode::Continuous,
+            ]
+            .into_iter()
+            .find(|mode| mode.id() == self.layout)?,
+            spread_direction: [SpreadDirection::Ltr, SpreadDirection::Rtl]
+                .into_iter()
+                .find(|direction| direction.id() == self.spread_direction)?,
+            spread_cover: [SpreadCoverPolicy::Paired, SpreadCoverPolicy::Cover]
+                .into_iter()
+                .find(|policy| policy.id() == self.spread_cover)?,
+        })
+    }
+}
+
+impl ReadingPositionStore {
+    pub fn new(file: impl Into<PathBuf>) -> Self {
+        Self { file: file.into() }
+    }
+
+    pub fn lookup(&self, path: &Path, doc_id: u64) -> Option<ReadingPosition> {
+        let path = document_key(path);
+        let saved = self.read();
+        saved
+            .documents
+            .iter()
+            .find(|entry| entry.path == path)
+            .or_else(|| saved.documents.iter().find(|entry| entry.doc_id == doc_id))
+            .and_then(SavedPosition::position)
+    }
+
+    pub fn save(&self, path: &Path, doc_id: u64, position: ReadingPosition) -> AppResult<()> {
+        let path = document_key(path);
+        let mut saved = self.read();
+        // A copy of the document elsewhere keeps its own position; a moved one is replaced.
+        saved
+            .documents
+            .retain(|entry| entry.path != path && (entry.doc_id != doc_id || entry.path.exists()));
+        saved
+            .documents
+            .insert(0, SavedPosition::new(path, doc_id, position));
+        saved.documents.truncate(MAX_SAVED_POSITIONS);
+        self.write(&saved)
+    }
+
+    /// Drops the saved position of the document; returns whether there was one.
+    ///
+    /// Like `save`, this leaves the positions of copies that still exist elsewhere alone.
+    pub fn forget(&self, path: &Path, doc_id: u64) -> AppResult<bool> {
+        let path = document_key(path);
+        let mut saved = self.read();
+        let before = saved.documents.len();
+        saved
+            .documents
+            .retain(|entry| entry.path != path && (entry.doc_id != doc_id || entry.path.exists()));
+        if saved.documents.len() == before {
+            return Ok(false);
+        }
+        self.write(&saved)?;
+        Ok(true)
+    }
+
+    /// Saved positions; a missing or unreadable file counts as empty.
+    fn read(&self) -> SavedPositions {
+        fs::read(&self.file)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    fn write(&self, saved: &SavedPositions) -> AppResult<()> {
+        let context = || format!("writing reading positions {}", self.file.display());
+        let encoded = serde_json::to_vec(saved)
+            .map_err(|source| AppError::io_with_context(io::Error::other(source), context()))?;
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|source| AppError::io_with_context(source, context()))?;
+        }
+        let partial = self.file.with_extension("partial");
+        let written = File::create(&partial)
+            .and_then(|mut file| file.write_all(&encoded))
+            .and_then(|()| fs::rename(&partial, &self.file));
+        if let Err(source) = written {
+            let _ = fs::remove_file(&partial);
+            return Err(AppError::io_with_context(source, context()));
+        }
+        Ok(())
+    }
+}
+
+fn document_key(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Reading position handling of one viewer session.
+#[derive(Debug, Clone)]
+pub(crate) struct ReadingPositionSession {
+    store: ReadingPositionStore,
+    pinned: PinnedView,
+    /// Set by `forget-position` so that closing the document does not save it again.
+    forgotten: bool,
+}
+
+impl ReadingPositionSession {
+    pub(crate) fn new(store: ReadingPositionStore, pinned: PinnedView) -> Self {
+        Self {
+            store,
+            pinned,
+            forgotten: false,
+        }
+    }
+}
+
+/// File holding the saved reading positions.
+pub fn reading_positions_path() -> Option<PathBuf> {
+    default_state_dir().map(|dir| dir.join(READING_POSITIONS_FILE))
+}
+
+impl App {
+    /// Reopens documents where they were left and saves the position on exit, unless
+    /// `view.remember_position` is off. Settings present in `explicit` win over saved ones.
+    pub fn remember_reading_positions(&mut self, explicit: &ViewOptions) {
+        if !self.view_policy.remember_position {
+            return;
+        }
+        let Some(file) = reading_positions_path() else {
+            return;
+        };
+        self.reading_positions = Some(ReadingPositionSession::new(
+            ReadingPositionStore::new(file),
+            PinnedView::from_options(explicit),
+        ));
+    }
+
+    pub(super) fn restore_reading_position(&mut self, pdf: &dyn PdfBackend) {
+        let Some(session) = &self.reading_positions else {
+            return;
+        };
+        if let Some(position) = session.store.lookup(pdf.path(), pdf.doc_id()) {
+            position.apply(&mut self.state, session.pinned);
+        }
+    }
+
+    pub(super) fn save_reading_position(&self, pdf: &dyn PdfBackend) {
+        let Some(session) = &self.reading_positions else {
+            return;
+        };
+        if session.forgotten {
+            return;
+        }
+        // Losing the position only costs the reader a jump, so it never fails the exit.
+        let _ = session
+            .store
+            .save(pdf.path(), pdf.doc_id(), ReadingPosition::of(&self.state));
+    }
+
+    /// Handles `forget-position`: drops the saved position and stops saving this session's.
+    pub(super) fn forget_reading_position(&mut self, pdf: &dyn PdfBackend) -> NoticeAction {
+        let Some(session) = &mut self.reading_positions else {
+            return NoticeAction::warning("reading positions are disabled");
+        };
+        session.forgotten = true;
+        match session.store.forget(pdf.path(), pdf.doc_id()) {
+            Ok(true) => NoticeAction::info("forgot saved position"),
+            Ok(false) => NoticeAction::info("no saved position"),
+            Err(err) => notice_action_for_error(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{PinnedView, ReadingPosition, ReadingPositionStore};
+    use crate::app::{AppState, FitMode, PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+    use crate::backend::PdfDoc;
+    use crate::backend::test_support::{build_pdf, unique_temp_path};
+
+    fn position(page: usize) -> ReadingPosition {
+        ReadingPosition {
+            page,
+            fit: Some(FitMode::Width),
+            zoom: 1.5,
+            pan_x: 12,
+            pan_y: -40,
+            layout: PageLayoutMode::Spread,
+            spread_direction: SpreadDirection::Rtl,
+            spread_cover: SpreadCoverPolicy::Cover,
+        }
+    }
+
+    #[test]
+    fn saved_positions_are_found_by_path_then_by_doc_id() {
+        let dir = unique_temp_path("reading_positions");
+        let store = ReadingPositionStore::new(dir.join("positions.json"));
+        let book = dir.join("book.pdf");
+        let moved = dir.join("moved.pdf");
+
+        assert_eq!(store.lookup(&book, 7), None);
+        store.save(&book, 7, position(41)).expect("save");
+        store
+            .save(&dir.join("other.pdf"), 8, position(3))
+            .expect("save");
+
+        assert_eq!(store.lookup(&book, 99), Some(position(41)));
+        assert_eq!(store.lookup(&moved, 7), Some(position(41)));
+        assert_eq!(store.lookup(&moved, 99), None);
+
+        assert!(store.forget(&book, 7).expect("forget"));
+        assert!(!store.forget(&book, 7).expect("forget again"));
+        assert_eq!(store.lookup(&book, 7), None);
+        assert_eq!(store.lookup(&dir.join("other.pdf"), 8), Some(position(3)));
+
+        fs::remove_dir_all(&dir).expect("state dir should be removed");
+    }
+
+    #[test]
+    fn a_copy_at_another_path_finds_the_saved_position_by_content() {
+        let dir = unique_temp_path("reading_positions_copy");
+        fs::create_dir_all(&dir).expect("state dir");
+        let store = ReadingPositionStore::new(dir.join("positions.json"));
+        let book = dir.join("book.pdf");
+        let copy = dir.join("copy.pdf");
+        let bytes = build_pdf(&["one", "two"]);
+        fs::write(&book, &bytes).expect("book pdf");
+        fs::write(&copy, &bytes).expect("copied pdf");
+        let book_doc = PdfDoc::open(&book).expect("book should open");
+        let copy_doc = PdfDoc::open(&copy).expect("copy should open");
+
+        store
+            .save(&book, book_doc.doc_id(), position(1))
+            .expect("save");
+        assert_eq!(store.lookup(&copy, copy_doc.doc_id()), Some(position(1)));
+
+        store
+            .save(&copy, copy_doc.doc_id(), position(0))
+            .expect("save copy");
+        assert!(store.forget(&copy, copy_doc.doc_id()).expect("forget copy"));
+        assert_eq!(store.lookup(&book, book_doc.doc_id()), Some(position(1)));
+
+        fs::remove_dir_all(&dir).expect("state dir should be removed");
+    }
+
+    #[test]
+    fn damaged_position_file_counts_as_empty_and_is_replaced() {
+        let dir = unique_temp_path("reading_positions_damaged");
+        fs::create_dir_all(&dir).expect("state dir");
+        let file = dir.join("positions.json");
+        fs::write(&file, b"{not json").expect("damaged file");
+        let store = ReadingPositionStore::new(&file);
+        let book = dir.join("book.pdf");
+
+        assert_eq!(store.lookup(&book, 7), None);
+        store.save(&book, 7, position(2)).expect("save");
+        assert_eq!(store.lookup(&book, 7), Some(position(2)));
+
+        fs::remove_dir_all(&dir).expect("state dir should be removed");
+    }
+
+    #[test]
+    fn pinned_settings_are_not_restored() {
+        let mut state = AppState {
+            current_page: 4,
+            zoom: 2.0,
+            ..AppState::default()
+        };
+
+        position(41).apply(
+            &mut state,
+            PinnedView {
+                page: true,
+                fit: false,
+                zoom: false,
+                layout: true,
+            },
+        );
+
+        assert_eq!(state.current_page, 4);
+        assert_eq!(state.fit_mode, FitMode::Width);
+        assert_eq!(state.zoom, 1.5);
+        assert_eq!((state.pan_x, state.pan_y), (0, 0));
+        assert_eq!(state.page_layout_mode, PageLayoutMode::Single);
+        assert_eq!(state.spread_direction, SpreadDirection::Rtl);
+
+        position(41).apply(&mut state, PinnedView::default());
+        assert_eq!(ReadingPosition::of(&state), position(41));
+    }
+
+    #[test]
+    fn zoom_is_only_restored_on_the_fit_it_was_chosen_for() {
+        let mut state = AppState::default();
+        let pinned_fit = PinnedView {
+            fit: true,
+            ..PinnedView::default()
+        };
+
+        position(41).apply(&mut state, pinned_fit);
+
+        assert_eq!(state.current_page, 41);
+        assert_eq!(state.fit_mode, FitMode::Page);
+        assert_eq!((state.zoom, state.pan_x, state.pan_y), (1.0, 0, 0));
+
+        let unknown_fit = ReadingPosition {
+            fit: None,
+            ..position(7)
+        };
+        unknown_fit.apply(&mut state, pinned_fit);
+        assert_eq!((state.current_page, state.zoom), (7, 1.5));
+    }
+}
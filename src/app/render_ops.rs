@@ -6,71 +6,64 @@ use crate::render::worker::{RenderWorker, RenderWorkerResult};
 use crate::work::WorkClass;
 
 use super::actors::{RenderActor, RenderNavSyncParts};
+use super::continuous;
 use super::core::RenderSubsystem;
 use super::frame_ops::{encode_work_class_for_completed_render, prepare_presenter_frame};
 use super::runtime::PrefetchEncodeRequest;
 use super::scale::{scale_eq, zoom_eq};
-use super::state::{AppState, VisiblePageSlots};
+use super::state::{AppState, PageLayoutMode, VisiblePageSlots};
 use super::view_ops::{InitialPreviewPlan, compute_initial_preview_plan};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(super) struct RequiredRenderPages {
-    pages: [usize; 2],
-    keys: [RenderedPageKey; 2],
-    len: usize,
+    pages: Vec<usize>,
+    keys: Vec<RenderedPageKey>,
 }
 
 impl RequiredRenderPages {
     pub(super) fn new(anchor_page: usize, anchor_key: RenderedPageKey) -> Self {
         Self {
-            pages: [anchor_page, 0],
-            keys: [anchor_key, anchor_key],
-            len: 1,
+            pages: vec![anchor_page],
+            keys: vec![anchor_key],
         }
     }
 
     pub(super) fn push_trailing(&mut self, page: usize, key: RenderedPageKey) {
-        debug_assert!(self.len < self.pages.len());
-        self.pages[self.len] = page;
-        self.keys[self.len] = key;
-        self.len += 1;
+        self.pages.push(page);
+        self.keys.push(key);
     }
 
     pub(super) fn keys(&self) -> &[RenderedPageKey] {
-        &self.keys[..self.len]
+        &self.keys
     }
 
     fn iter(&self) -> impl Iterator<Item = (usize, usize, RenderedPageKey)> + '_ {
-        (0..self.len).map(|idx| (idx, self.pages[idx], self.keys[idx]))
+        self.pages
+            .iter()
+            .zip(&self.keys)
+            .enumerate()
+            .map(|(idx, (page, key))| (idx, *page, *key))
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(super) struct CurrentInterestKeys {
-    keys: [RenderedPageKey; 4],
-    len: usize,
+    keys: Vec<RenderedPageKey>,
 }
 
 impl CurrentInterestKeys {
     pub(super) fn from_required(required: &RequiredRenderPages) -> Self {
-        let mut keys = [required.keys[0]; 4];
-        keys[..required.len].copy_from_slice(required.keys());
         Self {
-            keys,
-            len: required.len,
+            keys: required.keys.clone(),
         }
     }
 
     pub(super) fn extend(&mut self, keys: impl IntoIterator<Item = RenderedPageKey>) {
-        for key in keys {
-            debug_assert!(self.len < self.keys.len());
-            self.keys[self.len] = key;
-            self.len += 1;
-        }
+        self.keys.extend(keys);
     }
 
     pub(super) fn as_slice(&self) -> &[RenderedPageKey] {
-        &self.keys[..self.len]
+        &self.keys
     }
 }
 
@@ -82,7 +75,7 @@ pub(super) struct CurrentTaskContext {
     pub(super) preview_tasks: Vec<RenderTask>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(super) struct PrefetchDispatchContext {
     pub(super) required: RequiredRenderPages,
     pub(super) current_cached: bool,
@@ -143,12 +136,16 @@ impl CurrentRenderView {
         plan: PrefetchDispatchPlan,
     ) -> PrefetchDispatchContext {
         PrefetchDispatchContext {
-            required: self.required,
+            required: self.required.clone(),
             current_cached: self.current_cached,
             overlay_stamp: plan.overlay_stamp,
-            prefetch_viewport: plan.prefetch_viewport,
+            // Continuous pages are encoded as per-frame crops, so a whole-viewport encode of a
+            // prefetched page would never be shown.
+            prefetch_viewport: plan
+                .prefetch_viewport
+                .filter(|_| state.page_layout_mode != PageLayoutMode::Continuous),
             base_pan: plan.base_pan,
-            enable_crop: state.zoom > 1.0,
+            enable_crop: state.crops_to_viewport(),
             interactive: plan.interactive,
             dispatch_budget: plan.dispatch_budget,
         }
@@ -177,6 +174,7 @@ impl RenderSubsystem {
         pdf: &dyn PdfBackend,
         visible_pages: VisiblePageSlots,
         current_scale: f32,
+        viewport: Option<Viewport>,
         is_cold_start: bool,
     ) -> CurrentRenderView {
         let mut required = RequiredRenderPages::new(
@@ -189,6 +187,30 @@ impl RenderSubsystem {
                 RenderedPageKey::new(pdf.doc_id(), trailing_page, current_scale),
             );
         }
+        if let Some(viewport) = viewport
+            && state.page_layout_mode == PageLayoutMode::Continuous
+        {
+            let layout = continuous::layout_for_document(
+                pdf,
+                visible_pages.anchor_page,
+                PanOffset {
+                    cells_x: state.pan_x,
+                    cells_y: state.pan_y,
+                },
+                viewport,
+                self.presenter.capabilities().cell_px,
+                current_scale,
+            );
+            for page in layout
+                .pages()
+                .filter(|page| *page != visible_pages.anchor_page)
+            {
+                required.push_trailing(
+                    page,
+                    RenderedPageKey::new(pdf.doc_id(), page, current_scale),
+                );
+            }
+        }
         let current_cached = required
             .keys()
             .iter()
@@ -267,12 +289,21 @@ impl RenderSubsystem {
                         let _ = err;
                     }
                 }
-                self.runtime.ingest_rendered_frame(
-                    completed.key,
-                    frame,
-                    completed.elapsed,
-                    completed.class == WorkClass::CriticalCurrent,
-                );
+                let allow_single_oversize = completed.class == WorkClass::CriticalCurrent;
+                if completed.from_l3 {
+                    self.runtime.ingest_persisted_frame(
+                        completed.key,
+                        frame,
+                        allow_single_oversize,
+                    );
+                } else {
+                    self.runtime.ingest_rendered_frame(
+                        completed.key,
+                        frame,
+                        completed.elapsed,
+                        allow_single_oversize,
+                    );
+                }
                 current_keys.contains(&completed.key)
             }
             Err(err) => {
@@ -359,7 +390,18 @@ impl RenderSubsystem {
             return;
         }
 
-        for preview_task in ctx.preview_tasks {
+        // Frames persisted by an earlier session load faster than a preview renders.
+        let persisted = ctx
+            .required
+            .keys()
+            .iter()
+            .all(|key| self.runtime.has_persisted_frame(key));
+        let preview_tasks = if persisted {
+            Vec::new()
+        } else {
+            ctx.preview_tasks
+        };
+        for preview_task in preview_tasks {
             let preview_key =
                 RenderedPageKey::new(preview_task.doc_id, preview_task.page, preview_task.scale);
             if !self.runtime.has_cached_frame(&preview_key)
@@ -538,6 +580,7 @@ mod tests {
                 2,
                 AppError::invalid_argument("decode failed"),
             )),
+            from_l3: false,
             queue_wait: Duration::from_millis(1),
             elapsed: Duration::from_millis(2),
         }
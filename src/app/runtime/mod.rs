@@ -5,6 +5,7 @@ use crate::error::{AppError, AppResult};
 use crate::metrics::PerfStats;
 use crate::presenter::ImagePresenter;
 use crate::render::cache::{RenderedPageCache, RenderedPageKey};
+use crate::render::disk_cache::DiskRenderCache;
 use crate::render::scheduler::{
     NavIntent, PrefetchPolicy, RenderScheduler, RenderTask, build_prefetch_plan_with_policy,
 };
@@ -16,8 +17,8 @@ mod spread_canvas;
 #[cfg(test)]
 pub(crate) use prepare::CurrentPagePrepareRequest;
 pub(crate) use prepare::{
-    CachePrepareResult, FramePrepareOptions, PageSlotPrepareRequest, PrefetchEncodeRequest,
-    SpreadCanvasPrepareRequest,
+    CachePrepareResult, ContinuousPrepareRequest, FramePrepareOptions, PageSlotPrepareRequest,
+    PrefetchEncodeRequest, SpreadCanvasPrepareRequest,
 };
 
 #[derive(Debug, Default)]
@@ -26,6 +27,9 @@ pub struct RenderRuntime {
     pub scheduler: RenderScheduler,
     pub perf_stats: PerfStats,
     pub prefetch_policy: PrefetchPolicy,
+    pub l3_cache: Option<DiskRenderCache>,
+    /// Document whose renders may be read from and written to the L3 cache.
+    l3_doc_id: Option<u64>,
 }
 
 impl RenderRuntime {
@@ -35,9 +39,30 @@ impl RenderRuntime {
             scheduler: RenderScheduler::default(),
             perf_stats: PerfStats::default(),
             prefetch_policy: PrefetchPolicy::default(),
+            l3_cache: None,
+            l3_doc_id: None,
         }
     }
 
+    /// Makes `doc` the document the L3 cache serves.
+    ///
+    /// Pages of a document unlocked with a password are never written to disk, since the cache
+    /// would keep a readable copy of them outside the encrypted file.
+    pub fn attach_document(&mut self, doc: &dyn PdfBackend) {
+        self.l3_doc_id = doc.password().is_none().then(|| doc.doc_id());
+    }
+
+    /// The L3 cache render workers of the attached document read from, if it may use one.
+    pub fn worker_l3_cache(&self) -> Option<DiskRenderCache> {
+        self.l3_doc_id?;
+        self.l3_cache.clone()
+    }
+
+    pub fn has_persisted_frame(&self, key: &RenderedPageKey) -> bool {
+        self.l3_cache_for(key)
+            .is_some_and(|l3_cache| l3_cache.contains(key))
+    }
+
     pub fn schedule_navigation(
         &mut self,
         doc: &dyn PdfBackend,
@@ -113,10 +138,35 @@ impl RenderRuntime {
         allow_single_oversize: bool,
     ) {
         self.perf_stats.record_render(elapsed);
+        self.persist_frame(key, &frame);
         let _ = self.l1_cache.insert(key, frame, allow_single_oversize);
         self.perf_stats.set_l1_hit_rate(self.l1_cache.hit_rate());
     }
 
+    /// Takes a frame a render worker read from the L3 cache.
+    pub fn ingest_persisted_frame(
+        &mut self,
+        key: RenderedPageKey,
+        frame: RgbaFrame,
+        allow_single_oversize: bool,
+    ) {
+        let _ = self.l1_cache.insert(key, frame, allow_single_oversize);
+        self.perf_stats.set_l1_hit_rate(self.l1_cache.hit_rate());
+    }
+
+    fn persist_frame(&self, key: RenderedPageKey, frame: &RgbaFrame) {
+        if let Some(l3_cache) = self.l3_cache_for(&key) {
+            let _ = l3_cache.store(key, frame.clone());
+        }
+    }
+
+    fn l3_cache_for(&self, key: &RenderedPageKey) -> Option<&DiskRenderCache> {
+        if self.l3_doc_id != Some(key.doc_id) {
+            return None;
+        }
+        self.l3_cache.as_ref()
+    }
+
     pub fn set_queue_depth_with_inflight(&mut self, inflight: usize) {
         self.perf_stats.set_queue_depth(self.scheduler.len());
         self.perf_stats.set_render_in_flight(inflight);
@@ -141,6 +191,7 @@ impl RenderRuntime {
         }
 
         let key = RenderedPageKey::new(task.doc_id, task.page, task.scale);
+        let allow_single_oversize = task.class == WorkClass::CriticalCurrent;
         if let Some(cached) = self.l1_cache.get_cloned(&key) {
             self.perf_stats.set_l1_hit_rate(self.l1_cache.hit_rate());
             return Ok(cached);
@@ -149,7 +200,7 @@ impl RenderRuntime {
         let render_start = Instant::now();
         let frame = doc.render_page(task.page, task.scale)?;
         self.perf_stats.record_render(render_start.elapsed());
-        let allow_single_oversize = task.class == WorkClass::CriticalCurrent;
+        self.persist_frame(key, &frame);
         let _ = self
             .l1_cache
             .insert(key, frame.clone(), allow_single_oversize);
@@ -3,6 +3,7 @@ use ratatui::layout::Rect;
 use crate::backend::{PdfBackend, RgbaFrame};
 use crate::error::AppResult;
 use crate::highlight::HighlightOverlaySnapshot;
+use crate::highlight::geometry::PageCrop;
 use crate::presenter::{
     ImagePresenter, PanOffset, PresenterHorizontalAlign, PresenterRenderMode,
     PresenterRenderOptions, PresenterRenderSlot, PresenterSlot, Viewport,
@@ -10,9 +11,10 @@ use crate::presenter::{
 use crate::render::cache::RenderedPageKey;
 use crate::work::WorkClass;
 
+use super::super::continuous::ContinuousPageClip;
 use super::super::frame_ops::{
     PageRenderSpace, apply_highlight_overlay, crop_frame_region, effective_pan_for_viewport,
-    prepare_presenter_frame,
+    prepare_presenter_frame, viewport_crop_origin,
 };
 use super::super::state::VisiblePageSlots;
 use super::RenderRuntime;
@@ -56,6 +58,14 @@ pub(crate) struct SpreadCanvasPrepareRequest<'a> {
     pub(crate) gap_px: u32,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ContinuousPrepareRequest<'a> {
+    pub(crate) clips: &'a [ContinuousPageClip],
+    pub(crate) scale: f32,
+    pub(crate) cell_px: Option<(u16, u16)>,
+    pub(crate) overlay: &'a HighlightOverlaySnapshot,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct PrefetchEncodeRequest {
     pub(crate) viewport: Viewport,
@@ -108,6 +118,13 @@ impl PreparedPresenterSlots {
             .map(|slot| presenter_slot_from_prepared(slot.as_ref(), generation))
             .collect()
     }
+
+    pub(crate) fn page_crops(&self) -> Vec<Option<PageCrop>> {
+        self.slots
+            .iter()
+            .map(|slot| slot.as_ref().map(|slot| slot.crop))
+            .collect()
+    }
 }
 
 pub(crate) struct PreparedSpreadCanvas {
@@ -148,6 +165,13 @@ impl PreparedSpreadCanvas {
             .collect()
     }
 
+    pub(crate) fn page_crops(&self) -> Vec<Option<PageCrop>> {
+        self.presenter_slots
+            .iter()
+            .map(|slot| slot.as_ref().map(|slot| slot.crop))
+            .collect()
+    }
+
     pub(crate) fn render_slots(
         &self,
         render_mode: PresenterRenderMode,
@@ -176,6 +200,7 @@ struct PreparedPresenterSlot {
     viewport: Viewport,
     pan: PanOffset,
     overlay_stamp: u64,
+    crop: PageCrop,
 }
 
 struct CachedPageSlot {
@@ -215,21 +240,33 @@ impl RenderRuntime {
         let (frame, overlay_stamp) =
             decorate_single_page_frame(doc, task.page, &frame, request.options.overlay);
         let mut pan = request.pan;
-        let (frame, pan_for_presenter) = prepare_presenter_frame(
+        let (shown, pan_for_presenter) = prepare_presenter_frame(
             &frame,
             request.viewport,
             &mut pan,
             request.options.cell_px,
             request.options.crop,
         );
+        let origin = if request.options.crop {
+            viewport_crop_origin(
+                &frame,
+                request.viewport,
+                pan_for_presenter,
+                request.options.cell_px,
+            )
+        } else {
+            (0, 0)
+        };
+        let crop = page_crop(task.page, &frame, origin, &shown);
 
         Ok(PreparedPresenterSlots::single(
             PreparedPresenterSlot {
                 cache_key: RenderedPageKey::new(task.doc_id, task.page, task.scale),
-                frame,
+                frame: shown,
                 viewport: request.viewport,
                 pan: pan_for_presenter,
                 overlay_stamp,
+                crop,
             },
             pan,
         ))
@@ -296,12 +333,19 @@ impl RenderRuntime {
                     clip.crop_width,
                     clip.crop_height,
                 );
+                let crop = page_crop(
+                    page.key.page,
+                    &page.frame,
+                    (clip.crop_x, clip.crop_y),
+                    &frame,
+                );
                 presenter_slots[index] = Some(PreparedPresenterSlot {
                     cache_key: page.key,
                     frame,
                     viewport: clip.viewport,
                     pan: layout.pan,
                     overlay_stamp: page.overlay_stamp,
+                    crop,
                 });
             }
         }
@@ -315,6 +359,49 @@ impl RenderRuntime {
         }))
     }
 
+    /// Crops each visible page of a continuous layout out of its cached render. Pages that are
+    /// not cached yet leave their slot empty so the caller can show a loading label there.
+    pub(crate) fn prepare_continuous_from_cache(
+        &mut self,
+        doc: &dyn PdfBackend,
+        request: ContinuousPrepareRequest<'_>,
+    ) -> AppResult<CachePrepareResult<PreparedPresenterSlots>> {
+        let mut slots = Vec::with_capacity(request.clips.len());
+        for clip in request.clips {
+            let Some(page) =
+                self.cached_decorated_page(doc, Some(clip.page), request.scale, request.overlay)?
+            else {
+                slots.push(None);
+                continue;
+            };
+            let frame = crop_frame_region(
+                &page.frame,
+                clip.crop_x,
+                clip.crop_y,
+                clip.crop_width,
+                clip.crop_height,
+            );
+            let crop = page_crop(clip.page, &page.frame, (clip.crop_x, clip.crop_y), &frame);
+            slots.push(Some(PreparedPresenterSlot {
+                cache_key: page.key,
+                frame,
+                viewport: clip.area.into(),
+                pan: clip.presenter_pan(request.cell_px),
+                overlay_stamp: page.overlay_stamp,
+                crop,
+            }));
+        }
+        self.perf_stats.set_l1_hit_rate(self.l1_cache.hit_rate());
+
+        if slots.iter().all(Option::is_none) {
+            return Ok(CachePrepareResult::Miss);
+        }
+        Ok(CachePrepareResult::Prepared(PreparedPresenterSlots::new(
+            slots,
+            PanOffset::default(),
+        )))
+    }
+
     pub(crate) fn try_prefetch_encode_from_cache(
         &mut self,
         presenter: &mut dyn ImagePresenter,
@@ -408,19 +495,31 @@ impl RenderRuntime {
                 request.options.overlay,
             );
             let mut slot_pan = effective_pan;
-            let (frame, pan_for_presenter) = prepare_presenter_frame(
+            let (shown, pan_for_presenter) = prepare_presenter_frame(
                 &frame,
                 slot.viewport,
                 &mut slot_pan,
                 request.options.cell_px,
                 request.options.crop,
             );
+            let origin = if request.options.crop {
+                viewport_crop_origin(
+                    &frame,
+                    slot.viewport,
+                    pan_for_presenter,
+                    request.options.cell_px,
+                )
+            } else {
+                (0, 0)
+            };
+            let crop = page_crop(slot.key.page, &frame, origin, &shown);
             prepared.push(Some(PreparedPresenterSlot {
                 cache_key: slot.key,
-                frame,
+                frame: shown,
                 viewport: slot.viewport,
                 pan: pan_for_presenter,
                 overlay_stamp,
+                crop,
             }));
         }
 
@@ -504,6 +603,23 @@ fn scaled_page_dimension(points: f32, scale: f32) -> u32 {
     (points * scale).round().clamp(1.0, u32::MAX as f32) as u32
 }
 
+fn page_crop(
+    page: usize,
+    frame: &RgbaFrame,
+    (x_px, y_px): (u32, u32),
+    shown: &RgbaFrame,
+) -> PageCrop {
+    PageCrop {
+        page,
+        frame_width_px: frame.width,
+        frame_height_px: frame.height,
+        x_px,
+        y_px,
+        width_px: shown.width,
+        height_px: shown.height,
+    }
+}
+
 fn presenter_slot_from_prepared(
     slot: Option<&PreparedPresenterSlot>,
     generation: u64,
@@ -1,5 +1,7 @@
 use crate::presenter::Viewport;
 
+use super::state::FitMode;
+
 use super::constants::{DEFAULT_CELL_SIZE_PX, MIN_RENDER_SCALE, SCALE_QUANTUM};
 
 pub(crate) const ZOOM_MIN: f32 = 0.25;
@@ -71,6 +73,34 @@ pub(crate) fn compute_render_scale(
     adaptive_scale.clamp(MIN_RENDER_SCALE, effective_max)
 }
 
+/// Scale at which the page fits the viewport as `fit` asks, rounded down to the scale quantum so
+/// the fitted edge never overflows. These pages are shown pixel for pixel, so unlike
+/// [`compute_render_scale`] the result may go below [`MIN_RENDER_SCALE`].
+pub(crate) fn compute_fit_scale(
+    fit: FitMode,
+    viewport: Viewport,
+    cell_px: Option<(u16, u16)>,
+    (page_width_pt, page_height_pt): (f32, f32),
+    max_render_scale: f32,
+) -> f32 {
+    let (cell_width_px, cell_height_px) = resolved_cell_size_px(cell_px);
+    let width_scale = f32::from(viewport.width.max(1)) * f32::from(cell_width_px) / page_width_pt;
+    let height_scale =
+        f32::from(viewport.height.max(1)) * f32::from(cell_height_px) / page_height_pt;
+    let fit_scale = match fit {
+        FitMode::Page => width_scale.min(height_scale),
+        FitMode::Width => width_scale,
+        FitMode::Height => height_scale,
+        FitMode::Actual => return 1.0,
+    };
+    if !fit_scale.is_finite() || fit_scale <= 0.0 {
+        return MIN_RENDER_SCALE;
+    }
+
+    let fit_scale = fit_scale.min(max_render_scale.max(SCALE_QUANTUM));
+    ((fit_scale / SCALE_QUANTUM).floor() * SCALE_QUANTUM).max(SCALE_QUANTUM)
+}
+
 pub(crate) fn compute_scale(zoom: f32, render_scale: f32) -> f32 {
     quantize_scale(zoom * render_scale)
 }
@@ -104,11 +134,12 @@ pub(crate) fn prev_zoom_step(current: f32) -> f32 {
 mod tests {
     use std::time::Duration;
 
+    use crate::app::FitMode;
     use crate::presenter::Viewport;
 
     use super::{
-        compute_render_scale, compute_scale, next_zoom_step, prev_zoom_step, quantize_scale,
-        scale_eq, select_input_poll_timeout,
+        compute_fit_scale, compute_render_scale, compute_scale, next_zoom_step, prev_zoom_step,
+        quantize_scale, scale_eq, select_input_poll_timeout,
     };
 
     const DEFAULT_MAX_RENDER_SCALE: f32 = 2.5;
@@ -135,6 +166,40 @@ mod tests {
         assert!(scale_eq(scale, 1.75));
     }
 
+    #[test]
+    fn fit_scale_keeps_the_fitted_edge_within_the_viewport() {
+        let viewport = Viewport {
+            x: 0,
+            y: 0,
+            width: 80,
+            height: 24,
+        };
+        let letter = (612.0, 792.0);
+
+        let width = compute_fit_scale(FitMode::Width, viewport, Some((10, 20)), letter, 2.5);
+        assert!(scale_eq(width, 1.30));
+        assert!(612.0 * width <= 800.0);
+
+        let height = compute_fit_scale(FitMode::Height, viewport, Some((10, 20)), letter, 2.5);
+        assert!(scale_eq(height, 0.60));
+        assert!(792.0 * height <= 480.0);
+
+        let page = compute_fit_scale(FitMode::Page, viewport, Some((10, 20)), letter, 2.5);
+        assert!(scale_eq(page, height));
+
+        let actual = compute_fit_scale(FitMode::Actual, viewport, Some((10, 20)), letter, 2.5);
+        assert!(scale_eq(actual, 1.0));
+
+        let capped = compute_fit_scale(
+            FitMode::Width,
+            viewport,
+            Some((10, 20)),
+            (100.0, 100.0),
+            2.5,
+        );
+        assert!(scale_eq(capped, 2.5));
+    }
+
     #[test]
     fn render_scale_falls_back_when_cell_size_missing() {
         let viewport = Viewport {
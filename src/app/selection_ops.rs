@@ -0,0 +1,25 @@
+use crate::clipboard::copy_to_clipboard;
+
+use super::core::App;
+use super::state::{NoticeAction, notice_action_for_error};
+
+impl App {
+    /// Copies the visual mode selection to the clipboard and returns to normal mode.
+    pub(super) fn yank_visual_selection(&mut self) -> NoticeAction {
+        let Some(text) = self
+            .interaction
+            .extensions
+            .host
+            .command_ports()
+            .selection
+            .take_selected_text(&mut self.state)
+        else {
+            return NoticeAction::warning("no text selected");
+        };
+
+        match copy_to_clipboard(&text, self.clipboard_policy.command.as_deref()) {
+            Ok(()) => NoticeAction::info(format!("copied {} characters", text.chars().count())),
+            Err(err) => notice_action_for_error(err),
+        }
+    }
+}
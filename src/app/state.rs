@@ -1,3 +1,4 @@
+use crate::backend::PageLabels;
 use crate::error::AppError;
 use crate::palette::{PaletteKind, PaletteOpenPayload};
 
@@ -6,6 +7,8 @@ pub enum PageLayoutMode {
     #[default]
     Single,
     Spread,
+    /// Pages stacked top to bottom; vertical panning scrolls across page boundaries.
+    Continuous,
 }
 
 impl PageLayoutMode {
@@ -13,6 +16,49 @@ impl PageLayoutMode {
         match self {
             Self::Single => "single",
             Self::Spread => "spread",
+            Self::Continuous => "continuous",
+        }
+    }
+}
+
+/// Whether the viewer shows rendered page images or the page's reflowed extracted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageViewMode {
+    #[default]
+    Image,
+    Text,
+}
+
+impl PageViewMode {
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Image => "image",
+            Self::Text => "text",
+        }
+    }
+}
+
+/// What the base render scale fits before zoom is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FitMode {
+    /// Whole page inside the viewport.
+    #[default]
+    Page,
+    /// Page width fills the viewport; taller pages pan vertically.
+    Width,
+    /// Page height fills the viewport; wider pages pan horizontally.
+    Height,
+    /// One PDF point per pixel.
+    Actual,
+}
+
+impl FitMode {
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Page => "page",
+            Self::Width => "width",
+            Self::Height => "height",
+            Self::Actual => "actual",
         }
     }
 }
@@ -76,6 +122,8 @@ pub enum Mode {
     Normal,
     Palette,
     Help,
+    /// Caret and text selection on the current page.
+    Visual,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -89,6 +137,7 @@ pub enum PaletteRequest {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NoticeLevel {
+    Info,
     Warning,
     Error,
 }
@@ -107,6 +156,13 @@ pub enum NoticeAction {
 }
 
 impl NoticeAction {
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::Show {
+            level: NoticeLevel::Info,
+            message: message.into(),
+        }
+    }
+
     pub fn warning(message: impl Into<String>) -> Self {
         Self::Show {
             level: NoticeLevel::Warning,
@@ -137,6 +193,8 @@ pub struct CacheRefs {
 pub struct AppState {
     pub current_page: usize,
     pub page_layout_mode: PageLayoutMode,
+    pub view_mode: PageViewMode,
+    pub fit_mode: FitMode,
     pub spread_direction: SpreadDirection,
     pub spread_cover_policy: SpreadCoverPolicy,
     pub zoom: f32,
@@ -144,8 +202,10 @@ pub struct AppState {
     pub pan_y: i32,
     pub help_scroll: usize,
     pub debug_status_visible: bool,
+    pub status_title_visible: bool,
     pub mode: Mode,
     pub notice: Option<Notice>,
+    pub page_labels: PageLabels,
     pub caches: CacheRefs,
 }
 
@@ -154,6 +214,8 @@ impl Default for AppState {
         Self {
             current_page: 0,
             page_layout_mode: PageLayoutMode::Single,
+            view_mode: PageViewMode::Image,
+            fit_mode: FitMode::Page,
             spread_direction: SpreadDirection::Ltr,
             spread_cover_policy: SpreadCoverPolicy::Paired,
             zoom: 1.0,
@@ -161,8 +223,10 @@ impl Default for AppState {
             pan_y: 0,
             help_scroll: 0,
             debug_status_visible: false,
+            status_title_visible: false,
             mode: Mode::Normal,
             notice: None,
+            page_labels: PageLabels::default(),
             caches: CacheRefs::default(),
         }
     }
@@ -235,7 +299,7 @@ impl AppState {
 
     pub fn page_step(&self) -> usize {
         match self.page_layout_mode {
-            PageLayoutMode::Single => 1,
+            PageLayoutMode::Single | PageLayoutMode::Continuous => 1,
             PageLayoutMode::Spread => 2,
         }
     }
@@ -259,7 +323,7 @@ impl AppState {
 
         let clamped = page.min(page_count - 1);
         match self.page_layout_mode {
-            PageLayoutMode::Single => clamped,
+            PageLayoutMode::Single | PageLayoutMode::Continuous => clamped,
             PageLayoutMode::Spread => match self.spread_cover_policy {
                 SpreadCoverPolicy::Paired => clamped.saturating_sub(clamped % 2),
                 SpreadCoverPolicy::Cover => {
@@ -284,7 +348,9 @@ impl AppState {
 
         let anchor_page = self.normalize_page_for_layout(page, page_count);
         match self.page_layout_mode {
-            PageLayoutMode::Single => (anchor_page + 1).min(page_count - 1),
+            PageLayoutMode::Single | PageLayoutMode::Continuous => {
+                (anchor_page + 1).min(page_count - 1)
+            }
             PageLayoutMode::Spread => {
                 let next =
                     if self.spread_cover_policy == SpreadCoverPolicy::Cover && anchor_page == 0 {
@@ -304,7 +370,7 @@ impl AppState {
 
         let anchor_page = self.normalize_page_for_layout(page, page_count);
         match self.page_layout_mode {
-            PageLayoutMode::Single => anchor_page.saturating_sub(1),
+            PageLayoutMode::Single | PageLayoutMode::Continuous => anchor_page.saturating_sub(1),
             PageLayoutMode::Spread => {
                 if self.spread_cover_policy == SpreadCoverPolicy::Cover && anchor_page == 1 {
                     0
@@ -330,7 +396,9 @@ impl AppState {
         }
 
         let anchor_page = self.normalize_page_for_layout(page, page_count);
-        if self.page_layout_mode == PageLayoutMode::Single {
+        // Continuous layout reports only the page owning the viewport; the partially visible
+        // neighbours come from the continuous layout itself.
+        if self.page_layout_mode != PageLayoutMode::Spread {
             return VisiblePageSlots {
                 anchor_page,
                 trailing_page: None,
@@ -361,9 +429,16 @@ impl AppState {
         }
     }
 
+    /// Whether rendered pages are shown pixel for pixel and cropped to the viewport, rather than
+    /// shrunk to fit inside it.
+    pub fn crops_to_viewport(&self) -> bool {
+        self.fit_mode != FitMode::Page || self.zoom > 1.0
+    }
+
     pub fn page_presentation_for_slots(&self, slots: VisiblePageSlots) -> PageLayoutMode {
         match self.page_layout_mode {
             PageLayoutMode::Single => PageLayoutMode::Single,
+            PageLayoutMode::Continuous => PageLayoutMode::Continuous,
             PageLayoutMode::Spread
                 if self.spread_cover_policy == SpreadCoverPolicy::Cover
                     && slots.anchor_page == 0 =>
@@ -381,6 +456,7 @@ impl AppState {
     ) -> u16 {
         match (page_presentation, self.spread_direction, has_trailing_page) {
             (PageLayoutMode::Single, _, _) => 0,
+            (PageLayoutMode::Continuous, _, _) => 5,
             (PageLayoutMode::Spread, SpreadDirection::Ltr, true) => 1,
             (PageLayoutMode::Spread, SpreadDirection::Rtl, true) => 2,
             (PageLayoutMode::Spread, SpreadDirection::Ltr, false) => 3,
@@ -20,6 +20,7 @@ use crate::presenter::{
     PresenterRenderOutcome, PresenterRenderSlot, PresenterSlot, Viewport,
 };
 use crate::render::cache::RenderedPageKey;
+use crate::render::disk_cache::DiskRenderCache;
 use crate::render::scheduler::{NavDirection, NavIntent, RenderTask};
 use crate::render::worker::RenderWorker;
 use crate::work::WorkClass;
@@ -462,6 +463,55 @@ fn render_worker_rejects_duplicate_key_while_inflight() {
     fs::remove_file(&file).expect("test pdf should be removed");
 }
 
+#[test]
+fn render_workers_load_frames_persisted_by_a_later_runtime() {
+    let file = unique_temp_path("runtime_l3.pdf");
+    let cache_dir = unique_temp_path("runtime_l3_cache");
+    fs::write(&file, build_pdf(&["p1", "p2"])).expect("test pdf should be created");
+    let doc = Arc::new(PdfDoc::open(&file).expect("pdf should open"));
+    let key = RenderedPageKey::new(doc.doc_id(), 0, 1.0);
+    let frame = doc.render_page(0, 1.0).expect("page should render");
+
+    let mut first = RenderRuntime::default();
+    first.l3_cache = Some(DiskRenderCache::open(&cache_dir, 1 << 30).expect("l3 should open"));
+    first.attach_document(doc.as_ref());
+    first.ingest_rendered_frame(key, frame.clone(), Duration::ZERO, true);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !first.has_persisted_frame(&key) {
+        assert!(Instant::now() < deadline, "frame should be persisted");
+        thread::sleep(Duration::from_millis(5));
+    }
+    drop(first);
+
+    let mut second = RenderRuntime::default();
+    second.l3_cache = Some(DiskRenderCache::open(&cache_dir, 1 << 30).expect("l3 should reopen"));
+    assert!(second.worker_l3_cache().is_none());
+    second.attach_document(doc.as_ref());
+    assert!(second.has_persisted_frame(&key));
+    assert!(!second.has_persisted_frame(&RenderedPageKey::new(doc.doc_id(), 1, 1.0)));
+
+    let shared: SharedPdfBackend = doc.clone();
+    let mut worker = RenderWorker::spawn(shared, 1, second.worker_l3_cache());
+    assert!(worker.enqueue(render_task(doc.as_ref(), 0, WorkClass::CriticalCurrent, 1)));
+    let deadline = Instant::now() + Duration::from_secs(2);
+    let completed = loop {
+        if let Some(event) = worker.try_recv_result_event()
+            && let Some(result) = worker.accept_result_event(event)
+        {
+            break result;
+        }
+        assert!(Instant::now() < deadline, "render worker should answer");
+        thread::sleep(Duration::from_millis(5));
+    };
+
+    assert!(completed.from_l3);
+    let loaded = completed.result.expect("frame should load");
+    assert_eq!(&*loaded.pixels, &*frame.pixels);
+
+    fs::remove_file(&file).expect("test pdf should be removed");
+    fs::remove_dir_all(&cache_dir).expect("cache dir should be removed");
+}
+
 fn render_task(doc: &dyn PdfBackend, page: usize, class: WorkClass, generation: u64) -> RenderTask {
     RenderTask {
         doc_id: doc.doc_id(),
@@ -475,7 +525,7 @@ fn render_task(doc: &dyn PdfBackend, page: usize, class: WorkClass, generation:
 
 fn spawn_worker(doc: Arc<PdfDoc>, worker_threads: usize) -> RenderWorker {
     let doc: SharedPdfBackend = doc;
-    RenderWorker::spawn(doc, worker_threads)
+    RenderWorker::spawn(doc, worker_threads, None)
 }
 
 fn drain_render_results(worker: &mut RenderWorker) -> Vec<RenderedPageKey> {
@@ -0,0 +1,261 @@
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures_util::StreamExt;
+use tui_input::InputRequest;
+
+use crate::backend::{SharedPdfBackend, open_default_backend_with_password};
+use crate::error::{AppError, AppResult};
+use crate::palette::{PaletteKind, PaletteOpenPayload};
+use crate::ui;
+
+use super::core::App;
+use super::loop_driver::{InteractiveLoopDriver, LoopEventMode};
+use super::state::{Mode, PaletteRequest};
+use super::terminal_session::{InteractiveTerminalSession, TerminalSurface};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnlockKeyAction {
+    Insert(char),
+    Edit(InputRequest),
+    Submit,
+    Cancel,
+    Ignore,
+}
+
+impl App {
+    /// Opens the PDF at `path` and runs the viewer.
+    ///
+    /// Encrypted documents that cannot be opened with `password` are unlocked through the
+    /// password palette before the backend is built. Canceling the prompt exits cleanly.
+    pub async fn open_and_run(&mut self, path: &Path, password: Option<&str>) -> AppResult<()> {
+        let password_supplied = match open_default_backend_with_password(path, password) {
+            Ok(pdf) => return self.run(pdf).await,
+            Err(AppError::Encrypted { password_supplied }) => password_supplied,
+            Err(err) => return Err(err),
+        };
+
+        let mut session = InteractiveTerminalSession::enter()?;
+        let Some(pdf) = self
+            .prompt_for_password(&mut session, path, password_supplied)
+            .await?
+        else {
+            return session
+                .restore()
+                .map_err(|source| AppError::io_with_context(source, "restoring terminal session"));
+        };
+
+        let watch = self.run_options().watch;
+        self.run_loop(
+            pdf,
+            session,
+            LoopEventMode::Interactive { watch },
+            InteractiveLoopDriver,
+        )
+        .await
+    }
+
+    async fn prompt_for_password<S>(
+        &mut self,
+        session: &mut S,
+        path: &Path,
+        mut incorrect: bool,
+    ) -> AppResult<Option<SharedPdfBackend>>
+    where
+        S: TerminalSurface,
+    {
+        let mut events = EventStream::new();
+        loop {
+            self.open_password_palette(incorrect);
+            let Some(password) = self.read_password(session, &mut events).await? else {
+                return Ok(None);
+            };
+
+            let path = path.to_path_buf();
+            match open_encrypted_backend(path, password).await {
+                Ok(pdf) => return Ok(Some(pdf)),
+                Err(AppError::Encrypted { .. }) => incorrect = true,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn open_password_palette(&mut self, incorrect: bool) {
+        self.interaction
+            .palette
+            .pending_requests
+            .push_back(PaletteRequest::Open {
+                kind: PaletteKind::Password,
+                payload: Some(PaletteOpenPayload::PasswordPrompt { incorrect }),
+            });
+        self.interaction.apply_palette_requests(&mut self.state);
+    }
+
+    async fn read_password<S>(
+        &mut self,
+        session: &mut S,
+        events: &mut EventStream,
+    ) -> AppResult<Option<String>>
+    where
+        S: TerminalSurface,
+    {
+        loop {
+            self.draw_password_prompt(session)?;
+
+            let Some(event) = events.next().await else {
+                return Ok(None);
+            };
+            let event = event
+                .map_err(|source| AppError::io_with_context(source, "reading terminal input"))?;
+            let Event::Key(key) = event else {
+                continue;
+            };
+
+            let extensions = self.interaction.extensions.host.ui_snapshot();
+            let palette = &mut self.interaction.palette;
+            match unlock_key_action(key) {
+                UnlockKeyAction::Insert(ch) => {
+                    palette.manager.insert_text(
+                        &palette.registry,
+                        &self.state,
+                        &extensions,
+                        ch.encode_utf8(&mut [0; 4]),
+                    )?;
+                }
+                UnlockKeyAction::Edit(request) => {
+                    palette.manager.edit_input(
+                        &palette.registry,
+                        &self.state,
+                        &extensions,
+                        request,
+                    )?;
+                }
+                UnlockKeyAction::Submit => {
+                    let password = palette.manager.active_input().unwrap_or("").to_string();
+                    self.close_password_palette();
+                    return Ok(Some(password));
+                }
+                UnlockKeyAction::Cancel => {
+                    self.close_password_palette();
+                    return Ok(None);
+                }
+                UnlockKeyAction::Ignore => {}
+            }
+        }
+    }
+
+    fn close_password_palette(&mut self) {
+        self.interaction.palette.manager.close();
+        self.state.mode = Mode::Normal;
+    }
+
+    fn draw_password_prompt<S>(&self, session: &mut S) -> AppResult<()>
+    where
+        S: TerminalSurface,
+    {
+        let Some(view) = self.interaction.palette_view() else {
+            return Ok(());
+        };
+        session
+            .draw(|frame| ui::draw_palette_overlay(frame, frame.area(), &view))
+            .map_err(|source| AppError::io_with_context(source, "drawing password prompt"))
+    }
+}
+
+async fn open_encrypted_backend(path: PathBuf, password: String) -> AppResult<SharedPdfBackend> {
+    tokio::task::spawn_blocking(move || open_default_backend_with_password(&path, Some(&password)))
+        .await
+        .map_err(|err| AppError::io_with_context(std::io::Error::other(err), "opening pdf"))?
+}
+
+fn unlock_key_action(key: KeyEvent) -> UnlockKeyAction {
+    if key.kind == KeyEventKind::Release {
+        return UnlockKeyAction::Ignore;
+    }
+
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Enter => UnlockKeyAction::Submit,
+        KeyCode::Esc => UnlockKeyAction::Cancel,
+        KeyCode::Char('c') if ctrl => UnlockKeyAction::Cancel,
+        KeyCode::Char('a') if ctrl => UnlockKeyAction::Edit(InputRequest::GoToStart),
+        KeyCode::Char('e') if ctrl => UnlockKeyAction::Edit(InputRequest::GoToEnd),
+        KeyCode::Char('u') if ctrl => UnlockKeyAction::Edit(InputRequest::DeleteLine),
+        KeyCode::Char('w') if ctrl => UnlockKeyAction::Edit(InputRequest::DeletePrevWord),
+        KeyCode::Char(_) if ctrl => UnlockKeyAction::Ignore,
+        KeyCode::Char(ch) => UnlockKeyAction::Insert(ch),
+        KeyCode::Backspace => UnlockKeyAction::Edit(InputRequest::DeletePrevChar),
+        KeyCode::Delete => UnlockKeyAction::Edit(InputRequest::DeleteNextChar),
+        KeyCode::Left => UnlockKeyAction::Edit(InputRequest::GoToPrevChar),
+        KeyCode::Right => UnlockKeyAction::Edit(InputRequest::GoToNextChar),
+        KeyCode::Home => UnlockKeyAction::Edit(InputRequest::GoToStart),
+        KeyCode::End => UnlockKeyAction::Edit(InputRequest::GoToEnd),
+        _ => UnlockKeyAction::Ignore,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+    use tui_input::InputRequest;
+
+    use crate::app::Mode;
+    use crate::palette::PaletteKind;
+    use crate::presenter::PresenterKind;
+
+    use super::{App, UnlockKeyAction, unlock_key_action};
+
+    #[test]
+    fn unlock_keys_submit_cancel_and_edit_password_input() {
+        let key = |code, modifiers| unlock_key_action(KeyEvent::new(code, modifiers));
+
+        assert_eq!(
+            key(KeyCode::Enter, KeyModifiers::NONE),
+            UnlockKeyAction::Submit
+        );
+        assert_eq!(
+            key(KeyCode::Esc, KeyModifiers::NONE),
+            UnlockKeyAction::Cancel
+        );
+        assert_eq!(
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            UnlockKeyAction::Cancel
+        );
+        assert_eq!(
+            key(KeyCode::Char('Q'), KeyModifiers::SHIFT),
+            UnlockKeyAction::Insert('Q')
+        );
+        assert_eq!(
+            key(KeyCode::Backspace, KeyModifiers::NONE),
+            UnlockKeyAction::Edit(InputRequest::DeletePrevChar)
+        );
+        assert_eq!(
+            key(KeyCode::Char('x'), KeyModifiers::CONTROL),
+            UnlockKeyAction::Ignore
+        );
+    }
+
+    #[test]
+    fn unlock_keys_ignore_release_events() {
+        let mut release = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        release.kind = KeyEventKind::Release;
+        assert_eq!(unlock_key_action(release), UnlockKeyAction::Ignore);
+    }
+
+    #[test]
+    fn password_palette_opens_in_palette_mode_and_closes_to_normal() {
+        let mut app = App::new_with_config(PresenterKind::RatatuiImage, Default::default())
+            .expect("app init");
+
+        app.open_password_palette(false);
+        assert_eq!(app.state.mode, Mode::Palette);
+        assert_eq!(
+            app.interaction.palette.manager.active_kind(),
+            Some(PaletteKind::Password)
+        );
+
+        app.close_password_palette();
+        assert_eq!(app.state.mode, Mode::Normal);
+        assert!(!app.interaction.palette.manager.is_open());
+    }
+}
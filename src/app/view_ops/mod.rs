@@ -1,38 +1,47 @@
-use crate::app::PageLayoutMode;
+use crate::app::{FitMode, PageLayoutMode, PageViewMode};
 use crate::backend::PdfBackend;
 use crate::config::RenderPolicy;
 use crate::error::{AppError, AppResult};
 use crate::highlight::HighlightOverlaySnapshot;
+use crate::highlight::geometry::{PageCellPlacement, PageCrop};
+use crate::info::DocumentInfoView;
 use crate::input::sequence::SequenceRegistrySnapshot;
+use crate::links::LinkHintView;
 use crate::palette::PaletteView;
 use crate::presenter::{
     ImagePresenter, PanOffset, PresenterFeedback, PresenterHorizontalAlign, PresenterRenderMode,
     PresenterRenderOptions, PresenterRenderOutcome, PresenterRenderSlot, PresenterRuntimeInfo,
-    PresenterSlotOutcome, Viewport,
+    PresenterSlotOutcome, Viewport, fitted_image_area,
 };
 use crate::render::cache::RenderedPageKey;
 use crate::ui;
 use ratatui::layout::Rect;
 
 mod spread;
+mod text_view;
 mod viewer_outcome;
 
 use spread::{
     SpreadSlotAreas, clear_pending_spread_regions, format_loading_target, format_render_target,
     split_spread_slot_areas,
 };
+pub(crate) use text_view::TextViewCache;
 use viewer_outcome::{
-    draw_spread_loading_overlays, draw_viewer_outcome, normalize_render_outcome,
-    pending_spread_outcome, presenter_render_options, sync_render_notice,
+    draw_loading_overlays, draw_spread_loading_overlays, draw_viewer_outcome,
+    normalize_render_outcome, pending_spread_outcome, presenter_render_options,
+    slot_loading_overlays, sync_render_notice,
 };
 
 use super::constants::DEFAULT_PAGE_SIZE_PT;
+use super::continuous::{self, ContinuousLayout, ContinuousPageClip};
 use super::core::{App, RenderSubsystem};
 use super::runtime::{
-    CachePrepareResult, FramePrepareOptions, PageSlotPrepareRequest, SpreadCanvasPrepareRequest,
+    CachePrepareResult, ContinuousPrepareRequest, FramePrepareOptions, PageSlotPrepareRequest,
+    SpreadCanvasPrepareRequest,
 };
 use super::scale::{
-    compute_render_scale, compute_scale, quantize_scale, resolved_cell_size_px, scale_eq,
+    compute_fit_scale, compute_render_scale, compute_scale, quantize_scale, resolved_cell_size_px,
+    scale_eq,
 };
 use super::state::{AppState, Mode, VisiblePageSlots};
 use super::terminal_session::TerminalSurface;
@@ -57,6 +66,9 @@ pub(super) struct RenderFramePlan {
     pub(super) initial_preview: Option<InitialPreviewPlan>,
     pub(super) presenter_key: RenderedPageKey,
     pub(super) highlight_overlay: HighlightOverlaySnapshot,
+    pub(super) link_hints: Vec<LinkHintView>,
+    pub(super) document_info: Option<DocumentInfoView>,
+    pub(super) document_title: Option<String>,
     pub(super) generation: u64,
     pub(super) nav_streak: usize,
 }
@@ -70,12 +82,15 @@ struct RenderFrameDrawPlan {
     current_scale: f32,
     initial_preview: Option<InitialPreviewPlan>,
     highlight_overlay: HighlightOverlaySnapshot,
+    link_hints: Vec<LinkHintView>,
+    document_info: Option<DocumentInfoView>,
     generation: u64,
     mode: Mode,
     help_scroll: usize,
     debug_status_visible: bool,
     chrome: ui::ChromeViewState,
     page_presentation: PageLayoutMode,
+    view_mode: PageViewMode,
     enable_crop: bool,
     file_name: String,
     presenter_backend_name: &'static str,
@@ -111,6 +126,7 @@ struct FrameCachePreparer<'a> {
     cell_px: Option<(u16, u16)>,
     highlight_overlay: &'a HighlightOverlaySnapshot,
     generation: u64,
+    placements: Vec<PageCellPlacement>,
 }
 
 impl RenderFrameDrawPlan {
@@ -132,10 +148,18 @@ impl RenderFrameDrawPlan {
             initial_preview,
             presenter_key: _presenter_key,
             highlight_overlay,
+            link_hints,
+            document_info,
+            document_title,
             generation,
             nav_streak: _nav_streak,
         } = plan;
-        let image_occluded = palette_view.is_some() || state.mode == Mode::Help;
+        // Text view hides the image like an overlay does, so returning to image view redraws it.
+        let image_occluded = state.view_mode == PageViewMode::Text
+            || palette_view.is_some()
+            || state.mode == Mode::Help
+            || !link_hints.is_empty()
+            || document_info.is_some();
         let render_options = presenter_render_options(
             viewer_has_image,
             PresenterRenderMode::Full,
@@ -166,6 +190,8 @@ impl RenderFrameDrawPlan {
             current_scale,
             initial_preview,
             highlight_overlay,
+            link_hints,
+            document_info,
             generation,
             mode: state.mode,
             help_scroll: state.help_scroll,
@@ -173,12 +199,17 @@ impl RenderFrameDrawPlan {
             chrome: ui::ChromeViewState {
                 visible_pages,
                 page_presentation,
+                view_mode: state.view_mode,
+                fit_mode: state.fit_mode,
                 zoom: state.zoom,
                 debug_status_visible: state.debug_status_visible,
                 notice: state.notice.clone(),
+                document_title: document_title.filter(|_| state.status_title_visible),
+                page_labels: state.page_labels.clone(),
             },
             page_presentation,
-            enable_crop: state.zoom > 1.0,
+            view_mode: state.view_mode,
+            enable_crop: state.crops_to_viewport(),
             file_name,
             presenter_backend_name: presenter.backend_name,
             presenter_runtime: presenter.runtime,
@@ -233,6 +264,18 @@ pub(super) fn compute_current_scale_for_state(
     let max_scale = caps
         .preferred_max_render_scale
         .clamp(1.0, render_policy.max_render_scale);
+    // Fit page in single and spread layouts keeps the adaptive render scale and lets the
+    // presenter shrink the image; every other fit is shown pixel for pixel.
+    if state.fit_mode != FitMode::Page || page_presentation == PageLayoutMode::Continuous {
+        let fit_scale = compute_fit_scale(
+            state.fit_mode,
+            viewport,
+            caps.cell_px,
+            (page_width_pt, page_height_pt),
+            max_scale,
+        );
+        return compute_scale(state.zoom, fit_scale);
+    }
     let render_scale = compute_render_scale(
         viewport,
         caps.cell_px,
@@ -267,6 +310,36 @@ impl App {
         )
     }
 
+    /// Moves `current_page` to the page filling most of a continuous viewport and rebases the
+    /// vertical pan onto it, so history, the status bar and render scheduling follow scrolling.
+    pub(super) fn sync_continuous_scroll(
+        &mut self,
+        pdf: &dyn PdfBackend,
+        viewport: Option<Viewport>,
+    ) {
+        if self.state.page_layout_mode != PageLayoutMode::Continuous
+            || self.state.view_mode == PageViewMode::Text
+        {
+            return;
+        }
+        let Some(viewport) = viewport else {
+            return;
+        };
+
+        let scale = self.compute_current_scale(pdf, self.state.current_page, Some(viewport));
+        let layout = continuous::layout_for_document(
+            pdf,
+            self.state.current_page,
+            self.current_pan(),
+            viewport,
+            self.render.presenter.capabilities().cell_px,
+            scale,
+        );
+        self.state.current_page = layout.dominant_page;
+        self.state.pan_x = layout.pan.cells_x;
+        self.state.pan_y = layout.pan.cells_y;
+    }
+
     pub(super) fn current_pan(&self) -> PanOffset {
         PanOffset {
             cells_x: self.state.pan_x,
@@ -320,15 +393,14 @@ impl FrameCachePreparer<'_> {
             if let CachePrepareResult::Prepared(prepared) = result {
                 let presenter_slots = prepared.presenter_slots(self.generation);
                 self.presenter.prepare_slots(&presenter_slots)?;
-                return Ok(Some((
-                    render_mode,
-                    vec![PresenterRenderSlot {
-                        area: Rect::new(viewport.x, viewport.y, viewport.width, viewport.height),
-                        options: PresenterRenderOptions::new(false, render_mode),
-                        active: true,
-                        horizontal_align: PresenterHorizontalAlign::Center,
-                    }],
-                )));
+                let render_slots = vec![PresenterRenderSlot {
+                    area: Rect::new(viewport.x, viewport.y, viewport.width, viewport.height),
+                    options: PresenterRenderOptions::new(false, render_mode),
+                    active: true,
+                    horizontal_align: PresenterHorizontalAlign::Center,
+                }];
+                self.record_placements(prepared.page_crops(), &render_slots);
+                return Ok(Some((render_mode, render_slots)));
             }
         }
 
@@ -394,6 +466,7 @@ impl FrameCachePreparer<'_> {
                     let render_slots = prepared.render_slots(render_mode);
                     let presenter_slots = prepared.presenter_slots(self.generation);
                     self.presenter.prepare_slots(&presenter_slots)?;
+                    self.record_placements(prepared.page_crops(), &render_slots);
                     Ok(Some(render_slots))
                 }
                 CachePrepareResult::Miss => Ok(None),
@@ -418,13 +491,71 @@ impl FrameCachePreparer<'_> {
             let presenter_slots = prepared.presenter_slots(self.generation);
             self.presenter.prepare_slots(&presenter_slots)?;
             let options = PresenterRenderOptions::new(false, render_mode);
-            return Ok(Some(
-                slot_areas.render_slots_for_pages(draw_plan.visible_pages, options),
-            ));
+            let render_slots = slot_areas.render_slots_for_pages(draw_plan.visible_pages, options);
+            self.record_placements(prepared.page_crops(), &render_slots);
+            return Ok(Some(render_slots));
         }
 
         Ok(None)
     }
+
+    fn prepare_continuous_from_cache(
+        &mut self,
+        clips: &[ContinuousPageClip],
+        scale: f32,
+    ) -> AppResult<Option<(PresenterRenderMode, Vec<PresenterRenderSlot>)>> {
+        let result = self.runtime.prepare_continuous_from_cache(
+            self.pdf,
+            ContinuousPrepareRequest {
+                clips,
+                scale,
+                cell_px: self.cell_px,
+                overlay: self.highlight_overlay,
+            },
+        )?;
+        let CachePrepareResult::Prepared(prepared) = result else {
+            return Ok(None);
+        };
+        let presenter_slots = prepared.presenter_slots(self.generation);
+        self.presenter.prepare_slots(&presenter_slots)?;
+        let render_mode = PresenterRenderMode::Full;
+        let render_slots: Vec<_> = clips
+            .iter()
+            .map(|clip| PresenterRenderSlot {
+                area: clip.area,
+                options: PresenterRenderOptions::new(false, render_mode),
+                active: true,
+                horizontal_align: clip.horizontal_align,
+            })
+            .collect();
+        self.record_placements(prepared.page_crops(), &render_slots);
+        Ok(Some((render_mode, render_slots)))
+    }
+
+    fn record_placements(
+        &mut self,
+        crops: Vec<Option<PageCrop>>,
+        render_slots: &[PresenterRenderSlot],
+    ) {
+        let cell_px = resolved_cell_size_px(self.cell_px);
+        self.placements = crops
+            .into_iter()
+            .zip(render_slots)
+            .filter(|(_, slot)| slot.active)
+            .filter_map(|(crop, slot)| {
+                let crop = crop?;
+                let area = fitted_image_area(
+                    crop.width_px,
+                    crop.height_px,
+                    cell_px,
+                    slot.area,
+                    slot.horizontal_align,
+                    slot.options.is_initial_preview(),
+                );
+                Some(PageCellPlacement { crop, area })
+            })
+            .collect();
+    }
 }
 
 impl RenderSubsystem {
@@ -461,6 +592,7 @@ impl RenderSubsystem {
         draw_plan: RenderFrameDrawPlan,
     ) -> AppResult<RenderFrameFeedback> {
         let requested_pan = draw_plan.pan;
+        let mut pan = requested_pan;
         let mut render_failed = false;
         let mut render_feedback = PresenterFeedback::None;
         let mut viewer_has_image = self.viewer_has_image;
@@ -477,156 +609,246 @@ impl RenderSubsystem {
                 &draw_plan.status_bar_segments,
             );
 
-            let viewport = Viewport {
-                x: layout.viewer_inner.x,
-                y: layout.viewer_inner.y,
-                width: layout.viewer_inner.width.max(1),
-                height: layout.viewer_inner.height.max(1),
-            };
             let image_area = layout.viewer_inner;
-            let spread_slot_areas = split_spread_slot_areas(image_area, SPREAD_GAP_CELLS);
-
-            let prepare_result = {
-                let mut preparer = FrameCachePreparer {
-                    runtime: &mut self.runtime,
-                    presenter: self.presenter.as_mut(),
+            if draw_plan.view_mode == PageViewMode::Text {
+                pan = self.text_view.draw(
+                    frame,
+                    image_area,
                     pdf,
-                    cell_px: draw_plan.presenter_cell_px,
-                    highlight_overlay: &draw_plan.highlight_overlay,
-                    generation: draw_plan.generation,
+                    draw_plan.visible_pages,
+                    &draw_plan.chrome.page_labels,
+                    &draw_plan.highlight_overlay,
+                    requested_pan,
+                );
+                viewer_has_image = false;
+            } else {
+                let viewport = Viewport {
+                    x: layout.viewer_inner.x,
+                    y: layout.viewer_inner.y,
+                    width: layout.viewer_inner.width.max(1),
+                    height: layout.viewer_inner.height.max(1),
                 };
-                match draw_plan.page_presentation {
-                    PageLayoutMode::Single => preparer.prepare_single_page_or_preview_from_cache(
-                        viewport,
+                let spread_slot_areas = split_spread_slot_areas(image_area, SPREAD_GAP_CELLS);
+                let continuous_layout = if draw_plan.page_presentation == PageLayoutMode::Continuous
+                {
+                    let layout = continuous::layout_for_document(
+                        pdf,
                         draw_plan.visible_pages.anchor_page,
-                        draw_plan.current_scale,
-                        draw_plan.initial_preview.as_ref(),
                         requested_pan,
-                        draw_plan.enable_crop,
-                    ),
-                    PageLayoutMode::Spread => preparer.prepare_spread_or_preview_from_cache(
                         viewport,
-                        spread_slot_areas,
-                        &draw_plan,
-                        requested_pan,
-                    ),
-                }
-            };
+                        draw_plan.presenter_cell_px,
+                        draw_plan.current_scale,
+                    );
+                    // Pan is only rebased together with the current page, between frames.
+                    if layout.dominant_page == draw_plan.visible_pages.anchor_page {
+                        pan = layout.pan;
+                    }
+                    layout
+                } else {
+                    ContinuousLayout::default()
+                };
+                let continuous_pages: Vec<_> = continuous_layout.pages().map(Some).collect();
 
-            match prepare_result {
-                Ok(Some((render_mode, spread_render_slots))) => {
-                    let options = PresenterRenderOptions {
-                        render_mode,
-                        ..draw_plan.render_options
+                let (prepare_result, placements) = {
+                    let mut preparer = FrameCachePreparer {
+                        runtime: &mut self.runtime,
+                        presenter: self.presenter.as_mut(),
+                        pdf,
+                        cell_px: draw_plan.presenter_cell_px,
+                        highlight_overlay: &draw_plan.highlight_overlay,
+                        generation: draw_plan.generation,
+                        placements: Vec::new(),
                     };
-                    let render_result = match draw_plan.page_presentation {
-                        PageLayoutMode::Single => {
-                            let render_slots: Vec<_> = spread_render_slots
-                                .into_iter()
-                                .map(|slot| PresenterRenderSlot { options, ..slot })
-                                .collect();
-                            self.presenter.render_slots(frame, &render_slots)
-                        }
-                        PageLayoutMode::Spread => {
-                            spread_slot_areas.clear_gap(frame);
-                            let render_slots: Vec<_> = spread_render_slots
-                                .into_iter()
-                                .map(|slot| PresenterRenderSlot { options, ..slot })
-                                .collect();
-                            self.presenter.render_slots(frame, &render_slots)
-                        }
+                    let result = match draw_plan.page_presentation {
+                        PageLayoutMode::Single => preparer
+                            .prepare_single_page_or_preview_from_cache(
+                                viewport,
+                                draw_plan.visible_pages.anchor_page,
+                                draw_plan.current_scale,
+                                draw_plan.initial_preview.as_ref(),
+                                requested_pan,
+                                draw_plan.enable_crop,
+                            ),
+                        PageLayoutMode::Spread => preparer.prepare_spread_or_preview_from_cache(
+                            viewport,
+                            spread_slot_areas,
+                            &draw_plan,
+                            requested_pan,
+                        ),
+                        PageLayoutMode::Continuous => preparer.prepare_continuous_from_cache(
+                            &continuous_layout.clips,
+                            draw_plan.current_scale,
+                        ),
                     };
-                    match render_result {
-                        Ok(outcome) => {
-                            let outcome = normalize_render_outcome(render_mode, outcome);
-                            render_feedback = outcome.feedback;
-                            if outcome.drew_image {
-                                viewer_has_image = true;
+                    (result, preparer.placements)
+                };
+
+                match prepare_result {
+                    Ok(Some((render_mode, spread_render_slots))) => {
+                        let options = PresenterRenderOptions {
+                            render_mode,
+                            ..draw_plan.render_options
+                        };
+                        let render_result = match draw_plan.page_presentation {
+                            PageLayoutMode::Single => {
+                                let render_slots: Vec<_> = spread_render_slots
+                                    .into_iter()
+                                    .map(|slot| PresenterRenderSlot { options, ..slot })
+                                    .collect();
+                                self.presenter.render_slots(frame, &render_slots)
                             }
-                            let allow_viewer_loading =
-                                draw_plan.page_presentation == PageLayoutMode::Single;
-                            draw_viewer_outcome(
-                                frame,
-                                image_area,
-                                &outcome,
-                                draw_plan.loading_label.as_str(),
-                                None,
-                                viewer_has_image,
-                                allow_viewer_loading,
-                            );
-                            if draw_plan.page_presentation == PageLayoutMode::Spread {
+                            PageLayoutMode::Spread => {
+                                spread_slot_areas.clear_gap(frame);
+                                let render_slots: Vec<_> = spread_render_slots
+                                    .into_iter()
+                                    .map(|slot| PresenterRenderSlot { options, ..slot })
+                                    .collect();
+                                self.presenter.render_slots(frame, &render_slots)
+                            }
+                            PageLayoutMode::Continuous => {
+                                clear_continuous_gaps(frame, image_area, &continuous_layout);
+                                let render_slots: Vec<_> = spread_render_slots
+                                    .into_iter()
+                                    .map(|slot| PresenterRenderSlot { options, ..slot })
+                                    .collect();
+                                self.presenter.render_slots(frame, &render_slots)
+                            }
+                        };
+                        match render_result {
+                            Ok(outcome) => {
+                                let outcome = normalize_render_outcome(render_mode, outcome);
+                                render_feedback = outcome.feedback;
+                                if outcome.drew_image {
+                                    viewer_has_image = true;
+                                }
+                                let allow_viewer_loading =
+                                    draw_plan.page_presentation == PageLayoutMode::Single;
+                                draw_viewer_outcome(
+                                    frame,
+                                    image_area,
+                                    &outcome,
+                                    draw_plan.loading_label.as_str(),
+                                    None,
+                                    viewer_has_image,
+                                    allow_viewer_loading,
+                                );
+                                match draw_plan.page_presentation {
+                                    PageLayoutMode::Single => {}
+                                    PageLayoutMode::Spread => draw_spread_loading_overlays(
+                                        frame,
+                                        &outcome,
+                                        draw_plan.visible_pages,
+                                    ),
+                                    PageLayoutMode::Continuous => draw_loading_overlays(
+                                        frame,
+                                        slot_loading_overlays(&outcome, &continuous_pages),
+                                    ),
+                                }
+                                draw_link_hints(
+                                    frame,
+                                    image_area,
+                                    pdf,
+                                    &placements,
+                                    &draw_plan.link_hints,
+                                );
+                            }
+                            Err(err) => {
+                                let _ = err;
+                                render_failed = true;
+                                let outcome = PresenterRenderOutcome::failed();
+                                draw_viewer_outcome(
+                                    frame,
+                                    image_area,
+                                    &outcome,
+                                    draw_plan.loading_label.as_str(),
+                                    Some(draw_plan.render_target.as_str()),
+                                    viewer_has_image,
+                                    true,
+                                );
+                            }
+                        }
+                    }
+                    Ok(None) => {
+                        render_feedback = PresenterFeedback::Pending;
+                        let outcome = match draw_plan.page_presentation {
+                            PageLayoutMode::Single => PresenterRenderOutcome {
+                                slots: vec![PresenterSlotOutcome::active(
+                                    image_area,
+                                    false,
+                                    PresenterFeedback::Pending,
+                                    false,
+                                )],
+                                ..PresenterRenderOutcome::pending()
+                            },
+                            PageLayoutMode::Spread => pending_spread_outcome(
+                                spread_slot_areas,
+                                draw_plan.visible_pages,
+                                PresenterFeedback::Pending,
+                            ),
+                            PageLayoutMode::Continuous => PresenterRenderOutcome::aggregate_slots(
+                                continuous_layout
+                                    .clips
+                                    .iter()
+                                    .map(|clip| {
+                                        PresenterSlotOutcome::active(
+                                            clip.area,
+                                            false,
+                                            PresenterFeedback::Pending,
+                                            false,
+                                        )
+                                    })
+                                    .collect(),
+                            ),
+                        };
+                        let allow_viewer_loading =
+                            draw_plan.page_presentation == PageLayoutMode::Single;
+                        match draw_plan.page_presentation {
+                            PageLayoutMode::Single => {}
+                            PageLayoutMode::Spread => {
+                                clear_pending_spread_regions(frame, spread_slot_areas, &outcome);
+                            }
+                            PageLayoutMode::Continuous => {
+                                clear_continuous_gaps(frame, image_area, &continuous_layout);
+                            }
+                        }
+                        draw_viewer_outcome(
+                            frame,
+                            image_area,
+                            &outcome,
+                            draw_plan.loading_label.as_str(),
+                            None,
+                            viewer_has_image,
+                            allow_viewer_loading,
+                        );
+                        match draw_plan.page_presentation {
+                            PageLayoutMode::Single => {}
+                            PageLayoutMode::Spread => {
                                 draw_spread_loading_overlays(
                                     frame,
                                     &outcome,
                                     draw_plan.visible_pages,
                                 );
                             }
-                        }
-                        Err(err) => {
-                            let _ = err;
-                            render_failed = true;
-                            let outcome = PresenterRenderOutcome::failed();
-                            draw_viewer_outcome(
+                            PageLayoutMode::Continuous => draw_loading_overlays(
                                 frame,
-                                image_area,
-                                &outcome,
-                                draw_plan.loading_label.as_str(),
-                                Some(draw_plan.render_target.as_str()),
-                                viewer_has_image,
-                                true,
-                            );
+                                slot_loading_overlays(&outcome, &continuous_pages),
+                            ),
                         }
                     }
-                }
-                Ok(None) => {
-                    render_feedback = PresenterFeedback::Pending;
-                    let outcome = match draw_plan.page_presentation {
-                        PageLayoutMode::Single => PresenterRenderOutcome {
-                            slots: vec![PresenterSlotOutcome::active(
-                                image_area,
-                                false,
-                                PresenterFeedback::Pending,
-                                false,
-                            )],
-                            ..PresenterRenderOutcome::pending()
-                        },
-                        PageLayoutMode::Spread => pending_spread_outcome(
-                            spread_slot_areas,
-                            draw_plan.visible_pages,
-                            PresenterFeedback::Pending,
-                        ),
-                    };
-                    let allow_viewer_loading =
-                        draw_plan.page_presentation == PageLayoutMode::Single;
-                    if draw_plan.page_presentation == PageLayoutMode::Spread {
-                        clear_pending_spread_regions(frame, spread_slot_areas, &outcome);
+                    Err(err) => {
+                        let _ = err;
+                        render_failed = true;
+                        let outcome = PresenterRenderOutcome::failed();
+                        draw_viewer_outcome(
+                            frame,
+                            image_area,
+                            &outcome,
+                            draw_plan.loading_label.as_str(),
+                            Some(draw_plan.render_target.as_str()),
+                            viewer_has_image,
+                            true,
+                        );
                     }
-                    draw_viewer_outcome(
-                        frame,
-                        image_area,
-                        &outcome,
-                        draw_plan.loading_label.as_str(),
-                        None,
-                        viewer_has_image,
-                        allow_viewer_loading,
-                    );
-                    if draw_plan.page_presentation == PageLayoutMode::Spread {
-                        draw_spread_loading_overlays(frame, &outcome, draw_plan.visible_pages);
-                    }
-                }
-                Err(err) => {
-                    let _ = err;
-                    render_failed = true;
-                    let outcome = PresenterRenderOutcome::failed();
-                    draw_viewer_outcome(
-                        frame,
-                        image_area,
-                        &outcome,
-                        draw_plan.loading_label.as_str(),
-                        Some(draw_plan.render_target.as_str()),
-                        viewer_has_image,
-                        true,
-                    );
                 }
             }
 
@@ -641,12 +863,15 @@ impl RenderSubsystem {
                     &draw_plan.help_keymap,
                 );
             }
+            if let Some(info) = draw_plan.document_info.as_ref() {
+                ui::draw_info_overlay(frame, image_area, &info.rows);
+            }
         });
         draw_result
             .map_err(|source| AppError::io_with_context(source, "drawing terminal frame"))?;
 
         Ok(RenderFrameFeedback {
-            pan: requested_pan,
+            pan,
             render_failed,
             render_feedback,
             viewer_has_image,
@@ -671,6 +896,54 @@ impl RenderSubsystem {
     }
 }
 
+/// Blanks the viewer rows no page covers: the gaps between pages and the space past the last one.
+fn clear_continuous_gaps(
+    frame: &mut ratatui::Frame<'_>,
+    image_area: Rect,
+    layout: &ContinuousLayout,
+) {
+    for y in image_area.top()..image_area.bottom() {
+        if !layout
+            .clips
+            .iter()
+            .any(|clip| (clip.area.top()..clip.area.bottom()).contains(&y))
+        {
+            frame.render_widget(
+                ratatui::widgets::Clear,
+                Rect::new(image_area.x, y, image_area.width, 1),
+            );
+        }
+    }
+}
+
+fn draw_link_hints(
+    frame: &mut ratatui::Frame<'_>,
+    area: Rect,
+    pdf: &dyn PdfBackend,
+    placements: &[PageCellPlacement],
+    hints: &[LinkHintView],
+) {
+    if hints.is_empty() {
+        return;
+    }
+    let labels: Vec<_> = hints
+        .iter()
+        .filter_map(|hint| {
+            let page_size_pt = pdf.page_dimensions(hint.page).ok()?;
+            let cells = placements
+                .iter()
+                .filter(|placement| placement.crop.page == hint.page)
+                .find_map(|placement| placement.page_rect_to_cells(hint.rect, page_size_pt))?;
+            Some(ui::LinkHintLabel {
+                anchor: cells,
+                label: &hint.label,
+                typed_len: hint.typed_len,
+            })
+        })
+        .collect();
+    ui::draw_link_hints(frame, area, &labels);
+}
+
 fn resolve_layout_dimensions(
     pdf: &dyn PdfBackend,
     page_presentation: PageLayoutMode,
@@ -681,7 +954,7 @@ fn resolve_layout_dimensions(
         .unwrap_or(DEFAULT_PAGE_SIZE_PT);
     match slots.trailing_page {
         None => match page_presentation {
-            PageLayoutMode::Single => (anchor_width, anchor_height),
+            PageLayoutMode::Single | PageLayoutMode::Continuous => (anchor_width, anchor_height),
             // Tail spread still reserves a blank partner slot, so the scale
             // stays consistent with regular spread slot layout.
             PageLayoutMode::Spread => (anchor_width + anchor_width, anchor_height),
@@ -702,13 +975,18 @@ pub(super) fn compute_initial_preview_plan(
     page_presentation: PageLayoutMode,
     current_scale: f32,
 ) -> Option<InitialPreviewPlan> {
+    // Continuous pages are cropped to cell rows at the full scale, so a smaller preview frame
+    // would not line up with them.
+    if page_presentation == PageLayoutMode::Continuous {
+        return None;
+    }
     let preview_scale = quantize_scale(current_scale * INITIAL_PREVIEW_SCALE_RATIO);
     if scale_eq(preview_scale, current_scale) {
         return None;
     }
 
     let page_keys = match page_presentation {
-        PageLayoutMode::Single => vec![RenderedPageKey::new(
+        PageLayoutMode::Single | PageLayoutMode::Continuous => vec![RenderedPageKey::new(
             doc_id,
             visible_pages.anchor_page,
             preview_scale,
@@ -735,7 +1013,7 @@ mod tests {
     use std::path::{Path, PathBuf};
 
     use crate::app::{PageLayoutMode, VisiblePageSlots};
-    use crate::backend::{PdfBackend, RgbaFrame, TextPage};
+    use crate::backend::{PdfBackend, RgbaFrame, TextLayout, TextPage};
     use crate::render::cache::RenderedPageKey;
 
     struct DimPdf {
@@ -791,6 +1069,7 @@ mod tests {
                 height_pt,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
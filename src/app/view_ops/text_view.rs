@@ -0,0 +1,347 @@
+use std::sync::Arc;
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+use unicode_width::UnicodeWidthChar;
+
+use crate::app::state::VisiblePageSlots;
+use crate::backend::{PageLabels, PdfBackend, PdfRect, TextPage};
+use crate::error::AppResult;
+use crate::highlight::{HighlightOverlaySnapshot, HighlightSource};
+use crate::presenter::PanOffset;
+use crate::ui;
+
+/// Extracted pages kept around so paging back and forth does not re-run extraction.
+const MAX_CACHED_TEXT_PAGES: usize = 8;
+
+/// Extracted text for recently shown pages and the reflowed lines last drawn from it.
+#[derive(Default)]
+pub(crate) struct TextViewCache {
+    doc_id: Option<u64>,
+    /// Least recently used first.
+    pages: Vec<(usize, Arc<TextPage>)>,
+    reflow: Option<Reflow>,
+    /// Pages the scroll offset belongs to; a different set starts from the top again.
+    scrolled_pages: Option<[Option<usize>; 2]>,
+}
+
+struct Reflow {
+    key: ReflowKey,
+    lines: Vec<Line<'static>>,
+    first_hit_line: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReflowKey {
+    doc_id: u64,
+    pages: [Option<usize>; 2],
+    width: u16,
+    overlay_stamp: u64,
+}
+
+/// One reflowable source line: words of `(char, highlighted)` pairs.
+type SourceLine = Vec<Vec<(char, bool)>>;
+
+impl TextViewCache {
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Draws the visible pages' text into `area` and returns the pan clamped to it. Text view
+    /// only scrolls vertically, one cell per line.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn draw(
+        &mut self,
+        frame: &mut Frame<'_>,
+        area: Rect,
+        pdf: &dyn PdfBackend,
+        visible_pages: VisiblePageSlots,
+        labels: &PageLabels,
+        overlay: &HighlightOverlaySnapshot,
+        pan: PanOffset,
+    ) -> PanOffset {
+        frame.render_widget(Clear, area);
+        if area.width == 0 || area.height == 0 {
+            return PanOffset::default();
+        }
+
+        let pages = visible_pages.existing_pages();
+        let key = ReflowKey {
+            doc_id: pdf.doc_id(),
+            pages,
+            width: area.width,
+            overlay_stamp: overlay.stamp,
+        };
+        self.ensure_reflow(pdf, key, labels, overlay);
+        let Some(reflow) = self.reflow.as_ref() else {
+            return PanOffset::default();
+        };
+
+        let height = usize::from(area.height);
+        let max_scroll = reflow.lines.len().saturating_sub(height);
+        let scroll = if self.scrolled_pages == Some(pages) {
+            usize::try_from(pan.cells_y.max(0)).unwrap_or(usize::MAX)
+        } else {
+            // Land on the first search hit when it would otherwise start below the fold.
+            reflow
+                .first_hit_line
+                .filter(|line| *line >= height)
+                .map_or(0, |line| line - height / 3)
+        }
+        .min(max_scroll);
+        self.scrolled_pages = Some(pages);
+
+        let lines = reflow.lines[scroll..(scroll + height).min(reflow.lines.len())].to_vec();
+        frame.render_widget(Paragraph::new(lines).style(ui::primary_text()), area);
+
+        PanOffset {
+            cells_x: 0,
+            cells_y: i32::try_from(scroll).unwrap_or(i32::MAX),
+        }
+    }
+
+    fn ensure_reflow(
+        &mut self,
+        pdf: &dyn PdfBackend,
+        key: ReflowKey,
+        labels: &PageLabels,
+        overlay: &HighlightOverlaySnapshot,
+    ) {
+        if self.doc_id != Some(key.doc_id) {
+            self.clear();
+            self.doc_id = Some(key.doc_id);
+        }
+        if self.reflow.as_ref().is_some_and(|reflow| reflow.key == key) {
+            return;
+        }
+
+        let mut lines = Vec::new();
+        let mut first_hit_line = None;
+        let spread = key.pages[1].is_some();
+        for page in key.pages.into_iter().flatten() {
+            if spread {
+                if !lines.is_empty() {
+                    lines.push(Line::default());
+                }
+                lines.push(Line::styled(page_heading(page, labels), ui::heading_text()));
+            }
+            match self.text_page(pdf, page) {
+                Ok(text) => {
+                    let hits = search_hit_rects(overlay, page);
+                    let source = source_lines(&text, &hits);
+                    if source.is_empty() {
+                        lines.push(Line::styled("No text on this page.", ui::secondary_text()));
+                    }
+                    for words in &source {
+                        let start = lines.len();
+                        wrap_words(words, usize::from(key.width), &mut lines);
+                        if first_hit_line.is_none() && words.iter().flatten().any(|(_, hit)| *hit) {
+                            first_hit_line = Some(start);
+                        }
+                    }
+                }
+                Err(_) => lines.push(Line::styled(
+                    format!("Could not extract text from page {}.", page + 1),
+                    ui::error_text(),
+                )),
+            }
+        }
+        self.reflow = Some(Reflow {
+            key,
+            lines,
+            first_hit_line,
+        });
+    }
+
+    fn text_page(&mut self, pdf: &dyn PdfBackend, page: usize) -> AppResult<Arc<TextPage>> {
+        if let Some(index) = self.pages.iter().position(|(cached, _)| *cached == page) {
+            let entry = self.pages.remove(index);
+            let text = Arc::clone(&entry.1);
+            self.pages.push(entry);
+            return Ok(text);
+        }
+
+        let text = Arc::new(pdf.extract_text_page(page)?);
+        if self.pages.len() >= MAX_CACHED_TEXT_PAGES {
+            self.pages.remove(0);
+        }
+        self.pages.push((page, Arc::clone(&text)));
+        Ok(text)
+    }
+}
+
+fn page_heading(page: usize, labels: &PageLabels) -> String {
+    match labels.distinct(page) {
+        Some(label) => format!("p.{label}"),
+        None => format!("p.{}", page + 1),
+    }
+}
+
+fn search_hit_rects(overlay: &HighlightOverlaySnapshot, page: usize) -> Vec<PdfRect> {
+    overlay
+        .spans
+        .iter()
+        .filter(|span| span.source == HighlightSource::Search && span.page == page)
+        .flat_map(|span| span.rects.iter().copied())
+        .collect()
+}
+
+/// Splits the page into reading-order lines of words, with a blank line between blocks.
+fn source_lines(page: &TextPage, hits: &[PdfRect]) -> Vec<SourceLine> {
+    let is_hit = |bbox: Option<PdfRect>| {
+        bbox.is_some_and(|bbox| {
+            let (x, y) = ((bbox.x0 + bbox.x1) / 2.0, (bbox.y0 + bbox.y1) / 2.0);
+            hits.iter()
+                .any(|hit| hit.x0 <= x && x <= hit.x1 && hit.y0 <= y && y <= hit.y1)
+        })
+    };
+
+    if page.layout.is_empty() {
+        return page
+            .reading_order_text()
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|word| word.chars().map(|ch| (ch, false)).collect())
+                    .collect()
+            })
+            .collect();
+    }
+
+    let mut lines = Vec::new();
+    for block in &page.layout.blocks {
+        if !lines.is_empty() {
+            lines.push(Vec::new());
+        }
+        for line in page.layout.block_lines(block) {
+            lines.push(
+                page.layout
+                    .line_words(line)
+                    .iter()
+                    .map(|word| {
+                        page.glyphs[word.glyphs.clone()]
+                            .iter()
+                            .map(|glyph| (glyph.ch, is_hit(glyph.bbox)))
+                            .collect()
+                    })
+                    .collect(),
+            );
+        }
+    }
+    lines
+}
+
+/// Greedily wraps `words` to `width` columns, breaking inside words only when one is wider than
+/// the viewport. The space between two highlighted words is highlighted too, so phrase hits read
+/// as one run.
+fn wrap_words(words: &SourceLine, width: usize, out: &mut Vec<Line<'static>>) {
+    let width = width.max(1);
+    let mut builder = LineBuilder::default();
+    let mut previous_hit = false;
+    for word in words {
+        let word_width: usize = word.iter().map(|(ch, _)| char_width(*ch)).sum();
+        if builder.width > 0 {
+            if builder.width + 1 + word_width <= width {
+                let joined = previous_hit && word.first().is_some_and(|(_, hit)| *hit);
+                builder.push(' ', joined);
+            } else {
+                out.push(builder.finish());
+            }
+        }
+        for &(ch, hit) in word {
+            if builder.width > 0 && builder.width + char_width(ch) > width {
+                out.push(builder.finish());
+            }
+            builder.push(ch, hit);
+        }
+        previous_hit = word.last().is_some_and(|(_, hit)| *hit);
+    }
+    out.push(builder.finish());
+}
+
+fn char_width(ch: char) -> usize {
+    ch.width().unwrap_or(0)
+}
+
+#[derive(Default)]
+struct LineBuilder {
+    spans: Vec<Span<'static>>,
+    run: String,
+    run_hit: bool,
+    width: usize,
+}
+
+impl LineBuilder {
+    fn push(&mut self, ch: char, hit: bool) {
+        if hit != self.run_hit && !self.run.is_empty() {
+            self.flush();
+        }
+        self.run_hit = hit;
+        self.run.push(ch);
+        self.width += char_width(ch);
+    }
+
+    fn flush(&mut self) {
+        let style = if self.run_hit {
+            ui::hit_highlight_text()
+        } else {
+            Style::default()
+        };
+        self.spans
+            .push(Span::styled(std::mem::take(&mut self.run), style));
+    }
+
+    fn finish(&mut self) -> Line<'static> {
+        if !self.run.is_empty() {
+            self.flush();
+        }
+        self.width = 0;
+        Line::from(std::mem::take(&mut self.spans))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::text::Line;
+
+    use super::{SourceLine, wrap_words};
+    use crate::ui;
+
+    fn words(text: &str, hit: &str) -> SourceLine {
+        text.split(' ')
+            .map(|word| word.chars().map(|ch| (ch, word == hit)).collect())
+            .collect()
+    }
+
+    fn plain(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn wrap_words_breaks_at_word_boundaries_and_splits_overlong_words() {
+        let mut lines = Vec::new();
+        wrap_words(&words("the quick brown fox", ""), 10, &mut lines);
+        wrap_words(&words("abcdefghijkl", ""), 5, &mut lines);
+
+        let text: Vec<_> = lines.iter().map(plain).collect();
+        assert_eq!(text, ["the quick", "brown fox", "abcde", "fghij", "kl"]);
+    }
+
+    #[test]
+    fn wrap_words_styles_search_hits() {
+        let mut lines = Vec::new();
+        wrap_words(&words("find the needle here", "needle"), 80, &mut lines);
+
+        let spans = &lines[0].spans;
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1].content, "needle");
+        assert_eq!(spans[1].style, ui::hit_highlight_text());
+        assert_eq!(spans[2].content, " here");
+    }
+}
@@ -78,14 +78,24 @@ pub(super) fn spread_loading_overlays(
     outcome: &PresenterRenderOutcome,
     visible_pages: VisiblePageSlots,
 ) -> Vec<(ratatui::layout::Rect, String)> {
-    let pages = [visible_pages.left_page, visible_pages.right_page];
+    slot_loading_overlays(
+        outcome,
+        &[visible_pages.left_page, visible_pages.right_page],
+    )
+}
+
+/// Loading labels for pending slots, where `pages` lists the page shown in each slot.
+pub(super) fn slot_loading_overlays(
+    outcome: &PresenterRenderOutcome,
+    pages: &[Option<usize>],
+) -> Vec<(ratatui::layout::Rect, String)> {
     outcome
         .slots
         .iter()
         .zip(pages)
         .filter_map(|(slot, page)| {
             (slot.active && slot.feedback == PresenterFeedback::Pending)
-                .then_some((slot.area, format_page_target(page?)))
+                .then_some((slot.area, format_page_target((*page)?)))
         })
         .collect()
 }
@@ -95,7 +105,14 @@ pub(super) fn draw_spread_loading_overlays(
     outcome: &PresenterRenderOutcome,
     visible_pages: VisiblePageSlots,
 ) {
-    for (area, label) in spread_loading_overlays(outcome, visible_pages) {
+    draw_loading_overlays(frame, spread_loading_overlays(outcome, visible_pages));
+}
+
+pub(super) fn draw_loading_overlays(
+    frame: &mut ratatui::Frame<'_>,
+    overlays: Vec<(ratatui::layout::Rect, String)>,
+) {
+    for (area, label) in overlays {
         ui::draw_loading_overlay(frame, area, &label);
     }
 }
@@ -0,0 +1,254 @@
+use std::ops::RangeInclusive;
+
+use crate::backend::{PdfBackend, SharedPdfBackend};
+use crate::clipboard::{copy_to_clipboard, pipe_to_command};
+use crate::error::{AppError, AppResult};
+
+use super::core::App;
+use super::state::{NoticeAction, notice_action_for_error};
+
+/// Extracted text of several pages, with what could not be extracted.
+#[derive(Debug, Default, PartialEq)]
+struct PagesText {
+    text: String,
+    /// 1-based numbers of pages without a text layer.
+    empty_pages: Vec<usize>,
+    dropped_glyphs: usize,
+}
+
+/// A page yank that holds everything it needs, so extraction and the clipboard command can run
+/// away from the UI thread.
+pub(super) struct PagesYank {
+    pdf: SharedPdfBackend,
+    pages: Vec<usize>,
+    clipboard_command: Option<String>,
+}
+
+impl App {
+    /// Plans copying the text of the current page, or both pages of a spread.
+    pub(super) fn plan_visible_pages_yank(&self, pdf: SharedPdfBackend) -> PagesYank {
+        let pages = self
+            .state
+            .visible_page_slots(pdf.page_count())
+            .existing_pages()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        self.plan_pages_yank(pdf, pages)
+    }
+
+    /// Plans copying the text of the 1-based, inclusive page range `pages`.
+    pub(super) fn plan_page_range_yank(
+        &self,
+        pdf: SharedPdfBackend,
+        pages: RangeInclusive<usize>,
+    ) -> AppResult<PagesYank> {
+        let page_count = pdf.page_count();
+        if *pages.end() > page_count {
+            return Err(AppError::page_out_of_range(*pages.end(), page_count));
+        }
+        let pages = pages.map(|page| page - 1).collect();
+        Ok(self.plan_pages_yank(pdf, pages))
+    }
+
+    fn plan_pages_yank(&self, pdf: SharedPdfBackend, pages: Vec<usize>) -> PagesYank {
+        PagesYank {
+            pdf,
+            pages,
+            clipboard_command: self.clipboard_policy.command.clone(),
+        }
+    }
+
+    /// Writes the OSC 52 sequence a yank worker left to the event loop, which owns the terminal.
+    pub(super) fn finish_pages_yank(
+        &self,
+        notice: NoticeAction,
+        osc52_text: Option<String>,
+    ) -> NoticeAction {
+        let Some(text) = osc52_text else {
+            return notice;
+        };
+        match copy_to_clipboard(&text, None) {
+            Ok(()) => notice,
+            Err(err) => notice_action_for_error(err),
+        }
+    }
+}
+
+impl PagesYank {
+    /// Extracts the text and pipes it to the clipboard command; without one, the text comes back
+    /// with the notice for the event loop to copy over OSC 52.
+    pub(super) fn run(self) -> (NoticeAction, Option<String>) {
+        let extracted = match extract_pages_text(self.pdf.as_ref(), &self.pages) {
+            Ok(extracted) => extracted,
+            Err(err) => return (notice_action_for_error(err), None),
+        };
+        if extracted.text.is_empty() {
+            let notice = NoticeAction::warning(format!(
+                "no text layer on page {}",
+                page_list(&extracted.empty_pages)
+            ));
+            return (notice, None);
+        }
+        let notice = yank_notice(&extracted);
+        match self.clipboard_command.as_deref() {
+            Some(command) => match pipe_to_command(command, &extracted.text) {
+                Ok(()) => (notice, None),
+                Err(err) => (notice_action_for_error(err), None),
+            },
+            None => (notice, Some(extracted.text)),
+        }
+    }
+}
+
+/// Each page's text in reading order, pages separated by a blank line.
+fn extract_pages_text(pdf: &dyn PdfBackend, pages: &[usize]) -> AppResult<PagesText> {
+    let mut extracted = PagesText::default();
+    let mut texts = Vec::with_capacity(pages.len());
+    for &page in pages {
+        let text_page = pdf.extract_text_page(page)?;
+        extracted.dropped_glyphs += text_page.dropped_glyphs;
+        let text = text_page.reading_order_text();
+        if text.trim().is_empty() {
+            extracted.empty_pages.push(page + 1);
+            continue;
+        }
+        texts.push(text.trim_end().to_string());
+    }
+    extracted.text = texts.join("\n\n");
+    Ok(extracted)
+}
+
+fn yank_notice(extracted: &PagesText) -> NoticeAction {
+    let copied = format!("copied {} characters", extracted.text.chars().count());
+    let mut problems = Vec::new();
+    if !extracted.empty_pages.is_empty() {
+        problems.push(format!(
+            "no text layer on page {}",
+            page_list(&extracted.empty_pages)
+        ));
+    }
+    if extracted.dropped_glyphs > 0 {
+        problems.push(format!(
+            "{} glyphs had no text mapping",
+            extracted.dropped_glyphs
+        ));
+    }
+    if problems.is_empty() {
+        return NoticeAction::info(copied);
+    }
+    NoticeAction::warning(format!("{copied}; {}", problems.join("; ")))
+}
+
+fn page_list(pages: &[usize]) -> String {
+    pages
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::app::{NoticeAction, NoticeLevel};
+    use crate::backend::test_support::TextStubPdf;
+    use crate::backend::{
+        PdfRect, TextBlock, TextGlyph, TextLayout, TextLine, TextPage, TextWord, WritingDirection,
+    };
+
+    use super::{PagesText, PagesYank, extract_pages_text, yank_notice};
+
+    /// Stores the second line first in the glyph stream.
+    fn reordered_lines_page() -> TextPage {
+        let rect = PdfRect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 6.0,
+            y1: 10.0,
+        };
+        let line = |words| TextLine {
+            words,
+            bbox: Some(rect),
+            baseline: 10.0,
+            font_size: 10.0,
+            direction: WritingDirection::LeftToRight,
+        };
+        TextPage {
+            width_pt: 612.0,
+            height_pt: 792.0,
+            glyphs: "twoone"
+                .chars()
+                .map(|ch| TextGlyph {
+                    ch,
+                    bbox: Some(rect),
+                })
+                .collect(),
+            dropped_glyphs: 2,
+            layout: TextLayout {
+                words: vec![
+                    TextWord {
+                        glyphs: 3..6,
+                        bbox: Some(rect),
+                    },
+                    TextWord {
+                        glyphs: 0..3,
+                        bbox: Some(rect),
+                    },
+                ],
+                lines: vec![line(0..1), line(1..2)],
+                blocks: vec![TextBlock {
+                    lines: 0..2,
+                    bbox: Some(rect),
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn page_text_follows_lines_and_reports_missing_text() {
+        let pdf = TextStubPdf::blank(3).with_text_page(0, reordered_lines_page());
+
+        let extracted = extract_pages_text(&pdf, &[0, 1]).expect("extraction should succeed");
+
+        assert_eq!(
+            extracted,
+            PagesText {
+                text: "one\ntwo".to_string(),
+                empty_pages: vec![2],
+                dropped_glyphs: 2,
+            }
+        );
+        assert_eq!(
+            yank_notice(&extracted),
+            NoticeAction::warning(
+                "copied 7 characters; no text layer on page 2; 2 glyphs had no text mapping"
+            )
+        );
+    }
+
+    #[test]
+    fn yank_pipes_to_the_clipboard_command_or_leaves_osc52_to_the_caller() {
+        let pdf = Arc::new(TextStubPdf::new(&["first", ""]));
+        let yank = |clipboard_command: Option<&str>| PagesYank {
+            pdf: pdf.clone(),
+            pages: vec![0, 1],
+            clipboard_command: clipboard_command.map(str::to_string),
+        };
+        let copied = NoticeAction::warning("copied 5 characters; no text layer on page 2");
+
+        assert_eq!(yank(Some("cat")).run(), (copied.clone(), None));
+        assert_eq!(yank(None).run(), (copied, Some("first".to_string())));
+        assert!(matches!(
+            yank(Some("pvf-missing-clipboard-tool")).run(),
+            (
+                NoticeAction::Show {
+                    level: NoticeLevel::Error,
+                    ..
+                },
+                None
+            )
+        ));
+    }
+}
@@ -0,0 +1,23 @@
+pub mod palette;
+pub mod state;
+
+use crate::app::AppState;
+use crate::backend::SharedPdfBackend;
+use crate::extension::Extension;
+pub use palette::AttachmentsPaletteProvider;
+pub use state::{AttachmentsCommandPort, AttachmentsState, AttachmentsUiSnapshot};
+
+pub struct AttachmentsExtension;
+
+impl Extension for AttachmentsExtension {
+    type State = AttachmentsState;
+
+    fn init_state() -> Self::State {
+        AttachmentsState::default()
+    }
+
+    fn on_document_reloaded(state: &mut Self::State, app: &mut AppState, pdf: SharedPdfBackend) {
+        let _ = (app, pdf);
+        state.on_document_reloaded();
+    }
+}
@@ -0,0 +1,245 @@
+use std::path::Path;
+
+use crate::backend::{PageLabels, PdfAttachment};
+use crate::command::Command;
+use crate::error::AppResult;
+use crate::input::shortcut::format_shortcut_key;
+use crate::palette::{
+    PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PalettePayload,
+    PalettePostAction, PaletteProvider, PaletteSearchText, PaletteSubmitEffect, PaletteTextPart,
+};
+
+pub struct AttachmentsPaletteProvider;
+
+impl PaletteProvider for AttachmentsPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::Attachments
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Attachments".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::FilterCandidates
+    }
+
+    fn list(&self, ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok(ctx
+            .extensions
+            .attachments
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| attachment_candidate(index, entry, &ctx.app.page_labels))
+            .collect())
+    }
+
+    fn on_submit(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        let Some(index) = selected.and_then(|candidate| decode_payload(&candidate.payload)) else {
+            return Ok(PaletteSubmitEffect::Close);
+        };
+
+        Ok(PaletteSubmitEffect::Dispatch {
+            command: Command::SaveAttachment { index },
+            history_record: None,
+            next: PalettePostAction::Close,
+        })
+    }
+
+    fn assistive_text(
+        &self,
+        ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        let attachments = &ctx.extensions.attachments;
+        if attachments.entries.is_empty() {
+            return Some("No attachments in this document".to_string());
+        }
+
+        let enter = format_shortcut_key(crate::input::shortcut::ShortcutKey::key(
+            crossterm::event::KeyCode::Enter,
+        ));
+        let save = format!("{enter} save to {}", display_dir(&attachments.target_dir));
+        let description = selected
+            .and_then(|candidate| decode_payload(&candidate.payload))
+            .and_then(|index| attachments.entries.get(index))
+            .and_then(|entry| entry.description.as_deref());
+        Some(match description {
+            Some(description) => format!("{description} · {save}"),
+            None => save,
+        })
+    }
+}
+
+fn attachment_candidate(
+    index: usize,
+    entry: &PdfAttachment,
+    labels: &PageLabels,
+) -> PaletteCandidate {
+    let mut details = Vec::new();
+    if let Some(size) = entry.size {
+        details.push(format_size(size));
+    }
+    if let Some(mime_type) = &entry.mime_type {
+        details.push(mime_type.clone());
+    }
+    if let Some(page) = entry.page {
+        let label = labels
+            .get(page)
+            .map(str::to_string)
+            .unwrap_or_else(|| (page + 1).to_string());
+        details.push(format!("p.{label}"));
+    }
+
+    let mut search_texts = vec![PaletteSearchText::new(entry.name.clone())];
+    search_texts.extend(
+        [&entry.mime_type, &entry.description]
+            .into_iter()
+            .flatten()
+            .map(|text| PaletteSearchText::new(text.clone())),
+    );
+
+    PaletteCandidate {
+        id: format!("attachment-{index}"),
+        left: vec![PaletteTextPart::primary(entry.name.clone())],
+        right: vec![PaletteTextPart::secondary(details.join(" · "))],
+        search_texts,
+        payload: PalettePayload::Opaque(index.to_string()),
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn display_dir(dir: &Path) -> String {
+    if dir.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        dir.display().to_string()
+    }
+}
+
+fn decode_payload(payload: &PalettePayload) -> Option<usize> {
+    let PalettePayload::Opaque(payload) = payload else {
+        return None;
+    };
+    payload.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::backend::{PageLabels, PdfAttachment};
+    use crate::command::Command;
+    use crate::extension::ExtensionUiSnapshot;
+    use crate::palette::{
+        PaletteAppSnapshot, PaletteContext, PaletteKind, PaletteProvider, PaletteSubmitEffect,
+    };
+
+    use super::{AttachmentsPaletteProvider, format_size};
+
+    fn attachments_snapshot() -> ExtensionUiSnapshot {
+        ExtensionUiSnapshot {
+            attachments: crate::attachments::AttachmentsUiSnapshot {
+                entries: vec![
+                    PdfAttachment {
+                        name: "invoice.xml".to_string(),
+                        size: Some(2048),
+                        mime_type: Some("text/xml".to_string()),
+                        description: Some("Factur-X data".to_string()),
+                        page: None,
+                    },
+                    PdfAttachment {
+                        name: "notes.txt".to_string(),
+                        size: None,
+                        mime_type: None,
+                        description: None,
+                        page: Some(2),
+                    },
+                ]
+                .into(),
+                target_dir: PathBuf::from("out"),
+            },
+            ..ExtensionUiSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn list_shows_size_type_and_page_and_submit_saves_the_selection() {
+        let provider = AttachmentsPaletteProvider;
+        let extensions = attachments_snapshot();
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot {
+                page_labels: PageLabels::new(["i", "ii", "iii"].map(str::to_string).to_vec()),
+                ..PaletteAppSnapshot::default()
+            },
+            extensions: &extensions,
+            kind: PaletteKind::Attachments,
+            input: "",
+            open_payload: None,
+        };
+
+        let items = provider.list(&ctx).expect("attachments list should build");
+        assert_eq!(items[0].right[0].text, "2.0 KiB · text/xml");
+        assert_eq!(items[1].right[0].text, "p.iii");
+        assert_eq!(
+            provider.assistive_text(&ctx, Some(&items[0])).as_deref(),
+            Some("Factur-X data · <enter> save to out")
+        );
+
+        let effect = provider
+            .on_submit(&ctx, Some(&items[1]))
+            .expect("submit should succeed");
+        assert!(matches!(
+            effect,
+            PaletteSubmitEffect::Dispatch {
+                command: Command::SaveAttachment { index: 1 },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn empty_documents_explain_why_the_list_is_empty() {
+        let provider = AttachmentsPaletteProvider;
+        let extensions = ExtensionUiSnapshot::default();
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot::default(),
+            extensions: &extensions,
+            kind: PaletteKind::Attachments,
+            input: "",
+            open_payload: None,
+        };
+
+        assert!(provider.list(&ctx).expect("list should build").is_empty());
+        assert_eq!(
+            provider.assistive_text(&ctx, None).as_deref(),
+            Some("No attachments in this document")
+        );
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,218 @@
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::app::{NoticeAction, PaletteRequest};
+use crate::backend::{PdfAttachment, PdfBackend, SharedPdfBackend};
+use crate::command::CommandOutcome;
+use crate::error::{AppError, AppResult};
+use crate::palette::PaletteKind;
+
+struct AttachmentsCache {
+    doc_id: u64,
+    entries: Arc<[PdfAttachment]>,
+}
+
+#[derive(Default)]
+pub struct AttachmentsState {
+    cache: Option<AttachmentsCache>,
+    target_dir: PathBuf,
+}
+
+pub struct AttachmentsCommandPort<'a> {
+    state: &'a mut AttachmentsState,
+}
+
+impl<'a> AttachmentsCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut AttachmentsState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn open_palette(
+        &mut self,
+        pdf: SharedPdfBackend,
+        dir: Option<String>,
+    ) -> AppResult<PaletteRequest> {
+        self.state.open_palette(pdf, dir)
+    }
+
+    pub(crate) fn save(
+        &mut self,
+        pdf: &dyn PdfBackend,
+        index: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.save(pdf, index)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttachmentsUiSnapshot {
+    pub entries: Arc<[PdfAttachment]>,
+    pub target_dir: PathBuf,
+}
+
+impl AttachmentsState {
+    /// Loads the attachment list and remembers `dir` (default: the working directory) as the
+    /// save target for this palette session.
+    pub fn open_palette(
+        &mut self,
+        pdf: SharedPdfBackend,
+        dir: Option<String>,
+    ) -> AppResult<PaletteRequest> {
+        self.ensure_loaded(pdf.as_ref())?;
+        self.target_dir = dir.map(PathBuf::from).unwrap_or_default();
+        Ok(PaletteRequest::Open {
+            kind: PaletteKind::Attachments,
+            payload: None,
+        })
+    }
+
+    pub fn save(
+        &mut self,
+        pdf: &dyn PdfBackend,
+        index: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.ensure_loaded(pdf)?;
+        let Some(entry) = self.palette_entries().get(index).cloned() else {
+            return Err(AppError::invalid_argument(
+                "attachment index is out of range",
+            ));
+        };
+
+        let data = pdf.attachment_data(index)?;
+        let path = self.target_dir.join(safe_file_name(&entry.name));
+        write_new_file(&path, &data)?;
+        Ok((
+            CommandOutcome::Applied,
+            NoticeAction::info(format!("saved {} to {}", entry.name, path.display())),
+        ))
+    }
+
+    pub fn palette_entries(&self) -> Arc<[PdfAttachment]> {
+        self.cache
+            .as_ref()
+            .map(|cache| Arc::clone(&cache.entries))
+            .unwrap_or_else(|| Arc::from([]))
+    }
+
+    pub fn ui_snapshot(&self) -> AttachmentsUiSnapshot {
+        AttachmentsUiSnapshot {
+            entries: self.palette_entries(),
+            target_dir: self.target_dir.clone(),
+        }
+    }
+
+    pub fn on_document_reloaded(&mut self) {
+        self.cache = None;
+    }
+
+    fn ensure_loaded(&mut self, pdf: &dyn PdfBackend) -> AppResult<()> {
+        if self
+            .cache
+            .as_ref()
+            .is_some_and(|cache| cache.doc_id == pdf.doc_id())
+        {
+            return Ok(());
+        }
+
+        self.cache = Some(AttachmentsCache {
+            doc_id: pdf.doc_id(),
+            entries: Arc::from(pdf.attachments()?),
+        });
+        Ok(())
+    }
+}
+
+/// Keeps only the final path component so a crafted name cannot write outside the target dir.
+fn safe_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned = base
+        .chars()
+        .map(|ch| if ch.is_control() { '_' } else { ch })
+        .collect::<String>();
+    match cleaned.trim() {
+        "" | "." | ".." => "attachment".to_string(),
+        _ => cleaned,
+    }
+}
+
+fn write_new_file(path: &Path, data: &[u8]) -> AppResult<()> {
+    let context = format!("saving attachment to {}", path.display());
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|source| {
+            if source.kind() == ErrorKind::AlreadyExists {
+                AppError::invalid_argument(format!("{} already exists", path.display()))
+            } else {
+                AppError::io_with_context(source, context.clone())
+            }
+        })?;
+    file.write_all(data)
+        .map_err(|source| AppError::io_with_context(source, context))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+
+    use crate::app::NoticeAction;
+    use crate::backend::test_support::{TextStubPdf, unique_temp_path};
+    use crate::backend::{PdfAttachment, SharedPdfBackend};
+    use crate::command::CommandOutcome;
+    use crate::error::AppError;
+
+    use super::{AttachmentsState, safe_file_name};
+
+    #[test]
+    fn save_writes_into_target_dir_and_refuses_to_overwrite() {
+        let dir = unique_temp_path("attachments");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let pdf = Arc::new(
+            TextStubPdf::blank(1)
+                .with_path("attachments.pdf")
+                .with_attachment(
+                    PdfAttachment {
+                        name: "../report.csv".to_string(),
+                        size: Some(4),
+                        mime_type: Some("text/csv".to_string()),
+                        description: None,
+                        page: None,
+                    },
+                    b"a,b\n",
+                ),
+        ) as SharedPdfBackend;
+        let mut state = AttachmentsState::default();
+        state
+            .open_palette(Arc::clone(&pdf), Some(dir.display().to_string()))
+            .expect("palette should open");
+
+        let (outcome, notice) = state.save(pdf.as_ref(), 0).expect("save should succeed");
+        let saved = dir.join("report.csv");
+        assert_eq!(outcome, CommandOutcome::Applied);
+        assert_eq!(
+            notice,
+            NoticeAction::info(format!("saved ../report.csv to {}", saved.display()))
+        );
+        assert_eq!(fs::read(&saved).expect("file should exist"), b"a,b\n");
+
+        let again = state.save(pdf.as_ref(), 0);
+        let missing = state.save(pdf.as_ref(), 1);
+        fs::remove_dir_all(&dir).expect("temp dir should be removed");
+        assert!(
+            matches!(again, Err(AppError::InvalidArgument(message)) if message.contains("already exists"))
+        );
+        assert!(matches!(missing, Err(AppError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn safe_file_name_strips_directories_and_control_characters() {
+        assert_eq!(safe_file_name("dir/sub\\data.bin"), "data.bin");
+        assert_eq!(safe_file_name("a\nb.txt"), "a_b.txt");
+        assert_eq!(safe_file_name(".."), "attachment");
+        assert_eq!(safe_file_name(""), "attachment");
+    }
+}
@@ -0,0 +1,71 @@
+use hayro::hayro_syntax::Pdf;
+use hayro::hayro_syntax::object::dict::keys::{
+    ANNOTS, CONTENTS, CREATION_DATE, M, RECT, SUBTYPE, T,
+};
+use hayro::hayro_syntax::object::{Array, Dict, Name, Rect};
+
+use crate::backend::PdfAnnotation;
+use crate::error::{AppError, AppResult};
+
+use super::encoding::dict_text_entry;
+use super::links::page_space_rect;
+use super::metadata::parse_pdf_date;
+
+/// Markup annotation subtypes; links, widgets, popups and other non-markup kinds are skipped.
+const MARKUP_SUBTYPES: [&str; 17] = [
+    "Text",
+    "FreeText",
+    "Line",
+    "Square",
+    "Circle",
+    "Polygon",
+    "PolyLine",
+    "Highlight",
+    "Underline",
+    "Squiggly",
+    "StrikeOut",
+    "Stamp",
+    "Caret",
+    "Ink",
+    "FileAttachment",
+    "Sound",
+    "Redact",
+];
+
+pub(super) fn extract_page_annotations(pdf: &Pdf, page: usize) -> AppResult<Vec<PdfAnnotation>> {
+    let page_ref = pdf
+        .pages()
+        .get(page)
+        .ok_or(AppError::invalid_argument("page index is out of range"))?;
+    let Some(annots) = page_ref.raw().get::<Array<'_>>(ANNOTS) else {
+        return Ok(Vec::new());
+    };
+
+    let mut annotations = Vec::new();
+    for annot in annots.iter::<Dict<'_>>() {
+        let Some(subtype) = annot
+            .get::<Name<'_>>(SUBTYPE)
+            .map(|subtype| subtype.as_str().to_string())
+            .filter(|subtype| MARKUP_SUBTYPES.contains(&subtype.as_str()))
+        else {
+            continue;
+        };
+        let Some(rect) = annot.get::<Rect>(RECT) else {
+            continue;
+        };
+        let date = [M, CREATION_DATE]
+            .into_iter()
+            .filter_map(|key| dict_text_entry(&annot, key))
+            .find_map(|value| parse_pdf_date(&value));
+
+        annotations.push(PdfAnnotation {
+            subtype,
+            author: dict_text_entry(&annot, T),
+            date,
+            contents: dict_text_entry(&annot, CONTENTS),
+            rect: page_space_rect(page_ref, rect),
+        });
+    }
+
+    Ok(annotations)
+}
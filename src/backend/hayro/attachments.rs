@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use hayro::hayro_syntax::Pdf;
+use hayro::hayro_syntax::object::dict::keys::{
+    ANNOTS, CONTENTS, DESC, EF, EMBEDDED_FILES, F, FS, KIDS, NAMES, PARAMS, SIZE, SUBTYPE, UF,
+};
+use hayro::hayro_syntax::object::{Array, Dict, Name, Object, ObjectIdentifier, Stream};
+
+use crate::backend::PdfAttachment;
+use crate::error::{AppError, AppResult};
+
+use super::encoding::{decode_pdf_text_string, dict_text_entry};
+
+struct EmbeddedFile<'a> {
+    attachment: PdfAttachment,
+    stream: Stream<'a>,
+}
+
+pub(super) fn extract_attachments(pdf: &Pdf) -> Vec<PdfAttachment> {
+    collect_embedded_files(pdf)
+        .into_iter()
+        .map(|file| file.attachment)
+        .collect()
+}
+
+pub(super) fn extract_attachment_data(pdf: &Pdf, index: usize) -> AppResult<Vec<u8>> {
+    let file =
+        collect_embedded_files(pdf)
+            .into_iter()
+            .nth(index)
+            .ok_or(AppError::invalid_argument(
+                "attachment index is out of range",
+            ))?;
+    file.stream
+        .decoded()
+        .map(|bytes| bytes.into_owned())
+        .map_err(|_| {
+            AppError::unsupported(format!(
+                "failed to decode attachment {}",
+                file.attachment.name
+            ))
+        })
+}
+
+fn collect_embedded_files(pdf: &Pdf) -> Vec<EmbeddedFile<'_>> {
+    let mut files = Vec::new();
+    // A file shared by the name tree and an annotation is listed once, under its first source.
+    let mut seen_streams = HashSet::new();
+
+    let xref = pdf.xref();
+    if let Some(tree) = xref
+        .get::<Dict<'_>>(xref.root_id())
+        .and_then(|root| root.get::<Dict<'_>>(NAMES))
+        .and_then(|names| names.get::<Dict<'_>>(EMBEDDED_FILES))
+    {
+        let mut entries = Vec::new();
+        collect_name_tree_file_specs(tree, &mut entries, &mut HashSet::new());
+        for (key, spec) in entries {
+            if let Some(file) = embedded_file(&spec, Some(key), None, None) {
+                push_unique(&mut files, &mut seen_streams, file);
+            }
+        }
+    }
+
+    for (page, page_ref) in pdf.pages().iter().enumerate() {
+        let Some(annots) = page_ref.raw().get::<Array<'_>>(ANNOTS) else {
+            continue;
+        };
+        for annot in annots.iter::<Dict<'_>>() {
+            if annot
+                .get::<Name<'_>>(SUBTYPE)
+                .is_none_or(|subtype| subtype.as_str() != "FileAttachment")
+            {
+                continue;
+            }
+            let Some(spec) = annot.get::<Dict<'_>>(FS) else {
+                continue;
+            };
+            let contents = dict_text_entry(&annot, CONTENTS);
+            if let Some(file) = embedded_file(&spec, None, contents, Some(page)) {
+                push_unique(&mut files, &mut seen_streams, file);
+            }
+        }
+    }
+
+    files
+}
+
+fn push_unique<'a>(
+    files: &mut Vec<EmbeddedFile<'a>>,
+    seen_streams: &mut HashSet<ObjectIdentifier>,
+    file: EmbeddedFile<'a>,
+) {
+    if seen_streams.insert(file.stream.obj_id()) {
+        files.push(file);
+    }
+}
+
+fn collect_name_tree_file_specs<'a>(
+    node: Dict<'a>,
+    entries: &mut Vec<(String, Dict<'a>)>,
+    visited: &mut HashSet<ObjectIdentifier>,
+) {
+    if let Some(id) = node.obj_id()
+        && !visited.insert(id)
+    {
+        return;
+    }
+
+    if let Some(names) = node.get::<Array<'_>>(NAMES) {
+        let mut items = names.iter::<Object<'_>>();
+        while let (Some(key), Some(value)) = (items.next(), items.next()) {
+            if let (Object::String(key), Object::Dict(spec)) = (key, value) {
+                entries.push((decode_pdf_text_string(key.as_bytes()), spec));
+            }
+        }
+    }
+
+    if let Some(kids) = node.get::<Array<'_>>(KIDS) {
+        for kid in kids.iter::<Dict<'_>>() {
+            collect_name_tree_file_specs(kid, entries, visited);
+        }
+    }
+}
+
+fn embedded_file<'a>(
+    spec: &Dict<'a>,
+    tree_key: Option<String>,
+    annotation_contents: Option<String>,
+    page: Option<usize>,
+) -> Option<EmbeddedFile<'a>> {
+    let streams = spec.get::<Dict<'_>>(EF)?;
+    let stream = streams
+        .get::<Stream<'_>>(UF)
+        .or_else(|| streams.get::<Stream<'_>>(F))?;
+
+    // Prefer the Unicode file name, then the legacy one, then whatever the name tree calls it.
+    let name = dict_text_entry(spec, UF)
+        .or_else(|| dict_text_entry(spec, F))
+        .or(tree_key)
+        .unwrap_or_else(|| "attachment".to_string());
+    let params = stream.dict().get::<Dict<'_>>(PARAMS);
+    let size = params
+        .and_then(|params| params.get::<usize>(SIZE))
+        .or_else(|| stream.decoded().ok().map(|bytes| bytes.len()));
+    let mime_type = stream
+        .dict()
+        .get::<Name<'_>>(SUBTYPE)
+        .map(|subtype| subtype.as_str().to_string());
+
+    Some(EmbeddedFile {
+        attachment: PdfAttachment {
+            name,
+            size,
+            mime_type,
+            description: dict_text_entry(spec, DESC).or(annotation_contents),
+            page,
+        },
+        stream,
+    })
+}
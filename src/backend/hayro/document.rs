@@ -1,27 +1,38 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use bytemuck::allocation::cast_vec;
 use hayro::hayro_interpret::InterpreterSettings;
-use hayro::hayro_syntax::Pdf;
+use hayro::hayro_syntax::{DecryptionError, LoadPdfError, Pdf};
 use hayro::vello_cpu::color::palette::css::WHITE;
 use hayro::vello_cpu::{Pixmap, color::PremulRgba8};
 use hayro::{RenderCache, RenderSettings, render};
+use sha2::{Digest, Sha256};
 
-use crate::backend::{OutlineNode, RgbaFrame, TextPage};
+use crate::backend::{
+    OutlineNode, PageLabels, PdfAnnotation, PdfAttachment, PdfLink, PdfMetadata, RgbaFrame,
+    TextPage,
+};
 use crate::error::{AppError, AppResult};
 
 use super::PdfDoc;
-use super::outline::extract_outline_nodes;
+use super::annotations::extract_page_annotations;
+use super::attachments::{extract_attachment_data, extract_attachments};
+use super::links::extract_page_links;
+use super::metadata::extract_metadata;
+use super::outline::{DestinationIndex, extract_outline_nodes};
+use super::page_labels::extract_page_labels;
 use super::text::extract_text_page_with_device;
 
 impl PdfDoc {
     pub fn open(path: impl AsRef<Path>) -> AppResult<Self> {
+        Self::open_with_password(path, None)
+    }
+
+    pub fn open_with_password(path: impl AsRef<Path>, password: Option<&str>) -> AppResult<Self> {
         let path = path.as_ref();
         let bytes = Self::load_shared_bytes(path)?;
-        Self::open_with_shared_bytes(path, bytes)
+        Self::open_with_shared_bytes_and_password(path, bytes, password)
     }
 
     pub fn load_shared_bytes(path: impl AsRef<Path>) -> AppResult<Arc<Vec<u8>>> {
@@ -52,19 +63,31 @@ impl PdfDoc {
     }
 
     pub fn open_with_shared_bytes(path: impl AsRef<Path>, bytes: Arc<Vec<u8>>) -> AppResult<Self> {
+        Self::open_with_shared_bytes_and_password(path, bytes, None)
+    }
+
+    pub fn open_with_shared_bytes_and_password(
+        path: impl AsRef<Path>,
+        bytes: Arc<Vec<u8>>,
+        password: Option<&str>,
+    ) -> AppResult<Self> {
         let path = path.as_ref();
         if !bytes.as_slice().starts_with(b"%PDF-") {
             return Err(AppError::invalid_argument(
                 "input is not a valid PDF header",
             ));
         }
-        let doc_id = calculate_doc_id(path, bytes.as_slice());
-        let pdf = Pdf::new(bytes)
-            .map_err(|_| AppError::invalid_argument("failed to parse PDF with hayro"))?;
+        let doc_id = calculate_doc_id(bytes.as_slice());
+        let pdf = Pdf::new_with_password(bytes, password.unwrap_or(""))
+            .map_err(|err| load_error_to_app_error(err, password.is_some()))?;
+        let page_labels = extract_page_labels(&pdf);
 
         Ok(Self {
             path: path.to_path_buf(),
             doc_id,
+            password: password.map(str::to_string),
+            page_labels,
+            destinations: OnceLock::new(),
             pdf,
         })
     }
@@ -77,6 +100,14 @@ impl PdfDoc {
         self.doc_id
     }
 
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    pub fn page_labels(&self) -> PageLabels {
+        self.page_labels.clone()
+    }
+
     pub fn page_count(&self) -> usize {
         self.pdf.pages().len()
     }
@@ -157,14 +188,59 @@ impl PdfDoc {
     }
 
     pub fn extract_outline(&self) -> AppResult<Vec<OutlineNode>> {
-        extract_outline_nodes(&self.pdf)
+        extract_outline_nodes(&self.pdf, self.destinations())
+    }
+
+    pub fn extract_links(&self, page: usize) -> AppResult<Vec<PdfLink>> {
+        extract_page_links(&self.pdf, page, self.destinations())
+    }
+
+    pub fn extract_annotations(&self, page: usize) -> AppResult<Vec<PdfAnnotation>> {
+        extract_page_annotations(&self.pdf, page)
+    }
+
+    pub fn metadata(&self) -> PdfMetadata {
+        extract_metadata(&self.pdf)
+    }
+
+    pub fn attachments(&self) -> Vec<PdfAttachment> {
+        extract_attachments(&self.pdf)
+    }
+
+    pub fn attachment_data(&self, index: usize) -> AppResult<Vec<u8>> {
+        extract_attachment_data(&self.pdf, index)
+    }
+
+    /// Page and named-destination lookups, built the first time an outline or link needs them.
+    fn destinations(&self) -> &DestinationIndex {
+        self.destinations
+            .get_or_init(|| DestinationIndex::build(&self.pdf))
+    }
+}
+fn load_error_to_app_error(err: LoadPdfError, password_supplied: bool) -> AppError {
+    match err {
+        LoadPdfError::Decryption(DecryptionError::PasswordProtected) => {
+            AppError::encrypted(password_supplied)
+        }
+        LoadPdfError::Decryption(DecryptionError::UnsupportedAlgorithm) => {
+            AppError::unsupported("pdf encryption algorithm is not supported")
+        }
+        LoadPdfError::Decryption(
+            DecryptionError::MissingIDEntry | DecryptionError::InvalidEncryption,
+        ) => AppError::invalid_argument("pdf encryption dictionary is invalid"),
+        LoadPdfError::Invalid => AppError::invalid_argument("failed to parse PDF with hayro"),
     }
 }
-fn calculate_doc_id(path: &Path, bytes: &[u8]) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    path.hash(&mut hasher);
-    bytes.hash(&mut hasher);
-    hasher.finish()
+
+/// Identifies a document by its bytes alone, so a copy or moved file keeps its id.
+///
+/// The id names saved reading positions, cached frames and text index files, so it uses a fixed
+/// algorithm rather than the std hasher, whose output may change between Rust releases.
+fn calculate_doc_id(bytes: &[u8]) -> u64 {
+    let digest = Sha256::digest(bytes);
+    let mut prefix = [0; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(prefix)
 }
 
 pub(super) fn pixel_buffer_from_pixmap(pixmap: Pixmap) -> Vec<u8> {
@@ -1,3 +1,5 @@
+use hayro::hayro_syntax::object::Dict;
+
 pub(super) fn decode_pdf_text_string(bytes: &[u8]) -> String {
     if let Some(decoded) = decode_bom_prefixed_text(bytes) {
         return decoded;
@@ -9,6 +11,13 @@ pub(super) fn decode_pdf_text_string(bytes: &[u8]) -> String {
         .collect()
 }
 
+/// Decodes a text string entry, treating a blank value the same as a missing one.
+pub(super) fn dict_text_entry(dict: &Dict<'_>, key: &[u8]) -> Option<String> {
+    let value = dict.get::<hayro::hayro_syntax::object::String<'_>>(key)?;
+    let text = decode_pdf_text_string(value.as_bytes()).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
 fn decode_bom_prefixed_text(bytes: &[u8]) -> Option<String> {
     match bytes {
         [0xFE, 0xFF, rest @ ..] => decode_utf16_bytes(rest, Utf16Endian::Big),
@@ -0,0 +1,530 @@
+use std::ops::Range;
+
+use crate::backend::{
+    PdfRect, TextBlock, TextGlyph, TextLayout, TextLine, TextWord, WritingDirection,
+};
+
+/// Largest forward gap, in ems, that still continues a line. Wider gaps usually separate
+/// columns or table cells that a producer emitted on one baseline.
+const MAX_LINE_GAP_EM: f32 = 3.0;
+/// Baseline drift, in ems, tolerated within a line; covers superscripts and subscripts.
+const MAX_BASELINE_DRIFT_EM: f32 = 0.5;
+/// Gap between glyph boxes, in ems, that splits words when the producer emitted no space.
+const WORD_GAP_EM: f32 = 0.25;
+/// Largest baseline-to-baseline distance, in ems, between lines of one block.
+const MAX_BLOCK_LINE_GAP_EM: f32 = 2.0;
+/// Font size ratio above which adjacent lines are treated as separate blocks.
+const MAX_BLOCK_FONT_RATIO: f32 = 1.25;
+/// Column-aware ordering compares every block pair against every other block; past this many
+/// blocks the page keeps content order instead.
+const MAX_ORDERED_BLOCKS: usize = 256;
+
+/// Where a glyph sits on the page, recorded by the text device alongside each [`TextGlyph`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct GlyphMetrics {
+    /// Page-space glyph origin on the baseline.
+    pub x: f32,
+    pub y: f32,
+    /// Em size in page-space points.
+    pub font_size: f32,
+    /// Unit vector along the glyph's text-space x axis, in page space.
+    pub advance: (f32, f32),
+}
+
+struct LineDraft {
+    words: Vec<TextWord>,
+    bbox: Option<PdfRect>,
+    baseline: f32,
+    font_size: f32,
+    direction: WritingDirection,
+}
+
+struct BlockDraft {
+    lines: Vec<LineDraft>,
+    bbox: Option<PdfRect>,
+}
+
+/// Groups a page's glyph stream into words, lines and reading-order blocks.
+///
+/// `metrics` runs parallel to `glyphs`. Lines are contiguous runs of the stream, so producers
+/// that interleave columns glyph by glyph get one line per fragment rather than merged lines.
+pub(super) fn analyze_layout(glyphs: &[TextGlyph], metrics: &[GlyphMetrics]) -> TextLayout {
+    debug_assert_eq!(glyphs.len(), metrics.len());
+    let lines = split_lines(glyphs, metrics);
+    let blocks = group_blocks(lines);
+    let order = reading_order(&blocks);
+
+    let mut layout = TextLayout::default();
+    let mut blocks = blocks.into_iter().map(Some).collect::<Vec<_>>();
+    for index in order {
+        let Some(block) = blocks[index].take() else {
+            continue;
+        };
+        let first_line = layout.lines.len();
+        for line in block.lines {
+            let first_word = layout.words.len();
+            layout.words.extend(line.words);
+            layout.lines.push(TextLine {
+                words: first_word..layout.words.len(),
+                bbox: line.bbox,
+                baseline: line.baseline,
+                font_size: line.font_size,
+                direction: line.direction,
+            });
+        }
+        layout.blocks.push(TextBlock {
+            lines: first_line..layout.lines.len(),
+            bbox: block.bbox,
+        });
+    }
+    layout
+}
+
+fn split_lines(glyphs: &[TextGlyph], metrics: &[GlyphMetrics]) -> Vec<LineDraft> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut flow = None;
+    let mut previous: Option<usize> = None;
+    let mut placed = 0;
+
+    for index in 0..glyphs.len() {
+        // Whitespace stays with the line it was emitted in; it only separates words.
+        if glyphs[index].ch.is_whitespace() {
+            continue;
+        }
+        if let Some(previous) = previous {
+            let line_flow = flow.unwrap_or(metrics[previous].advance);
+            match continue_line(&metrics[previous], &metrics[index], line_flow, placed) {
+                Some(next_flow) => flow = Some(next_flow),
+                None => {
+                    if let Some(line) = build_line(glyphs, metrics, start..index, line_flow) {
+                        lines.push(line);
+                    }
+                    start = index;
+                    flow = None;
+                    placed = 0;
+                }
+            }
+        }
+        previous = Some(index);
+        placed += 1;
+    }
+
+    if let Some(previous) = previous {
+        let line_flow = flow.unwrap_or(metrics[previous].advance);
+        if let Some(line) = build_line(glyphs, metrics, start..glyphs.len(), line_flow) {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Returns the line's flow direction when `next` continues the line that ends at `previous`.
+fn continue_line(
+    previous: &GlyphMetrics,
+    next: &GlyphMetrics,
+    flow: (f32, f32),
+    placed: usize,
+) -> Option<(f32, f32)> {
+    let size = previous.font_size.max(next.font_size).max(1.0);
+    let step = (next.x - previous.x, next.y - previous.y);
+    let along = dot(step, flow);
+    let across = cross(flow, step);
+
+    if across.abs() <= MAX_BASELINE_DRIFT_EM * size
+        && along >= -MAX_BASELINE_DRIFT_EM * size
+        && along <= MAX_LINE_GAP_EM * size
+    {
+        return Some(flow);
+    }
+
+    // The second glyph of a line settles which way it runs: backwards for right-to-left text
+    // emitted in logical order, or stacked for vertical writing with upright glyphs.
+    if placed == 1 {
+        let length = (step.0 * step.0 + step.1 * step.1).sqrt();
+        let backwards = across.abs() <= MAX_BASELINE_DRIFT_EM * size
+            && along < 0.0
+            && -along <= MAX_LINE_GAP_EM * size;
+        let stacked = along.abs() <= 0.2 * size && length <= 1.5 * size;
+        if (backwards || stacked) && length > 0.0 {
+            return Some((step.0 / length, step.1 / length));
+        }
+    }
+    None
+}
+
+fn build_line(
+    glyphs: &[TextGlyph],
+    metrics: &[GlyphMetrics],
+    range: Range<usize>,
+    flow: (f32, f32),
+) -> Option<LineDraft> {
+    let direction = writing_direction(flow);
+    let mut words = Vec::new();
+    let mut word_start = None;
+    let mut previous_bbox: Option<PdfRect> = None;
+    let mut sizes = Vec::new();
+    let mut baselines = Vec::new();
+
+    for index in range.clone() {
+        let glyph = &glyphs[index];
+        if glyph.ch.is_whitespace() {
+            if let Some(start) = word_start.take() {
+                words.push(build_word(glyphs, start..index));
+            }
+            previous_bbox = None;
+            continue;
+        }
+
+        let gap_splits = match (previous_bbox, glyph.bbox) {
+            (Some(previous), Some(bbox)) => {
+                let (_, previous_end) = extent_along(previous, flow);
+                let (start, _) = extent_along(bbox, flow);
+                start - previous_end > WORD_GAP_EM * metrics[index].font_size
+            }
+            _ => false,
+        };
+        if gap_splits && let Some(start) = word_start.take() {
+            words.push(build_word(glyphs, start..index));
+        }
+        word_start.get_or_insert(index);
+        previous_bbox = glyph.bbox.or(previous_bbox);
+
+        sizes.push(metrics[index].font_size);
+        baselines.push(if direction.is_vertical() {
+            metrics[index].x
+        } else {
+            metrics[index].y
+        });
+    }
+    if let Some(start) = word_start {
+        words.push(build_word(glyphs, start..range.end));
+    }
+    if words.is_empty() {
+        return None;
+    }
+
+    Some(LineDraft {
+        bbox: union_all(words.iter().map(|word| word.bbox)),
+        words,
+        baseline: median(&mut baselines),
+        font_size: median(&mut sizes),
+        direction,
+    })
+}
+
+fn build_word(glyphs: &[TextGlyph], range: Range<usize>) -> TextWord {
+    TextWord {
+        bbox: union_all(glyphs[range.clone()].iter().map(|glyph| glyph.bbox)),
+        glyphs: range,
+    }
+}
+
+fn group_blocks(lines: Vec<LineDraft>) -> Vec<BlockDraft> {
+    let mut blocks: Vec<BlockDraft> = Vec::new();
+    for line in lines {
+        // Producers that emit columns row by row interleave their lines, so a line may
+        // continue any earlier block, not only the latest one.
+        match blocks
+            .iter_mut()
+            .rev()
+            .find(|block| joins_block(block, &line))
+        {
+            Some(block) => {
+                block.bbox = union_all([block.bbox, line.bbox]);
+                block.lines.push(line);
+            }
+            _ => blocks.push(BlockDraft {
+                bbox: line.bbox,
+                lines: vec![line],
+            }),
+        }
+    }
+    blocks
+}
+
+fn joins_block(block: &BlockDraft, line: &LineDraft) -> bool {
+    let Some(previous) = block.lines.last() else {
+        return false;
+    };
+    if previous.direction != line.direction {
+        return false;
+    }
+
+    let (small, large) = if previous.font_size < line.font_size {
+        (previous.font_size, line.font_size)
+    } else {
+        (line.font_size, previous.font_size)
+    };
+    if large > small.max(0.1) * MAX_BLOCK_FONT_RATIO {
+        return false;
+    }
+
+    // Horizontal lines stack down the page; vertical ones may stack either way.
+    let advance = line.baseline - previous.baseline;
+    let gap = if line.direction.is_vertical() {
+        advance.abs()
+    } else {
+        advance
+    };
+    if gap <= MAX_BASELINE_DRIFT_EM * large || gap > MAX_BLOCK_LINE_GAP_EM * large {
+        return false;
+    }
+
+    match (previous.bbox, line.bbox) {
+        (Some(previous_bbox), Some(bbox)) => {
+            let flow = if line.direction.is_vertical() {
+                (0.0, 1.0)
+            } else {
+                (1.0, 0.0)
+            };
+            let (previous_start, previous_end) = extent_along(previous_bbox, flow);
+            let (start, end) = extent_along(bbox, flow);
+            start < previous_end && previous_start < end
+        }
+        _ => true,
+    }
+}
+
+/// Orders left-to-right blocks top to bottom within columns and columns left to right, falling
+/// back to content order wherever geometry does not decide.
+fn reading_order(blocks: &[BlockDraft]) -> Vec<usize> {
+    let count = blocks.len();
+    let rects = blocks
+        .iter()
+        .map(|block| {
+            block.bbox.filter(|_| {
+                block
+                    .lines
+                    .iter()
+                    .all(|line| line.direction == WritingDirection::LeftToRight)
+            })
+        })
+        .collect::<Vec<_>>();
+    if count > MAX_ORDERED_BLOCKS {
+        return (0..count).collect();
+    }
+
+    let successors = (0..count)
+        .map(|first| {
+            (0..count)
+                .filter(|&second| first != second && precedes(&rects, first, second))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut indegree = vec![0usize; count];
+    for &second in successors.iter().flatten() {
+        indegree[second] += 1;
+    }
+
+    // Kahn's algorithm, taking the earliest block in content order whenever several are ready.
+    // A cycle from overlapping geometry is broken the same way.
+    let mut order = Vec::with_capacity(count);
+    let mut placed = vec![false; count];
+    while order.len() < count {
+        let next = (0..count)
+            .find(|&index| !placed[index] && indegree[index] == 0)
+            .or_else(|| (0..count).find(|&index| !placed[index]))
+            .unwrap_or_default();
+        placed[next] = true;
+        order.push(next);
+        for &successor in &successors[next] {
+            indegree[successor] = indegree[successor].saturating_sub(1);
+        }
+    }
+    order
+}
+
+fn precedes(rects: &[Option<PdfRect>], first: usize, second: usize) -> bool {
+    let (Some(a), Some(b)) = (rects[first], rects[second]) else {
+        return false;
+    };
+    if overlaps_x(a, b) {
+        return a.y0 < b.y0;
+    }
+    if a.x1 > b.x0 {
+        return false;
+    }
+
+    // `a` is left of `b`; it reads first unless a block spanning both sits between them, which
+    // puts them in different sections of the page.
+    let (upper, lower) = if a.y0 <= b.y0 { (a, b) } else { (b, a) };
+    !rects.iter().enumerate().any(|(index, rect)| {
+        index != first
+            && index != second
+            && rect.is_some_and(|rect| {
+                overlaps_x(rect, a)
+                    && overlaps_x(rect, b)
+                    && rect.y0 >= upper.y1
+                    && rect.y1 <= lower.y0
+            })
+    })
+}
+
+fn overlaps_x(a: PdfRect, b: PdfRect) -> bool {
+    a.x0 < b.x1 && b.x0 < a.x1
+}
+
+fn writing_direction((x, y): (f32, f32)) -> WritingDirection {
+    // Page space grows downward, so a positive y flow runs top to bottom.
+    if x.abs() >= y.abs() {
+        if x >= 0.0 {
+            WritingDirection::LeftToRight
+        } else {
+            WritingDirection::RightToLeft
+        }
+    } else if y > 0.0 {
+        WritingDirection::TopToBottom
+    } else {
+        WritingDirection::BottomToTop
+    }
+}
+
+/// Projects `rect` onto `flow` and returns the covered interval.
+fn extent_along(rect: PdfRect, flow: (f32, f32)) -> (f32, f32) {
+    let corners = [
+        (rect.x0, rect.y0),
+        (rect.x1, rect.y0),
+        (rect.x0, rect.y1),
+        (rect.x1, rect.y1),
+    ];
+    corners
+        .iter()
+        .map(|corner| dot(*corner, flow))
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+fn union_all(rects: impl IntoIterator<Item = Option<PdfRect>>) -> Option<PdfRect> {
+    rects.into_iter().flatten().reduce(|left, right| PdfRect {
+        x0: left.x0.min(right.x0),
+        y0: left.y0.min(right.y0),
+        x1: left.x1.max(right.x1),
+        y1: left.y1.max(right.y1),
+    })
+}
+
+fn median(values: &mut [f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(f32::total_cmp);
+    values[values.len() / 2]
+}
+
+fn dot(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+fn cross(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::{PdfRect, TextGlyph, TextPage, WritingDirection};
+
+    use super::{GlyphMetrics, analyze_layout};
+
+    const SIZE: f32 = 10.0;
+    const WIDTH: f32 = 6.0;
+
+    /// Lays `text` out left to right from `(x, y)`, one glyph box per char.
+    fn run(text: &str, x: f32, y: f32) -> Vec<(TextGlyph, GlyphMetrics)> {
+        text.chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                let x = x + index as f32 * WIDTH;
+                let bbox = (!ch.is_whitespace()).then_some(PdfRect {
+                    x0: x,
+                    y0: y - SIZE * 0.7,
+                    x1: x + WIDTH * 0.9,
+                    y1: y,
+                });
+                (
+                    TextGlyph { ch, bbox },
+                    GlyphMetrics {
+                        x,
+                        y,
+                        font_size: SIZE,
+                        advance: (1.0, 0.0),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn page(runs: Vec<Vec<(TextGlyph, GlyphMetrics)>>) -> TextPage {
+        let (glyphs, metrics): (Vec<_>, Vec<_>) = runs.into_iter().flatten().unzip();
+        let layout = analyze_layout(&glyphs, &metrics);
+        TextPage {
+            width_pt: 600.0,
+            height_pt: 800.0,
+            glyphs,
+            dropped_glyphs: 0,
+            layout,
+        }
+    }
+
+    #[test]
+    fn splits_words_on_spaces_and_wide_gaps_and_lines_on_baseline_changes() {
+        let mut first_line = run("Hello world", 10.0, 20.0);
+        // No space glyph, but a gap of more than a quarter em before "again".
+        first_line.extend(run("again", 10.0 + 11.0 * WIDTH + 4.0, 20.0));
+        let page = page(vec![first_line, run("Next line", 10.0, 32.0)]);
+
+        let layout = &page.layout;
+        assert_eq!(layout.blocks.len(), 1);
+        assert_eq!(layout.lines.len(), 2);
+        assert_eq!(page.line_text(&layout.lines[0]), "Hello world again");
+        assert_eq!(page.line_text(&layout.lines[1]), "Next line");
+        assert_eq!(layout.lines[0].baseline, 20.0);
+        assert_eq!(layout.lines[0].font_size, SIZE);
+        assert_eq!(layout.lines[0].direction, WritingDirection::LeftToRight);
+        assert_eq!(layout.words[0].glyphs, 0..5);
+        assert_eq!(page.plain_text(), "Hello worldagainNext line");
+    }
+
+    #[test]
+    fn reading_order_reads_columns_top_to_bottom_before_moving_right() {
+        // A title spanning both columns, then column rows emitted interleaved.
+        let page = page(vec![
+            run("Title across both columns of the page", 10.0, 20.0),
+            run("left one", 10.0, 60.0),
+            run("right one", 200.0, 60.0),
+            run("left two", 10.0, 72.0),
+            run("right two", 200.0, 72.0),
+            run("Footer across both columns of the page", 10.0, 120.0),
+        ]);
+
+        assert_eq!(
+            page.reading_order_text(),
+            "Title across both columns of the page\n\n\
+             left one\nleft two\n\n\
+             right one\nright two\n\n\
+             Footer across both columns of the page"
+        );
+    }
+
+    #[test]
+    fn second_glyph_sets_right_to_left_and_vertical_flow() {
+        let mut rtl = run("ab", 100.0, 20.0);
+        rtl[1].1.x = 100.0 - WIDTH;
+        let mut vertical = run("cd", 300.0, 200.0);
+        vertical[1].1.x = 300.0;
+        vertical[1].1.y = 200.0 + SIZE;
+        let page = page(vec![rtl, vertical]);
+
+        let directions = page
+            .layout
+            .lines
+            .iter()
+            .map(|line| line.direction)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directions,
+            vec![WritingDirection::RightToLeft, WritingDirection::TopToBottom]
+        );
+    }
+}
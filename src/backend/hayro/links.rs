@@ -0,0 +1,76 @@
+use hayro::hayro_interpret::util::{RectExt, TransformExt};
+use hayro::hayro_syntax::Pdf;
+use hayro::hayro_syntax::object::dict::keys::{A, ANNOTS, RECT, S, SUBTYPE, URI};
+use hayro::hayro_syntax::object::{Array, Dict, Name, Rect};
+use hayro::hayro_syntax::page::Page;
+
+use crate::backend::{PdfLink, PdfLinkTarget, PdfRect};
+use crate::error::{AppError, AppResult};
+
+use super::outline::{DestinationIndex, resolve_dest_or_goto_page};
+
+pub(super) fn extract_page_links(
+    pdf: &Pdf,
+    page: usize,
+    destinations: &DestinationIndex,
+) -> AppResult<Vec<PdfLink>> {
+    let page_ref = pdf
+        .pages()
+        .get(page)
+        .ok_or(AppError::invalid_argument("page index is out of range"))?;
+    let Some(annots) = page_ref.raw().get::<Array<'_>>(ANNOTS) else {
+        return Ok(Vec::new());
+    };
+
+    let xref = pdf.xref();
+    let mut links = Vec::new();
+    for annot in annots.iter::<Dict<'_>>() {
+        if annot
+            .get::<Name<'_>>(SUBTYPE)
+            .is_none_or(|subtype| subtype.as_str() != "Link")
+        {
+            continue;
+        }
+        let Some(rect) = annot.get::<Rect>(RECT) else {
+            continue;
+        };
+        let target = match link_uri(&annot) {
+            Some(uri) => PdfLinkTarget::Uri(uri),
+            None => match resolve_dest_or_goto_page(&annot, xref, destinations) {
+                Some(page) => PdfLinkTarget::Page(page),
+                None => continue,
+            },
+        };
+        links.push(PdfLink {
+            rect: page_space_rect(page_ref, rect),
+            target,
+        });
+    }
+
+    Ok(links)
+}
+
+fn link_uri(annot: &Dict<'_>) -> Option<String> {
+    let action = annot.get::<Dict<'_>>(A)?;
+    if action.get::<Name<'_>>(S)?.as_str() != "URI" {
+        return None;
+    }
+    let uri = action.get::<hayro::hayro_syntax::object::String<'_>>(URI)?;
+    let uri = String::from_utf8_lossy(uri.as_bytes()).trim().to_string();
+    (!uri.is_empty()).then_some(uri)
+}
+
+pub(super) fn page_space_rect(page: &Page<'_>, rect: Rect) -> PdfRect {
+    // Annotation rects live in default user space; map them through the same transform the text
+    // extractor uses so link bounds line up with glyph bboxes and the highlight overlay.
+    let bbox = page
+        .initial_transform(true)
+        .to_kurbo()
+        .transform_rect_bbox(rect.to_kurbo());
+    PdfRect {
+        x0: bbox.x0 as f32,
+        y0: bbox.y0 as f32,
+        x1: bbox.x1 as f32,
+        y1: bbox.y1 as f32,
+    }
+}
@@ -0,0 +1,400 @@
+use hayro::hayro_syntax::object::dict::keys::{ENCRYPT, MARK_INFO, METADATA};
+use hayro::hayro_syntax::object::{DateTime, Dict, FromBytes, Stream};
+use hayro::hayro_syntax::{Pdf, PdfVersion};
+
+use crate::backend::{PdfDateTime, PdfMetadata};
+
+use super::encoding::decode_pdf_text_string;
+
+pub(super) fn extract_metadata(pdf: &Pdf) -> PdfMetadata {
+    let info = pdf.metadata();
+    let xref = pdf.xref();
+    let root = xref.get::<Dict<'_>>(xref.root_id());
+    let xmp = root
+        .as_ref()
+        .and_then(|root| root.get::<Stream<'_>>(METADATA))
+        .and_then(|stream| stream.decoded().ok())
+        .map(|bytes| parse_xmp(&String::from_utf8_lossy(&bytes)))
+        .unwrap_or_default();
+
+    // The Info dictionary wins where both set a field; XMP fills what Info omits.
+    PdfMetadata {
+        title: info_text(info.title.as_deref()).or(xmp.title),
+        author: info_text(info.author.as_deref()).or(xmp.author),
+        subject: info_text(info.subject.as_deref()).or(xmp.subject),
+        keywords: info_text(info.keywords.as_deref()).or(xmp.keywords),
+        creator: info_text(info.creator.as_deref()).or(xmp.creator),
+        producer: info_text(info.producer.as_deref()).or(xmp.producer),
+        created: info.creation_date.map(info_date).or(xmp.created),
+        modified: info.modification_date.map(info_date).or(xmp.modified),
+        version: Some(version_label(pdf.version()).to_string()),
+        encrypted: has_encrypt_entry(pdf.data().as_ref()),
+        tagged: root
+            .and_then(|root| root.get::<Dict<'_>>(MARK_INFO))
+            .and_then(|mark_info| mark_info.get::<bool>(b"Marked"))
+            .unwrap_or(false),
+    }
+}
+
+fn info_text(bytes: Option<&[u8]>) -> Option<String> {
+    let text = decode_pdf_text_string(bytes?).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn info_date(date: DateTime) -> PdfDateTime {
+    let offset = i16::from(date.utc_offset_hour) * 60;
+    let minutes = i16::from(date.utc_offset_minute);
+    PdfDateTime {
+        year: date.year,
+        month: date.month,
+        day: date.day,
+        hour: date.hour,
+        minute: date.minute,
+        second: date.second,
+        utc_offset_minutes: Some(if offset < 0 {
+            offset - minutes
+        } else {
+            offset + minutes
+        }),
+    }
+}
+
+fn version_label(version: PdfVersion) -> &'static str {
+    match version {
+        PdfVersion::Pdf10 => "1.0",
+        PdfVersion::Pdf11 => "1.1",
+        PdfVersion::Pdf12 => "1.2",
+        PdfVersion::Pdf13 => "1.3",
+        PdfVersion::Pdf14 => "1.4",
+        PdfVersion::Pdf15 => "1.5",
+        PdfVersion::Pdf16 => "1.6",
+        PdfVersion::Pdf17 => "1.7",
+        PdfVersion::Pdf20 => "2.0",
+    }
+}
+
+fn has_encrypt_entry(bytes: &[u8]) -> bool {
+    trailer_dict(bytes).is_some_and(|trailer| trailer.contains_key(ENCRYPT))
+}
+
+/// The trailer dictionary the last `startxref` points at: the dictionary after `trailer` for an
+/// xref table, or the stream dictionary of an xref stream.
+///
+/// hayro keeps its resolved trailer private, so only the lookup is done here and the dictionary
+/// itself is parsed by hayro.
+fn trailer_dict(bytes: &[u8]) -> Option<Dict<'_>> {
+    const STARTXREF: &[u8] = b"startxref";
+    let marker = find_last(bytes, STARTXREF)?;
+    let digits = skip_whitespace(&bytes[marker + STARTXREF.len()..]);
+    let end = digits
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(digits.len());
+    let offset = std::str::from_utf8(&digits[..end])
+        .ok()?
+        .parse::<usize>()
+        .ok()?;
+
+    let section = bytes.get(offset..)?;
+    let dict_start = if section.starts_with(b"xref") {
+        find_first(section, b"trailer")? + b"trailer".len()
+    } else {
+        find_first(section, b"obj")? + b"obj".len()
+    };
+    Dict::from_bytes(skip_whitespace(&section[dict_start..]))
+}
+
+fn find_first(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn find_last(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
+
+fn skip_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct XmpFields {
+    pub(super) title: Option<String>,
+    pub(super) author: Option<String>,
+    pub(super) subject: Option<String>,
+    pub(super) keywords: Option<String>,
+    pub(super) creator: Option<String>,
+    pub(super) producer: Option<String>,
+    pub(super) created: Option<PdfDateTime>,
+    pub(super) modified: Option<PdfDateTime>,
+}
+
+/// Reads the handful of Dublin Core, XMP basic, and Adobe PDF properties the info overlay shows.
+///
+/// This is a tolerant string scan rather than an RDF parser: it understands both the element
+/// and the attribute forms of a property and takes `rdf:li` items from Alt, Seq, and Bag values.
+pub(super) fn parse_xmp(xml: &str) -> XmpFields {
+    XmpFields {
+        title: xmp_items(xml, "dc:title").into_iter().next(),
+        author: join_items(xmp_items(xml, "dc:creator")),
+        subject: xmp_items(xml, "dc:description").into_iter().next(),
+        keywords: xmp_items(xml, "pdf:Keywords")
+            .into_iter()
+            .next()
+            .or_else(|| join_items(xmp_items(xml, "dc:subject"))),
+        creator: xmp_items(xml, "xmp:CreatorTool").into_iter().next(),
+        producer: xmp_items(xml, "pdf:Producer").into_iter().next(),
+        created: xmp_items(xml, "xmp:CreateDate")
+            .first()
+            .and_then(|value| parse_xmp_date(value)),
+        modified: xmp_items(xml, "xmp:ModifyDate")
+            .first()
+            .and_then(|value| parse_xmp_date(value)),
+    }
+}
+
+fn join_items(items: Vec<String>) -> Option<String> {
+    (!items.is_empty()).then(|| items.join("; "))
+}
+
+fn xmp_items(xml: &str, name: &str) -> Vec<String> {
+    if let Some(body) = element_body(xml, name) {
+        let items = element_bodies(body, "rdf:li")
+            .into_iter()
+            .filter_map(xml_text)
+            .collect::<Vec<_>>();
+        if !items.is_empty() {
+            return items;
+        }
+        return xml_text(body).into_iter().collect();
+    }
+    attribute_value(xml, name)
+        .and_then(xml_text)
+        .into_iter()
+        .collect()
+}
+
+fn element_body<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    element_bodies(xml, name).into_iter().next()
+}
+
+fn element_bodies<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let open = format!("<{name}");
+    let close = format!("</{name}>");
+    let mut bodies = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        let after_name = &rest[start + open.len()..];
+        if !after_name.starts_with(['>', '/', ' ', '\t', '\r', '\n']) {
+            rest = after_name;
+            continue;
+        }
+        let Some(tag_end) = after_name.find('>') else {
+            break;
+        };
+        if after_name[..tag_end].ends_with('/') {
+            rest = &after_name[tag_end + 1..];
+            continue;
+        }
+        let body = &after_name[tag_end + 1..];
+        let Some(body_end) = body.find(&close) else {
+            break;
+        };
+        bodies.push(&body[..body_end]);
+        rest = &body[body_end + close.len()..];
+    }
+    bodies
+}
+
+fn attribute_value<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = xml;
+    while let Some(start) = rest.find(name) {
+        let preceded_by_space = rest[..start].ends_with(char::is_whitespace);
+        rest = &rest[start + name.len()..];
+        if !preceded_by_space {
+            continue;
+        }
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let Some(quote) = value.chars().next().filter(|ch| matches!(ch, '"' | '\'')) else {
+            continue;
+        };
+        let value = &value[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+    None
+}
+
+fn xml_text(raw: &str) -> Option<String> {
+    let mut text = String::with_capacity(raw.len());
+    let mut in_tag = false;
+    for ch in raw.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    let text = decode_xml_entities(text.trim());
+    (!text.is_empty()).then_some(text)
+}
+
+fn decode_xml_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let entity = &rest[start + 1..];
+        let Some(end) = entity.find(';') else {
+            decoded.push_str(&rest[start..]);
+            return decoded;
+        };
+        let name = &entity[..end];
+        let ch = match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => name
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| name.strip_prefix('#').map(str::parse::<u32>))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match ch {
+            Some(ch) => decoded.push(ch),
+            None => decoded.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &entity[end + 1..];
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Parses the ISO 8601 subset XMP uses: `YYYY[-MM[-DD[THH:MM[:SS[.s]][TZD]]]]`.
+pub(super) fn parse_xmp_date(value: &str) -> Option<PdfDateTime> {
+    let value = value.trim();
+    let (date, time) = value.split_once('T').unwrap_or((value, ""));
+    let mut date_parts = date.split('-');
+    let year = date_parts.next()?.parse().ok()?;
+    let month = date_parts
+        .next()
+        .map_or(Some(1), |part| part.parse().ok())?;
+    let day = date_parts
+        .next()
+        .map_or(Some(1), |part| part.parse().ok())?;
+
+    let (clock, utc_offset_minutes) = split_time_zone(time)?;
+    let mut clock_parts = clock.split(':');
+    let mut clock_part = || -> Option<u8> {
+        clock_parts
+            .next()
+            .map_or(Some(0), |part| part.split('.').next()?.parse().ok())
+    };
+    let hour = if clock.is_empty() { 0 } else { clock_part()? };
+    let minute = if clock.is_empty() { 0 } else { clock_part()? };
+    let second = if clock.is_empty() { 0 } else { clock_part()? };
+
+    let valid = (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && hour < 24
+        && minute < 60
+        && second < 61;
+    valid.then_some(PdfDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        utc_offset_minutes,
+    })
+}
+
+/// Parses a PDF date string, `D:YYYY[MM[DD[HH[mm[SS]]]]][Z|+HH'mm'|-HH'mm']`, such as an
+/// annotation `/M` entry. Info dictionary dates come pre-parsed from hayro instead.
+pub(super) fn parse_pdf_date(value: &str) -> Option<PdfDateTime> {
+    let value = value.trim();
+    let value = value.strip_prefix("D:").unwrap_or(value);
+    let digits_end = value
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (digits, zone) = value.split_at(digits_end);
+    let year = digits.get(..4)?.parse().ok()?;
+    let field = |start: usize, default: u8| -> Option<u8> {
+        digits
+            .get(start..start + 2)
+            .map_or(Some(default), |part| part.parse().ok())
+    };
+    let month = field(4, 1)?;
+    let day = field(6, 1)?;
+    let hour = field(8, 0)?;
+    let minute = field(10, 0)?;
+    let second = field(12, 0)?;
+
+    let utc_offset_minutes = match zone.chars().next() {
+        None => None,
+        Some('Z') => Some(0),
+        Some(sign @ ('+' | '-')) => {
+            let mut parts = zone[1..].split('\'').filter(|part| !part.is_empty());
+            let hours = parts.next().unwrap_or("0");
+            let minutes = parts.next().unwrap_or("0");
+            Some(zone_offset_minutes(sign == '-', hours, minutes)?)
+        }
+        Some(_) => return None,
+    };
+
+    let valid = (1..=12).contains(&month)
+        && (1..=31).contains(&day)
+        && hour < 24
+        && minute < 60
+        && second < 61;
+    valid.then_some(PdfDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        utc_offset_minutes,
+    })
+}
+
+fn split_time_zone(time: &str) -> Option<(&str, Option<i16>)> {
+    if let Some(clock) = time.strip_suffix('Z') {
+        return Some((clock, Some(0)));
+    }
+    let Some(sign_at) = time.rfind(['+', '-']) else {
+        return Some((time, None));
+    };
+    let (clock, zone) = time.split_at(sign_at);
+    let (hours, minutes) = zone[1..].split_once(':').unwrap_or((&zone[1..], "0"));
+    let offset = zone_offset_minutes(zone.starts_with('-'), hours, minutes)?;
+    Some((clock, Some(offset)))
+}
+
+/// Signed UTC offset in minutes, or `None` unless the hours fit a day and the minutes an hour.
+fn zone_offset_minutes(negative: bool, hours: &str, minutes: &str) -> Option<i16> {
+    let hours = hours
+        .parse::<i16>()
+        .ok()
+        .filter(|hours| (0..24).contains(hours))?;
+    let minutes = minutes
+        .parse::<i16>()
+        .ok()
+        .filter(|minutes| (0..60).contains(minutes))?;
+    let offset = hours * 60 + minutes;
+    Some(if negative { -offset } else { offset })
+}
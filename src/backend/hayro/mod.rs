@@ -1,20 +1,33 @@
+mod annotations;
+mod attachments;
 mod document;
 mod encoding;
+mod layout;
+mod links;
+mod metadata;
 mod outline;
+mod page_labels;
 mod text;
 
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use hayro::RenderCache;
 use hayro::hayro_syntax::Pdf;
 
 use crate::error::AppResult;
 
-use super::traits::{OutlineNode, PdfBackend, PdfRenderContext, RgbaFrame, TextPage};
+use super::traits::{
+    OutlineNode, PageLabels, PdfAnnotation, PdfAttachment, PdfBackend, PdfLink, PdfMetadata,
+    PdfRenderContext, RgbaFrame, TextPage,
+};
 
 pub struct PdfDoc {
     path: PathBuf,
     doc_id: u64,
+    password: Option<String>,
+    page_labels: PageLabels,
+    destinations: OnceLock<outline::DestinationIndex>,
     pdf: Pdf,
 }
 
@@ -34,6 +47,10 @@ impl PdfBackend for PdfDoc {
         PdfDoc::doc_id(self)
     }
 
+    fn password(&self) -> Option<&str> {
+        PdfDoc::password(self)
+    }
+
     fn page_count(&self) -> usize {
         PdfDoc::page_count(self)
     }
@@ -60,6 +77,30 @@ impl PdfBackend for PdfDoc {
     fn extract_outline(&self) -> AppResult<Vec<OutlineNode>> {
         PdfDoc::extract_outline(self)
     }
+
+    fn extract_links(&self, page: usize) -> AppResult<Vec<PdfLink>> {
+        PdfDoc::extract_links(self, page)
+    }
+
+    fn extract_annotations(&self, page: usize) -> AppResult<Vec<PdfAnnotation>> {
+        PdfDoc::extract_annotations(self, page)
+    }
+
+    fn metadata(&self) -> AppResult<PdfMetadata> {
+        Ok(PdfDoc::metadata(self))
+    }
+
+    fn page_labels(&self) -> PageLabels {
+        PdfDoc::page_labels(self)
+    }
+
+    fn attachments(&self) -> AppResult<Vec<PdfAttachment>> {
+        Ok(PdfDoc::attachments(self))
+    }
+
+    fn attachment_data(&self, index: usize) -> AppResult<Vec<u8>> {
+        PdfDoc::attachment_data(self, index)
+    }
 }
 
 impl PdfRenderContext for HayroRenderContext<'_> {
@@ -73,14 +114,18 @@ mod tests {
     use std::fs;
 
     use hayro::vello_cpu::Pixmap;
+    use sha2::{Digest, Sha256};
 
     use crate::backend::test_support::{
-        build_pdf, build_pdf_from_objects, build_pdf_with_raw_streams, unique_temp_path,
+        build_encrypted_pdf, build_pdf, build_pdf_from_objects,
+        build_pdf_from_objects_with_trailer, build_pdf_with_raw_streams, unique_temp_path,
     };
     use crate::error::AppError;
 
-    use crate::backend::PdfBackend;
+    use crate::backend::{PdfAttachment, PdfBackend, PdfDateTime, PdfLinkTarget, PdfRect};
 
+    use super::metadata::{parse_pdf_date, parse_xmp, parse_xmp_date};
+    use super::page_labels::{letter_numeral, roman_numeral};
     use super::{PdfDoc, document::pixel_buffer_from_pixmap, encoding::decode_pdf_text_string};
 
     #[test]
@@ -98,6 +143,46 @@ mod tests {
         fs::remove_dir_all(&dir).expect("test directory should be removed");
     }
 
+    #[test]
+    fn open_reports_encrypted_pdf_without_password() {
+        let file = unique_temp_path("encrypted.pdf");
+        fs::write(&file, build_encrypted_pdf("secret page", "hunter2"))
+            .expect("test file should be created");
+
+        let missing = PdfDoc::open(&file);
+        let incorrect = PdfDoc::open_with_password(&file, Some("wrong"));
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert!(matches!(
+            missing,
+            Err(AppError::Encrypted {
+                password_supplied: false
+            })
+        ));
+        assert!(matches!(
+            incorrect,
+            Err(AppError::Encrypted {
+                password_supplied: true
+            })
+        ));
+    }
+
+    #[test]
+    fn open_with_password_unlocks_encrypted_pdf() {
+        let file = unique_temp_path("encrypted.pdf");
+        fs::write(&file, build_encrypted_pdf("secret page", "hunter2"))
+            .expect("test file should be created");
+
+        let doc = PdfDoc::open_with_password(&file, Some("hunter2"))
+            .expect("correct password should unlock the document");
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(doc.page_count(), 1);
+        assert_eq!(doc.password(), Some("hunter2"));
+        let text = doc.extract_text_page(0).expect("text should be extracted");
+        assert!(text.plain_text().contains("secret page"));
+    }
+
     #[test]
     fn open_accepts_valid_pdf_with_page_count() {
         let file = unique_temp_path("file.pdf");
@@ -134,6 +219,25 @@ mod tests {
         fs::remove_file(&file).expect("test file should be removed");
     }
 
+    #[test]
+    fn doc_id_depends_only_on_content_and_uses_sha256() {
+        let bytes = build_pdf(&["copied"]);
+        let original = unique_temp_path("doc_id_original.pdf");
+        let copy = unique_temp_path("doc_id_copy.pdf");
+        fs::write(&original, &bytes).expect("original pdf should be created");
+        fs::write(&copy, &bytes).expect("copied pdf should be created");
+
+        let original_doc = PdfDoc::open(&original).expect("original pdf should open");
+        let copy_doc = PdfDoc::open(&copy).expect("copied pdf should open");
+
+        let digest = Sha256::digest(&bytes);
+        let expected = u64::from_le_bytes(digest[..8].try_into().expect("8 byte prefix"));
+        assert_eq!(original_doc.doc_id(), expected);
+        assert_eq!(copy_doc.doc_id(), expected);
+        fs::remove_file(&original).expect("original pdf should be removed");
+        fs::remove_file(&copy).expect("copied pdf should be removed");
+    }
+
     #[test]
     fn doc_id_is_stable_for_same_path_and_content() {
         let file = unique_temp_path("doc_id_stable.pdf");
@@ -220,6 +324,32 @@ mod tests {
         fs::remove_file(&file).expect("test file should be removed");
     }
 
+    #[test]
+    fn extract_text_page_groups_words_into_lines_and_blocks() {
+        let file = unique_temp_path("layout.pdf");
+        fs::write(
+            &file,
+            build_pdf_with_raw_streams(&[
+                "BT /F1 12 Tf 36 260 Td (first line) Tj 0 -14 Td (second line) Tj ET \
+                 BT /F1 12 Tf 36 120 Td (later paragraph) Tj ET",
+            ]),
+        )
+        .expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let page = doc.extract_text_page(0).expect("extract should succeed");
+        assert_eq!(page.layout.blocks.len(), 2);
+        assert_eq!(page.layout.lines.len(), 3);
+        assert_eq!(page.layout.words.len(), 6);
+        assert!((page.layout.lines[0].font_size - 12.0).abs() < 0.01);
+        assert_eq!(
+            page.reading_order_text(),
+            "first line\nsecond line\n\nlater paragraph"
+        );
+
+        fs::remove_file(&file).expect("test file should be removed");
+    }
+
     #[test]
     fn render_page_uses_hayro_pixmap_output() {
         let file = unique_temp_path("pixmap.pdf");
@@ -351,6 +481,376 @@ mod tests {
         fs::remove_file(&file).expect("test file should be removed");
     }
 
+    #[test]
+    fn extract_links_resolves_goto_named_and_uri_targets_in_page_space() {
+        let file = unique_temp_path("links.pdf");
+        fs::write(&file, build_pdf_with_links()).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let links = doc
+            .extract_links(0)
+            .expect("link extraction should succeed");
+        let second_page_links = doc
+            .extract_links(1)
+            .expect("link extraction should succeed");
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(
+            links
+                .iter()
+                .map(|link| link.target.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PdfLinkTarget::Page(1),
+                PdfLinkTarget::Page(1),
+                PdfLinkTarget::Uri("https://example.com/spec".to_string()),
+            ]
+        );
+        assert_eq!(
+            links[0].rect,
+            PdfRect {
+                x0: 10.0,
+                y0: 30.0,
+                x1: 110.0,
+                y1: 50.0,
+            }
+        );
+        assert!(second_page_links.is_empty());
+    }
+
+    #[test]
+    fn extract_links_rejects_out_of_range_page() {
+        let file = unique_temp_path("links_range.pdf");
+        fs::write(&file, build_pdf(&["hello"])).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let result = doc.extract_links(4);
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert!(matches!(
+            result,
+            Err(AppError::InvalidArgument(message)) if message == "page index is out of range"
+        ));
+    }
+
+    #[test]
+    fn extract_annotations_keeps_markup_with_author_date_and_page_space_rect() {
+        let file = unique_temp_path("annotations.pdf");
+        fs::write(&file, build_pdf_with_annotations()).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let annotations = doc
+            .extract_annotations(0)
+            .expect("annotation extraction should succeed");
+        let out_of_range = doc.extract_annotations(3);
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(
+            annotations
+                .iter()
+                .map(|annotation| annotation.subtype.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Text", "Highlight"]
+        );
+        assert_eq!(annotations[0].author.as_deref(), Some("Ada"));
+        assert_eq!(
+            annotations[0].contents.as_deref(),
+            Some("Check this figure")
+        );
+        assert_eq!(
+            annotations[0].date.map(|date| date.to_string()),
+            Some("2024-05-01 09:30:00 +02:00".to_string())
+        );
+        assert_eq!(
+            annotations[0].rect,
+            PdfRect {
+                x0: 10.0,
+                y0: 30.0,
+                x1: 60.0,
+                y1: 50.0,
+            }
+        );
+        assert_eq!(annotations[1].author, None);
+        assert_eq!(
+            annotations[1].date.map(|date| date.to_string()),
+            Some("2023-12-24 00:00:00".to_string())
+        );
+        assert!(matches!(out_of_range, Err(AppError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn parse_pdf_date_reads_offsets_and_reduced_precision() {
+        assert_eq!(
+            parse_pdf_date("D:20240501093000-05'30'").map(|date| date.to_string()),
+            Some("2024-05-01 09:30:00 -05:30".to_string())
+        );
+        assert_eq!(
+            parse_pdf_date("D:199812Z").map(|date| date.to_string()),
+            Some("1998-12-01 00:00:00 UTC".to_string())
+        );
+        assert_eq!(parse_pdf_date("D:20241301"), None);
+        assert_eq!(parse_pdf_date("last week"), None);
+    }
+
+    #[test]
+    fn parse_pdf_date_rejects_out_of_range_offsets() {
+        assert_eq!(parse_pdf_date("D:20200101+999'00'"), None);
+        assert_eq!(parse_pdf_date("D:20200101-05'60'"), None);
+        assert_eq!(parse_pdf_date("D:20200101+32767'32767'"), None);
+        assert_eq!(
+            parse_pdf_date("D:20200101+23'59'").map(|date| date.to_string()),
+            Some("2020-01-01 00:00:00 +23:59".to_string())
+        );
+    }
+
+    #[test]
+    fn metadata_prefers_info_strings_and_fills_gaps_from_xmp() {
+        let file = unique_temp_path("metadata.pdf");
+        fs::write(&file, build_pdf_with_metadata()).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let metadata = doc.metadata();
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(metadata.title.as_deref(), Some("Caf\u{e9} Notes"));
+        assert_eq!(metadata.author.as_deref(), Some("Ada; Grace"));
+        assert_eq!(metadata.producer.as_deref(), Some("pvf tests"));
+        assert_eq!(metadata.creator.as_deref(), Some("Writer & Co"));
+        assert_eq!(
+            metadata.created,
+            Some(PdfDateTime {
+                year: 2024,
+                month: 3,
+                day: 9,
+                hour: 14,
+                minute: 5,
+                second: 0,
+                utc_offset_minutes: Some(90),
+            })
+        );
+        assert_eq!(metadata.version.as_deref(), Some("1.4"));
+        assert!(metadata.tagged);
+        assert!(!metadata.encrypted);
+    }
+
+    #[test]
+    fn metadata_reports_encryption() {
+        let file = unique_temp_path("metadata_encrypted.pdf");
+        fs::write(&file, build_encrypted_pdf("secret page", "hunter2"))
+            .expect("test file should be created");
+
+        let doc = PdfDoc::open_with_password(&file, Some("hunter2")).expect("pdf should open");
+        let metadata = doc.metadata();
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert!(metadata.encrypted);
+        assert!(!metadata.tagged);
+        assert_eq!(metadata.title, None);
+    }
+
+    #[test]
+    fn metadata_ignores_encrypt_tokens_outside_the_trailer() {
+        let file = unique_temp_path("metadata_encrypt_text.pdf");
+        fs::write(&file, build_pdf(&["see /Encrypt in the trailer"]))
+            .expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let metadata = doc.metadata();
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert!(!metadata.encrypted);
+    }
+
+    #[test]
+    fn parse_xmp_reads_attribute_form_and_decodes_entities() {
+        let fields = parse_xmp(
+            r#"<rdf:Description rdf:about="" pdf:Producer="A &amp; B" xmp:ModifyDate="2023-11-02T08:30:15Z">
+                 <dc:subject><rdf:Bag><rdf:li>pdf</rdf:li><rdf:li>viewer</rdf:li></rdf:Bag></dc:subject>
+               </rdf:Description>"#,
+        );
+
+        assert_eq!(fields.producer.as_deref(), Some("A & B"));
+        assert_eq!(fields.keywords.as_deref(), Some("pdf; viewer"));
+        assert_eq!(
+            fields.modified.map(|date| date.to_string()),
+            Some("2023-11-02 08:30:15 UTC".to_string())
+        );
+        assert_eq!(fields.title, None);
+    }
+
+    #[test]
+    fn parse_xmp_date_accepts_reduced_precision_and_rejects_garbage() {
+        assert_eq!(
+            parse_xmp_date("2021-07").map(|date| date.to_string()),
+            Some("2021-07-01 00:00:00".to_string())
+        );
+        assert_eq!(
+            parse_xmp_date("2021-07-04T10:20-05:00").map(|date| date.to_string()),
+            Some("2021-07-04 10:20:00 -05:00".to_string())
+        );
+        assert_eq!(parse_xmp_date("yesterday"), None);
+        assert_eq!(parse_xmp_date("2021-07-04T10:20+999:00"), None);
+    }
+
+    #[test]
+    fn page_labels_follow_number_tree_ranges_across_kids() {
+        let file = unique_temp_path("page_labels.pdf");
+        fs::write(&file, build_pdf_with_page_labels()).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let labels = (0..doc.page_count())
+            .map(|page| doc.page_labels().get(page).unwrap_or_default().to_string())
+            .collect::<Vec<_>>();
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(labels, vec!["i", "ii", "1", "2", "A-3", "AA", "Cover"]);
+    }
+
+    #[test]
+    fn page_labels_are_empty_without_a_page_labels_tree() {
+        let file = unique_temp_path("page_labels_missing.pdf");
+        fs::write(&file, build_pdf(&["one", "two"])).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert!(doc.page_labels().is_empty());
+    }
+
+    #[test]
+    fn attachments_list_name_tree_and_annotation_files_once() {
+        let file = unique_temp_path("attachments.pdf");
+        fs::write(&file, build_pdf_with_attachments()).expect("test file should be created");
+
+        let doc = PdfDoc::open(&file).expect("pdf should open");
+        let attachments = doc.attachments();
+        let notes = doc.attachment_data(1);
+        let missing = doc.attachment_data(2);
+
+        fs::remove_file(&file).expect("test file should be removed");
+        assert_eq!(
+            attachments,
+            vec![
+                PdfAttachment {
+                    name: "invoice.xml".to_string(),
+                    size: Some(10),
+                    mime_type: Some("text/xml".to_string()),
+                    description: Some("Factur-X data".to_string()),
+                    page: None,
+                },
+                PdfAttachment {
+                    name: "notes.txt".to_string(),
+                    size: Some(5),
+                    mime_type: None,
+                    description: Some("Reviewer notes".to_string()),
+                    page: Some(0),
+                },
+            ]
+        );
+        assert_eq!(notes.expect("attachment should decode"), b"hello");
+        assert!(matches!(missing, Err(AppError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn label_numerals_cover_roman_and_repeated_letters() {
+        assert_eq!(roman_numeral(4), "iv");
+        assert_eq!(roman_numeral(1994), "mcmxciv");
+        assert_eq!(letter_numeral(1), "a");
+        assert_eq!(letter_numeral(26), "z");
+        assert_eq!(letter_numeral(28), "bb");
+    }
+
+    fn build_pdf_with_metadata() -> Vec<u8> {
+        let xmp = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF><rdf:Description rdf:about="">
+<dc:title><rdf:Alt><rdf:li xml:lang="x-default">Ignored Title</rdf:li></rdf:Alt></dc:title>
+<dc:creator><rdf:Seq><rdf:li>Ada</rdf:li><rdf:li>Grace</rdf:li></rdf:Seq></dc:creator>
+<xmp:CreatorTool>Writer &amp; Co</xmp:CreatorTool>
+</rdf:Description></rdf:RDF></x:xmpmeta>"#;
+        let objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R /Metadata 6 0 R /MarkInfo << /Marked true >> >>"
+                .to_string(),
+            "<< /Type /Pages /Kids [4 0 R] /Count 1 >>".to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Resources << /Font << /F1 3 0 R >> >> /Contents 5 0 R >>".to_string(),
+            "<< /Length 36 >>\nstream\nBT /F1 14 Tf 36 260 Td (hello) Tj ET\nendstream".to_string(),
+            format!(
+                "<< /Type /Metadata /Subtype /XML /Length {} >>\nstream\n{xmp}\nendstream",
+                xmp.len()
+            ),
+            "<< /Title <FEFF00430061006600E90020004E006F007400650073> /Producer (pvf tests) /CreationDate (D:20240309140500+01'30') >>".to_string(),
+        ];
+
+        build_pdf_from_objects_with_trailer(&objects, "/Info 7 0 R")
+    }
+
+    fn build_pdf_with_annotations() -> Vec<u8> {
+        let objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Annots [4 0 R 5 0 R 6 0 R 7 0 R] >>"
+                .to_string(),
+            "<< /Type /Annot /Subtype /Text /Rect [10 250 60 270] /T (Ada) /Contents (Check this figure) /M (D:20240501093000+02'00') /Popup 5 0 R >>".to_string(),
+            "<< /Type /Annot /Subtype /Popup /Rect [100 100 200 200] /Parent 4 0 R >>".to_string(),
+            "<< /Type /Annot /Subtype /Link /Rect [0 0 10 10] /A << /S /URI /URI (https://example.com) >> >>".to_string(),
+            "<< /Type /Annot /Subtype /Highlight /Rect [20 20 80 30] /M (garbage) /CreationDate (D:20231224) >>".to_string(),
+        ];
+
+        build_pdf_from_objects(&objects)
+    }
+
+    fn build_pdf_with_attachments() -> Vec<u8> {
+        let objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R /Names << /EmbeddedFiles 4 0 R >> >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Annots [8 0 R 9 0 R] >>"
+                .to_string(),
+            "<< /Kids [10 0 R] >>".to_string(),
+            "<< /Type /Filespec /F (invoice.xml) /UF (invoice.xml) /Desc (Factur-X data) /EF << /F 6 0 R >> >>".to_string(),
+            "<< /Type /EmbeddedFile /Subtype /text#2Fxml /Params << /Size 10 >> /Length 10 >>\nstream\n<invoice/>\nendstream".to_string(),
+            "<< /Type /Filespec /F (notes.txt) /EF << /F 11 0 R >> >>".to_string(),
+            "<< /Type /Annot /Subtype /FileAttachment /Rect [0 0 10 10] /FS 7 0 R /Contents (Reviewer notes) >>".to_string(),
+            "<< /Type /Annot /Subtype /FileAttachment /Rect [20 0 30 10] /FS 5 0 R >>".to_string(),
+            "<< /Names [(invoice.xml) 5 0 R] >>".to_string(),
+            "<< /Type /EmbeddedFile /Length 5 >>\nstream\nhello\nendstream".to_string(),
+        ];
+
+        build_pdf_from_objects(&objects)
+    }
+
+    fn build_pdf_with_page_labels() -> Vec<u8> {
+        let page_ids = (5..12).map(|id| format!("{id} 0 R")).collect::<Vec<_>>();
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R /PageLabels 3 0 R >>".to_string(),
+            format!("<< /Type /Pages /Kids [{}] /Count 7 >>", page_ids.join(" ")),
+            "<< /Kids [4 0 R 12 0 R] >>".to_string(),
+            "<< /Nums [0 << /S /r >> 2 << /S /D >> 4 << /S /D /P (A-) /St 3 >>] >>".to_string(),
+        ];
+        objects.extend(
+            (0..7).map(|_| "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] >>".to_string()),
+        );
+        objects.push("<< /Nums [5 << /S /A /St 27 >> 6 << /P (Cover) >>] >>".to_string());
+
+        build_pdf_from_objects(&objects)
+    }
+
+    fn build_pdf_with_links() -> Vec<u8> {
+        let objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R /Names << /Dests 9 0 R >> >>".to_string(),
+            "<< /Type /Pages /Kids [4 0 R 5 0 R] /Count 2 >>".to_string(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Resources << /Font << /F1 3 0 R >> >> /Contents 10 0 R /Annots [6 0 R 7 0 R 8 0 R 11 0 R] >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Resources << /Font << /F1 3 0 R >> >> /Contents 10 0 R >>".to_string(),
+            "<< /Type /Annot /Subtype /Link /Rect [10 250 110 270] /A << /S /GoTo /D [5 0 R /Fit] >> >>".to_string(),
+            "<< /Type /Annot /Subtype /Link /Rect [10 200 110 220] /Dest (appendix) >>".to_string(),
+            "<< /Type /Annot /Subtype /Link /Rect [10 150 110 170] /A << /S /URI /URI (https://example.com/spec) >> >>".to_string(),
+            "<< /Names [(appendix) [5 0 R /XYZ 0 300 0]] >>".to_string(),
+            "<< /Length 36 >>\nstream\nBT /F1 14 Tf 36 260 Td (hello) Tj ET\nendstream".to_string(),
+            "<< /Type /Annot /Subtype /Text /Rect [0 0 10 10] /Contents (note) >>".to_string(),
+        ];
+
+        build_pdf_from_objects(&objects)
+    }
+
     fn build_pdf_with_named_outline() -> Vec<u8> {
         let objects = vec![
             "<< /Type /Catalog /Pages 2 0 R /Outlines 4 0 R /Names << /Dests 7 0 R >> >>"
@@ -11,7 +11,10 @@ use crate::error::{AppError, AppResult};
 
 use super::encoding::decode_pdf_text_string;
 
-pub(super) fn extract_outline_nodes(pdf: &Pdf) -> AppResult<Vec<OutlineNode>> {
+pub(super) fn extract_outline_nodes(
+    pdf: &Pdf,
+    destinations: &DestinationIndex,
+) -> AppResult<Vec<OutlineNode>> {
     let xref = pdf.xref();
     let Some(root) = xref.get::<Dict<'_>>(xref.root_id()) else {
         return Err(AppError::unsupported("failed to resolve pdf catalog"));
@@ -26,29 +29,74 @@ pub(super) fn extract_outline_nodes(pdf: &Pdf) -> AppResult<Vec<OutlineNode>> {
         return Ok(Vec::new());
     };
 
-    let page_index = pdf
-        .pages()
-        .iter()
-        .enumerate()
-        .filter_map(|(page, pdf_page)| pdf_page.raw().obj_id().map(|id| (id, page)))
-        .collect::<HashMap<_, _>>();
-    let named_destinations = build_named_destination_index(root);
     let mut visited = HashSet::new();
 
     Ok(read_outline_siblings(
         xref,
         first_ref.into(),
-        &page_index,
-        &named_destinations,
+        destinations,
         &mut visited,
     ))
 }
 
-fn read_outline_siblings<'a>(
-    xref: &'a hayro::hayro_syntax::xref::XRef,
+/// Page numbers of page objects and named destinations, built once per document and shared by
+/// outline and link extraction.
+#[derive(Debug)]
+pub(super) struct DestinationIndex {
+    pages: HashMap<ObjectIdentifier, usize>,
+    named: HashMap<Vec<u8>, usize>,
+}
+
+impl DestinationIndex {
+    pub(super) fn build(pdf: &Pdf) -> Self {
+        let pages = build_page_index(pdf);
+        let xref = pdf.xref();
+        let Some(root) = xref.get::<Dict<'_>>(xref.root_id()) else {
+            return Self {
+                pages,
+                named: HashMap::new(),
+            };
+        };
+
+        let raw = build_named_destination_index(root);
+        let named = raw
+            .keys()
+            .filter_map(|name| {
+                let page = resolve_named_destination(
+                    name,
+                    xref,
+                    &pages,
+                    NamedPages::Raw(&raw),
+                    &mut HashSet::new(),
+                    &mut HashSet::new(),
+                )?;
+                Some((name.clone(), page))
+            })
+            .collect();
+        Self { pages, named }
+    }
+}
+
+/// Where a named destination's page comes from: the raw destination objects while the index
+/// is being built, and the finished index afterwards.
+#[derive(Clone, Copy)]
+enum NamedPages<'m, 'a> {
+    Raw(&'m HashMap<Vec<u8>, NamedDestination<'a>>),
+    Resolved(&'m HashMap<Vec<u8>, usize>),
+}
+
+fn build_page_index(pdf: &Pdf) -> HashMap<ObjectIdentifier, usize> {
+    pdf.pages()
+        .iter()
+        .enumerate()
+        .filter_map(|(page, pdf_page)| pdf_page.raw().obj_id().map(|id| (id, page)))
+        .collect()
+}
+
+fn read_outline_siblings(
+    xref: &hayro::hayro_syntax::xref::XRef,
     start: ObjectIdentifier,
-    page_index: &HashMap<ObjectIdentifier, usize>,
-    named_destinations: &HashMap<Vec<u8>, NamedDestination<'a>>,
+    destinations: &DestinationIndex,
     visited: &mut HashSet<ObjectIdentifier>,
 ) -> Vec<OutlineNode> {
     let mut nodes = Vec::new();
@@ -65,12 +113,10 @@ fn read_outline_siblings<'a>(
         let next = item.get_ref(NEXT).map(Into::into);
         let mut children = item
             .get_ref(FIRST)
-            .map(|first| {
-                read_outline_siblings(xref, first.into(), page_index, named_destinations, visited)
-            })
+            .map(|first| read_outline_siblings(xref, first.into(), destinations, visited))
             .unwrap_or_default();
 
-        if let Some(page) = resolve_outline_page(&item, xref, page_index, named_destinations) {
+        if let Some(page) = resolve_dest_or_goto_page(&item, xref, destinations) {
             nodes.push(OutlineNode {
                 title: outline_title(&item),
                 page,
@@ -86,12 +132,14 @@ fn read_outline_siblings<'a>(
     nodes
 }
 
-fn resolve_outline_page<'a>(
+/// Resolves the page targeted by an outline item or link annotation, via `/Dest` or a GoTo `/A`.
+pub(super) fn resolve_dest_or_goto_page<'a>(
     item: &Dict<'a>,
     xref: &'a hayro::hayro_syntax::xref::XRef,
-    page_index: &HashMap<ObjectIdentifier, usize>,
-    named_destinations: &HashMap<Vec<u8>, NamedDestination<'a>>,
+    destinations: &DestinationIndex,
 ) -> Option<usize> {
+    let page_index = &destinations.pages;
+    let named_destinations = NamedPages::Resolved(&destinations.named);
     if let Some(dest) = item.get_raw::<Object<'_>>(DEST) {
         return resolve_destination(
             dest,
@@ -124,7 +172,7 @@ fn resolve_destination<'a>(
     value: MaybeRef<Object<'a>>,
     xref: &'a hayro::hayro_syntax::xref::XRef,
     page_index: &HashMap<ObjectIdentifier, usize>,
-    named_destinations: &HashMap<Vec<u8>, NamedDestination<'a>>,
+    named_destinations: NamedPages<'_, 'a>,
     visited: &mut HashSet<ObjectIdentifier>,
     visited_names: &mut HashSet<Vec<u8>>,
 ) -> Option<usize> {
@@ -276,14 +324,18 @@ fn resolve_named_destination<'a>(
     name: &[u8],
     xref: &'a hayro::hayro_syntax::xref::XRef,
     page_index: &HashMap<ObjectIdentifier, usize>,
-    named_destinations: &HashMap<Vec<u8>, NamedDestination<'a>>,
+    named_destinations: NamedPages<'_, 'a>,
     visited: &mut HashSet<ObjectIdentifier>,
     visited_names: &mut HashSet<Vec<u8>>,
 ) -> Option<usize> {
+    let raw = match named_destinations {
+        NamedPages::Raw(raw) => raw,
+        NamedPages::Resolved(pages) => return pages.get(name).copied(),
+    };
     if !visited_names.insert(name.to_vec()) {
         return None;
     }
-    let dest = named_destinations.get(name)?.to_maybe_ref();
+    let dest = raw.get(name)?.to_maybe_ref();
     resolve_destination(
         dest,
         xref,
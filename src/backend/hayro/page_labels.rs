@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use hayro::hayro_syntax::Pdf;
+use hayro::hayro_syntax::object::dict::keys::{KIDS, NUMS, P, PAGE_LABELS, S, ST};
+use hayro::hayro_syntax::object::{Array, Dict, Name, Object, ObjectIdentifier};
+
+use crate::backend::PageLabels;
+
+use super::encoding::decode_pdf_text_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberingStyle {
+    Decimal,
+    UpperRoman,
+    LowerRoman,
+    UpperLetters,
+    LowerLetters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LabelRange {
+    first_page: usize,
+    style: Option<NumberingStyle>,
+    prefix: String,
+    start: usize,
+}
+
+impl LabelRange {
+    fn label(&self, page: usize) -> String {
+        let value = self.start + (page - self.first_page);
+        let numeral = match self.style {
+            None => String::new(),
+            Some(NumberingStyle::Decimal) => value.to_string(),
+            Some(NumberingStyle::UpperRoman) => roman_numeral(value).to_uppercase(),
+            Some(NumberingStyle::LowerRoman) => roman_numeral(value),
+            Some(NumberingStyle::UpperLetters) => letter_numeral(value).to_uppercase(),
+            Some(NumberingStyle::LowerLetters) => letter_numeral(value),
+        };
+        format!("{}{numeral}", self.prefix)
+    }
+}
+
+pub(super) fn extract_page_labels(pdf: &Pdf) -> PageLabels {
+    let xref = pdf.xref();
+    let Some(tree) = xref
+        .get::<Dict<'_>>(xref.root_id())
+        .and_then(|root| root.get::<Dict<'_>>(PAGE_LABELS))
+    else {
+        return PageLabels::default();
+    };
+
+    let mut ranges = Vec::new();
+    collect_label_ranges(tree, &mut ranges, &mut HashSet::new());
+    if ranges.is_empty() {
+        return PageLabels::default();
+    }
+    ranges.sort_by_key(|range| range.first_page);
+
+    PageLabels::new(page_labels_from_ranges(&ranges, pdf.pages().len()))
+}
+
+fn page_labels_from_ranges(ranges: &[LabelRange], page_count: usize) -> Vec<String> {
+    let mut next_range = 0;
+    let mut active: Option<&LabelRange> = None;
+    (0..page_count)
+        .map(|page| {
+            while let Some(range) = ranges.get(next_range)
+                && range.first_page <= page
+            {
+                active = Some(range);
+                next_range += 1;
+            }
+            // Pages before the first range are out of spec; fall back to their number.
+            active.map_or_else(|| (page + 1).to_string(), |range| range.label(page))
+        })
+        .collect()
+}
+
+fn collect_label_ranges(
+    node: Dict<'_>,
+    ranges: &mut Vec<LabelRange>,
+    visited: &mut HashSet<ObjectIdentifier>,
+) {
+    if let Some(id) = node.obj_id()
+        && !visited.insert(id)
+    {
+        return;
+    }
+
+    if let Some(nums) = node.get::<Array<'_>>(NUMS) {
+        let mut items = nums.iter::<Object<'_>>();
+        while let (Some(key), Some(value)) = (items.next(), items.next()) {
+            if let (Object::Number(key), Object::Dict(label)) = (key, value)
+                && let Ok(first_page) = usize::try_from(key.as_i64())
+            {
+                ranges.push(label_range(first_page, &label));
+            }
+        }
+    }
+
+    if let Some(kids) = node.get::<Array<'_>>(KIDS) {
+        for kid in kids.iter::<Dict<'_>>() {
+            collect_label_ranges(kid, ranges, visited);
+        }
+    }
+}
+
+fn label_range(first_page: usize, label: &Dict<'_>) -> LabelRange {
+    let style = label
+        .get::<Name<'_>>(S)
+        .and_then(|style| match style.as_str() {
+            "D" => Some(NumberingStyle::Decimal),
+            "R" => Some(NumberingStyle::UpperRoman),
+            "r" => Some(NumberingStyle::LowerRoman),
+            "A" => Some(NumberingStyle::UpperLetters),
+            "a" => Some(NumberingStyle::LowerLetters),
+            _ => None,
+        });
+    let prefix = label
+        .get::<hayro::hayro_syntax::object::String<'_>>(P)
+        .map(|prefix| decode_pdf_text_string(prefix.as_bytes()))
+        .unwrap_or_default();
+    let start = label.get::<usize>(ST).unwrap_or(1).max(1);
+
+    LabelRange {
+        first_page,
+        style,
+        prefix,
+        start,
+    }
+}
+
+pub(super) fn roman_numeral(mut value: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+
+    let mut numeral = String::new();
+    for (weight, digits) in NUMERALS {
+        while value >= weight {
+            numeral.push_str(digits);
+            value -= weight;
+        }
+    }
+    numeral
+}
+
+/// Letters run a..z, then aa..zz, then aaa..zzz, as the PDF spec describes.
+pub(super) fn letter_numeral(value: usize) -> String {
+    let index = value.saturating_sub(1);
+    let letter = char::from(b'a' + (index % 26) as u8);
+    std::iter::repeat_n(letter, index / 26 + 1).collect()
+}
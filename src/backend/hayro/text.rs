@@ -10,6 +10,11 @@ use kurbo::{Affine, BezPath, Point, Shape};
 
 use crate::backend::{PdfRect, TextGlyph, TextPage};
 
+use super::layout::{GlyphMetrics, analyze_layout};
+
+/// Glyph outlines come out of hayro scaled to a 1000-unit em.
+const UNITS_PER_EM: f64 = 1000.0;
+
 pub(super) fn extract_text_page_with_device(page: &Page<'_>) -> TextPage {
     let cache = InterpreterCache::new();
     let mut context = Context::new(
@@ -29,29 +34,36 @@ pub(super) fn extract_text_page_with_device(page: &Page<'_>) -> TextPage {
 struct TextPageExtractDevice {
     last_glyph: Option<(String, i32, i32)>,
     glyphs: Vec<TextGlyph>,
+    /// Placement of each entry in `glyphs`, for layout analysis.
+    metrics: Vec<GlyphMetrics>,
     dropped_glyphs: usize,
 }
 
 impl TextPageExtractDevice {
     fn finish(self, width_pt: f32, height_pt: f32) -> TextPage {
+        let layout = analyze_layout(&self.glyphs, &self.metrics);
         TextPage {
             width_pt,
             height_pt,
             glyphs: self.glyphs,
             dropped_glyphs: self.dropped_glyphs,
+            layout,
         }
     }
 
-    fn push_glyph_text(&mut self, text: String, bbox: Option<PdfRect>, x: f64, y: f64) {
+    fn push_glyph_text(&mut self, text: String, bbox: Option<PdfRect>, metrics: GlyphMetrics) {
+        let (x, y) = (f64::from(metrics.x), f64::from(metrics.y));
         if self.is_duplicate_glyph(&text, x, y) {
             return;
         }
 
+        let count = text.chars().count();
         if bbox.is_none() {
-            self.dropped_glyphs += text.chars().count();
+            self.dropped_glyphs += count;
         }
         self.glyphs
             .extend(text.chars().map(|ch| TextGlyph { ch, bbox }));
+        self.metrics.extend(std::iter::repeat_n(metrics, count));
         self.set_last_glyph(text, x, y);
     }
 
@@ -104,9 +116,9 @@ impl<'a> Device<'a> for TextPageExtractDevice {
             return;
         };
 
-        let position = (transform * glyph_transform) * Point::ORIGIN;
         let bbox = glyph_bbox(glyph, transform, glyph_transform);
-        self.push_glyph_text(bf_string_text(ch), bbox, position.x, position.y);
+        let metrics = glyph_metrics(transform * glyph_transform);
+        self.push_glyph_text(bf_string_text(ch), bbox, metrics);
     }
 
     fn draw_image(&mut self, _image: Image<'a, '_>, _transform: Affine) {}
@@ -120,6 +132,24 @@ fn quantize_coord(value: f64) -> i32 {
     (value * 100.0).round() as i32
 }
 
+fn glyph_metrics(transform: Affine) -> GlyphMetrics {
+    let origin = transform * Point::ORIGIN;
+    let [a, b, c, d, _, _] = transform.as_coeffs();
+    let advance_length = a.hypot(b);
+    let advance = if advance_length > 0.0 {
+        ((a / advance_length) as f32, (b / advance_length) as f32)
+    } else {
+        (1.0, 0.0)
+    };
+
+    GlyphMetrics {
+        x: origin.x as f32,
+        y: origin.y as f32,
+        font_size: (c.hypot(d) * UNITS_PER_EM) as f32,
+        advance,
+    }
+}
+
 fn glyph_bbox(glyph: &Glyph<'_>, transform: Affine, glyph_transform: Affine) -> Option<PdfRect> {
     let outline = match glyph {
         Glyph::Outline(outline) => outline.outline(),
@@ -147,7 +177,16 @@ fn bf_string_text(value: BfString) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{PdfRect, TextPageExtractDevice};
+    use super::{GlyphMetrics, PdfRect, TextPageExtractDevice};
+
+    fn metrics_at(x: f32, y: f32) -> GlyphMetrics {
+        GlyphMetrics {
+            x,
+            y,
+            font_size: 10.0,
+            advance: (1.0, 0.0),
+        }
+    }
 
     #[test]
     fn duplicate_filter_preserves_repeated_chars_in_same_glyph_token() {
@@ -159,8 +198,8 @@ mod tests {
             y1: 4.0,
         });
 
-        device.push_glyph_text("ff".to_owned(), bbox, 10.0, 20.0);
-        device.push_glyph_text("ff".to_owned(), bbox, 10.0, 20.0);
+        device.push_glyph_text("ff".to_owned(), bbox, metrics_at(10.0, 20.0));
+        device.push_glyph_text("ff".to_owned(), bbox, metrics_at(10.0, 20.0));
 
         let text: String = device.glyphs.iter().map(|glyph| glyph.ch).collect();
         assert_eq!(text, "ff");
@@ -171,11 +210,13 @@ mod tests {
     fn dropped_glyph_count_matches_emitted_unbounded_glyphs() {
         let mut device = TextPageExtractDevice::default();
 
-        device.push_glyph_text("ffi".to_owned(), None, 10.0, 20.0);
-        device.push_glyph_text("ffi".to_owned(), None, 10.0, 20.0);
+        device.push_glyph_text("ffi".to_owned(), None, metrics_at(10.0, 20.0));
+        device.push_glyph_text("ffi".to_owned(), None, metrics_at(10.0, 20.0));
 
         let page = device.finish(100.0, 100.0);
         assert_eq!(page.glyphs.len(), 3);
         assert_eq!(page.dropped_glyphs, 3);
+        assert_eq!(page.layout.words.len(), 1);
+        assert_eq!(page.layout.words[0].glyphs, 0..3);
     }
 }
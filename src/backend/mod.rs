@@ -10,8 +10,9 @@ mod traits;
 
 pub use hayro::{HayroPdfBackend, PdfDoc};
 pub use traits::{
-    OutlineNode, PdfBackend, PdfRect, PdfRenderContext, PixelBuffer, PixelBufferPool, RgbaFrame,
-    TextGlyph, TextPage,
+    OutlineNode, PageLabels, PdfAnnotation, PdfAttachment, PdfBackend, PdfDateTime, PdfLink,
+    PdfLinkTarget, PdfMetadata, PdfRect, PdfRenderContext, PixelBuffer, PixelBufferPool, RgbaFrame,
+    TextBlock, TextGlyph, TextLayout, TextLine, TextPage, TextWord, WritingDirection,
 };
 
 pub type SharedPdfBackend = Arc<dyn PdfBackend>;
@@ -20,6 +21,13 @@ pub fn open_default_backend(path: impl AsRef<Path>) -> AppResult<SharedPdfBacken
     PdfDoc::open(path).map(|doc| Arc::new(doc) as SharedPdfBackend)
 }
 
+pub fn open_default_backend_with_password(
+    path: impl AsRef<Path>,
+    password: Option<&str>,
+) -> AppResult<SharedPdfBackend> {
+    PdfDoc::open_with_password(path, password).map(|doc| Arc::new(doc) as SharedPdfBackend)
+}
+
 pub fn load_default_shared_bytes(path: impl AsRef<Path>) -> AppResult<Arc<Vec<u8>>> {
     PdfDoc::load_shared_bytes(path)
 }
@@ -1,8 +1,15 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::error::{AppError, AppResult};
+
+use super::{
+    OutlineNode, PdfAnnotation, PdfAttachment, PdfBackend, PdfLink, PdfMetadata, PdfRect,
+    RgbaFrame, TextGlyph, TextLayout, TextPage,
+};
+
 static TEMP_PATH_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 pub(crate) fn unique_temp_path(suffix: &str) -> PathBuf {
@@ -88,7 +95,58 @@ fn build_pdf_from_streams(page_streams: &[String]) -> Vec<u8> {
     build_pdf_from_objects(&objects)
 }
 
+/// Builds a one-page PDF protected by a standard security handler (revision 5) user password.
+///
+/// Streams and strings use the identity crypt filter, so only the password check is exercised
+/// and the page content stays readable once unlocked.
+pub(crate) fn build_encrypted_pdf(text: &str, user_password: &str) -> Vec<u8> {
+    use sha2::{Digest, Sha256};
+
+    let validation_salt = [0x11_u8; 8];
+    let key_salt = [0x22_u8; 8];
+    let mut user_entry = Sha256::new()
+        .chain_update(user_password.as_bytes())
+        .chain_update(validation_salt)
+        .finalize()
+        .to_vec();
+    user_entry.extend_from_slice(&validation_salt);
+    user_entry.extend_from_slice(&key_salt);
+
+    let encrypt = format!(
+        "/Encrypt << /Filter /Standard /V 5 /R 5 /Length 256 /P -4 \
+         /StmF /Identity /StrF /Identity /O <{}> /U <{}> /OE <{}> /UE <{}> >> \
+         /ID [<{}> <{}>]",
+        hex(&[0_u8; 48]),
+        hex(&user_entry),
+        hex(&[0_u8; 32]),
+        hex(&[0_u8; 32]),
+        hex(&[0x42_u8; 16]),
+        hex(&[0x42_u8; 16]),
+    );
+
+    let stream = format!(
+        "BT /F1 14 Tf 36 260 Td ({}) Tj ET",
+        escape_literal_string(text)
+    );
+    let objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [4 0 R] /Count 1 >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 300 300] /Resources << /Font << /F1 3 0 R >> >> /Contents 5 0 R >>".to_string(),
+        format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream),
+    ];
+
+    build_pdf_from_objects_with_trailer(&objects, &encrypt)
+}
+
 pub(crate) fn build_pdf_from_objects(objects: &[String]) -> Vec<u8> {
+    build_pdf_from_objects_with_trailer(objects, "")
+}
+
+pub(crate) fn build_pdf_from_objects_with_trailer(
+    objects: &[String],
+    trailer_entries: &str,
+) -> Vec<u8> {
     let mut bytes = Vec::new();
     bytes.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
 
@@ -109,8 +167,9 @@ pub(crate) fn build_pdf_from_objects(objects: &[String]) -> Vec<u8> {
 
     bytes.extend_from_slice(
         format!(
-            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            "trailer\n<< /Size {} /Root 1 0 R {} >>\nstartxref\n{}\n%%EOF\n",
             objects.len() + 1,
+            trailer_entries,
             xref_start
         )
         .as_bytes(),
@@ -119,6 +178,10 @@ pub(crate) fn build_pdf_from_objects(objects: &[String]) -> Vec<u8> {
     bytes
 }
 
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
 fn escape_literal_string(text: &str) -> String {
     let mut out = String::with_capacity(text.len());
 
@@ -136,3 +199,183 @@ fn escape_literal_string(text: &str) -> String {
 
     out
 }
+
+/// In-memory document for tests that need a few backend answers rather than a real PDF.
+///
+/// Pages are US Letter and hold their text as one line of 6 x 10 pt glyphs; the `with_*`
+/// builders swap in whatever else a test looks at.
+pub(crate) struct TextStubPdf {
+    path: PathBuf,
+    doc_id: u64,
+    pages: Vec<StubPage>,
+    metadata: PdfMetadata,
+    attachments: Vec<(PdfAttachment, Vec<u8>)>,
+}
+
+struct StubPage {
+    size: (f32, f32),
+    text: TextPage,
+    links: Vec<PdfLink>,
+    annotations: Vec<PdfAnnotation>,
+}
+
+impl TextStubPdf {
+    pub(crate) fn new(page_texts: &[&str]) -> Self {
+        let pages = page_texts
+            .iter()
+            .map(|text| StubPage {
+                size: (612.0, 792.0),
+                text: line_text_page(text, (612.0, 792.0)),
+                links: Vec::new(),
+                annotations: Vec::new(),
+            })
+            .collect();
+        Self {
+            path: PathBuf::from("stub.pdf"),
+            doc_id: 1,
+            pages,
+            metadata: PdfMetadata::default(),
+            attachments: Vec::new(),
+        }
+    }
+
+    /// `page_count` pages without text.
+    pub(crate) fn blank(page_count: usize) -> Self {
+        Self::new(&vec![""; page_count])
+    }
+
+    pub(crate) fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Gives page `n` the `n`th size, keeping its text on the resized page.
+    pub(crate) fn with_page_sizes(mut self, sizes: &[(f32, f32)]) -> Self {
+        for (page, &(width, height)) in self.pages.iter_mut().zip(sizes) {
+            page.size = (width, height);
+            page.text.width_pt = width;
+            page.text.height_pt = height;
+        }
+        self
+    }
+
+    /// Replaces the text of `page` with a hand-built layout.
+    pub(crate) fn with_text_page(mut self, page: usize, text: TextPage) -> Self {
+        self.pages[page].text = text;
+        self
+    }
+
+    pub(crate) fn with_links(mut self, page: usize, links: Vec<PdfLink>) -> Self {
+        self.pages[page].links = links;
+        self
+    }
+
+    pub(crate) fn with_annotations(mut self, page: usize, annotations: Vec<PdfAnnotation>) -> Self {
+        self.pages[page].annotations = annotations;
+        self
+    }
+
+    pub(crate) fn with_metadata(mut self, metadata: PdfMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub(crate) fn with_attachment(mut self, attachment: PdfAttachment, data: &[u8]) -> Self {
+        self.attachments.push((attachment, data.to_vec()));
+        self
+    }
+
+    fn page(&self, page: usize) -> AppResult<&StubPage> {
+        self.pages
+            .get(page)
+            .ok_or_else(|| AppError::page_out_of_range(page + 1, self.pages.len().max(1)))
+    }
+}
+
+impl PdfBackend for TextStubPdf {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn doc_id(&self) -> u64 {
+        self.doc_id
+    }
+
+    fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    fn page_dimensions(&self, page: usize) -> AppResult<(f32, f32)> {
+        self.page(page).map(|page| page.size)
+    }
+
+    fn render_page(&self, page: usize, _scale: f32) -> AppResult<RgbaFrame> {
+        self.page(page)?;
+        Ok(RgbaFrame {
+            width: 1,
+            height: 1,
+            pixels: vec![0, 0, 0, 0].into(),
+        })
+    }
+
+    fn extract_text_page(&self, page: usize) -> AppResult<TextPage> {
+        self.page(page).map(|page| page.text.clone())
+    }
+
+    fn extract_outline(&self) -> AppResult<Vec<OutlineNode>> {
+        Ok(Vec::new())
+    }
+
+    fn extract_links(&self, page: usize) -> AppResult<Vec<PdfLink>> {
+        self.page(page).map(|page| page.links.clone())
+    }
+
+    fn extract_annotations(&self, page: usize) -> AppResult<Vec<PdfAnnotation>> {
+        self.page(page).map(|page| page.annotations.clone())
+    }
+
+    fn metadata(&self) -> AppResult<PdfMetadata> {
+        Ok(self.metadata.clone())
+    }
+
+    fn attachments(&self) -> AppResult<Vec<PdfAttachment>> {
+        Ok(self
+            .attachments
+            .iter()
+            .map(|(attachment, _)| attachment.clone())
+            .collect())
+    }
+
+    fn attachment_data(&self, index: usize) -> AppResult<Vec<u8>> {
+        self.attachments
+            .get(index)
+            .map(|(_, data)| data.clone())
+            .ok_or_else(|| AppError::invalid_argument(format!("no attachment {index}")))
+    }
+}
+
+fn line_text_page(text: &str, (width_pt, height_pt): (f32, f32)) -> TextPage {
+    let glyphs = text
+        .chars()
+        .enumerate()
+        .map(|(index, ch)| {
+            let x0 = index as f32 * 6.0;
+            TextGlyph {
+                ch,
+                bbox: Some(PdfRect {
+                    x0,
+                    y0: 10.0,
+                    x1: x0 + 6.0,
+                    y1: 20.0,
+                }),
+            }
+        })
+        .collect();
+    TextPage {
+        width_pt,
+        height_pt,
+        glyphs,
+        dropped_glyphs: 0,
+        layout: TextLayout::default(),
+    }
+}
@@ -1,8 +1,11 @@
-use std::ops::Deref;
+use std::fmt;
+use std::ops::{Deref, Range};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
-use crate::error::AppResult;
+use serde::Serialize;
+
+use crate::error::{AppError, AppResult};
 
 #[derive(Debug, Default)]
 pub struct PixelBufferPool {
@@ -132,9 +135,10 @@ impl RgbaFrame {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct OutlineNode {
     pub title: String,
+    /// 0-based index of the destination page.
     pub page: usize,
     pub children: Vec<OutlineNode>,
 }
@@ -157,6 +161,138 @@ impl PdfRect {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfLinkTarget {
+    /// Zero-based page index of an internal GoTo or named destination.
+    Page(usize),
+    /// External URI action target.
+    Uri(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfLink {
+    /// Page-space link annotation bounds, in the same space as [`TextGlyph::bbox`].
+    pub rect: PdfRect,
+    pub target: PdfLinkTarget,
+}
+
+/// A markup annotation such as a sticky note, text highlight, ink drawing, or free-text comment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfAnnotation {
+    /// Annotation `/Subtype`, such as `Text`, `Highlight`, or `FreeText`.
+    pub subtype: String,
+    /// Author from the `/T` entry.
+    pub author: Option<String>,
+    /// Last modification date, falling back to the creation date.
+    pub date: Option<PdfDateTime>,
+    pub contents: Option<String>,
+    /// Page-space annotation bounds, in the same space as [`TextGlyph::bbox`].
+    pub rect: PdfRect,
+}
+
+/// A file embedded through the `/EmbeddedFiles` name tree or a file attachment annotation.
+///
+/// Portfolio members are listed the same way, since portfolios keep their files in the same tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfAttachment {
+    pub name: String,
+    /// Uncompressed size in bytes from the file's `/Params`, or its decoded length.
+    pub size: Option<usize>,
+    pub mime_type: Option<String>,
+    pub description: Option<String>,
+    /// Zero-based page of the annotation that carries the file; `None` for document-level files.
+    pub page: Option<usize>,
+}
+
+/// Display labels from the catalog `/PageLabels` number tree, indexed by zero-based page.
+///
+/// Empty when the document defines no labels; pages are then shown by their 1-based number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageLabels(Arc<[String]>);
+
+impl PageLabels {
+    pub fn new(labels: Vec<String>) -> Self {
+        Self(Arc::from(labels))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, page: usize) -> Option<&str> {
+        self.0.get(page).map(String::as_str)
+    }
+
+    /// Returns the label for `page` only when it says something the page number does not.
+    pub fn distinct(&self, page: usize) -> Option<&str> {
+        self.get(page)
+            .filter(|label| *label != (page + 1).to_string())
+    }
+
+    /// Finds the first page labeled `label`, preferring an exact match over a case-insensitive one.
+    pub fn find(&self, label: &str) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|candidate| candidate == label)
+            .or_else(|| {
+                let label = label.to_lowercase();
+                self.0
+                    .iter()
+                    .position(|candidate| candidate.to_lowercase() == label)
+            })
+    }
+}
+
+/// Calendar date and time from an Info dictionary or XMP packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdfDateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    /// Offset from UTC in minutes; `None` when the source leaves the time zone unspecified.
+    pub utc_offset_minutes: Option<i16>,
+}
+
+impl fmt::Display for PdfDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        match self.utc_offset_minutes {
+            Some(0) => write!(f, " UTC"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(f, " {sign}{:02}:{:02}", offset / 60, offset % 60)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Document-level metadata merged from the Info dictionary and the catalog XMP packet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PdfMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    pub creator: Option<String>,
+    pub producer: Option<String>,
+    pub created: Option<PdfDateTime>,
+    pub modified: Option<PdfDateTime>,
+    /// Effective PDF version, such as `1.7`.
+    pub version: Option<String>,
+    pub encrypted: bool,
+    /// Whether the catalog marks the document as a tagged PDF.
+    pub tagged: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TextGlyph {
     /// Unicode text extracted from the PDF glyph stream.
@@ -166,23 +302,137 @@ pub struct TextGlyph {
     pub bbox: Option<PdfRect>,
 }
 
+/// Direction in which a line's glyphs advance on the page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WritingDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+impl WritingDirection {
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::TopToBottom | Self::BottomToTop)
+    }
+}
+
+/// A run of non-whitespace glyphs set close enough together to read as one word.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextWord {
+    /// Range into [`TextPage::glyphs`].
+    pub glyphs: Range<usize>,
+    /// Union of the word's glyph bounds; `None` when no glyph has bounds.
+    pub bbox: Option<PdfRect>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine {
+    /// Range into [`TextLayout::words`].
+    pub words: Range<usize>,
+    pub bbox: Option<PdfRect>,
+    /// Page-space baseline: the y coordinate for horizontal lines, x for vertical ones.
+    pub baseline: f32,
+    /// Median glyph em size in page-space points.
+    pub font_size: f32,
+    pub direction: WritingDirection,
+}
+
+/// Consecutive lines that read as one paragraph, heading, or column fragment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextBlock {
+    /// Range into [`TextLayout::lines`].
+    pub lines: Range<usize>,
+    pub bbox: Option<PdfRect>,
+}
+
+/// Layout analysis of a page's glyph stream.
+///
+/// Blocks are in reading order, lines are stored block by block, and words line by line, so
+/// walking `blocks` visits the whole page in reading order. Glyph ranges still point into the
+/// original stream, which stays in content order for search.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextLayout {
+    pub words: Vec<TextWord>,
+    pub lines: Vec<TextLine>,
+    pub blocks: Vec<TextBlock>,
+}
+
+impl TextLayout {
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn block_lines(&self, block: &TextBlock) -> &[TextLine] {
+        &self.lines[block.lines.clone()]
+    }
+
+    pub fn line_words(&self, line: &TextLine) -> &[TextWord] {
+        &self.words[line.words.clone()]
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TextPage {
     pub width_pt: f32,
     pub height_pt: f32,
     pub glyphs: Vec<TextGlyph>,
     pub dropped_glyphs: usize,
+    pub layout: TextLayout,
 }
 
 impl TextPage {
     pub fn plain_text(&self) -> String {
         self.glyphs.iter().map(|glyph| glyph.ch).collect()
     }
+
+    pub fn word_text(&self, word: &TextWord) -> String {
+        self.glyphs[word.glyphs.clone()]
+            .iter()
+            .map(|glyph| glyph.ch)
+            .collect()
+    }
+
+    pub fn line_text(&self, line: &TextLine) -> String {
+        self.layout
+            .line_words(line)
+            .iter()
+            .map(|word| self.word_text(word))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Text in layout reading order: one line per text line, blocks separated by a blank line.
+    /// Falls back to [`TextPage::plain_text`] when the page has no layout analysis.
+    pub fn reading_order_text(&self) -> String {
+        if self.layout.is_empty() {
+            return self.plain_text();
+        }
+
+        self.layout
+            .blocks
+            .iter()
+            .map(|block| {
+                self.layout
+                    .block_lines(block)
+                    .iter()
+                    .map(|line| self.line_text(line))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
 }
 
 pub trait PdfBackend: Send + Sync {
     fn path(&self) -> &Path;
     fn doc_id(&self) -> u64;
+    /// Password that unlocked an encrypted document; reused when the document is reloaded.
+    fn password(&self) -> Option<&str> {
+        None
+    }
     fn page_count(&self) -> usize;
     fn page_dimensions(&self, page: usize) -> AppResult<(f32, f32)>;
     fn render_page(&self, page: usize, scale: f32) -> AppResult<RgbaFrame>;
@@ -191,6 +441,29 @@ pub trait PdfBackend: Send + Sync {
     }
     fn extract_text_page(&self, page: usize) -> AppResult<TextPage>;
     fn extract_outline(&self) -> AppResult<Vec<OutlineNode>>;
+    fn extract_links(&self, page: usize) -> AppResult<Vec<PdfLink>> {
+        let _ = page;
+        Ok(Vec::new())
+    }
+    /// Markup annotations on `page`, in the page's `/Annots` order.
+    fn extract_annotations(&self, page: usize) -> AppResult<Vec<PdfAnnotation>> {
+        let _ = page;
+        Ok(Vec::new())
+    }
+    fn metadata(&self) -> AppResult<PdfMetadata> {
+        Ok(PdfMetadata::default())
+    }
+    fn page_labels(&self) -> PageLabels {
+        PageLabels::default()
+    }
+    fn attachments(&self) -> AppResult<Vec<PdfAttachment>> {
+        Ok(Vec::new())
+    }
+    /// Decoded contents of the attachment at `index` in [`PdfBackend::attachments`] order.
+    fn attachment_data(&self, index: usize) -> AppResult<Vec<u8>> {
+        let _ = index;
+        Err(AppError::unsupported("this backend has no attachments"))
+    }
 }
 
 pub trait PdfRenderContext {
@@ -210,7 +483,8 @@ impl<T: PdfBackend + ?Sized> PdfRenderContext for DefaultPdfRenderContext<'_, T>
 #[cfg(test)]
 mod tests {
     use super::{
-        PdfBackend, PdfRect, PixelBuffer, PixelBufferPool, RgbaFrame, TextGlyph, TextPage,
+        PageLabels, PdfBackend, PdfRect, PixelBuffer, PixelBufferPool, RgbaFrame, TextGlyph,
+        TextLayout, TextPage,
     };
 
     #[test]
@@ -272,6 +546,23 @@ mod tests {
         assert_eq!(pool.available(), 1);
     }
 
+    #[test]
+    fn page_labels_find_prefers_exact_matches_and_distinct_skips_numbers() {
+        let labels = PageLabels::new(vec![
+            "i".to_string(),
+            "I".to_string(),
+            "3".to_string(),
+            "A-1".to_string(),
+        ]);
+
+        assert_eq!(labels.find("I"), Some(1));
+        assert_eq!(labels.find("a-1"), Some(3));
+        assert_eq!(labels.find("iv"), None);
+        assert_eq!(labels.distinct(0), Some("i"));
+        assert_eq!(labels.distinct(2), None);
+        assert_eq!(PageLabels::default().distinct(0), None);
+    }
+
     fn _assert_pdf_backend_object_safe(_: &dyn PdfBackend) {}
 
     #[test]
@@ -294,6 +585,7 @@ mod tests {
                 },
             ],
             dropped_glyphs: 0,
+            layout: TextLayout::default(),
         };
 
         assert_eq!(page.plain_text(), "A B");
@@ -329,6 +621,7 @@ mod tests {
                 },
             ],
             dropped_glyphs: 1,
+            layout: TextLayout::default(),
         };
 
         assert_eq!(page.plain_text(), "A B");
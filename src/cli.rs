@@ -1,20 +1,158 @@
-use std::path::PathBuf;
+use std::io::{ErrorKind, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
 
-use clap::{ArgAction, Parser, ValueEnum};
-use pvf::app::PageLayoutMode;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use pvf::app::{FitMode, PageLayoutMode, PageViewMode};
+use pvf::backend::{OutlineNode, PdfBackend, SharedPdfBackend};
 use pvf::config::{AppOptions, ConfigFileSelection, ViewOptions, WatchOptions};
+use pvf::error::{AppError, AppResult};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum CliAction {
+    View(Box<CliOptions>),
+    Cache(CacheCommand),
+    Document(DocumentCommand),
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub(super) struct CliOptions {
     pub(super) pdf_path: PathBuf,
+    pub(super) password_file: Option<PathBuf>,
     pub(super) config: ConfigFileSelection,
     pub(super) options: AppOptions,
 }
 
+impl CliOptions {
+    /// Resolves the document password from `--password-file`, then `PVF_PASSWORD`.
+    #[cfg(not(test))]
+    pub(super) fn password(&self) -> AppResult<Option<String>> {
+        resolve_password(
+            self.password_file.as_deref(),
+            std::env::var("PVF_PASSWORD").ok(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub(super) enum CacheCommand {
+    /// Delete every page render kept in the on-disk cache
+    Clear,
+}
+
+/// Subcommands that read a document and print to stdout instead of opening the viewer.
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub(super) enum DocumentCommand {
+    /// Print the text of each page in reading order, pages separated by a form feed
+    Text {
+        #[command(flatten)]
+        document: DocumentArgs,
+        #[arg(
+            long,
+            value_name = "RANGE",
+            value_parser = parse_page_range,
+            help = "Print only the pages in RANGE, such as 3 or 1-5"
+        )]
+        pages: Option<RangeInclusive<usize>>,
+    },
+    /// Render a page to a PNG image
+    Render {
+        #[command(flatten)]
+        document: DocumentArgs,
+        #[arg(
+            short,
+            long,
+            value_name = "N",
+            default_value_t = 1,
+            help = "Render page N"
+        )]
+        page: usize,
+        #[arg(
+            short,
+            long,
+            value_name = "S",
+            default_value_t = 1.0,
+            help = "Render at S pixels per PDF point (1.0 is 72 DPI)"
+        )]
+        scale: f32,
+        #[arg(short, long, value_name = "PATH", help = "Write the PNG to PATH")]
+        output: PathBuf,
+    },
+    /// Print the page count and the size of each page
+    Info {
+        #[command(flatten)]
+        document: DocumentArgs,
+        #[arg(long, help = "Print JSON instead of text")]
+        json: bool,
+    },
+    /// Print the document outline
+    Outline {
+        #[command(flatten)]
+        document: DocumentArgs,
+        #[arg(long, help = "Print JSON instead of text")]
+        json: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Args)]
+pub(super) struct DocumentArgs {
+    #[arg(value_name = "FILE")]
+    pdf_path: PathBuf,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Read the password for an encrypted PDF from PATH (default: $PVF_PASSWORD)"
+    )]
+    password_file: Option<PathBuf>,
+}
+
+/// Why a document subcommand failed, reported as its exit code so scripts can tell the causes
+/// apart. Argument errors share clap's code 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum DocumentFailure {
+    Other = 1,
+    InvalidArgument = 2,
+    MissingFile = 3,
+    InvalidPdf = 4,
+    Unsupported = 5,
+    PasswordRequired = 6,
+}
+
+impl DocumentFailure {
+    /// Classifies `err`; `opening` tells errors loading the document from later ones.
+    fn of(err: &AppError, opening: bool) -> Self {
+        match err {
+            AppError::Io { source, .. } if opening && source.kind() == ErrorKind::NotFound => {
+                Self::MissingFile
+            }
+            AppError::Io { .. } => Self::Other,
+            AppError::Encrypted { .. } => Self::PasswordRequired,
+            AppError::Unsupported(_) | AppError::Unimplemented(_) => Self::Unsupported,
+            AppError::PdfRender { .. } => Self::InvalidPdf,
+            _ if opening => Self::InvalidPdf,
+            AppError::InvalidArgument(_) => Self::InvalidArgument,
+        }
+    }
+
+    pub(super) fn exit_code(self) -> i32 {
+        self as i32
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum CliCommand {
+    /// Manage the on-disk render cache
+    #[command(subcommand)]
+    Cache(CacheCommand),
+    #[command(flatten)]
+    Document(DocumentCommand),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 enum CliPageLayout {
     Single,
     Spread,
+    Continuous,
 }
 
 impl From<CliPageLayout> for PageLayoutMode {
@@ -22,6 +160,41 @@ impl From<CliPageLayout> for PageLayoutMode {
         match value {
             CliPageLayout::Single => Self::Single,
             CliPageLayout::Spread => Self::Spread,
+            CliPageLayout::Continuous => Self::Continuous,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CliPageView {
+    Image,
+    Text,
+}
+
+impl From<CliPageView> for PageViewMode {
+    fn from(value: CliPageView) -> Self {
+        match value {
+            CliPageView::Image => Self::Image,
+            CliPageView::Text => Self::Text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CliFit {
+    Page,
+    Width,
+    Height,
+    Actual,
+}
+
+impl From<CliFit> for FitMode {
+    fn from(value: CliFit) -> Self {
+        match value {
+            CliFit::Page => Self::Page,
+            CliFit::Width => Self::Width,
+            CliFit::Height => Self::Height,
+            CliFit::Actual => Self::Actual,
         }
     }
 }
@@ -30,7 +203,9 @@ impl From<CliPageLayout> for PageLayoutMode {
 #[command(
     version,
     about = "PDF viewer for the terminal",
-    disable_version_flag = true
+    disable_version_flag = true,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
 )]
 struct Cli {
     #[arg(short = 'v', long, action = ArgAction::Version, help = "Print version")]
@@ -63,18 +238,248 @@ struct Cli {
         help = "Set the initial zoom ratio relative to fit"
     )]
     zoom: Option<f32>,
+    #[arg(
+        long,
+        value_enum,
+        help = "Fit the page, its width, its height, or show it at actual size"
+    )]
+    fit: Option<CliFit>,
     #[arg(short, long, value_enum, help = "Set the initial page layout")]
     layout: Option<CliPageLayout>,
-    #[arg(value_name = "FILE")]
-    pdf_path: PathBuf,
+    #[arg(
+        long,
+        value_enum,
+        help = "Show rendered pages (image) or their reflowed text (text)"
+    )]
+    view: Option<CliPageView>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Read the password for an encrypted PDF from PATH (default: $PVF_PASSWORD)"
+    )]
+    password_file: Option<PathBuf>,
+    #[arg(
+        value_name = "FILE",
+        required = true,
+        help = "PDF to open; put -- before a FILE named like a subcommand"
+    )]
+    pdf_path: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<CliCommand>,
 }
 
 #[cfg(not(test))]
-pub(super) fn parse() -> CliOptions {
+pub(super) fn parse() -> CliAction {
     parse_cli(Cli::parse())
 }
 
-fn parse_cli(cli: Cli) -> CliOptions {
+#[cfg(not(test))]
+pub(super) fn run_cache_command(command: CacheCommand) -> AppResult<()> {
+    match command {
+        CacheCommand::Clear => {
+            let (dir, cleared) = pvf::app::clear_render_cache()?;
+            println!(
+                "Removed {} cached page renders ({:.1} MiB) from {}",
+                cleared.frames,
+                cleared.bytes as f64 / (1024.0 * 1024.0),
+                dir.display()
+            );
+            let (dir, cleared) = pvf::app::clear_text_index()?;
+            println!(
+                "Removed search text of {} documents ({:.1} MiB) from {}",
+                cleared.documents,
+                cleared.bytes as f64 / (1024.0 * 1024.0),
+                dir.display()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Runs a document subcommand against the document `open` loads, writing its output to `out`
+/// and returning the error together with how it failed.
+pub(super) fn run_document_command(
+    command: DocumentCommand,
+    out: &mut impl Write,
+    open: impl FnOnce(&Path, Option<&str>) -> AppResult<SharedPdfBackend>,
+) -> Result<(), (DocumentFailure, AppError)> {
+    let document = match &command {
+        DocumentCommand::Text { document, .. }
+        | DocumentCommand::Render { document, .. }
+        | DocumentCommand::Info { document, .. }
+        | DocumentCommand::Outline { document, .. } => document,
+    };
+    let password = resolve_password(
+        document.password_file.as_deref(),
+        std::env::var("PVF_PASSWORD").ok(),
+    )
+    .map_err(|err| (DocumentFailure::of(&err, false), err))?;
+    let pdf = open(&document.pdf_path, password.as_deref())
+        .map_err(|err| (DocumentFailure::of(&err, true), err))?;
+
+    let result = match command {
+        DocumentCommand::Text { pages, .. } => print_text(out, pdf.as_ref(), pages),
+        DocumentCommand::Render {
+            page,
+            scale,
+            output,
+            ..
+        } => render_png(pdf.as_ref(), page, scale, &output),
+        DocumentCommand::Info { json, .. } => print_info(out, pdf.as_ref(), json),
+        DocumentCommand::Outline { json, .. } => print_outline(out, pdf.as_ref(), json),
+    };
+    result.map_err(|err| (DocumentFailure::of(&err, false), err))
+}
+
+fn print_text(
+    out: &mut impl Write,
+    pdf: &dyn PdfBackend,
+    pages: Option<RangeInclusive<usize>>,
+) -> AppResult<()> {
+    let page_count = pdf.page_count();
+    let pages = pages.unwrap_or(1..=page_count);
+    if *pages.end() > page_count {
+        return Err(AppError::page_out_of_range(*pages.end(), page_count));
+    }
+
+    for (index, page) in pages.enumerate() {
+        let text = pdf.extract_text_page(page - 1)?.reading_order_text();
+        let separator = if index == 0 { "" } else { "\x0c" };
+        write_stdout(out, &format!("{separator}{}\n", text.trim_end()))?;
+    }
+    Ok(())
+}
+
+fn render_png(pdf: &dyn PdfBackend, page: usize, scale: f32, output: &Path) -> AppResult<()> {
+    if page == 0 || page > pdf.page_count() {
+        return Err(AppError::page_out_of_range(page, pdf.page_count()));
+    }
+    if !scale.is_finite() || scale <= 0.0 || scale > 16.0 {
+        return Err(AppError::invalid_argument(
+            "scale must be greater than 0 and at most 16",
+        ));
+    }
+
+    let frame = pdf.render_page(page - 1, scale)?;
+    let image = image::RgbaImage::from_raw(frame.width, frame.height, frame.into_pixels_vec())
+        .ok_or(AppError::invalid_argument(
+            "rgba frame pixels length does not match dimensions",
+        ))?;
+    image
+        .save_with_format(output, image::ImageFormat::Png)
+        .map_err(|err| {
+            let source = match err {
+                image::ImageError::IoError(source) => source,
+                other => std::io::Error::other(other),
+            };
+            AppError::io_with_context(source, format!("writing {}", output.display()))
+        })
+}
+
+fn print_info(out: &mut impl Write, pdf: &dyn PdfBackend, json: bool) -> AppResult<()> {
+    #[derive(serde::Serialize)]
+    struct PageSize {
+        width_pt: f32,
+        height_pt: f32,
+    }
+    #[derive(serde::Serialize)]
+    struct DocumentInfo {
+        page_count: usize,
+        page_sizes: Vec<PageSize>,
+    }
+
+    let page_sizes = (0..pdf.page_count())
+        .map(|page| {
+            let (width_pt, height_pt) = pdf.page_dimensions(page)?;
+            Ok(PageSize {
+                width_pt,
+                height_pt,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+    let info = DocumentInfo {
+        page_count: pdf.page_count(),
+        page_sizes,
+    };
+    if json {
+        return write_json(out, &info);
+    }
+
+    write_stdout(out, &format!("pages: {}\n", info.page_count))?;
+    for (index, size) in info.page_sizes.iter().enumerate() {
+        write_stdout(
+            out,
+            &format!(
+                "page {}: {} x {} pt\n",
+                index + 1,
+                size.width_pt,
+                size.height_pt
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+fn print_outline(out: &mut impl Write, pdf: &dyn PdfBackend, json: bool) -> AppResult<()> {
+    fn write_nodes(out: &mut impl Write, nodes: &[OutlineNode], depth: usize) -> AppResult<()> {
+        for node in nodes {
+            let indent = "  ".repeat(depth);
+            write_stdout(
+                out,
+                &format!("{indent}{} (page {})\n", node.title, node.page + 1),
+            )?;
+            write_nodes(out, &node.children, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    let outline = pdf.extract_outline()?;
+    if json {
+        return write_json(out, &outline);
+    }
+    write_nodes(out, &outline, 0)
+}
+
+fn write_json(out: &mut impl Write, value: &impl serde::Serialize) -> AppResult<()> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|source| AppError::io_with_context(source.into(), "encoding JSON"))?;
+    write_stdout(out, &format!("{json}\n"))
+}
+
+/// Writes `text` to stdout, treating a reader that went away (as `head` does) as success.
+fn write_stdout(out: &mut impl Write, text: &str) -> AppResult<()> {
+    match out.write_all(text.as_bytes()) {
+        Err(source) if source.kind() != ErrorKind::BrokenPipe => {
+            Err(AppError::io_with_context(source, "writing to stdout"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parses a 1-based page range such as `3` or `1-5`.
+fn parse_page_range(value: &str) -> Result<RangeInclusive<usize>, String> {
+    let (first, last) = value.split_once('-').unwrap_or((value, value));
+    let parse_page = |text: &str| {
+        text.trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|page| *page >= 1)
+            .ok_or_else(|| format!("page range must look like 3 or 1-5, got '{value}'"))
+    };
+    let (first, last) = (parse_page(first)?, parse_page(last)?);
+    if first > last {
+        return Err("page range must not end before it starts".to_string());
+    }
+    Ok(first..=last)
+}
+
+fn parse_cli(cli: Cli) -> CliAction {
+    match cli.command {
+        Some(CliCommand::Cache(command)) => return CliAction::Cache(command),
+        Some(CliCommand::Document(command)) => return CliAction::Document(command),
+        None => {}
+    }
+
     let config = if cli.no_config {
         ConfigFileSelection::Disabled
     } else if let Some(path) = cli.config {
@@ -82,14 +487,19 @@ fn parse_cli(cli: Cli) -> CliOptions {
     } else {
         ConfigFileSelection::Default
     };
-    CliOptions {
-        pdf_path: cli.pdf_path,
+    CliAction::View(Box::new(CliOptions {
+        pdf_path: cli
+            .pdf_path
+            .expect("clap requires FILE unless a subcommand is given"),
+        password_file: cli.password_file,
         config,
         options: AppOptions {
             view: ViewOptions {
                 initial_page: cli.page,
                 initial_zoom: cli.zoom,
                 initial_layout: cli.layout.map(PageLayoutMode::from),
+                initial_view: cli.view.map(PageViewMode::from),
+                fit: cli.fit.map(FitMode::from),
                 ..ViewOptions::default()
             },
             watch: WatchOptions {
@@ -104,23 +514,137 @@ fn parse_cli(cli: Cli) -> CliOptions {
             },
             ..AppOptions::default()
         },
+    }))
+}
+
+fn resolve_password(
+    password_file: Option<&Path>,
+    env_password: Option<String>,
+) -> AppResult<Option<String>> {
+    let Some(path) = password_file else {
+        return Ok(env_password.filter(|password| !password.is_empty()));
+    };
+
+    let mut password = std::fs::read_to_string(path).map_err(|source| {
+        AppError::io_with_context(source, format!("reading password file {}", path.display()))
+    })?;
+    // Only the line terminator written by editors and `echo` is stripped; other whitespace may
+    // be part of the password.
+    if password.ends_with('\n') {
+        password.pop();
+        if password.ends_with('\r') {
+            password.pop();
+        }
     }
+    Ok(Some(password))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
 
     use clap::{Parser, error::ErrorKind};
-    use pvf::app::PageLayoutMode;
+    use pvf::app::{FitMode, PageLayoutMode, PageViewMode};
+    use pvf::backend::{
+        OutlineNode, PdfBackend, RgbaFrame, SharedPdfBackend, TextGlyph, TextLayout, TextPage,
+    };
     use pvf::config::ConfigFileSelection;
+    use pvf::error::{AppError, AppResult};
 
-    use super::{Cli, parse_cli};
+    use super::{
+        CacheCommand, Cli, CliAction, CliOptions, DocumentArgs, DocumentCommand, DocumentFailure,
+        parse_cli, resolve_password, run_document_command,
+    };
+
+    fn view_options(cli: Cli) -> CliOptions {
+        match parse_cli(cli) {
+            CliAction::View(options) => *options,
+            other => panic!("expected viewer options, got {other:?}"),
+        }
+    }
+
+    /// Two-page document with one line of text per page and a nested outline. The library's
+    /// shared test stub is crate-private, so the binary keeps this small one.
+    struct StubPdf;
+
+    const STUB_PAGES: [(&str, (f32, f32)); 2] = [
+        ("First page", (612.0, 792.0)),
+        ("Second page", (842.0, 595.0)),
+    ];
+
+    impl PdfBackend for StubPdf {
+        fn path(&self) -> &Path {
+            Path::new("stub.pdf")
+        }
+
+        fn doc_id(&self) -> u64 {
+            1
+        }
+
+        fn page_count(&self) -> usize {
+            STUB_PAGES.len()
+        }
+
+        fn page_dimensions(&self, page: usize) -> AppResult<(f32, f32)> {
+            Ok(STUB_PAGES[page].1)
+        }
+
+        fn render_page(&self, _page: usize, scale: f32) -> AppResult<RgbaFrame> {
+            let side = scale.round() as u32;
+            Ok(RgbaFrame {
+                width: side,
+                height: side,
+                pixels: vec![255; (side * side * 4) as usize].into(),
+            })
+        }
+
+        fn extract_text_page(&self, page: usize) -> AppResult<TextPage> {
+            let (text, (width_pt, height_pt)) = STUB_PAGES[page];
+            Ok(TextPage {
+                width_pt,
+                height_pt,
+                glyphs: text
+                    .chars()
+                    .map(|ch| TextGlyph { ch, bbox: None })
+                    .collect(),
+                dropped_glyphs: 0,
+                layout: TextLayout::default(),
+            })
+        }
+
+        fn extract_outline(&self) -> AppResult<Vec<OutlineNode>> {
+            Ok(vec![OutlineNode {
+                title: "Intro".to_string(),
+                page: 0,
+                children: vec![OutlineNode {
+                    title: "Details".to_string(),
+                    page: 1,
+                    children: Vec::new(),
+                }],
+            }])
+        }
+    }
+
+    fn run_on_stub(command: DocumentCommand) -> Result<String, (DocumentFailure, AppError)> {
+        let mut out = Vec::new();
+        run_document_command(command, &mut out, |_, _| {
+            Ok(Arc::new(StubPdf) as SharedPdfBackend)
+        })?;
+        Ok(String::from_utf8(out).expect("output should be UTF-8"))
+    }
+
+    fn stub_document() -> DocumentArgs {
+        DocumentArgs {
+            pdf_path: PathBuf::from("stub.pdf"),
+            password_file: None,
+        }
+    }
 
     #[test]
     fn parse_cli_accepts_plain_pdf_path() {
         let cli = Cli::try_parse_from(["pvf", "sample.pdf"]).expect("single arg should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.pdf_path, PathBuf::from("sample.pdf"));
         assert_eq!(options.config, ConfigFileSelection::Default);
         assert_eq!(options.options.watch.enabled, None);
@@ -130,7 +654,7 @@ mod tests {
     fn parse_cli_accepts_watch_flag() {
         let cli =
             Cli::try_parse_from(["pvf", "--watch", "sample.pdf"]).expect("watch flag should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.pdf_path, PathBuf::from("sample.pdf"));
         assert_eq!(options.config, ConfigFileSelection::Default);
         assert_eq!(options.options.watch.enabled, Some(true));
@@ -140,7 +664,7 @@ mod tests {
     fn parse_cli_accepts_short_watch_flag() {
         let cli = Cli::try_parse_from(["pvf", "-w", "sample.pdf"])
             .expect("short watch flag should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.options.watch.enabled, Some(true));
     }
 
@@ -148,7 +672,7 @@ mod tests {
     fn parse_cli_accepts_explicit_config_path() {
         let cli = Cli::try_parse_from(["pvf", "--config", "pvf.toml", "sample.pdf"])
             .expect("config path should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(
             options.config,
             ConfigFileSelection::Path(PathBuf::from("pvf.toml"))
@@ -159,7 +683,7 @@ mod tests {
     fn parse_cli_accepts_short_config_path() {
         let cli =
             Cli::try_parse_from(["pvf", "-c", "pvf.toml", "sample.pdf"]).expect("config path");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(
             options.config,
             ConfigFileSelection::Path(PathBuf::from("pvf.toml"))
@@ -170,7 +694,7 @@ mod tests {
     fn parse_cli_accepts_no_config() {
         let cli = Cli::try_parse_from(["pvf", "--no-config", "sample.pdf"])
             .expect("no-config should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.config, ConfigFileSelection::Disabled);
     }
 
@@ -178,7 +702,7 @@ mod tests {
     fn parse_cli_accepts_no_watch_override() {
         let cli = Cli::try_parse_from(["pvf", "--no-watch", "sample.pdf"])
             .expect("no-watch should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.options.watch.enabled, Some(false));
     }
 
@@ -192,16 +716,19 @@ mod tests {
             "1.25",
             "--layout",
             "spread",
+            "--view",
+            "text",
             "sample.pdf",
         ])
         .expect("view overrides should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.options.view.initial_page, Some(10));
         assert_eq!(options.options.view.initial_zoom, Some(1.25));
         assert_eq!(
             options.options.view.initial_layout,
             Some(PageLayoutMode::Spread)
         );
+        assert_eq!(options.options.view.initial_view, Some(PageViewMode::Text));
     }
 
     #[test]
@@ -217,7 +744,7 @@ mod tests {
             "sample.pdf",
         ])
         .expect("short view overrides should parse");
-        let options = parse_cli(cli);
+        let options = view_options(cli);
         assert_eq!(options.options.view.initial_page, Some(10));
         assert_eq!(options.options.view.initial_zoom, Some(1.25));
         assert_eq!(
@@ -226,6 +753,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_cli_accepts_fit_mode() {
+        let cli = Cli::try_parse_from(["pvf", "--fit", "width", "sample.pdf"])
+            .expect("fit mode should parse");
+        let options = view_options(cli);
+        assert_eq!(options.options.view.fit, Some(FitMode::Width));
+        assert!(Cli::try_parse_from(["pvf", "--fit", "stretch", "sample.pdf"]).is_err());
+    }
+
+    #[test]
+    fn parse_cli_accepts_continuous_layout() {
+        let cli = Cli::try_parse_from(["pvf", "--layout", "continuous", "sample.pdf"])
+            .expect("continuous layout should parse");
+        let options = view_options(cli);
+        assert_eq!(
+            options.options.view.initial_layout,
+            Some(PageLayoutMode::Continuous)
+        );
+    }
+
+    #[test]
+    fn parse_cli_accepts_password_file() {
+        let cli = Cli::try_parse_from(["pvf", "--password-file", "secret.txt", "sample.pdf"])
+            .expect("password file should parse");
+        let options = view_options(cli);
+        assert_eq!(options.password_file, Some(PathBuf::from("secret.txt")));
+    }
+
+    #[test]
+    fn resolve_password_prefers_file_and_strips_trailing_newline() {
+        let path = std::env::temp_dir().join(format!("pvf_password_{}.txt", std::process::id()));
+        std::fs::write(&path, " s3cret \r\n").expect("password file should be written");
+
+        let password = resolve_password(Some(&path), Some("from-env".to_string()))
+            .expect("password file should be read");
+        std::fs::remove_file(&path).expect("password file should be removed");
+
+        assert_eq!(password.as_deref(), Some(" s3cret "));
+    }
+
+    #[test]
+    fn resolve_password_falls_back_to_non_empty_env() {
+        assert_eq!(
+            resolve_password(None, Some("from-env".to_string())).expect("env password"),
+            Some("from-env".to_string())
+        );
+        assert_eq!(
+            resolve_password(None, Some(String::new())).expect("empty env password"),
+            None
+        );
+        assert!(resolve_password(Some(std::path::Path::new("/nonexistent/pvf")), None).is_err());
+    }
+
+    #[test]
+    fn parse_cli_accepts_cache_clear_without_file() {
+        let cli = Cli::try_parse_from(["pvf", "cache", "clear"]).expect("cache clear should parse");
+        assert_eq!(parse_cli(cli), CliAction::Cache(CacheCommand::Clear));
+        assert!(Cli::try_parse_from(["pvf", "cache"]).is_err());
+        assert!(Cli::try_parse_from(["pvf", "--watch", "cache", "clear"]).is_err());
+    }
+
+    #[test]
+    fn parse_cli_accepts_document_subcommands() {
+        let document = DocumentArgs {
+            pdf_path: PathBuf::from("sample.pdf"),
+            password_file: None,
+        };
+        let parse = |args: &[&str]| {
+            parse_cli(Cli::try_parse_from(args).expect("document subcommand should parse"))
+        };
+
+        assert_eq!(
+            parse(&["pvf", "text", "sample.pdf", "--pages", "2-4"]),
+            CliAction::Document(DocumentCommand::Text {
+                document: document.clone(),
+                pages: Some(2..=4),
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "pvf",
+                "render",
+                "sample.pdf",
+                "-p",
+                "3",
+                "-s",
+                "2",
+                "-o",
+                "out.png"
+            ]),
+            CliAction::Document(DocumentCommand::Render {
+                document: document.clone(),
+                page: 3,
+                scale: 2.0,
+                output: PathBuf::from("out.png"),
+            })
+        );
+        assert_eq!(
+            parse(&["pvf", "outline", "sample.pdf", "--json"]),
+            CliAction::Document(DocumentCommand::Outline {
+                document,
+                json: true,
+            })
+        );
+        assert!(Cli::try_parse_from(["pvf", "text", "sample.pdf", "--pages", "5-2"]).is_err());
+        assert!(Cli::try_parse_from(["pvf", "render", "sample.pdf"]).is_err());
+        assert!(Cli::try_parse_from(["pvf", "info"]).is_err());
+        assert!(Cli::try_parse_from(["pvf", "--watch", "info", "sample.pdf"]).is_err());
+    }
+
+    #[test]
+    fn document_failures_tell_missing_invalid_and_unsupported_apart() {
+        let missing = AppError::io_with_context(
+            std::io::Error::new(std::io::ErrorKind::NotFound, "missing file"),
+            "pdf file not found: sample.pdf",
+        );
+        let invalid = AppError::invalid_argument("failed to parse PDF with hayro");
+        let unsupported = AppError::unsupported("pdf encryption algorithm is not supported");
+
+        assert_eq!(
+            DocumentFailure::of(&missing, true),
+            DocumentFailure::MissingFile
+        );
+        assert_eq!(
+            DocumentFailure::of(&invalid, true),
+            DocumentFailure::InvalidPdf
+        );
+        assert_eq!(
+            DocumentFailure::of(&invalid, false),
+            DocumentFailure::InvalidArgument
+        );
+        assert_eq!(
+            DocumentFailure::of(&unsupported, true),
+            DocumentFailure::Unsupported
+        );
+        assert_eq!(
+            DocumentFailure::of(&AppError::encrypted(false), true),
+            DocumentFailure::PasswordRequired
+        );
+        assert_eq!(DocumentFailure::MissingFile.exit_code(), 3);
+    }
+
     #[test]
     fn parse_cli_uses_lowercase_v_for_version() {
         let err = Cli::try_parse_from(["pvf", "-v"]).expect_err("version should exit early");
@@ -246,5 +915,130 @@ mod tests {
         );
         assert!(Cli::try_parse_from(["pvf", "--watch", "--no-watch", "sample.pdf"]).is_err());
         assert!(Cli::try_parse_from(["pvf", "--layout", "grid", "sample.pdf"]).is_err());
+        assert!(Cli::try_parse_from(["pvf", "--view", "html", "sample.pdf"]).is_err());
+    }
+
+    #[test]
+    fn double_dash_opens_a_file_named_like_a_subcommand() {
+        let cli = Cli::try_parse_from(["pvf", "--", "text"]).expect("escaped FILE should parse");
+        assert_eq!(view_options(cli).pdf_path, PathBuf::from("text"));
+
+        let cli = Cli::try_parse_from(["pvf", "./info"]).expect("relative FILE should parse");
+        assert_eq!(view_options(cli).pdf_path, PathBuf::from("./info"));
+    }
+
+    #[test]
+    fn text_command_prints_pages_separated_by_form_feeds() {
+        let all = run_on_stub(DocumentCommand::Text {
+            document: stub_document(),
+            pages: None,
+        })
+        .expect("text should print");
+        assert_eq!(all, "First page\n\x0cSecond page\n");
+
+        let second = run_on_stub(DocumentCommand::Text {
+            document: stub_document(),
+            pages: Some(2..=2),
+        })
+        .expect("text should print");
+        assert_eq!(second, "Second page\n");
+
+        let (failure, _) = run_on_stub(DocumentCommand::Text {
+            document: stub_document(),
+            pages: Some(1..=3),
+        })
+        .expect_err("page 3 does not exist");
+        assert_eq!(failure, DocumentFailure::InvalidArgument);
+    }
+
+    #[test]
+    fn info_command_prints_page_sizes_as_text_or_json() {
+        let text = run_on_stub(DocumentCommand::Info {
+            document: stub_document(),
+            json: false,
+        })
+        .expect("info should print");
+        assert_eq!(
+            text,
+            "pages: 2\npage 1: 612 x 792 pt\npage 2: 842 x 595 pt\n"
+        );
+
+        let json = run_on_stub(DocumentCommand::Info {
+            document: stub_document(),
+            json: true,
+        })
+        .expect("info should print");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("info should be JSON");
+        assert_eq!(value["page_count"], 2);
+        assert_eq!(value["page_sizes"][1]["width_pt"], 842.0);
+    }
+
+    #[test]
+    fn outline_command_indents_children_and_numbers_pages_from_one() {
+        let text = run_on_stub(DocumentCommand::Outline {
+            document: stub_document(),
+            json: false,
+        })
+        .expect("outline should print");
+        assert_eq!(text, "Intro (page 1)\n  Details (page 2)\n");
+
+        let json = run_on_stub(DocumentCommand::Outline {
+            document: stub_document(),
+            json: true,
+        })
+        .expect("outline should print");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("outline should be JSON");
+        assert_eq!(value[0]["children"][0]["page"], 1);
+    }
+
+    #[test]
+    fn render_command_writes_a_png_of_the_scaled_page() {
+        let output =
+            std::env::temp_dir().join(format!("pvf-cli-render-{}.png", std::process::id()));
+        let render = |page, scale| {
+            run_on_stub(DocumentCommand::Render {
+                document: stub_document(),
+                page,
+                scale,
+                output: output.clone(),
+            })
+        };
+
+        render(2, 3.0).expect("render should write the PNG");
+        let image = image::open(&output).expect("output should be a PNG");
+        std::fs::remove_file(&output).expect("output should be removed");
+        assert_eq!((image.width(), image.height()), (3, 3));
+
+        assert_eq!(
+            render(3, 1.0).expect_err("page 3 does not exist").0,
+            DocumentFailure::InvalidArgument
+        );
+        assert_eq!(
+            render(1, 0.0).expect_err("scale must be positive").0,
+            DocumentFailure::InvalidArgument
+        );
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn document_command_reports_a_missing_file_as_such() {
+        let mut out = Vec::new();
+        let (failure, _) = run_document_command(
+            DocumentCommand::Info {
+                document: stub_document(),
+                json: false,
+            },
+            &mut out,
+            |path, _| {
+                Err(AppError::io_with_context(
+                    std::io::Error::new(std::io::ErrorKind::NotFound, "missing file"),
+                    format!("pdf file not found: {}", path.display()),
+                ))
+            },
+        )
+        .expect_err("opening should fail");
+
+        assert_eq!(failure, DocumentFailure::MissingFile);
+        assert!(out.is_empty());
     }
 }
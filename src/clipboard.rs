@@ -0,0 +1,96 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::error::{AppError, AppResult};
+
+/// Largest text sent over OSC 52; terminals commonly drop sequences past 100 000 encoded bytes.
+const OSC52_MAX_TEXT_BYTES: usize = 74_994;
+
+/// Copies `text` through the `[clipboard] command` when one is set, and over OSC 52 otherwise.
+pub(crate) fn copy_to_clipboard(text: &str, command: Option<&str>) -> AppResult<()> {
+    if let Some(command) = command {
+        return pipe_to_command(command, text);
+    }
+    if text.len() > OSC52_MAX_TEXT_BYTES {
+        return Err(AppError::unsupported(
+            "text is too large for OSC 52; set [clipboard] command to copy it",
+        ));
+    }
+    copy_via_osc52(text)
+}
+
+/// Copies `text` to the system clipboard through the terminal's OSC 52 escape sequence.
+pub(crate) fn copy_via_osc52(text: &str) -> AppResult<()> {
+    let mut stdout = io::stdout().lock();
+    stdout
+        .write_all(osc52_sequence(text).as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|source| AppError::io_with_context(source, "writing clipboard sequence"))
+}
+
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+pub(crate) fn pipe_to_command(command_line: &str, text: &str) -> AppResult<()> {
+    let mut parts = command_line.split_whitespace();
+    let Some(program) = parts.next() else {
+        return Err(AppError::invalid_argument("clipboard.command is empty"));
+    };
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|source| {
+            AppError::io_with_context(
+                source,
+                format!("running clipboard command `{command_line}`"),
+            )
+        })?;
+    let written = child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(text.as_bytes());
+    // Some clipboard tools stay around to serve the selection, so reap them in the background.
+    std::thread::spawn(move || child.wait());
+    written.map_err(|source| {
+        AppError::io_with_context(
+            source,
+            format!("writing to clipboard command `{command_line}`"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copy_to_clipboard, osc52_sequence, pipe_to_command};
+
+    #[test]
+    fn osc52_sequence_wraps_base64_payload() {
+        assert_eq!(
+            osc52_sequence("https://example.com"),
+            "\x1b]52;c;aHR0cHM6Ly9leGFtcGxlLmNvbQ==\x07"
+        );
+    }
+
+    #[test]
+    fn oversized_text_needs_a_clipboard_command() {
+        let text = "x".repeat(80_000);
+
+        assert!(copy_to_clipboard(&text, None).is_err());
+        assert!(copy_to_clipboard(&text, Some("cat")).is_ok());
+        assert!(pipe_to_command("  ", "text").is_err());
+    }
+
+    #[test]
+    fn configured_command_is_used_even_for_short_text() {
+        assert!(copy_to_clipboard("short", Some("cat")).is_ok());
+        assert!(copy_to_clipboard("short", Some("pvf-missing-clipboard-tool")).is_err());
+    }
+}
@@ -5,9 +5,10 @@ use crate::palette::{PaletteKind, PaletteOpenPayload};
 use super::dispatch::CommandExecContext;
 use super::effects::CommandExecution;
 use super::types::{
-    ArgHint, ArgKind, ArgSpec, CommandExposure, CommandInvocationPolicy, CommandInvocationSource,
-    CommandRole, CommandSpec, CommandTargetRequirement, PanAmount, PanDirection, SearchMatcherKind,
-    SpreadCoverPolicyArg, SpreadDirectionArg,
+    ArgHint, ArgKind, ArgSpec, CaretMotion, CommandExposure, CommandInvocationPolicy,
+    CommandInvocationSource, CommandRole, CommandSpec, CommandTargetRequirement, ExportScope,
+    PanAmount, PanDirection, SearchMatcherKind, SelectionKind, SpreadCoverPolicyArg,
+    SpreadDirectionArg,
 };
 
 const NO_ARGS: [ArgSpec; 0] = [];
@@ -20,6 +21,12 @@ const ARGS_GOTO_PAGE: [ArgSpec; 1] = [ArgSpec {
     required: true,
     hint: ArgHint::None,
 }];
+const ARGS_GOTO_PAGE_LABEL: [ArgSpec; 1] = [ArgSpec {
+    name: "label",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::None,
+}];
 const ARGS_ZOOM: [ArgSpec; 1] = [ArgSpec {
     name: "ratio",
     kind: ArgKind::F32,
@@ -68,6 +75,21 @@ const ARGS_OPEN_PALETTE: [ArgSpec; 2] = [
         hint: ArgHint::None,
     },
 ];
+const ARGS_PREVIEW_SEARCH: [ArgSpec; 2] = [
+    ArgSpec {
+        name: "query",
+        kind: ArgKind::String,
+        required: false,
+        hint: ArgHint::None,
+    },
+    ArgSpec {
+        name: "matcher",
+        kind: ArgKind::String,
+        required: false,
+        hint: ArgHint::None,
+    },
+];
+
 const ARGS_SUBMIT_SEARCH: [ArgSpec; 2] = [
     ArgSpec {
         name: "query",
@@ -82,6 +104,18 @@ const ARGS_SUBMIT_SEARCH: [ArgSpec; 2] = [
         hint: ArgHint::None,
     },
 ];
+const ARGS_PIN_SEARCH: [ArgSpec; 1] = [ArgSpec {
+    name: "name",
+    kind: ArgKind::String,
+    required: false,
+    hint: ArgHint::None,
+}];
+const ARGS_HIGHLIGHT_LAYER: [ArgSpec; 1] = [ArgSpec {
+    name: "layer",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::None,
+}];
 const ARGS_TEXT_INSERT: [ArgSpec; 1] = [ArgSpec {
     name: "text",
     kind: ArgKind::String,
@@ -102,6 +136,74 @@ const ARGS_OUTLINE_GOTO: [ArgSpec; 2] = [
         hint: ArgHint::None,
     },
 ];
+const ARGS_ATTACHMENTS: [ArgSpec; 1] = [ArgSpec {
+    name: "dir",
+    kind: ArgKind::String,
+    required: false,
+    hint: ArgHint::None,
+}];
+const ARGS_SAVE_ATTACHMENT: [ArgSpec; 1] = [ArgSpec {
+    name: "index",
+    kind: ArgKind::I32,
+    required: true,
+    hint: ArgHint::None,
+}];
+const ARGS_ANNOTATION_GOTO: [ArgSpec; 1] = [ArgSpec {
+    name: "index",
+    kind: ArgKind::I32,
+    required: true,
+    hint: ArgHint::None,
+}];
+const ARGS_MARK: [ArgSpec; 1] = [ArgSpec {
+    name: "name",
+    kind: ArgKind::String,
+    required: false,
+    hint: ArgHint::None,
+}];
+const ARGS_LINK_OPEN: [ArgSpec; 1] = [ArgSpec {
+    name: "uri",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::None,
+}];
+const ARGS_YANK_PAGES: [ArgSpec; 1] = [ArgSpec {
+    name: "pages",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::None,
+}];
+const ARGS_EXPORT_IMAGE: [ArgSpec; 3] = [
+    ArgSpec {
+        name: "scope",
+        kind: ArgKind::String,
+        required: false,
+        hint: ArgHint::Enum(ExportScope::values),
+    },
+    ArgSpec {
+        name: "dpi",
+        kind: ArgKind::I32,
+        required: false,
+        hint: ArgHint::None,
+    },
+    ArgSpec {
+        name: "path",
+        kind: ArgKind::String,
+        required: false,
+        hint: ArgHint::None,
+    },
+];
+const ARGS_VISUAL_MOVE: [ArgSpec; 1] = [ArgSpec {
+    name: "motion",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::Enum(CaretMotion::values),
+}];
+const ARGS_VISUAL_SELECT: [ArgSpec; 1] = [ArgSpec {
+    name: "kind",
+    kind: ArgKind::String,
+    required: true,
+    hint: ArgHint::Enum(SelectionKind::values),
+}];
 
 macro_rules! define_commands {
     (
@@ -249,10 +351,20 @@ macro_rules! define_commands {
     (@role CloseHelp) => { CommandRole::SurfaceControl };
     (@role HelpScrollDown) => { CommandRole::SurfaceControl };
     (@role HelpScrollUp) => { CommandRole::SurfaceControl };
+    (@role VisualMove) => { CommandRole::SurfaceControl };
+    (@role VisualSelect) => { CommandRole::SurfaceControl };
+    (@role VisualYank) => { CommandRole::SurfaceControl };
+    (@role ExitVisualMode) => { CommandRole::SurfaceControl };
     (@role SubmitSearch) => { CommandRole::InternalEffect };
+    (@role PreviewSearch) => { CommandRole::InternalEffect };
+    (@role EndSearchPreview) => { CommandRole::InternalEffect };
     (@role SearchResultGoto) => { CommandRole::InternalEffect };
     (@role HistoryGoto) => { CommandRole::InternalEffect };
     (@role OutlineGoto) => { CommandRole::InternalEffect };
+    (@role LinkGoto) => { CommandRole::InternalEffect };
+    (@role OpenLinkUri) => { CommandRole::InternalEffect };
+    (@role SaveAttachment) => { CommandRole::InternalEffect };
+    (@role AnnotationGoto) => { CommandRole::InternalEffect };
     (@role $variant:ident) => { CommandRole::UserIntent };
 
     (@target ClosePalette) => { CommandTargetRequirement::ActivePalette };
@@ -279,6 +391,10 @@ macro_rules! define_commands {
     (@target CloseHelp) => { CommandTargetRequirement::ActiveHelp };
     (@target HelpScrollDown) => { CommandTargetRequirement::ActiveHelp };
     (@target HelpScrollUp) => { CommandTargetRequirement::ActiveHelp };
+    (@target VisualMove) => { CommandTargetRequirement::ActiveVisual };
+    (@target VisualSelect) => { CommandTargetRequirement::ActiveVisual };
+    (@target VisualYank) => { CommandTargetRequirement::ActiveVisual };
+    (@target ExitVisualMode) => { CommandTargetRequirement::ActiveVisual };
     (@target $variant:ident) => { CommandTargetRequirement::App };
 }
 
@@ -333,6 +449,17 @@ define_commands! {
         parse: (super::parse::parse_goto_page),
         exec: super::handlers::goto_page,
     }
+    // `goto-page` parses non-numeric arguments into this, so it stays out of the palette list.
+    GotoPageLabel(label: String) {
+        id: "goto-page-label",
+        title: "Go to Page Label",
+        args: &ARGS_GOTO_PAGE_LABEL,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_goto_page_label),
+        exec: super::handlers::goto_page_label,
+    }
     SetZoom(value: f32) {
         id: "zoom",
         title: "Zoom",
@@ -373,6 +500,46 @@ define_commands! {
         parse: no_args,
         exec: super::handlers::zoom_reset,
     }
+    FitPage {
+        id: "fit-page",
+        title: "Fit Page",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::fit_page,
+    }
+    FitWidth {
+        id: "fit-width",
+        title: "Fit Width",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::fit_width,
+    }
+    FitHeight {
+        id: "fit-height",
+        title: "Fit Height",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::fit_height,
+    }
+    ActualSize {
+        id: "actual-size",
+        title: "Actual Size",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::actual_size,
+    }
     Pan(direction: PanDirection, amount: PanAmount) {
         id: "pan",
         title: "Pan",
@@ -406,6 +573,26 @@ define_commands! {
         parse: (super::parse::parse_page_layout_spread),
         exec: super::handlers::page_layout_spread,
     }
+    PageLayoutContinuous {
+        id: "layout-continuous",
+        title: "Continuous Layout",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::page_layout_continuous,
+    }
+    TextViewToggle {
+        id: "text-view-toggle",
+        title: "Toggle Text View",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::text_view_toggle,
+    }
     DebugStatusShow {
         id: "debug-show",
         title: "Show Debug Info",
@@ -669,6 +856,16 @@ define_commands! {
         parse: no_args,
         exec: super::handlers::open_help,
     }
+    DocumentInfo {
+        id: "info",
+        title: "Show Document Info",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::document_info,
+    }
     CloseHelp {
         id: "close-help",
         title: "Close Help",
@@ -733,6 +930,26 @@ define_commands! {
         parse: (super::parse::parse_submit_search),
         exec: super::handlers::submit_search,
     }
+    PreviewSearch(query: String, matcher: SearchMatcherKind) {
+        id: "preview-search",
+        title: "Preview Search",
+        args: &ARGS_PREVIEW_SEARCH,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_preview_search),
+        exec: super::handlers::preview_search,
+    }
+    EndSearchPreview {
+        id: "end-search-preview",
+        title: "End Search Preview",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::end_search_preview,
+    }
     SearchResultGoto(page: usize) {
         id: "search-goto",
         title: "Search Go to Result",
@@ -763,6 +980,47 @@ define_commands! {
         parse: no_args,
         exec: super::handlers::prev_search_hit,
     }
+    PinSearch(name: Option<String>) {
+        id: "pin-search",
+        title: "Pin Search as Highlight Layer",
+        args: &ARGS_PIN_SEARCH,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::All(&REQUIRES_SEARCH_ACTIVE),
+        parse: (super::parse::parse_pin_search),
+        exec: super::handlers::pin_search,
+    }
+    OpenHighlightLayers {
+        id: "highlight-layers",
+        title: "Open Highlight Layers",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::open_highlight_layers,
+    }
+    // Layers are picked by name, or by the number shown in the layers palette.
+    ToggleHighlightLayer(layer: String) {
+        id: "toggle-highlight-layer",
+        title: "Show/Hide Highlight Layer",
+        args: &ARGS_HIGHLIGHT_LAYER,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_toggle_highlight_layer),
+        exec: super::handlers::toggle_highlight_layer,
+    }
+    RemoveHighlightLayer(layer: String) {
+        id: "remove-highlight-layer",
+        title: "Remove Highlight Layer",
+        args: &ARGS_HIGHLIGHT_LAYER,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_remove_highlight_layer),
+        exec: super::handlers::remove_highlight_layer,
+    }
     HistoryBack {
         id: "history-back",
         title: "History Back",
@@ -823,6 +1081,205 @@ define_commands! {
         parse: (super::parse::parse_outline_goto),
         exec: super::handlers::outline_goto,
     }
+    OpenAttachments(dir: Option<String>) {
+        id: "attachments",
+        title: "Open Attachments",
+        args: &ARGS_ATTACHMENTS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_open_attachments),
+        exec: super::handlers::open_attachments,
+    }
+    SaveAttachment(index: usize) {
+        id: "save-attachment",
+        title: "Save Attachment",
+        args: &ARGS_SAVE_ATTACHMENT,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_save_attachment),
+        exec: super::handlers::save_attachment,
+    }
+    OpenAnnotations {
+        id: "annotations",
+        title: "Open Annotations",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::open_annotations,
+    }
+    AnnotationGoto(index: usize) {
+        id: "annotation-goto",
+        title: "Go to Annotation",
+        args: &ARGS_ANNOTATION_GOTO,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_annotation_goto),
+        exec: super::handlers::annotation_goto,
+    }
+    // Without a name these wait for the next key press to pick the mark letter.
+    SetMark(name: Option<char>) {
+        id: "set-mark",
+        title: "Set Mark",
+        args: &ARGS_MARK,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_set_mark),
+        exec: super::handlers::set_mark,
+    }
+    GotoMark(name: Option<char>) {
+        id: "goto-mark",
+        title: "Go to Mark",
+        args: &ARGS_MARK,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_goto_mark),
+        exec: super::handlers::goto_mark,
+    }
+    OpenMarks {
+        id: "marks",
+        title: "Open Marks",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::open_marks,
+    }
+    LinkHints {
+        id: "link-hints",
+        title: "Show Link Hints",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::link_hints,
+    }
+    LinkGoto(page: usize) {
+        id: "link-goto",
+        title: "Follow Link to Page",
+        args: &ARGS_GOTO_PAGE,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_link_goto),
+        exec: super::handlers::link_goto,
+    }
+    // Opening a URI needs the link opener config and the terminal, so the event loop
+    // intercepts this command before dispatch (see `App::follow_link_uri`).
+    OpenLinkUri(uri: String) {
+        id: "link-open",
+        title: "Open Link URI",
+        args: &ARGS_LINK_OPEN,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::InternalOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_link_open),
+        exec: super::handlers::open_link_uri,
+    }
+    VisualMode {
+        id: "visual-mode",
+        title: "Select Text on Page",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::visual_mode,
+    }
+    VisualMove(motion: CaretMotion) {
+        id: "visual-move",
+        title: "Move Visual Caret",
+        args: &ARGS_VISUAL_MOVE,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::BindingOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_visual_move),
+        exec: super::handlers::visual_move,
+    }
+    VisualSelect(kind: SelectionKind) {
+        id: "visual-select",
+        title: "Toggle Visual Selection",
+        args: &ARGS_VISUAL_SELECT,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::BindingOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_visual_select),
+        exec: super::handlers::visual_select,
+    }
+    // Copying needs the clipboard config and the terminal, so the event loop intercepts this
+    // command before dispatch (see `App::yank_visual_selection`).
+    VisualYank {
+        id: "visual-yank",
+        title: "Copy Visual Selection",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::BindingOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::visual_yank,
+    }
+    ExitVisualMode {
+        id: "exit-visual-mode",
+        title: "Exit Visual Mode",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Internal,
+        invocation: CommandInvocationPolicy::BindingOnly,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::exit_visual_mode,
+    }
+    // Copying needs the clipboard config and the terminal, so the event loop intercepts the
+    // yank commands before dispatch and extracts the text on a worker (see `App::plan_pages_yank`).
+    YankPage {
+        id: "yank-page",
+        title: "Copy Page Text",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::yank_page,
+    }
+    YankPages(first: usize, last: usize) {
+        id: "yank-pages",
+        title: "Copy Text of Pages",
+        args: &ARGS_YANK_PAGES,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_yank_pages),
+        exec: super::handlers::yank_pages,
+    }
+    // Exporting the view needs the terminal viewport, so the event loop intercepts this command
+    // before dispatch (see `App::export_image`).
+    ExportImage(scope: ExportScope, dpi: Option<u32>, path: Option<String>) {
+        id: "export-image",
+        title: "Export Image",
+        args: &ARGS_EXPORT_IMAGE,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: (super::parse::parse_export_image),
+        exec: super::handlers::export_image,
+    }
+    OpenExport {
+        id: "export",
+        title: "Open Export Image",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::open_export,
+    }
     CancelSearch {
         id: "cancel-search",
         title: "Cancel Search",
@@ -843,6 +1300,18 @@ define_commands! {
         parse: no_args,
         exec: super::handlers::reload_document,
     }
+    // The saved position lives with the app, so the event loop intercepts this command before
+    // dispatch (see `App::forget_reading_position`).
+    ForgetPosition {
+        id: "forget-position",
+        title: "Forget Saved Reading Position",
+        args: &NO_ARGS,
+        exposure: CommandExposure::Public,
+        invocation: CommandInvocationPolicy::User,
+        enabled_when: ConditionExpr::Always,
+        parse: no_args,
+        exec: super::handlers::forget_position,
+    }
     Quit {
         id: "quit",
         title: "Quit",
@@ -861,7 +1330,9 @@ mod tests {
     use std::sync::Arc;
 
     use crate::app::AppState;
-    use crate::backend::{OutlineNode, PdfBackend, RgbaFrame, SharedPdfBackend, TextPage};
+    use crate::backend::{
+        OutlineNode, PdfBackend, RgbaFrame, SharedPdfBackend, TextLayout, TextPage,
+    };
     use crate::extension::ExtensionHost;
     use crate::palette::{PaletteManager, PaletteRegistry};
 
@@ -914,6 +1385,7 @@ mod tests {
                 height_pt: 792.0,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
@@ -1,6 +1,7 @@
 use crate::app::scale::{ZOOM_MAX, ZOOM_MIN};
 use crate::app::{
-    AppState, Mode, NoticeAction, PageLayoutMode, SpreadCoverPolicy, SpreadDirection,
+    AppState, FitMode, Mode, NoticeAction, PageLayoutMode, PageViewMode, SpreadCoverPolicy,
+    SpreadDirection,
 };
 use crate::error::{AppError, AppResult};
 
@@ -96,6 +97,7 @@ pub(crate) fn set_page_layout(
     let next_mode = match mode {
         PageLayoutModeArg::Single => PageLayoutMode::Single,
         PageLayoutModeArg::Spread => PageLayoutMode::Spread,
+        PageLayoutModeArg::Continuous => PageLayoutMode::Continuous,
     };
     let next_direction = match direction {
         Some(SpreadDirectionArg::Ltr) => SpreadDirection::Ltr,
@@ -106,10 +108,11 @@ pub(crate) fn set_page_layout(
         Some(SpreadCoverPolicyArg::Cover) => SpreadCoverPolicy::Cover,
         Some(SpreadCoverPolicyArg::Paired) | None => SpreadCoverPolicy::Paired,
     };
-    if next_mode == PageLayoutMode::Single && (direction.is_some() || cover_policy.is_some()) {
-        return Err(AppError::invalid_argument(
-            "single layout does not accept spread arguments",
-        ));
+    if next_mode != PageLayoutMode::Spread && (direction.is_some() || cover_policy.is_some()) {
+        return Err(AppError::invalid_argument(format!(
+            "{} layout does not accept spread arguments",
+            next_mode.id()
+        )));
     }
 
     let changed = app.page_layout_mode != next_mode
@@ -131,6 +134,34 @@ pub(crate) fn set_page_layout(
     Ok(applied())
 }
 
+pub(crate) fn set_page_view_mode(
+    app: &mut AppState,
+    mode: PageViewMode,
+) -> AppResult<CommandNoticeResult> {
+    if app.view_mode == mode {
+        return Ok(noop());
+    }
+
+    app.view_mode = mode;
+    // Pan is a pixel offset in image view and a line offset in text view, so neither carries over.
+    app.pan_x = 0;
+    app.pan_y = 0;
+    Ok(applied())
+}
+
+pub(crate) fn set_fit_mode(app: &mut AppState, mode: FitMode) -> AppResult<CommandNoticeResult> {
+    if app.fit_mode == mode && app.zoom == 1.0 && app.pan_x == 0 && app.pan_y == 0 {
+        return Ok(noop());
+    }
+
+    // Zoom is relative to the fit, so a new fit starts from its own 1.0x.
+    app.fit_mode = mode;
+    app.zoom = 1.0;
+    app.pan_x = 0;
+    app.pan_y = 0;
+    Ok(applied())
+}
+
 pub(crate) fn set_zoom(app: &mut AppState, value: f32) -> AppResult<CommandNoticeResult> {
     set_zoom_with_notice(app, value, NoticeAction::Clear)
 }
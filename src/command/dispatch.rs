@@ -187,7 +187,9 @@ fn derive_nav_reason(command: &Command, extension_host: &ExtensionHost) -> Optio
         Command::NextPage | Command::PrevPage => Some(NavReason::Step),
         Command::FirstPage => Some(NavReason::PageGoto(PageGotoKind::First)),
         Command::LastPage => Some(NavReason::PageGoto(PageGotoKind::Last)),
-        Command::GotoPage { .. } => Some(NavReason::PageGoto(PageGotoKind::Specific)),
+        Command::GotoPage { .. } | Command::GotoPageLabel { .. } => {
+            Some(NavReason::PageGoto(PageGotoKind::Specific))
+        }
         Command::PageLayoutSingle | Command::PageLayoutSpread { .. } => {
             Some(NavReason::LayoutNormalize)
         }
@@ -202,6 +204,9 @@ fn derive_nav_reason(command: &Command, extension_host: &ExtensionHost) -> Optio
         Command::OutlineGoto { title, .. } => Some(NavReason::Outline {
             title: title.clone(),
         }),
+        Command::LinkGoto { .. } => Some(NavReason::Link),
+        Command::AnnotationGoto { .. } => Some(NavReason::Annotation),
+        Command::GotoMark { name: Some(name) } => Some(NavReason::Mark { name: *name }),
         _ => None,
     }
 }
@@ -216,7 +221,9 @@ mod tests {
     use crate::app::{
         AppState, Mode, Notice, NoticeLevel, PaletteRequest, SpreadCoverPolicy, SpreadDirection,
     };
-    use crate::backend::{PdfBackend, RgbaFrame, SharedPdfBackend, TextPage};
+    use crate::backend::{
+        PageLabels, PdfBackend, RgbaFrame, SharedPdfBackend, TextLayout, TextPage,
+    };
     use crate::command::{
         Command, CommandId, CommandInvocationSource, CommandLifecycleEffect, CommandOutcome,
         PanAmount, PanDirection, SearchMatcherKind, SpreadCoverPolicyArg,
@@ -278,6 +285,7 @@ mod tests {
                 height_pt: 792.0,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
@@ -385,6 +393,101 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn dispatch_goto_mark_records_the_jump_with_the_mark_reason() {
+        let mut app = AppState::default();
+        let pdf = Arc::new(StubPdf::new(3)) as SharedPdfBackend;
+        let mut host = ExtensionHost::default();
+        let mut palette_requests = VecDeque::new();
+
+        app.current_page = 2;
+        dispatch(
+            &mut app,
+            Command::SetMark { name: Some('t') },
+            CommandInvocationSource::Binding,
+            Arc::clone(&pdf),
+            &mut host,
+            &mut palette_requests,
+        )
+        .expect("set-mark should succeed");
+        app.current_page = 0;
+
+        let result = dispatch(
+            &mut app,
+            Command::GotoMark { name: Some('t') },
+            CommandInvocationSource::Binding,
+            pdf,
+            &mut host,
+            &mut palette_requests,
+        )
+        .expect("goto-mark should succeed");
+
+        assert_eq!(app.current_page, 2);
+        assert!(matches!(
+            result.emitted_events[0],
+            AppEvent::PageChanged {
+                from: 0,
+                to: 2,
+                reason: NavReason::Mark { name: 't' }
+            }
+        ));
+    }
+
+    #[test]
+    fn dispatch_goto_page_keeps_numbers_physical_and_resolves_labels_by_name() {
+        let labels = ["i", "ii", "1", "2", "A-1"].map(str::to_string);
+        let mut app = AppState {
+            page_labels: PageLabels::new(labels.to_vec()),
+            ..AppState::default()
+        };
+        let pdf = Arc::new(StubPdf::new(5)) as SharedPdfBackend;
+        let mut host = ExtensionHost::default();
+        let mut palette_requests = VecDeque::new();
+        let mut goto = |app: &mut AppState, cmd| {
+            dispatch(
+                app,
+                cmd,
+                CommandInvocationSource::CommandPaletteInput,
+                Arc::clone(&pdf),
+                &mut host,
+                &mut palette_requests,
+            )
+        };
+
+        goto(&mut app, Command::GotoPage { page: 2 }).expect("dispatch should succeed");
+        assert_eq!(app.current_page, 1);
+        goto(
+            &mut app,
+            Command::GotoPageLabel {
+                label: "2".to_string(),
+            },
+        )
+        .expect("dispatch should succeed");
+        assert_eq!(app.current_page, 3);
+        goto(
+            &mut app,
+            Command::GotoPageLabel {
+                label: "a-1".to_string(),
+            },
+        )
+        .expect("dispatch should succeed");
+        assert_eq!(app.current_page, 4);
+        goto(&mut app, Command::GotoPage { page: 5 }).expect("dispatch should succeed");
+        assert_eq!(app.current_page, 4);
+
+        let err = goto(
+            &mut app,
+            Command::GotoPageLabel {
+                label: "xii".to_string(),
+            },
+        )
+        .expect_err("unknown labels should fail");
+        assert_eq!(
+            err.to_string(),
+            "invalid argument: no page is labeled `xii`"
+        );
+    }
+
     #[test]
     fn dispatch_zoom_in_and_out_follow_the_zoom_ladder() {
         let mut app = AppState {
@@ -455,6 +558,31 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn dispatch_fit_width_restarts_zoom_from_the_new_fit() {
+        let mut app = AppState {
+            zoom: 2.0,
+            pan_y: 7,
+            ..AppState::default()
+        };
+        let (pdf, mut host, mut palette_requests) = new_zoom_test_fixture();
+
+        let result = dispatch(
+            &mut app,
+            Command::FitWidth,
+            CommandInvocationSource::Binding,
+            pdf,
+            &mut host,
+            &mut palette_requests,
+        )
+        .expect("dispatch should succeed");
+
+        assert_eq!(result.outcome, CommandOutcome::Applied);
+        assert_eq!(app.fit_mode, crate::app::FitMode::Width);
+        assert!(zoom_eq(app.zoom, 1.0));
+        assert_eq!(app.pan_y, 0);
+    }
+
     #[test]
     fn dispatch_pan_applies_explicit_cell_amount() {
         let mut app = AppState::default();
@@ -822,6 +950,71 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn dispatch_search_palette_typing_previews_and_closing_ends_preview() {
+        let mut app = AppState {
+            mode: Mode::Palette,
+            ..AppState::default()
+        };
+        let pdf = Arc::new(StubPdf::new(3)) as SharedPdfBackend;
+        let mut host = ExtensionHost::default();
+        let registry = PaletteRegistry::default();
+        let mut manager = PaletteManager::default();
+        let extensions = host.ui_snapshot();
+        manager
+            .open(
+                &registry,
+                &app,
+                &extensions,
+                PaletteKind::Search,
+                None,
+                None,
+            )
+            .expect("search palette should open");
+        let mut palette_requests = VecDeque::new();
+        let mut history = InputHistoryService::default();
+
+        let mut follow_ups = Vec::new();
+        for command in [
+            Command::TextInsert {
+                text: "ne".to_string(),
+            },
+            Command::ClosePalette,
+        ] {
+            let result = dispatch_with_view_policy(
+                &mut app,
+                ViewPolicy::default(),
+                command,
+                CommandInvocationSource::Binding,
+                CommandDispatchContext {
+                    pdf: Arc::clone(&pdf),
+                    extension_host: &mut host,
+                    palette_registry: &registry,
+                    palette_manager: &mut manager,
+                    palette_requests: &mut palette_requests,
+                    input_history: &mut history,
+                },
+            )
+            .expect("palette command should dispatch");
+            follow_ups.extend(result.follow_up_commands);
+        }
+
+        let commands = follow_ups
+            .into_iter()
+            .map(|request| request.command)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            commands,
+            vec![
+                Command::PreviewSearch {
+                    query: "ne".to_string(),
+                    matcher: SearchMatcherKind::ContainsInsensitive,
+                },
+                Command::EndSearchPreview,
+            ]
+        );
+    }
+
     #[test]
     fn dispatch_rejects_palette_command_without_active_palette() {
         let mut app = AppState::default();
@@ -1150,6 +1343,35 @@ mod tests {
         assert_eq!(app.current_page, 0);
     }
 
+    #[test]
+    fn dispatch_page_layout_continuous_keeps_page_and_resets_pan() {
+        let mut app = AppState {
+            current_page: 3,
+            page_layout_mode: crate::app::PageLayoutMode::Spread,
+            pan_x: 2,
+            pan_y: 5,
+            ..AppState::default()
+        };
+        let pdf = Arc::new(StubPdf::new(8)) as SharedPdfBackend;
+        let mut host = ExtensionHost::default();
+        let mut palette_requests = VecDeque::new();
+
+        let result = dispatch(
+            &mut app,
+            Command::PageLayoutContinuous,
+            CommandInvocationSource::Binding,
+            pdf,
+            &mut host,
+            &mut palette_requests,
+        )
+        .expect("dispatch should succeed");
+
+        assert_eq!(result.outcome, CommandOutcome::Applied);
+        assert_eq!(app.page_layout_mode, crate::app::PageLayoutMode::Continuous);
+        assert_eq!(app.current_page, 3);
+        assert_eq!((app.pan_x, app.pan_y), (0, 0));
+    }
+
     #[test]
     fn dispatch_page_layout_spread_cover_keeps_cover_policy() {
         let mut app = AppState::default();
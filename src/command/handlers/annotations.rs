@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn open_annotations(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let request = ctx
+        .extension_host
+        .command_ports()
+        .annotations
+        .open_palette(pdf)?;
+    Ok(CommandExecution::applied().with_palette_request(request))
+}
+
+pub(in crate::command) fn annotation_goto(
+    ctx: &mut CommandExecContext<'_>,
+    index: usize,
+) -> AppResult<CommandExecution> {
+    let page_count = ctx.page_count();
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .annotations
+        .goto(ctx.app, page_count, index)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn open_attachments(
+    ctx: &mut CommandExecContext<'_>,
+    dir: Option<String>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let request = ctx
+        .extension_host
+        .command_ports()
+        .attachments
+        .open_palette(pdf, dir)?;
+    Ok(CommandExecution::applied().with_palette_request(request))
+}
+
+pub(in crate::command) fn save_attachment(
+    ctx: &mut CommandExecContext<'_>,
+    index: usize,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .attachments
+        .save(ctx.pdf.as_ref(), index)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
@@ -19,6 +19,12 @@ pub(in crate::command) fn reload_document(
     Ok(CommandExecution::applied())
 }
 
+pub(in crate::command) fn forget_position(
+    _ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
+
 pub(in crate::command) fn quit(_ctx: &mut CommandExecContext<'_>) -> AppResult<CommandExecution> {
     Ok(CommandExecution::applied().with_lifecycle(CommandLifecycleEffect::Quit))
 }
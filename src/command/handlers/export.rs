@@ -0,0 +1,27 @@
+use crate::app::PaletteRequest;
+use crate::error::AppResult;
+use crate::palette::PaletteKind;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+use super::super::types::ExportScope;
+
+pub(in crate::command) fn export_image(
+    _ctx: &mut CommandExecContext<'_>,
+    _scope: ExportScope,
+    _dpi: Option<u32>,
+    _path: Option<String>,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
+
+pub(in crate::command) fn open_export(
+    _ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    Ok(
+        CommandExecution::applied().with_palette_request(PaletteRequest::Open {
+            kind: PaletteKind::Export,
+            payload: None,
+        }),
+    )
+}
@@ -0,0 +1,15 @@
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn document_info(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .info
+        .show(ctx.pdf.as_ref())?;
+    Ok(CommandExecution::from_notice_result(result))
+}
@@ -1,6 +1,7 @@
+use crate::app::PageViewMode;
 use crate::error::AppResult;
 
-use super::super::core::set_page_layout;
+use super::super::core::{set_page_layout, set_page_view_mode};
 use super::super::dispatch::CommandExecContext;
 use super::super::effects::CommandExecution;
 use super::super::types::{PageLayoutModeArg, SpreadCoverPolicyArg, SpreadDirectionArg};
@@ -32,6 +33,19 @@ pub(in crate::command) fn page_layout_single(
     Ok(CommandExecution::from_notice_result(result))
 }
 
+pub(in crate::command) fn page_layout_continuous(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = set_page_layout(
+        ctx.app,
+        ctx.page_count(),
+        PageLayoutModeArg::Continuous,
+        None,
+        None,
+    )?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
 pub(in crate::command) fn page_layout_spread(
     ctx: &mut CommandExecContext<'_>,
     direction: Option<SpreadDirectionArg>,
@@ -48,3 +62,14 @@ pub(in crate::command) fn page_layout_spread(
     )?;
     Ok(CommandExecution::from_notice_result(result))
 }
+
+pub(in crate::command) fn text_view_toggle(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let mode = match ctx.app.view_mode {
+        PageViewMode::Image => PageViewMode::Text,
+        PageViewMode::Text => PageViewMode::Image,
+    };
+    let result = set_page_view_mode(ctx.app, mode)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
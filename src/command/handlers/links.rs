@@ -0,0 +1,35 @@
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn link_hints(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .links
+        .show(ctx.app, ctx.pdf.as_ref())?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn link_goto(
+    ctx: &mut CommandExecContext<'_>,
+    page: usize,
+) -> AppResult<CommandExecution> {
+    let page_count = ctx.page_count();
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .links
+        .goto(ctx.app, page_count, page)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn open_link_uri(
+    _ctx: &mut CommandExecContext<'_>,
+    _uri: String,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn set_mark(
+    ctx: &mut CommandExecContext<'_>,
+    name: Option<char>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .marks
+        .set(ctx.app, pdf.as_ref(), name)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn goto_mark(
+    ctx: &mut CommandExecContext<'_>,
+    name: Option<char>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .marks
+        .goto(ctx.app, pdf.as_ref(), name)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn open_marks(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let request = ctx
+        .extension_host
+        .command_ports()
+        .marks
+        .open_palette(pdf.as_ref());
+    Ok(CommandExecution::applied().with_palette_request(request))
+}
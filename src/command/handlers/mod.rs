@@ -1,29 +1,48 @@
+mod annotations;
+mod attachments;
 mod control;
 mod debug;
+mod export;
 mod help;
 mod history;
+mod info;
 mod layout;
+mod links;
+mod marks;
 mod navigation;
 mod outline;
 mod palette;
 mod search;
 mod text;
 mod viewport;
+mod visual;
+mod yank;
 
-pub(super) use control::{cancel_search, quit, reload_document};
+pub(super) use annotations::{annotation_goto, open_annotations};
+pub(super) use attachments::{open_attachments, save_attachment};
+pub(super) use control::{cancel_search, forget_position, quit, reload_document};
 pub(super) use debug::{debug_status_hide, debug_status_show, debug_status_toggle};
+pub(super) use export::{export_image, open_export};
 pub(super) use help::{close_help, help_scroll_down, help_scroll_up, open_help};
 pub(super) use history::{history_back, history_forward, history_goto, open_history};
-pub(super) use layout::{page_layout_single, page_layout_spread};
-pub(super) use navigation::{first_page, goto_page, last_page, next_page, prev_page};
+pub(super) use info::document_info;
+pub(super) use layout::{
+    page_layout_continuous, page_layout_single, page_layout_spread, text_view_toggle,
+};
+pub(super) use links::{link_goto, link_hints, open_link_uri};
+pub(super) use marks::{goto_mark, open_marks, set_mark};
+pub(super) use navigation::{
+    first_page, goto_page, goto_page_label, last_page, next_page, prev_page,
+};
 pub(super) use outline::{open_outline, outline_goto};
 pub(super) use palette::{
     close_palette, open_palette, palette_complete, palette_select_next, palette_select_prev,
     palette_submit,
 };
 pub(super) use search::{
-    next_search_hit, open_search, open_search_results, prev_search_hit, search_result_goto,
-    submit_search,
+    end_search_preview, next_search_hit, open_highlight_layers, open_search, open_search_results,
+    pin_search, prev_search_hit, preview_search, remove_highlight_layer, search_result_goto,
+    submit_search, toggle_highlight_layer,
 };
 pub(super) use text::{
     palette_input_history_newer, palette_input_history_older, text_delete_backward,
@@ -31,4 +50,8 @@ pub(super) use text::{
     text_delete_to_end, text_insert, text_move_end, text_move_left, text_move_next_word,
     text_move_prev_word, text_move_right, text_move_start, text_yank,
 };
-pub(super) use viewport::{pan, set_zoom, zoom_in, zoom_out, zoom_reset};
+pub(super) use viewport::{
+    actual_size, fit_height, fit_page, fit_width, pan, set_zoom, zoom_in, zoom_out, zoom_reset,
+};
+pub(super) use visual::{exit_visual_mode, visual_mode, visual_move, visual_select, visual_yank};
+pub(super) use yank::{yank_page, yank_pages};
@@ -1,4 +1,4 @@
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 
 use super::super::core::{
     first_page as first_page_core, goto_page as goto_page_core, last_page as last_page_core,
@@ -42,3 +42,16 @@ pub(in crate::command) fn goto_page(
     let result = goto_page_core(ctx.app, ctx.page_count(), page)?;
     Ok(CommandExecution::from_notice_result(result))
 }
+
+pub(in crate::command) fn goto_page_label(
+    ctx: &mut CommandExecContext<'_>,
+    label: String,
+) -> AppResult<CommandExecution> {
+    let Some(index) = ctx.app.page_labels.find(&label) else {
+        return Err(AppError::invalid_argument(format!(
+            "no page is labeled `{label}`"
+        )));
+    };
+    let result = goto_page_core(ctx.app, ctx.page_count(), index + 1)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
@@ -1,6 +1,6 @@
 use crate::app::Mode;
 use crate::app::PaletteRequest;
-use crate::command::{CommandInvocationSource, CommandOutcome, CommandRequest};
+use crate::command::{CommandInvocationSource, CommandRequest};
 use crate::error::AppResult;
 use crate::palette::{PaletteKind, PaletteOpenPayload, PalettePostAction, PaletteSubmitEffect};
 
@@ -16,9 +16,20 @@ pub(in crate::command) fn open_palette(
 }
 
 pub(in crate::command) fn close_palette(
-    _ctx: &mut CommandExecContext<'_>,
+    ctx: &mut CommandExecContext<'_>,
 ) -> AppResult<CommandExecution> {
-    Ok(CommandExecution::applied().with_palette_request(PaletteRequest::Close))
+    let extensions = ctx.extension_host.ui_snapshot();
+    let cancel = ctx
+        .palette_manager
+        .cancel_command(ctx.palette_registry, ctx.app, &extensions);
+    let mut execution = CommandExecution::applied().with_palette_request(PaletteRequest::Close);
+    if let Some(command) = cancel {
+        execution = execution.with_follow_up(CommandRequest::new(
+            command,
+            CommandInvocationSource::Internal,
+        ));
+    }
+    Ok(execution)
 }
 
 pub(in crate::command) fn palette_submit(
@@ -58,7 +69,13 @@ pub(in crate::command) fn palette_submit(
                 PaletteKind::Search
                 | PaletteKind::SearchResults
                 | PaletteKind::History
-                | PaletteKind::Outline => CommandInvocationSource::Internal,
+                | PaletteKind::Outline
+                | PaletteKind::Attachments
+                | PaletteKind::Annotations
+                | PaletteKind::Marks
+                | PaletteKind::HighlightLayers
+                | PaletteKind::Export
+                | PaletteKind::Password => CommandInvocationSource::Internal,
             };
             execution = execution.with_follow_up(CommandRequest::new(command, source));
             match next {
@@ -80,40 +97,41 @@ pub(in crate::command) fn palette_complete(
     let changed = ctx
         .palette_manager
         .complete(ctx.palette_registry, ctx.app, &extensions)?;
-    Ok(CommandExecution::from_notice_result((
-        if changed {
-            CommandOutcome::Applied
-        } else {
-            CommandOutcome::Noop
-        },
-        crate::app::NoticeAction::Clear,
-    )))
+    Ok(with_palette_change(ctx, changed))
 }
 
 pub(in crate::command) fn palette_select_next(
     ctx: &mut CommandExecContext<'_>,
 ) -> AppResult<CommandExecution> {
     let changed = ctx.palette_manager.select_next_item();
-    Ok(CommandExecution::from_notice_result((
-        if changed {
-            CommandOutcome::Applied
-        } else {
-            CommandOutcome::Noop
-        },
-        crate::app::NoticeAction::Clear,
-    )))
+    Ok(with_palette_change(ctx, changed))
 }
 
 pub(in crate::command) fn palette_select_prev(
     ctx: &mut CommandExecContext<'_>,
 ) -> AppResult<CommandExecution> {
     let changed = ctx.palette_manager.select_previous();
-    Ok(CommandExecution::from_notice_result((
-        if changed {
-            CommandOutcome::Applied
-        } else {
-            CommandOutcome::Noop
-        },
-        crate::app::NoticeAction::Clear,
-    )))
+    Ok(with_palette_change(ctx, changed))
+}
+
+/// Result of a palette edit, running the provider's change command when something changed.
+pub(super) fn with_palette_change(
+    ctx: &mut CommandExecContext<'_>,
+    changed: bool,
+) -> CommandExecution {
+    if !changed {
+        return CommandExecution::noop();
+    }
+    let extensions = ctx.extension_host.ui_snapshot();
+    let execution = CommandExecution::applied();
+    match ctx
+        .palette_manager
+        .change_command(ctx.palette_registry, ctx.app, &extensions)
+    {
+        Some(command) => execution.with_follow_up(CommandRequest::new(
+            command,
+            CommandInvocationSource::Internal,
+        )),
+        None => execution,
+    }
 }
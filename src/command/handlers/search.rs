@@ -41,6 +41,75 @@ pub(in crate::command) fn submit_search(
     Ok(CommandExecution::from_notice_result(result))
 }
 
+pub(in crate::command) fn preview_search(
+    ctx: &mut CommandExecContext<'_>,
+    query: String,
+    matcher: SearchMatcherKind,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .search
+        .preview(ctx.app, pdf, query, matcher);
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn end_search_preview(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .search
+        .end_preview(ctx.app);
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn pin_search(
+    ctx: &mut CommandExecContext<'_>,
+    name: Option<String>,
+) -> AppResult<CommandExecution> {
+    let pdf = Arc::clone(&ctx.pdf);
+    let result = ctx.extension_host.command_ports().search.pin(pdf, name);
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn open_highlight_layers(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let request = ctx
+        .extension_host
+        .command_ports()
+        .search
+        .open_layers_palette();
+    Ok(CommandExecution::applied().with_palette_request(request))
+}
+
+pub(in crate::command) fn toggle_highlight_layer(
+    ctx: &mut CommandExecContext<'_>,
+    layer: String,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .search
+        .toggle_layer(&layer)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn remove_highlight_layer(
+    ctx: &mut CommandExecContext<'_>,
+    layer: String,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .search
+        .remove_layer(&layer)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
 pub(in crate::command) fn search_result_goto(
     ctx: &mut CommandExecContext<'_>,
     page: usize,
@@ -1,22 +1,22 @@
-use crate::app::NoticeAction;
-use crate::command::CommandOutcome;
 use crate::error::AppResult;
 use tui_input::InputRequest;
 
 use super::super::dispatch::CommandExecContext;
 use super::super::effects::CommandExecution;
+use super::palette::with_palette_change;
 
 pub(in crate::command) fn text_insert(
     ctx: &mut CommandExecContext<'_>,
     text: String,
 ) -> AppResult<CommandExecution> {
     let extensions = ctx.extension_host.ui_snapshot();
-    text_execution(ctx.palette_manager.insert_text(
+    let changed = ctx.palette_manager.insert_text(
         ctx.palette_registry,
         ctx.app,
         &extensions,
         text.as_str(),
-    )?)
+    )?;
+    text_execution(ctx, changed)
 }
 
 pub(in crate::command) fn text_delete_backward(
@@ -102,45 +102,32 @@ fn text_edit(
     request: InputRequest,
 ) -> AppResult<CommandExecution> {
     let extensions = ctx.extension_host.ui_snapshot();
-    text_execution(ctx.palette_manager.edit_input(
-        ctx.palette_registry,
-        ctx.app,
-        &extensions,
-        request,
-    )?)
+    let changed =
+        ctx.palette_manager
+            .edit_input(ctx.palette_registry, ctx.app, &extensions, request)?;
+    text_execution(ctx, changed)
 }
 
 pub(in crate::command) fn palette_input_history_older(
     ctx: &mut CommandExecContext<'_>,
 ) -> AppResult<CommandExecution> {
     let extensions = ctx.extension_host.ui_snapshot();
-    text_execution(ctx.palette_manager.recall_history(
-        ctx.palette_registry,
-        ctx.app,
-        &extensions,
-        true,
-    )?)
+    let changed =
+        ctx.palette_manager
+            .recall_history(ctx.palette_registry, ctx.app, &extensions, true)?;
+    text_execution(ctx, changed)
 }
 
 pub(in crate::command) fn palette_input_history_newer(
     ctx: &mut CommandExecContext<'_>,
 ) -> AppResult<CommandExecution> {
     let extensions = ctx.extension_host.ui_snapshot();
-    text_execution(ctx.palette_manager.recall_history(
-        ctx.palette_registry,
-        ctx.app,
-        &extensions,
-        false,
-    )?)
-}
-
-fn text_execution(changed: bool) -> AppResult<CommandExecution> {
-    Ok(CommandExecution::from_notice_result((
-        if changed {
-            CommandOutcome::Applied
-        } else {
-            CommandOutcome::Noop
-        },
-        NoticeAction::Clear,
-    )))
+    let changed =
+        ctx.palette_manager
+            .recall_history(ctx.palette_registry, ctx.app, &extensions, false)?;
+    text_execution(ctx, changed)
+}
+
+fn text_execution(ctx: &mut CommandExecContext<'_>, changed: bool) -> AppResult<CommandExecution> {
+    Ok(with_palette_change(ctx, changed))
 }
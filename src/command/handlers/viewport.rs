@@ -1,8 +1,10 @@
-use crate::app::NoticeAction;
 use crate::app::scale::{ZOOM_MAX, ZOOM_MIN, next_zoom_step, prev_zoom_step};
+use crate::app::{FitMode, NoticeAction};
 use crate::error::AppResult;
 
-use super::super::core::{reset_zoom, set_zoom as set_zoom_core, set_zoom_with_notice};
+use super::super::core::{
+    reset_zoom, set_fit_mode, set_zoom as set_zoom_core, set_zoom_with_notice,
+};
 use super::super::dispatch::CommandExecContext;
 use super::super::effects::CommandExecution;
 use super::super::types::{PanAmount, PanDirection};
@@ -46,6 +48,34 @@ pub(in crate::command) fn zoom_reset(
     Ok(CommandExecution::from_notice_result(result))
 }
 
+pub(in crate::command) fn fit_page(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = set_fit_mode(ctx.app, FitMode::Page)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn fit_width(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = set_fit_mode(ctx.app, FitMode::Width)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn fit_height(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = set_fit_mode(ctx.app, FitMode::Height)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn actual_size(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = set_fit_mode(ctx.app, FitMode::Actual)?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
 pub(in crate::command) fn pan(
     ctx: &mut CommandExecContext<'_>,
     direction: PanDirection,
@@ -0,0 +1,49 @@
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+use super::super::types::{CaretMotion, SelectionKind};
+
+pub(in crate::command) fn visual_mode(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .selection
+        .enter(ctx.app, ctx.pdf.as_ref())?;
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn visual_move(
+    ctx: &mut CommandExecContext<'_>,
+    motion: CaretMotion,
+) -> AppResult<CommandExecution> {
+    let result = ctx
+        .extension_host
+        .command_ports()
+        .selection
+        .move_caret(motion);
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn visual_select(
+    ctx: &mut CommandExecContext<'_>,
+    kind: SelectionKind,
+) -> AppResult<CommandExecution> {
+    let result = ctx.extension_host.command_ports().selection.select(kind);
+    Ok(CommandExecution::from_notice_result(result))
+}
+
+pub(in crate::command) fn visual_yank(
+    _ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
+
+pub(in crate::command) fn exit_visual_mode(
+    ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    let result = ctx.extension_host.command_ports().selection.exit(ctx.app);
+    Ok(CommandExecution::from_notice_result(result))
+}
@@ -0,0 +1,18 @@
+use crate::error::AppResult;
+
+use super::super::dispatch::CommandExecContext;
+use super::super::effects::CommandExecution;
+
+pub(in crate::command) fn yank_page(
+    _ctx: &mut CommandExecContext<'_>,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
+
+pub(in crate::command) fn yank_pages(
+    _ctx: &mut CommandExecContext<'_>,
+    _first: usize,
+    _last: usize,
+) -> AppResult<CommandExecution> {
+    Ok(CommandExecution::applied())
+}
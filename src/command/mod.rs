@@ -23,8 +23,8 @@ pub use spec::{
     CommandPolicyContext, all_command_specs, find_command_spec, is_command_visible_in_palette,
 };
 pub use types::{
-    ArgHint, ArgKind, ArgSpec, CommandInvocationSource, CommandOutcome, CommandSpec, PanAmount,
-    PanDirection, SearchMatcherKind,
+    ArgHint, ArgKind, ArgSpec, CaretMotion, CommandInvocationSource, CommandOutcome, CommandSpec,
+    ExportScope, PanAmount, PanDirection, SearchMatcherKind, SelectionKind,
 };
 #[cfg(test)]
 pub use types::{CommandExposure, SpreadCoverPolicyArg, SpreadDirectionArg};
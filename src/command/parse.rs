@@ -6,9 +6,13 @@ use crate::palette::{PaletteKind, PaletteOpenPayload};
 use super::catalog::{self, Command};
 use super::spec::{CommandPolicyContext, find_command_spec, validate_command_id_for_policy};
 use super::types::{
-    PanAmount, PanDirection, SearchMatcherKind, SpreadCoverPolicyArg, SpreadDirectionArg,
+    CaretMotion, ExportScope, PanAmount, PanDirection, SearchMatcherKind, SelectionKind,
+    SpreadCoverPolicyArg, SpreadDirectionArg,
 };
 
+/// Highest DPI `export-image` renders at; an A4 page is about 560 MB of pixels there.
+const MAX_EXPORT_DPI: u32 = 1200;
+
 pub fn parse_command_text(input: &str) -> AppResult<Command> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -97,6 +101,12 @@ fn parse_open_palette_payload(kind: PaletteKind, input: &str) -> Option<PaletteO
         PaletteKind::SearchResults => PaletteOpenPayload::SearchResultsQuery(input.to_string()),
         PaletteKind::History => PaletteOpenPayload::HistorySeed(input.to_string()),
         PaletteKind::Outline => PaletteOpenPayload::OutlineQuery(input.to_string()),
+        PaletteKind::Attachments => PaletteOpenPayload::AttachmentsQuery(input.to_string()),
+        PaletteKind::Annotations => PaletteOpenPayload::AnnotationsQuery(input.to_string()),
+        PaletteKind::Marks => PaletteOpenPayload::MarksQuery(input.to_string()),
+        PaletteKind::HighlightLayers => PaletteOpenPayload::HighlightLayersQuery(input.to_string()),
+        PaletteKind::Export => PaletteOpenPayload::ExportInput(input.to_string()),
+        PaletteKind::Password => return None,
     })
 }
 
@@ -124,9 +134,11 @@ pub(super) fn parse_goto_page(args_text: &str) -> AppResult<Command> {
         ));
     }
 
-    let page = page_text
-        .parse::<i32>()
-        .map_err(|_| AppError::invalid_argument("goto-page page must be an integer"))?;
+    let Ok(page) = page_text.parse::<i32>() else {
+        return Ok(Command::GotoPageLabel {
+            label: page_text.to_string(),
+        });
+    };
     if page < 1 {
         return Err(AppError::invalid_argument("page number must be >= 1"));
     }
@@ -136,6 +148,19 @@ pub(super) fn parse_goto_page(args_text: &str) -> AppResult<Command> {
     })
 }
 
+pub(super) fn parse_goto_page_label(args_text: &str) -> AppResult<Command> {
+    let label = args_text.trim();
+    if label.is_empty() {
+        return Err(AppError::invalid_argument(
+            "goto-page-label requires 1 argument: label",
+        ));
+    }
+
+    Ok(Command::GotoPageLabel {
+        label: label.to_string(),
+    })
+}
+
 pub(super) fn parse_zoom(args_text: &str) -> AppResult<Command> {
     let mut parts = args_text.split_whitespace();
     let Some(value_text) = parts.next() else {
@@ -248,24 +273,34 @@ pub(super) fn parse_submit_search(args_text: &str) -> AppResult<Command> {
         ));
     }
 
-    let mut query = trimmed.to_string();
-    let mut matcher = SearchMatcherKind::ContainsInsensitive;
-
-    if let Some((head, tail)) = split_last_token(trimmed)
-        && let Some(parsed) = SearchMatcherKind::parse(tail)
-    {
-        if head.trim().is_empty() {
-            return Err(AppError::invalid_argument(
-                "submit-search requires at least 1 argument: query",
-            ));
-        }
-        query = head.trim().to_string();
-        matcher = parsed;
+    let (query, matcher) = split_search_matcher(trimmed);
+    if query.is_empty() {
+        return Err(AppError::invalid_argument(
+            "submit-search requires at least 1 argument: query",
+        ));
     }
 
     Ok(Command::SubmitSearch { query, matcher })
 }
 
+pub(super) fn parse_preview_search(args_text: &str) -> AppResult<Command> {
+    let (query, matcher) = split_search_matcher(args_text.trim());
+    Ok(Command::PreviewSearch { query, matcher })
+}
+
+/// Splits an optional trailing matcher id off a search query.
+fn split_search_matcher(args_text: &str) -> (String, SearchMatcherKind) {
+    if let Some((head, tail)) = split_last_token(args_text)
+        && let Some(matcher) = SearchMatcherKind::parse(tail)
+    {
+        return (head.trim().to_string(), matcher);
+    }
+    (
+        args_text.to_string(),
+        SearchMatcherKind::ContainsInsensitive,
+    )
+}
+
 pub(super) fn parse_search_goto(args_text: &str) -> AppResult<Command> {
     let mut parts = args_text.split_whitespace();
     let Some(page_text) = parts.next() else {
@@ -350,6 +385,226 @@ pub(super) fn parse_outline_goto(args_text: &str) -> AppResult<Command> {
     })
 }
 
+pub(super) fn parse_link_goto(args_text: &str) -> AppResult<Command> {
+    let mut parts = args_text.split_whitespace();
+    let Some(page_text) = parts.next() else {
+        return Err(AppError::invalid_argument(
+            "link-goto requires 1 argument: page",
+        ));
+    };
+    if parts.next().is_some() {
+        return Err(AppError::invalid_argument(
+            "link-goto accepts exactly 1 argument",
+        ));
+    }
+
+    let page = page_text
+        .parse::<i32>()
+        .map_err(|_| AppError::invalid_argument("link-goto page must be an integer"))?;
+    if page < 1 {
+        return Err(AppError::invalid_argument("page number must be >= 1"));
+    }
+
+    Ok(Command::LinkGoto {
+        page: (page - 1) as usize,
+    })
+}
+
+pub(super) fn parse_open_attachments(args_text: &str) -> AppResult<Command> {
+    let dir = args_text.trim();
+    Ok(Command::OpenAttachments {
+        dir: (!dir.is_empty()).then(|| dir.to_string()),
+    })
+}
+
+pub(super) fn parse_save_attachment(args_text: &str) -> AppResult<Command> {
+    let index = parse_index_argument("save-attachment", args_text)?;
+    Ok(Command::SaveAttachment { index })
+}
+
+pub(super) fn parse_annotation_goto(args_text: &str) -> AppResult<Command> {
+    let index = parse_index_argument("annotation-goto", args_text)?;
+    Ok(Command::AnnotationGoto { index })
+}
+
+pub(super) fn parse_pin_search(args_text: &str) -> AppResult<Command> {
+    let name = args_text.trim();
+    Ok(Command::PinSearch {
+        name: (!name.is_empty()).then(|| name.to_string()),
+    })
+}
+
+pub(super) fn parse_toggle_highlight_layer(args_text: &str) -> AppResult<Command> {
+    let layer = parse_layer_argument("toggle-highlight-layer", args_text)?;
+    Ok(Command::ToggleHighlightLayer { layer })
+}
+
+pub(super) fn parse_remove_highlight_layer(args_text: &str) -> AppResult<Command> {
+    let layer = parse_layer_argument("remove-highlight-layer", args_text)?;
+    Ok(Command::RemoveHighlightLayer { layer })
+}
+
+fn parse_layer_argument(command_id: &str, args_text: &str) -> AppResult<String> {
+    let layer = args_text.trim();
+    if layer.is_empty() {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} requires a layer name or number"
+        )));
+    }
+    Ok(layer.to_string())
+}
+
+pub(super) fn parse_yank_pages(args_text: &str) -> AppResult<Command> {
+    let range = parse_single_token("yank-pages", "a page range", args_text)?;
+    let (first, last) = range.split_once('-').unwrap_or((range, range));
+    let parse_page = |text: &str| {
+        text.trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|page| *page >= 1)
+            .ok_or_else(|| {
+                AppError::invalid_argument(format!(
+                    "yank-pages range must look like 3 or 3-7, got '{range}'"
+                ))
+            })
+    };
+    let (first, last) = (parse_page(first)?, parse_page(last)?);
+    if first > last {
+        return Err(AppError::invalid_argument(
+            "yank-pages range must not end before it starts",
+        ));
+    }
+    Ok(Command::YankPages { first, last })
+}
+
+/// Parses `[scope] [dpi] [path]`, each optional but in that order; the path is the rest of the
+/// input, so it may contain spaces.
+pub(super) fn parse_export_image(args_text: &str) -> AppResult<Command> {
+    let mut rest = args_text.trim();
+    let mut scope = ExportScope::Page;
+    if let Some(parsed) = ExportScope::parse(first_token(rest)) {
+        scope = parsed;
+        rest = rest[first_token(rest).len()..].trim_start();
+    }
+
+    let mut dpi = None;
+    let dpi_text = first_token(rest);
+    if !dpi_text.is_empty() && dpi_text.bytes().all(|byte| byte.is_ascii_digit()) {
+        let value = dpi_text
+            .parse::<u32>()
+            .ok()
+            .filter(|value| (1..=MAX_EXPORT_DPI).contains(value))
+            .ok_or_else(|| {
+                AppError::invalid_argument(format!(
+                    "export-image dpi must be between 1 and {MAX_EXPORT_DPI}"
+                ))
+            })?;
+        dpi = Some(value);
+        rest = rest[dpi_text.len()..].trim_start();
+    }
+
+    Ok(Command::ExportImage {
+        scope,
+        dpi,
+        path: (!rest.is_empty()).then(|| rest.to_string()),
+    })
+}
+
+pub(super) fn parse_visual_move(args_text: &str) -> AppResult<Command> {
+    let motion = parse_single_token("visual-move", "motion", args_text)?;
+    let motion = CaretMotion::parse(motion).ok_or_else(|| {
+        AppError::invalid_argument(format!(
+            "visual-move motion must be one of: {}",
+            CaretMotion::values().join(", ")
+        ))
+    })?;
+    Ok(Command::VisualMove { motion })
+}
+
+pub(super) fn parse_visual_select(args_text: &str) -> AppResult<Command> {
+    let kind = parse_single_token("visual-select", "kind", args_text)?;
+    let kind = SelectionKind::parse(kind).ok_or(AppError::invalid_argument(
+        "visual-select kind must be one of: char, line",
+    ))?;
+    Ok(Command::VisualSelect { kind })
+}
+
+fn parse_single_token<'a>(command_id: &str, name: &str, args_text: &'a str) -> AppResult<&'a str> {
+    let mut parts = args_text.split_whitespace();
+    let Some(token) = parts.next() else {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} requires {name}"
+        )));
+    };
+    if parts.next().is_some() {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} accepts only {name}"
+        )));
+    }
+    Ok(token)
+}
+
+pub(super) fn parse_set_mark(args_text: &str) -> AppResult<Command> {
+    let name = parse_mark_argument("set-mark", args_text)?;
+    Ok(Command::SetMark { name })
+}
+
+pub(super) fn parse_goto_mark(args_text: &str) -> AppResult<Command> {
+    let name = parse_mark_argument("goto-mark", args_text)?;
+    Ok(Command::GotoMark { name })
+}
+
+fn parse_mark_argument(command_id: &str, args_text: &str) -> AppResult<Option<char>> {
+    let mut parts = args_text.split_whitespace();
+    let Some(name_text) = parts.next() else {
+        return Ok(None);
+    };
+    if parts.next().is_some() {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} accepts at most 1 argument"
+        )));
+    }
+
+    let mut chars = name_text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(name), None) if name.is_ascii_alphabetic() => Ok(Some(name)),
+        _ => Err(AppError::invalid_argument(format!(
+            "{command_id} name must be a letter a-z or A-Z"
+        ))),
+    }
+}
+
+fn parse_index_argument(command_id: &str, args_text: &str) -> AppResult<usize> {
+    let mut parts = args_text.split_whitespace();
+    let Some(index_text) = parts.next() else {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} requires 1 argument: index"
+        )));
+    };
+    if parts.next().is_some() {
+        return Err(AppError::invalid_argument(format!(
+            "{command_id} accepts exactly 1 argument"
+        )));
+    }
+
+    index_text.parse::<usize>().map_err(|_| {
+        AppError::invalid_argument(format!("{command_id} index must be a non-negative integer"))
+    })
+}
+
+pub(super) fn parse_link_open(args_text: &str) -> AppResult<Command> {
+    let uri = args_text.trim();
+    if uri.is_empty() {
+        return Err(AppError::invalid_argument(
+            "link-open requires 1 argument: uri",
+        ));
+    }
+
+    Ok(Command::OpenLinkUri {
+        uri: uri.to_string(),
+    })
+}
+
 fn split_last_token(input: &str) -> Option<(&str, &str)> {
     let trimmed = input.trim_end();
     if trimmed.is_empty() {
@@ -366,7 +621,7 @@ fn split_last_token(input: &str) -> Option<(&str, &str)> {
 mod tests {
     use super::{first_token, parse_command_text};
     use crate::command::{
-        ArgHint, ArgKind, ArgSpec, Command, CommandExposure, PanAmount, PanDirection,
+        ArgHint, ArgKind, ArgSpec, Command, CommandExposure, ExportScope, PanAmount, PanDirection,
         SearchMatcherKind, SpreadCoverPolicyArg, SpreadDirectionArg, all_command_specs,
     };
     use crate::palette::{PaletteKind, PaletteOpenPayload};
@@ -467,6 +722,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_preview_search_allows_empty_query() {
+        assert_eq!(
+            parse_command_text("preview-search hello regex").expect("parse should succeed"),
+            Command::PreviewSearch {
+                query: "hello".to_string(),
+                matcher: SearchMatcherKind::Regex,
+            }
+        );
+        assert_eq!(
+            parse_command_text("preview-search").expect("parse should succeed"),
+            Command::PreviewSearch {
+                query: String::new(),
+                matcher: SearchMatcherKind::ContainsInsensitive,
+            }
+        );
+    }
+
     #[test]
     fn parse_search_goto_accepts_page() {
         assert_eq!(
@@ -587,6 +860,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_yank_pages_accepts_single_pages_and_ranges() {
+        assert_eq!(
+            parse_command_text("yank-pages 3-7").expect("parse should succeed"),
+            Command::YankPages { first: 3, last: 7 }
+        );
+        assert_eq!(
+            parse_command_text("yank-pages 4").expect("parse should succeed"),
+            Command::YankPages { first: 4, last: 4 }
+        );
+        assert!(parse_command_text("yank-pages 7-3").is_err());
+        assert!(parse_command_text("yank-pages 0-2").is_err());
+        assert!(parse_command_text("yank-pages").is_err());
+    }
+
+    #[test]
+    fn parse_export_image_takes_optional_scope_dpi_and_path() {
+        assert_eq!(
+            parse_command_text("export-image").expect("parse should succeed"),
+            Command::ExportImage {
+                scope: ExportScope::Page,
+                dpi: None,
+                path: None,
+            }
+        );
+        assert_eq!(
+            parse_command_text("export-image view 300 shots/{stem} p{page}.png")
+                .expect("parse should succeed"),
+            Command::ExportImage {
+                scope: ExportScope::View,
+                dpi: Some(300),
+                path: Some("shots/{stem} p{page}.png".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_command_text("export-image out.png").expect("parse should succeed"),
+            Command::ExportImage {
+                scope: ExportScope::Page,
+                dpi: None,
+                path: Some("out.png".to_string()),
+            }
+        );
+        assert!(parse_command_text("export-image spread 0").is_err());
+        assert!(parse_command_text("export-image 5000").is_err());
+    }
+
     #[test]
     fn parse_pan_clamps_out_of_i32_range_values() {
         assert_eq!(
@@ -619,6 +938,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_goto_page_treats_non_numeric_arguments_as_labels() {
+        assert_eq!(
+            parse_command_text("goto-page xii").expect("parse should succeed"),
+            Command::GotoPageLabel {
+                label: "xii".to_string()
+            }
+        );
+        assert_eq!(
+            parse_command_text("goto-page 12").expect("parse should succeed"),
+            Command::GotoPage { page: 12 }
+        );
+        assert!(parse_command_text("goto-page A-3 extra").is_err());
+    }
+
     #[test]
     fn parse_pan_rejects_non_integer_amounts() {
         let err = parse_command_text("pan right nope").expect_err("parse should fail");
@@ -121,6 +121,7 @@ fn is_target_available(target: CommandTargetRequirement, ctx: &CommandPolicyCont
         CommandTargetRequirement::App => true,
         CommandTargetRequirement::ActivePalette => ctx.runtime.active_palette.is_some(),
         CommandTargetRequirement::ActiveHelp => ctx.runtime.mode == Mode::Help,
+        CommandTargetRequirement::ActiveVisual => ctx.runtime.mode == Mode::Visual,
     }
 }
 
@@ -143,6 +144,9 @@ fn condition_unavailable_message(id: &str, condition: RuntimeCondition) -> Strin
         RuntimeCondition::ModeIs(Mode::Help) | RuntimeCondition::HelpIsOpen => {
             format!("{id} is unavailable outside help")
         }
+        RuntimeCondition::ModeIs(Mode::Visual) => {
+            format!("{id} is unavailable outside visual mode")
+        }
         RuntimeCondition::ModeIsNot(Mode::Normal) => {
             format!("{id} is unavailable in normal mode")
         }
@@ -152,6 +156,9 @@ fn condition_unavailable_message(id: &str, condition: RuntimeCondition) -> Strin
         RuntimeCondition::ModeIsNot(Mode::Help) | RuntimeCondition::HelpIsClosed => {
             format!("{id} is unavailable while help is closed")
         }
+        RuntimeCondition::ModeIsNot(Mode::Visual) => {
+            format!("{id} is unavailable in visual mode")
+        }
         RuntimeCondition::SearchIsActive => {
             format!("{id} is unavailable while search is inactive")
         }
@@ -191,6 +198,9 @@ fn target_unavailable_message(spec: CommandSpec) -> String {
         CommandTargetRequirement::ActiveHelp => {
             format!("{} is unavailable outside help", spec.id)
         }
+        CommandTargetRequirement::ActiveVisual => {
+            format!("{} is unavailable outside visual mode", spec.id)
+        }
     }
 }
 
@@ -84,6 +84,7 @@ fn assert_binding_is_invocable(command_id: &str, enabled_when: ConditionExpr, la
             RuntimeConditionContext::new(Mode::Palette, Some(PaletteKind::Command), extensions),
             RuntimeConditionContext::new(Mode::Palette, Some(PaletteKind::Outline), extensions),
             RuntimeConditionContext::new(Mode::Help, None, extensions),
+            RuntimeConditionContext::new(Mode::Visual, None, extensions),
         ]
     });
     let mut enabled_context_found = false;
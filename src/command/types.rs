@@ -6,15 +6,24 @@ use crate::condition::ConditionExpr;
 pub enum SearchMatcherKind {
     ContainsInsensitive,
     ContainsSensitive,
+    Regex,
+    Boolean,
 }
 
 impl SearchMatcherKind {
-    const VARIANTS: [Self; 2] = [Self::ContainsInsensitive, Self::ContainsSensitive];
+    const VARIANTS: [Self; 4] = [
+        Self::ContainsInsensitive,
+        Self::ContainsSensitive,
+        Self::Regex,
+        Self::Boolean,
+    ];
 
     pub fn as_str(self) -> &'static str {
         match self {
             Self::ContainsInsensitive => "contains-insensitive",
             Self::ContainsSensitive => "contains-sensitive",
+            Self::Regex => "regex",
+            Self::Boolean => "boolean",
         }
     }
 
@@ -47,6 +56,7 @@ impl SearchMatcherKind {
 pub enum PageLayoutModeArg {
     Single,
     Spread,
+    Continuous,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -172,6 +182,148 @@ impl PanDirection {
     }
 }
 
+/// Caret movement in visual mode, named after the vim motion it mirrors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaretMotion {
+    Left,
+    Right,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineDown,
+    LineUp,
+    LineStart,
+    LineEnd,
+}
+
+impl CaretMotion {
+    const VARIANTS: [Self; 9] = [
+        Self::Left,
+        Self::Right,
+        Self::WordForward,
+        Self::WordBackward,
+        Self::WordEnd,
+        Self::LineDown,
+        Self::LineUp,
+        Self::LineStart,
+        Self::LineEnd,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::WordForward => "word-forward",
+            Self::WordBackward => "word-backward",
+            Self::WordEnd => "word-end",
+            Self::LineDown => "line-down",
+            Self::LineUp => "line-up",
+            Self::LineStart => "line-start",
+            Self::LineEnd => "line-end",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|candidate| candidate.as_str() == value)
+    }
+
+    pub fn values() -> &'static [&'static str] {
+        static VALUES: OnceLock<Box<[&'static str]>> = OnceLock::new();
+
+        VALUES
+            .get_or_init(|| {
+                CaretMotion::VARIANTS
+                    .iter()
+                    .map(|candidate| candidate.as_str())
+                    .collect()
+            })
+            .as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionKind {
+    Char,
+    Line,
+}
+
+impl SelectionKind {
+    const VARIANTS: [Self; 2] = [Self::Char, Self::Line];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Char => "char",
+            Self::Line => "line",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|candidate| candidate.as_str() == value)
+    }
+
+    pub fn values() -> &'static [&'static str] {
+        static VALUES: OnceLock<Box<[&'static str]>> = OnceLock::new();
+
+        VALUES
+            .get_or_init(|| {
+                SelectionKind::VARIANTS
+                    .iter()
+                    .map(|candidate| candidate.as_str())
+                    .collect()
+            })
+            .as_ref()
+    }
+}
+
+/// What `export-image` renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportScope {
+    /// The current page.
+    Page,
+    /// Every page on screen, side by side as a spread shows them.
+    Spread,
+    /// The part of the page the viewer shows when zoomed in.
+    View,
+}
+
+impl ExportScope {
+    const VARIANTS: [Self; 3] = [Self::Page, Self::Spread, Self::View];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Page => "page",
+            Self::Spread => "spread",
+            Self::View => "view",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|candidate| candidate.as_str() == value)
+    }
+
+    pub fn values() -> &'static [&'static str] {
+        static VALUES: OnceLock<Box<[&'static str]>> = OnceLock::new();
+
+        VALUES
+            .get_or_init(|| {
+                ExportScope::VARIANTS
+                    .iter()
+                    .map(|candidate| candidate.as_str())
+                    .collect()
+            })
+            .as_ref()
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PanAmount {
     DefaultStep,
@@ -203,6 +355,7 @@ pub enum CommandTargetRequirement {
     App,
     ActivePalette,
     ActiveHelp,
+    ActiveVisual,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -276,6 +429,8 @@ mod tests {
             &[
                 SearchMatcherKind::ContainsInsensitive.as_str(),
                 SearchMatcherKind::ContainsSensitive.as_str(),
+                SearchMatcherKind::Regex.as_str(),
+                SearchMatcherKind::Boolean.as_str(),
             ]
         );
     }
@@ -317,6 +317,7 @@ fn mode_key(mode: Mode) -> u8 {
         Mode::Normal => 0,
         Mode::Palette => 1,
         Mode::Help => 2,
+        Mode::Visual => 3,
     }
 }
 
@@ -327,6 +328,12 @@ fn palette_kind_key(kind: PaletteKind) -> u8 {
         PaletteKind::SearchResults => 2,
         PaletteKind::History => 3,
         PaletteKind::Outline => 4,
+        PaletteKind::Password => 5,
+        PaletteKind::Attachments => 6,
+        PaletteKind::Annotations => 7,
+        PaletteKind::Marks => 8,
+        PaletteKind::HighlightLayers => 9,
+        PaletteKind::Export => 10,
     }
 }
 
@@ -4,11 +4,12 @@ use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 
-use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
 use crate::error::{AppError, AppResult};
 
 use super::options::{
-    AppOptions, CacheOptions, InputOptions, KeymapOptions, RenderOptions, ViewOptions, WatchOptions,
+    AppOptions, CacheOptions, ClipboardOptions, InputOptions, KeymapOptions, LinksOptions,
+    RenderOptions, SearchOptions, ViewOptions, WatchOptions,
 };
 use super::policy::AppOptionsResolver;
 use super::types::Config;
@@ -68,6 +69,9 @@ struct RawConfig {
     keymap_preset: Option<String>,
     keymap: Option<Vec<RawKeymapEntry>>,
     watch: Option<RawWatchConfig>,
+    links: Option<RawLinksConfig>,
+    clipboard: Option<RawClipboardConfig>,
+    search: Option<RawSearchConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default, PartialEq)]
@@ -90,6 +94,8 @@ struct RawCacheConfig {
     l2_memory_budget_mb: Option<usize>,
     l1_max_entries: Option<usize>,
     l2_max_entries: Option<usize>,
+    l3_disk_budget_mb: Option<usize>,
+    text_index_budget_mb: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default, PartialEq)]
@@ -98,8 +104,12 @@ struct RawViewConfig {
     initial_page: Option<usize>,
     initial_zoom: Option<f32>,
     initial_layout: Option<String>,
+    initial_view: Option<String>,
+    fit: Option<String>,
     spread_direction: Option<String>,
     spread_cover: Option<String>,
+    status_title: Option<bool>,
+    remember_position: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
@@ -130,6 +140,28 @@ struct RawWatchConfig {
     settle_delay_ms: Option<u64>,
 }
 
+#[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+struct RawLinksConfig {
+    opener: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+struct RawClipboardConfig {
+    command: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+struct RawSearchConfig {
+    fold_compatibility: Option<bool>,
+    fold_diacritics: Option<bool>,
+    fold_width: Option<bool>,
+    join_hyphenated: Option<bool>,
+    incremental: Option<bool>,
+}
+
 impl RawConfig {
     fn into_options(self) -> AppResult<AppOptions> {
         Ok(AppOptions {
@@ -143,6 +175,12 @@ impl RawConfig {
             input: self.input.map(InputOptions::from).unwrap_or_default(),
             keymap: parse_keymap_options(self.keymap_preset.as_deref(), self.keymap)?,
             watch: self.watch.map(WatchOptions::from).unwrap_or_default(),
+            links: self.links.map(LinksOptions::from).unwrap_or_default(),
+            clipboard: self
+                .clipboard
+                .map(ClipboardOptions::from)
+                .unwrap_or_default(),
+            search: self.search.map(SearchOptions::from).unwrap_or_default(),
         })
     }
 }
@@ -169,6 +207,8 @@ impl From<RawCacheConfig> for CacheOptions {
             l2_memory_budget_mb: raw.l2_memory_budget_mb,
             l1_max_entries: raw.l1_max_entries,
             l2_max_entries: raw.l2_max_entries,
+            l3_disk_budget_mb: raw.l3_disk_budget_mb,
+            text_index_budget_mb: raw.text_index_budget_mb,
         }
     }
 }
@@ -185,6 +225,12 @@ impl TryFrom<RawViewConfig> for ViewOptions {
                 .as_deref()
                 .map(parse_page_layout_mode)
                 .transpose()?,
+            initial_view: raw
+                .initial_view
+                .as_deref()
+                .map(parse_page_view_mode)
+                .transpose()?,
+            fit: raw.fit.as_deref().map(parse_fit_mode).transpose()?,
             spread_direction: raw
                 .spread_direction
                 .as_deref()
@@ -195,6 +241,8 @@ impl TryFrom<RawViewConfig> for ViewOptions {
                 .as_deref()
                 .map(parse_spread_cover)
                 .transpose()?,
+            status_title: raw.status_title,
+            remember_position: raw.remember_position,
         })
     }
 }
@@ -244,16 +292,65 @@ impl From<RawWatchConfig> for WatchOptions {
     }
 }
 
+impl From<RawLinksConfig> for LinksOptions {
+    fn from(raw: RawLinksConfig) -> Self {
+        Self { opener: raw.opener }
+    }
+}
+
+impl From<RawClipboardConfig> for ClipboardOptions {
+    fn from(raw: RawClipboardConfig) -> Self {
+        Self {
+            command: raw.command,
+        }
+    }
+}
+
+impl From<RawSearchConfig> for SearchOptions {
+    fn from(raw: RawSearchConfig) -> Self {
+        Self {
+            fold_compatibility: raw.fold_compatibility,
+            fold_diacritics: raw.fold_diacritics,
+            fold_width: raw.fold_width,
+            join_hyphenated: raw.join_hyphenated,
+            incremental: raw.incremental,
+        }
+    }
+}
+
 fn parse_page_layout_mode(value: &str) -> AppResult<PageLayoutMode> {
     match value {
         "single" => Ok(PageLayoutMode::Single),
         "spread" => Ok(PageLayoutMode::Spread),
+        "continuous" => Ok(PageLayoutMode::Continuous),
         _ => Err(AppError::invalid_argument(format!(
             "unknown view.initial_layout: {value}"
         ))),
     }
 }
 
+fn parse_fit_mode(value: &str) -> AppResult<FitMode> {
+    match value {
+        "page" => Ok(FitMode::Page),
+        "width" => Ok(FitMode::Width),
+        "height" => Ok(FitMode::Height),
+        "actual" => Ok(FitMode::Actual),
+        _ => Err(AppError::invalid_argument(format!(
+            "unknown view.fit: {value}"
+        ))),
+    }
+}
+
+fn parse_page_view_mode(value: &str) -> AppResult<PageViewMode> {
+    match value {
+        "image" => Ok(PageViewMode::Image),
+        "text" => Ok(PageViewMode::Text),
+        _ => Err(AppError::invalid_argument(format!(
+            "unknown view.initial_view: {value}"
+        ))),
+    }
+}
+
 fn parse_spread_direction(value: &str) -> AppResult<SpreadDirection> {
     match value {
         "ltr" => Ok(SpreadDirection::Ltr),
@@ -357,6 +454,51 @@ fn default_config_path_from_env(
     None
 }
 
+/// Directory for data pvf can rebuild at any time, such as the on-disk render cache.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    default_cache_dir_from_env(|key| std::env::var_os(key))
+}
+
+fn default_cache_dir_from_env(env_var: impl FnMut(&str) -> Option<OsString>) -> Option<PathBuf> {
+    user_dir_from_env(env_var, "XDG_CACHE_HOME", &[".cache"], "cache")
+}
+
+/// Directory for state pvf keeps between sessions, such as saved reading positions.
+pub fn default_state_dir() -> Option<PathBuf> {
+    default_state_dir_from_env(|key| std::env::var_os(key))
+}
+
+fn default_state_dir_from_env(env_var: impl FnMut(&str) -> Option<OsString>) -> Option<PathBuf> {
+    user_dir_from_env(env_var, "XDG_STATE_HOME", &[".local", "state"], "state")
+}
+
+/// `$<xdg_var>/pvf`, else `$HOME/<home_dirs>/pvf`, else `%LOCALAPPDATA%/pvf/<appdata_leaf>`.
+fn user_dir_from_env(
+    mut env_var: impl FnMut(&str) -> Option<OsString>,
+    xdg_var: &str,
+    home_dirs: &[&str],
+    appdata_leaf: &str,
+) -> Option<PathBuf> {
+    if let Some(xdg) = env_var(xdg_var)
+        && !xdg.is_empty()
+    {
+        return Some(PathBuf::from(xdg).join("pvf"));
+    }
+    if let Some(home) = env_var("HOME")
+        && !home.is_empty()
+    {
+        let mut dir = PathBuf::from(home);
+        dir.extend(home_dirs);
+        return Some(dir.join("pvf"));
+    }
+    if let Some(local_appdata) = env_var("LOCALAPPDATA")
+        && !local_appdata.is_empty()
+    {
+        return Some(PathBuf::from(local_appdata).join("pvf").join(appdata_leaf));
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use std::ffi::OsString;
@@ -365,7 +507,7 @@ mod tests {
     use std::process;
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+    use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
     use crate::command::Command;
     use crate::config::{AppOptionsResolver, KeymapBinding, KeymapPreset, KeymapWhen};
     use crate::extension::ExtensionUiSnapshot;
@@ -373,10 +515,12 @@ mod tests {
         DEFAULT_SEQUENCE_TIMEOUT, KeyBindingContext, SequenceResolution, SequenceResolver,
     };
     use crate::input::shortcut::ShortcutKey;
+    use crate::search::matcher::SearchFolding;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     use super::{
-        Config, ConfigFileSelection, default_config_path_from_env, load_options_from_explicit_path,
+        Config, ConfigFileSelection, default_cache_dir_from_env, default_config_path_from_env,
+        default_state_dir_from_env, load_options_from_explicit_path,
     };
 
     fn handle_normal_key(resolver: &mut SequenceResolver, key: KeyEvent) -> SequenceResolution {
@@ -419,13 +563,19 @@ mod tests {
 
             [cache]
             l1_memory_budget_mb = 256
+            l3_disk_budget_mb = 2048
+            text_index_budget_mb = 64
 
             [view]
             initial_page = 4
             initial_zoom = 1.25
             initial_layout = "spread"
+            initial_view = "text"
+            fit = "width"
             spread_direction = "rtl"
             spread_cover = "cover"
+            status_title = true
+            remember_position = false
 
             [input]
             sequence_timeout_ms = 333
@@ -451,11 +601,17 @@ mod tests {
         assert_eq!(config.cache.l2_memory_budget_mb, 64);
         assert_eq!(config.cache.l1_max_entries, 128);
         assert_eq!(config.cache.l2_max_entries, 96);
+        assert_eq!(config.cache.l3_disk_budget_mb, 2048);
+        assert_eq!(config.cache.text_index_budget_mb, 64);
         assert_eq!(config.view.initial_page, 4);
         assert_eq!(config.view.initial_zoom, 1.25);
         assert_eq!(config.view.initial_layout, PageLayoutMode::Spread);
+        assert_eq!(config.view.initial_view, PageViewMode::Text);
+        assert_eq!(config.view.fit, FitMode::Width);
         assert_eq!(config.view.spread_direction, SpreadDirection::Rtl);
         assert_eq!(config.view.spread_cover, SpreadCoverPolicy::Cover);
+        assert!(config.view.status_title);
+        assert!(!config.view.remember_position);
         assert_eq!(config.input.sequence_timeout_ms, 333);
         assert!(config.watch.enabled);
         assert_eq!(config.watch.poll_interval_ms, 125);
@@ -948,6 +1104,37 @@ mod tests {
         fs::remove_file(&path).expect("config file should be removed");
     }
 
+    #[test]
+    fn explicit_config_reads_search_folding_section() {
+        let path = unique_temp_path("search-options.toml");
+        fs::write(
+            &path,
+            r#"
+            [search]
+            fold_diacritics = false
+            join_hyphenated = false
+            incremental = false
+            "#,
+        )
+        .expect("config file should be written");
+
+        let options = load_options_from_explicit_path(&path).expect("options should parse");
+        assert_eq!(options.search.fold_diacritics, Some(false));
+        assert_eq!(options.search.fold_width, None);
+        let resolved = AppOptionsResolver::new().apply_options(options).resolve();
+        assert_eq!(
+            resolved.search.folding,
+            SearchFolding {
+                diacritics: false,
+                hyphenation: false,
+                ..SearchFolding::default()
+            }
+        );
+        assert!(!resolved.search.incremental);
+
+        fs::remove_file(&path).expect("config file should be removed");
+    }
+
     #[test]
     fn explicit_config_rejects_unknown_view_enum_values() {
         let path = unique_temp_path("bad-view-options.toml");
@@ -1032,4 +1219,38 @@ mod tests {
 
         assert_eq!(found, Some(expected));
     }
+
+    #[test]
+    fn cache_dir_prefers_xdg_cache_home_over_home() {
+        let xdg = default_cache_dir_from_env(|key| match key {
+            "XDG_CACHE_HOME" => Some(OsString::from("/tmp/pvf-xdg-cache")),
+            "HOME" => Some(OsString::from("/tmp/pvf-home")),
+            _ => None,
+        });
+        let home = default_cache_dir_from_env(|key| match key {
+            "XDG_CACHE_HOME" => Some(OsString::new()),
+            "HOME" => Some(OsString::from("/tmp/pvf-home")),
+            _ => None,
+        });
+
+        assert_eq!(xdg, Some(PathBuf::from("/tmp/pvf-xdg-cache/pvf")));
+        assert_eq!(home, Some(PathBuf::from("/tmp/pvf-home/.cache/pvf")));
+        assert_eq!(default_cache_dir_from_env(|_| None), None);
+    }
+
+    #[test]
+    fn state_dir_falls_back_to_local_state_under_home() {
+        let xdg = default_state_dir_from_env(|key| match key {
+            "XDG_STATE_HOME" => Some(OsString::from("/tmp/pvf-xdg-state")),
+            "HOME" => Some(OsString::from("/tmp/pvf-home")),
+            _ => None,
+        });
+        let home = default_state_dir_from_env(|key| match key {
+            "HOME" => Some(OsString::from("/tmp/pvf-home")),
+            _ => None,
+        });
+
+        assert_eq!(xdg, Some(PathBuf::from("/tmp/pvf-xdg-state/pvf")));
+        assert_eq!(home, Some(PathBuf::from("/tmp/pvf-home/.local/state/pvf")));
+    }
 }
@@ -24,6 +24,7 @@ const WHEN_NORMAL_SEARCH_INACTIVE: [RuntimeCondition; 2] = [
     RuntimeCondition::SearchIsInactive,
 ];
 const WHEN_HELP: [RuntimeCondition; 1] = [RuntimeCondition::ModeIs(Mode::Help)];
+const WHEN_VISUAL: [RuntimeCondition; 1] = [RuntimeCondition::ModeIs(Mode::Visual)];
 const WHEN_PALETTE: [RuntimeCondition; 1] = [RuntimeCondition::ModeIs(Mode::Palette)];
 const WHEN_PALETTE_COMMAND: [RuntimeCondition; 1] =
     [RuntimeCondition::PaletteKindIs(PaletteKind::Command)];
@@ -35,6 +36,17 @@ const WHEN_PALETTE_HISTORY: [RuntimeCondition; 1] =
     [RuntimeCondition::PaletteKindIs(PaletteKind::History)];
 const WHEN_PALETTE_OUTLINE: [RuntimeCondition; 1] =
     [RuntimeCondition::PaletteKindIs(PaletteKind::Outline)];
+const WHEN_PALETTE_ATTACHMENTS: [RuntimeCondition; 1] =
+    [RuntimeCondition::PaletteKindIs(PaletteKind::Attachments)];
+const WHEN_PALETTE_ANNOTATIONS: [RuntimeCondition; 1] =
+    [RuntimeCondition::PaletteKindIs(PaletteKind::Annotations)];
+const WHEN_PALETTE_MARKS: [RuntimeCondition; 1] =
+    [RuntimeCondition::PaletteKindIs(PaletteKind::Marks)];
+const WHEN_PALETTE_HIGHLIGHT_LAYERS: [RuntimeCondition; 1] = [RuntimeCondition::PaletteKindIs(
+    PaletteKind::HighlightLayers,
+)];
+const WHEN_PALETTE_EXPORT: [RuntimeCondition; 1] =
+    [RuntimeCondition::PaletteKindIs(PaletteKind::Export)];
 const WHEN_PALETTE_WITH_INPUT_HISTORY: [RuntimeCondition; 1] =
     [RuntimeCondition::PaletteInputHistoryIsAvailable];
 const WHEN_PALETTE_NO_INPUT_HISTORY: [RuntimeCondition; 1] =
@@ -49,12 +61,18 @@ pub enum KeymapWhen {
     NormalSearchActive,
     NormalSearchInactive,
     Help,
+    Visual,
     Palette,
     PaletteCommand,
     PaletteSearch,
     PaletteSearchResults,
     PaletteHistory,
     PaletteOutline,
+    PaletteAttachments,
+    PaletteAnnotations,
+    PaletteMarks,
+    PaletteHighlightLayers,
+    PaletteExport,
     PaletteWithInputHistory,
     PaletteNoInputHistory,
     PaletteInputEmpty,
@@ -68,12 +86,18 @@ impl KeymapWhen {
             "normal.search-active" => Some(Self::NormalSearchActive),
             "normal.search-inactive" => Some(Self::NormalSearchInactive),
             "help" => Some(Self::Help),
+            "visual" => Some(Self::Visual),
             "palette" => Some(Self::Palette),
             "palette.command" => Some(Self::PaletteCommand),
             "palette.search" => Some(Self::PaletteSearch),
             "palette.search-results" => Some(Self::PaletteSearchResults),
             "palette.history" => Some(Self::PaletteHistory),
             "palette.outline" => Some(Self::PaletteOutline),
+            "palette.attachments" => Some(Self::PaletteAttachments),
+            "palette.annotations" => Some(Self::PaletteAnnotations),
+            "palette.marks" => Some(Self::PaletteMarks),
+            "palette.highlight-layers" => Some(Self::PaletteHighlightLayers),
+            "palette.export" => Some(Self::PaletteExport),
             "palette.with-input-history" => Some(Self::PaletteWithInputHistory),
             "palette.no-input-history" => Some(Self::PaletteNoInputHistory),
             "palette.input-empty" => Some(Self::PaletteInputEmpty),
@@ -88,12 +112,18 @@ impl KeymapWhen {
             Self::NormalSearchActive => ConditionExpr::All(&WHEN_NORMAL_SEARCH_ACTIVE),
             Self::NormalSearchInactive => ConditionExpr::All(&WHEN_NORMAL_SEARCH_INACTIVE),
             Self::Help => ConditionExpr::All(&WHEN_HELP),
+            Self::Visual => ConditionExpr::All(&WHEN_VISUAL),
             Self::Palette => ConditionExpr::All(&WHEN_PALETTE),
             Self::PaletteCommand => ConditionExpr::All(&WHEN_PALETTE_COMMAND),
             Self::PaletteSearch => ConditionExpr::All(&WHEN_PALETTE_SEARCH),
             Self::PaletteSearchResults => ConditionExpr::All(&WHEN_PALETTE_SEARCH_RESULTS),
             Self::PaletteHistory => ConditionExpr::All(&WHEN_PALETTE_HISTORY),
             Self::PaletteOutline => ConditionExpr::All(&WHEN_PALETTE_OUTLINE),
+            Self::PaletteAttachments => ConditionExpr::All(&WHEN_PALETTE_ATTACHMENTS),
+            Self::PaletteAnnotations => ConditionExpr::All(&WHEN_PALETTE_ANNOTATIONS),
+            Self::PaletteMarks => ConditionExpr::All(&WHEN_PALETTE_MARKS),
+            Self::PaletteHighlightLayers => ConditionExpr::All(&WHEN_PALETTE_HIGHLIGHT_LAYERS),
+            Self::PaletteExport => ConditionExpr::All(&WHEN_PALETTE_EXPORT),
             Self::PaletteWithInputHistory => ConditionExpr::All(&WHEN_PALETTE_WITH_INPUT_HISTORY),
             Self::PaletteNoInputHistory => ConditionExpr::All(&WHEN_PALETTE_NO_INPUT_HISTORY),
             Self::PaletteInputEmpty => ConditionExpr::All(&WHEN_PALETTE_INPUT_EMPTY),
@@ -110,6 +140,11 @@ impl KeymapWhen {
                 | Self::PaletteSearchResults
                 | Self::PaletteHistory
                 | Self::PaletteOutline
+                | Self::PaletteAttachments
+                | Self::PaletteAnnotations
+                | Self::PaletteMarks
+                | Self::PaletteHighlightLayers
+                | Self::PaletteExport
                 | Self::PaletteWithInputHistory
                 | Self::PaletteNoInputHistory
                 | Self::PaletteInputEmpty
@@ -120,6 +155,10 @@ impl KeymapWhen {
     pub(crate) fn includes_help(self) -> bool {
         self == Self::Help
     }
+
+    pub(crate) fn includes_visual(self) -> bool {
+        self == Self::Visual
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -298,6 +337,7 @@ fn validate_command_for_keymap_condition(id: &str, when: KeymapWhen) -> AppResul
         CommandTargetRequirement::App => Ok(()),
         CommandTargetRequirement::ActivePalette if when.includes_palette() => Ok(()),
         CommandTargetRequirement::ActiveHelp if when.includes_help() => Ok(()),
+        CommandTargetRequirement::ActiveVisual if when.includes_visual() => Ok(()),
         CommandTargetRequirement::ActivePalette => Err(AppError::invalid_argument(format!(
             "{} requires an active palette",
             spec.id
@@ -306,6 +346,10 @@ fn validate_command_for_keymap_condition(id: &str, when: KeymapWhen) -> AppResul
             "{} requires active help",
             spec.id
         ))),
+        CommandTargetRequirement::ActiveVisual => Err(AppError::invalid_argument(format!(
+            "{} requires visual mode",
+            spec.id
+        ))),
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::command::{Command, PanAmount, PanDirection};
+use crate::command::{CaretMotion, Command, PanAmount, PanDirection, SelectionKind};
 use crate::condition::ConditionExpr;
 use crate::palette::PaletteKind;
 
@@ -36,6 +36,7 @@ pub(crate) fn build_default_sequence_registry() -> SequenceRegistry {
     register_page_navigation_bindings(&mut registry);
     register_view_bindings(&mut registry);
     register_history_bindings(&mut registry);
+    register_mark_bindings(&mut registry);
     register_search_navigation_bindings(&mut registry);
     register_quit_binding(&mut registry);
     registry
@@ -50,6 +51,7 @@ fn build_base_sequence_registry() -> SequenceRegistry {
     register_search_cancellation_binding(&mut registry);
     register_palette_bindings(&mut registry);
     register_help_bindings(&mut registry);
+    register_visual_bindings(&mut registry);
     registry
 }
 
@@ -71,6 +73,24 @@ fn register_surface_open_bindings(registry: &mut SequenceRegistry) {
         Command::OpenSearch,
     );
     register_exact_binding(registry, when, &[ShortcutKey::char('?')], Command::OpenHelp);
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('f')],
+        Command::LinkHints,
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::ctrl('g')],
+        Command::DocumentInfo,
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('v')],
+        Command::VisualMode,
+    );
 }
 
 fn register_page_navigation_bindings(registry: &mut SequenceRegistry) {
@@ -131,6 +151,12 @@ fn register_view_bindings(registry: &mut SequenceRegistry) {
             amount: PanAmount::DefaultStep,
         },
     );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('t')],
+        Command::TextViewToggle,
+    );
     register_exact_binding(registry, when, &[ShortcutKey::char('+')], Command::ZoomIn);
     register_exact_binding(registry, when, &[ShortcutKey::char('-')], Command::ZoomOut);
     register_exact_binding(
@@ -139,6 +165,8 @@ fn register_view_bindings(registry: &mut SequenceRegistry) {
         &[ShortcutKey::char('=')],
         Command::ZoomReset,
     );
+    register_exact_binding(registry, when, &[ShortcutKey::char('a')], Command::FitPage);
+    register_exact_binding(registry, when, &[ShortcutKey::char('s')], Command::FitWidth);
 }
 
 fn register_history_bindings(registry: &mut SequenceRegistry) {
@@ -157,6 +185,22 @@ fn register_history_bindings(registry: &mut SequenceRegistry) {
     );
 }
 
+fn register_mark_bindings(registry: &mut SequenceRegistry) {
+    let when = KeymapWhen::Normal.condition();
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('m')],
+        Command::SetMark { name: None },
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('\'')],
+        Command::GotoMark { name: None },
+    );
+}
+
 fn register_search_navigation_bindings(registry: &mut SequenceRegistry) {
     let when = KeymapWhen::Normal.condition();
     register_exact_binding(
@@ -424,6 +468,60 @@ fn register_help_bindings(registry: &mut SequenceRegistry) {
     );
 }
 
+fn register_visual_bindings(registry: &mut SequenceRegistry) {
+    use crossterm::event::KeyCode;
+
+    let when = KeymapWhen::Visual.condition();
+    let motions = [
+        (ShortcutKey::char('h'), CaretMotion::Left),
+        (ShortcutKey::key(KeyCode::Left), CaretMotion::Left),
+        (ShortcutKey::char('l'), CaretMotion::Right),
+        (ShortcutKey::key(KeyCode::Right), CaretMotion::Right),
+        (ShortcutKey::char('w'), CaretMotion::WordForward),
+        (ShortcutKey::char('b'), CaretMotion::WordBackward),
+        (ShortcutKey::char('e'), CaretMotion::WordEnd),
+        (ShortcutKey::char('j'), CaretMotion::LineDown),
+        (ShortcutKey::key(KeyCode::Down), CaretMotion::LineDown),
+        (ShortcutKey::char('k'), CaretMotion::LineUp),
+        (ShortcutKey::key(KeyCode::Up), CaretMotion::LineUp),
+        (ShortcutKey::char('0'), CaretMotion::LineStart),
+        (ShortcutKey::key(KeyCode::Home), CaretMotion::LineStart),
+        (ShortcutKey::char('$'), CaretMotion::LineEnd),
+        (ShortcutKey::key(KeyCode::End), CaretMotion::LineEnd),
+    ];
+    for (key, motion) in motions {
+        register_exact_binding(registry, when, &[key], Command::VisualMove { motion });
+    }
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('v')],
+        Command::VisualSelect {
+            kind: SelectionKind::Char,
+        },
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('V')],
+        Command::VisualSelect {
+            kind: SelectionKind::Line,
+        },
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::char('y')],
+        Command::VisualYank,
+    );
+    register_exact_binding(
+        registry,
+        when,
+        &[ShortcutKey::key(KeyCode::Esc)],
+        Command::ExitVisualMode,
+    );
+}
+
 fn register_exact_binding(
     registry: &mut SequenceRegistry,
     enabled_when: ConditionExpr,
@@ -452,7 +550,7 @@ mod tests {
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     use crate::app::Mode;
-    use crate::command::{Command, PanAmount, PanDirection};
+    use crate::command::{CaretMotion, Command, PanAmount, PanDirection, SelectionKind};
     use crate::condition::RuntimeConditionContext;
     use crate::extension::ExtensionUiSnapshot;
     use crate::input::sequence::KeyBindingContext;
@@ -497,6 +595,12 @@ mod tests {
             KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL),
         );
         assert_eq!(back, SequenceResolution::Dispatch(Command::HistoryBack));
+
+        let links = handle_normal_key(
+            &mut resolver,
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE),
+        );
+        assert_eq!(links, SequenceResolution::Dispatch(Command::LinkHints));
     }
 
     #[test]
@@ -730,6 +834,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn visual_bindings_apply_only_in_visual_mode() {
+        let registry = build_default_sequence_registry();
+        let mut resolver = SequenceResolver::new(registry, DEFAULT_SEQUENCE_TIMEOUT);
+        let extensions = ExtensionUiSnapshot::default();
+        let visual = KeyBindingContext {
+            runtime: RuntimeConditionContext::new(Mode::Visual, None, &extensions),
+        };
+
+        assert_eq!(
+            handle_normal_key(
+                &mut resolver,
+                KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE)
+            ),
+            SequenceResolution::Dispatch(Command::VisualMode)
+        );
+        for (key, expected) in [
+            (
+                KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+                Command::VisualMove {
+                    motion: CaretMotion::LineDown,
+                },
+            ),
+            (
+                KeyEvent::new(KeyCode::Char('$'), KeyModifiers::SHIFT),
+                Command::VisualMove {
+                    motion: CaretMotion::LineEnd,
+                },
+            ),
+            (
+                KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT),
+                Command::VisualSelect {
+                    kind: SelectionKind::Line,
+                },
+            ),
+            (
+                KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE),
+                Command::VisualYank,
+            ),
+            (
+                KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+                Command::ExitVisualMode,
+            ),
+        ] {
+            assert_eq!(
+                resolver.handle_key_in_context(visual, key),
+                SequenceResolution::Dispatch(expected)
+            );
+        }
+    }
+
     fn palette_key_context<'a>(
         kind: PaletteKind,
         extensions: &'a ExtensionUiSnapshot,
@@ -5,15 +5,19 @@ mod policy;
 mod types;
 
 pub use file::{
-    ConfigFileSelection, default_config_path, load_default_app_options,
-    load_options_from_explicit_path,
+    ConfigFileSelection, default_cache_dir, default_config_path, default_state_dir,
+    load_default_app_options, load_options_from_explicit_path,
 };
 pub use options::{
-    AppOptions, CacheOptions, InputOptions, KeymapBinding, KeymapOptions, KeymapPreset, KeymapWhen,
-    RenderOptions, ViewOptions, WatchOptions,
+    AppOptions, CacheOptions, ClipboardOptions, InputOptions, KeymapBinding, KeymapOptions,
+    KeymapPreset, KeymapWhen, LinksOptions, RenderOptions, SearchOptions, ViewOptions,
+    WatchOptions,
 };
 pub use policy::{
-    AppOptionsResolver, CachePolicy, EventLoopPolicy, InputPolicy, RenderPolicy,
-    ResolvedAppOptions, ViewPolicy, WatchPolicy,
+    AppOptionsResolver, CachePolicy, ClipboardPolicy, EventLoopPolicy, InputPolicy, LinksPolicy,
+    RenderPolicy, ResolvedAppOptions, SearchPolicy, ViewPolicy, WatchPolicy,
+};
+pub use types::{
+    CacheConfig, ClipboardConfig, Config, InputConfig, LinksConfig, RenderConfig, SearchConfig,
+    ViewConfig, WatchConfig,
 };
-pub use types::{CacheConfig, Config, InputConfig, RenderConfig, ViewConfig, WatchConfig};
@@ -1,4 +1,4 @@
-use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
 
 pub use super::keymap::{KeymapBinding, KeymapOptions, KeymapPreset, KeymapWhen};
 use super::types::Config;
@@ -11,6 +11,9 @@ pub struct AppOptions {
     pub input: InputOptions,
     pub keymap: KeymapOptions,
     pub watch: WatchOptions,
+    pub links: LinksOptions,
+    pub clipboard: ClipboardOptions,
+    pub search: SearchOptions,
 }
 
 impl AppOptions {
@@ -21,6 +24,9 @@ impl AppOptions {
         self.input = self.input.merge(next.input);
         self.keymap = self.keymap.merge(next.keymap);
         self.watch = self.watch.merge(next.watch);
+        self.links = self.links.merge(next.links);
+        self.clipboard = self.clipboard.merge(next.clipboard);
+        self.search = self.search.merge(next.search);
         self
     }
 }
@@ -45,13 +51,19 @@ impl From<Config> for AppOptions {
                 l2_memory_budget_mb: Some(config.cache.l2_memory_budget_mb),
                 l1_max_entries: Some(config.cache.l1_max_entries),
                 l2_max_entries: Some(config.cache.l2_max_entries),
+                l3_disk_budget_mb: Some(config.cache.l3_disk_budget_mb),
+                text_index_budget_mb: Some(config.cache.text_index_budget_mb),
             },
             view: ViewOptions {
                 initial_page: Some(config.view.initial_page),
                 initial_zoom: Some(config.view.initial_zoom),
                 initial_layout: Some(config.view.initial_layout),
+                initial_view: Some(config.view.initial_view),
+                fit: Some(config.view.fit),
                 spread_direction: Some(config.view.spread_direction),
                 spread_cover: Some(config.view.spread_cover),
+                status_title: Some(config.view.status_title),
+                remember_position: Some(config.view.remember_position),
             },
             input: InputOptions {
                 sequence_timeout_ms: Some(config.input.sequence_timeout_ms),
@@ -62,6 +74,19 @@ impl From<Config> for AppOptions {
                 poll_interval_ms: Some(config.watch.poll_interval_ms),
                 settle_delay_ms: Some(config.watch.settle_delay_ms),
             },
+            links: LinksOptions {
+                opener: config.links.opener,
+            },
+            clipboard: ClipboardOptions {
+                command: config.clipboard.command,
+            },
+            search: SearchOptions {
+                fold_compatibility: Some(config.search.fold_compatibility),
+                fold_diacritics: Some(config.search.fold_diacritics),
+                fold_width: Some(config.search.fold_width),
+                join_hyphenated: Some(config.search.join_hyphenated),
+                incremental: Some(config.search.incremental),
+            },
         }
     }
 }
@@ -107,6 +132,8 @@ pub struct CacheOptions {
     pub l2_memory_budget_mb: Option<usize>,
     pub l1_max_entries: Option<usize>,
     pub l2_max_entries: Option<usize>,
+    pub l3_disk_budget_mb: Option<usize>,
+    pub text_index_budget_mb: Option<usize>,
 }
 
 impl CacheOptions {
@@ -116,6 +143,8 @@ impl CacheOptions {
             l2_memory_budget_mb: next.l2_memory_budget_mb.or(self.l2_memory_budget_mb),
             l1_max_entries: next.l1_max_entries.or(self.l1_max_entries),
             l2_max_entries: next.l2_max_entries.or(self.l2_max_entries),
+            l3_disk_budget_mb: next.l3_disk_budget_mb.or(self.l3_disk_budget_mb),
+            text_index_budget_mb: next.text_index_budget_mb.or(self.text_index_budget_mb),
         }
     }
 }
@@ -125,8 +154,12 @@ pub struct ViewOptions {
     pub initial_page: Option<usize>,
     pub initial_zoom: Option<f32>,
     pub initial_layout: Option<PageLayoutMode>,
+    pub initial_view: Option<PageViewMode>,
+    pub fit: Option<FitMode>,
     pub spread_direction: Option<SpreadDirection>,
     pub spread_cover: Option<SpreadCoverPolicy>,
+    pub status_title: Option<bool>,
+    pub remember_position: Option<bool>,
 }
 
 impl ViewOptions {
@@ -135,8 +168,12 @@ impl ViewOptions {
             initial_page: next.initial_page.or(self.initial_page),
             initial_zoom: next.initial_zoom.or(self.initial_zoom),
             initial_layout: next.initial_layout.or(self.initial_layout),
+            initial_view: next.initial_view.or(self.initial_view),
+            fit: next.fit.or(self.fit),
             spread_direction: next.spread_direction.or(self.spread_direction),
             spread_cover: next.spread_cover.or(self.spread_cover),
+            status_title: next.status_title.or(self.status_title),
+            remember_position: next.remember_position.or(self.remember_position),
         }
     }
 }
@@ -170,3 +207,50 @@ impl WatchOptions {
         }
     }
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinksOptions {
+    pub opener: Option<String>,
+}
+
+impl LinksOptions {
+    pub(super) fn merge(self, next: Self) -> Self {
+        Self {
+            opener: next.opener.or(self.opener),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardOptions {
+    pub command: Option<String>,
+}
+
+impl ClipboardOptions {
+    pub(super) fn merge(self, next: Self) -> Self {
+        Self {
+            command: next.command.or(self.command),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    pub fold_compatibility: Option<bool>,
+    pub fold_diacritics: Option<bool>,
+    pub fold_width: Option<bool>,
+    pub join_hyphenated: Option<bool>,
+    pub incremental: Option<bool>,
+}
+
+impl SearchOptions {
+    pub(super) fn merge(self, next: Self) -> Self {
+        Self {
+            fold_compatibility: next.fold_compatibility.or(self.fold_compatibility),
+            fold_diacritics: next.fold_diacritics.or(self.fold_diacritics),
+            fold_width: next.fold_width.or(self.fold_width),
+            join_hyphenated: next.join_hyphenated.or(self.join_hyphenated),
+            incremental: next.incremental.or(self.incremental),
+        }
+    }
+}
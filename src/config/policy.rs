@@ -1,12 +1,16 @@
 use std::time::Duration;
 
 use crate::app::scale::{ZOOM_MAX, ZOOM_MIN};
-use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
 use crate::input::sequence::{DEFAULT_SEQUENCE_TIMEOUT, SequenceRegistry};
+use crate::search::matcher::SearchFolding;
 
 use super::keymap::build_default_sequence_registry;
 use super::options::AppOptions;
-use super::types::{CacheConfig, Config, InputConfig, RenderConfig, ViewConfig, WatchConfig};
+use super::types::{
+    CacheConfig, ClipboardConfig, Config, InputConfig, LinksConfig, RenderConfig, SearchConfig,
+    ViewConfig, WatchConfig,
+};
 
 #[derive(Debug, Clone)]
 pub struct ResolvedAppOptions {
@@ -16,6 +20,9 @@ pub struct ResolvedAppOptions {
     pub cache: CachePolicy,
     pub input: InputPolicy,
     pub watch: WatchPolicy,
+    pub links: LinksPolicy,
+    pub clipboard: ClipboardPolicy,
+    pub search: SearchPolicy,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -64,6 +71,8 @@ pub struct CachePolicy {
     pub l2_memory_budget_mb: usize,
     pub l1_max_entries: usize,
     pub l2_max_entries: usize,
+    pub l3_disk_budget_mb: usize,
+    pub text_index_budget_mb: usize,
 }
 
 impl Default for CachePolicy {
@@ -74,6 +83,8 @@ impl Default for CachePolicy {
             l2_memory_budget_mb: cache.l2_memory_budget_mb,
             l1_max_entries: cache.l1_max_entries,
             l2_max_entries: cache.l2_max_entries,
+            l3_disk_budget_mb: cache.l3_disk_budget_mb,
+            text_index_budget_mb: cache.text_index_budget_mb,
         }
     }
 }
@@ -92,6 +103,14 @@ impl CachePolicy {
             .saturating_mul(Self::MEBIBYTE)
             .max(1)
     }
+
+    pub fn l3_disk_budget_bytes(&self) -> u64 {
+        (self.l3_disk_budget_mb as u64).saturating_mul(Self::MEBIBYTE as u64)
+    }
+
+    pub fn text_index_budget_bytes(&self) -> u64 {
+        (self.text_index_budget_mb as u64).saturating_mul(Self::MEBIBYTE as u64)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -99,8 +118,14 @@ pub struct ViewPolicy {
     pub initial_page_index: usize,
     pub initial_zoom: f32,
     pub initial_layout: PageLayoutMode,
+    pub initial_view: PageViewMode,
+    pub fit: FitMode,
     pub spread_direction: SpreadDirection,
     pub spread_cover: SpreadCoverPolicy,
+    /// Show the document title instead of the file name in the status bar when one is set.
+    pub status_title: bool,
+    /// Restore and save the reading position of each document across sessions.
+    pub remember_position: bool,
 }
 
 impl Default for ViewPolicy {
@@ -110,8 +135,12 @@ impl Default for ViewPolicy {
             initial_page_index: view.initial_page - 1,
             initial_zoom: view.initial_zoom,
             initial_layout: view.initial_layout,
+            initial_view: view.initial_view,
+            fit: view.fit,
             spread_direction: view.spread_direction,
             spread_cover: view.spread_cover,
+            status_title: view.status_title,
+            remember_position: view.remember_position,
         }
     }
 }
@@ -149,6 +178,25 @@ impl Default for WatchPolicy {
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinksPolicy {
+    /// Command that receives a followed URI as its last argument; `None` copies it instead.
+    pub opener: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardPolicy {
+    /// Command fed copied text on stdin in place of the terminal's OSC 52 clipboard.
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchPolicy {
+    pub folding: SearchFolding,
+    /// Whether the search palette previews matches on the visible pages while typing.
+    pub incremental: bool,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct AppOptionsResolver {
     options: AppOptions,
@@ -198,13 +246,19 @@ impl From<ResolvedAppOptions> for Config {
                 l2_memory_budget_mb: options.cache.l2_memory_budget_mb,
                 l1_max_entries: options.cache.l1_max_entries,
                 l2_max_entries: options.cache.l2_max_entries,
+                l3_disk_budget_mb: options.cache.l3_disk_budget_mb,
+                text_index_budget_mb: options.cache.text_index_budget_mb,
             },
             view: ViewConfig {
                 initial_page: options.view.initial_page_index + 1,
                 initial_zoom: options.view.initial_zoom,
                 initial_layout: options.view.initial_layout,
+                initial_view: options.view.initial_view,
+                fit: options.view.fit,
                 spread_direction: options.view.spread_direction,
                 spread_cover: options.view.spread_cover,
+                status_title: options.view.status_title,
+                remember_position: options.view.remember_position,
             },
             input: InputConfig {
                 sequence_timeout_ms: options.input.sequence_timeout.as_millis() as u64,
@@ -214,6 +268,19 @@ impl From<ResolvedAppOptions> for Config {
                 poll_interval_ms: options.watch.poll_interval.as_millis() as u64,
                 settle_delay_ms: options.watch.settle_delay.as_millis() as u64,
             },
+            links: LinksConfig {
+                opener: options.links.opener,
+            },
+            clipboard: ClipboardConfig {
+                command: options.clipboard.command,
+            },
+            search: SearchConfig {
+                fold_compatibility: options.search.folding.compatibility,
+                fold_diacritics: options.search.folding.diacritics,
+                fold_width: options.search.folding.width,
+                join_hyphenated: options.search.folding.hyphenation,
+                incremental: options.search.incremental,
+            },
         }
     }
 }
@@ -223,6 +290,7 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
     let cache_defaults = CacheConfig::default();
     let view_defaults = ViewConfig::default();
     let watch_defaults = WatchConfig::default();
+    let search_defaults = SearchConfig::default();
 
     let worker_threads = options
         .render
@@ -308,6 +376,11 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
                 .view
                 .initial_layout
                 .unwrap_or(view_defaults.initial_layout),
+            initial_view: options
+                .view
+                .initial_view
+                .unwrap_or(view_defaults.initial_view),
+            fit: options.view.fit.unwrap_or(view_defaults.fit),
             spread_direction: options
                 .view
                 .spread_direction
@@ -316,6 +389,14 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
                 .view
                 .spread_cover
                 .unwrap_or(view_defaults.spread_cover),
+            status_title: options
+                .view
+                .status_title
+                .unwrap_or(view_defaults.status_title),
+            remember_position: options
+                .view
+                .remember_position
+                .unwrap_or(view_defaults.remember_position),
         },
         event_loop: EventLoopPolicy {
             input_poll_timeout_idle: Duration::from_millis(input_poll_timeout_idle_ms),
@@ -342,6 +423,14 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
                 .cache
                 .l2_max_entries
                 .unwrap_or(cache_defaults.l2_max_entries),
+            l3_disk_budget_mb: options
+                .cache
+                .l3_disk_budget_mb
+                .unwrap_or(cache_defaults.l3_disk_budget_mb),
+            text_index_budget_mb: options
+                .cache
+                .text_index_budget_mb
+                .unwrap_or(cache_defaults.text_index_budget_mb),
         },
         input: InputPolicy {
             sequence_timeout: Duration::from_millis(sequence_timeout_ms),
@@ -352,6 +441,44 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
             poll_interval: Duration::from_millis(watch_poll_interval_ms),
             settle_delay: Duration::from_millis(watch_settle_delay_ms),
         },
+        links: LinksPolicy {
+            opener: options
+                .links
+                .opener
+                .map(|opener| opener.trim().to_string())
+                .filter(|opener| !opener.is_empty()),
+        },
+        clipboard: ClipboardPolicy {
+            command: options
+                .clipboard
+                .command
+                .map(|command| command.trim().to_string())
+                .filter(|command| !command.is_empty()),
+        },
+        search: SearchPolicy {
+            folding: SearchFolding {
+                compatibility: options
+                    .search
+                    .fold_compatibility
+                    .unwrap_or(search_defaults.fold_compatibility),
+                diacritics: options
+                    .search
+                    .fold_diacritics
+                    .unwrap_or(search_defaults.fold_diacritics),
+                width: options
+                    .search
+                    .fold_width
+                    .unwrap_or(search_defaults.fold_width),
+                hyphenation: options
+                    .search
+                    .join_hyphenated
+                    .unwrap_or(search_defaults.join_hyphenated),
+            },
+            incremental: options
+                .search
+                .incremental
+                .unwrap_or(search_defaults.incremental),
+        },
     }
 }
 
@@ -359,7 +486,7 @@ fn resolve_options(options: AppOptions) -> ResolvedAppOptions {
 mod tests {
     use std::time::Duration;
 
-    use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+    use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
 
     use crate::config::{AppOptions, RenderOptions, ViewOptions, WatchOptions};
 
@@ -382,8 +509,12 @@ mod tests {
                 initial_page: Some(0),
                 initial_zoom: Some(10.0),
                 initial_layout: Some(PageLayoutMode::Spread),
+                initial_view: Some(PageViewMode::Text),
+                fit: Some(FitMode::Actual),
                 spread_direction: Some(SpreadDirection::Rtl),
                 spread_cover: Some(SpreadCoverPolicy::Cover),
+                status_title: Some(true),
+                remember_position: Some(false),
             },
             watch: WatchOptions {
                 enabled: Some(true),
@@ -421,8 +552,12 @@ mod tests {
         assert_eq!(resolved.view.initial_page_index, 0);
         assert_eq!(resolved.view.initial_zoom, 4.0);
         assert_eq!(resolved.view.initial_layout, PageLayoutMode::Spread);
+        assert_eq!(resolved.view.initial_view, PageViewMode::Text);
+        assert_eq!(resolved.view.fit, FitMode::Actual);
         assert_eq!(resolved.view.spread_direction, SpreadDirection::Rtl);
         assert_eq!(resolved.view.spread_cover, SpreadCoverPolicy::Cover);
+        assert!(resolved.view.status_title);
+        assert!(!resolved.view.remember_position);
         assert!(resolved.watch.enabled);
         assert_eq!(resolved.watch.poll_interval, Duration::from_millis(1));
         assert_eq!(resolved.watch.settle_delay, Duration::from_millis(1));
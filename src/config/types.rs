@@ -1,7 +1,8 @@
 use serde::Deserialize;
 
-use crate::app::{PageLayoutMode, SpreadCoverPolicy, SpreadDirection};
+use crate::app::{FitMode, PageLayoutMode, PageViewMode, SpreadCoverPolicy, SpreadDirection};
 use crate::input::sequence::DEFAULT_SEQUENCE_TIMEOUT;
+use crate::search::matcher::SearchFolding;
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Config {
@@ -10,6 +11,9 @@ pub struct Config {
     pub view: ViewConfig,
     pub input: InputConfig,
     pub watch: WatchConfig,
+    pub links: LinksConfig,
+    pub clipboard: ClipboardConfig,
+    pub search: SearchConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -47,6 +51,10 @@ pub struct CacheConfig {
     pub l2_memory_budget_mb: usize,
     pub l1_max_entries: usize,
     pub l2_max_entries: usize,
+    /// Size budget of the on-disk render cache; `0`, the default, disables it.
+    pub l3_disk_budget_mb: usize,
+    /// Size budget of the on-disk search text index; `0`, the default, disables it.
+    pub text_index_budget_mb: usize,
 }
 
 impl Default for CacheConfig {
@@ -56,6 +64,8 @@ impl Default for CacheConfig {
             l2_memory_budget_mb: 64,
             l1_max_entries: 128,
             l2_max_entries: 96,
+            l3_disk_budget_mb: 0,
+            text_index_budget_mb: 0,
         }
     }
 }
@@ -74,6 +84,14 @@ impl CacheConfig {
             .saturating_mul(Self::MEBIBYTE)
             .max(1)
     }
+
+    pub fn l3_disk_budget_bytes(&self) -> u64 {
+        (self.l3_disk_budget_mb as u64).saturating_mul(Self::MEBIBYTE as u64)
+    }
+
+    pub fn text_index_budget_bytes(&self) -> u64 {
+        (self.text_index_budget_mb as u64).saturating_mul(Self::MEBIBYTE as u64)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -81,8 +99,13 @@ pub struct ViewConfig {
     pub initial_page: usize,
     pub initial_zoom: f32,
     pub initial_layout: PageLayoutMode,
+    pub initial_view: PageViewMode,
+    pub fit: FitMode,
     pub spread_direction: SpreadDirection,
     pub spread_cover: SpreadCoverPolicy,
+    pub status_title: bool,
+    /// Reopen each document at the page, zoom and layout it was last closed with.
+    pub remember_position: bool,
 }
 
 impl Default for ViewConfig {
@@ -91,8 +114,12 @@ impl Default for ViewConfig {
             initial_page: 1,
             initial_zoom: 1.0,
             initial_layout: PageLayoutMode::Single,
+            initial_view: PageViewMode::Image,
+            fit: FitMode::Page,
             spread_direction: SpreadDirection::Ltr,
             spread_cover: SpreadCoverPolicy::Paired,
+            status_title: false,
+            remember_position: true,
         }
     }
 }
@@ -126,3 +153,35 @@ impl Default for WatchConfig {
         }
     }
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinksConfig {
+    pub opener: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardConfig {
+    pub command: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchConfig {
+    pub fold_compatibility: bool,
+    pub fold_diacritics: bool,
+    pub fold_width: bool,
+    pub join_hyphenated: bool,
+    pub incremental: bool,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        let folding = SearchFolding::default();
+        Self {
+            fold_compatibility: folding.compatibility,
+            fold_diacritics: folding.diacritics,
+            fold_width: folding.width,
+            join_hyphenated: folding.hyphenation,
+            incremental: true,
+        }
+    }
+}
@@ -14,6 +14,11 @@ pub enum AppError {
         #[source]
         source: Box<dyn std::error::Error + Send + Sync>,
     },
+    #[error(
+        "encrypted pdf: {}",
+        if *password_supplied { "incorrect password" } else { "password required" }
+    )]
+    Encrypted { password_supplied: bool },
     #[error("invalid argument: {0}")]
     InvalidArgument(String),
     #[error("unsupported: {0}")]
@@ -39,6 +44,10 @@ impl AppError {
         }
     }
 
+    pub fn encrypted(password_supplied: bool) -> Self {
+        Self::Encrypted { password_supplied }
+    }
+
     pub fn invalid_argument(message: impl Into<String>) -> Self {
         Self::InvalidArgument(message.into())
     }
@@ -76,6 +85,18 @@ mod tests {
         assert_eq!(err.to_string(), "PDF render failed for page 7");
     }
 
+    #[test]
+    fn encrypted_error_distinguishes_missing_and_incorrect_password() {
+        assert_eq!(
+            AppError::encrypted(false).to_string(),
+            "encrypted pdf: password required"
+        );
+        assert_eq!(
+            AppError::encrypted(true).to_string(),
+            "encrypted pdf: incorrect password"
+        );
+    }
+
     #[test]
     fn page_out_of_range_uses_human_friendly_range() {
         assert_eq!(
@@ -1,7 +1,8 @@
 use crossterm::event::Event;
 use std::fmt;
+use std::path::PathBuf;
 
-use crate::app::Mode;
+use crate::app::{Mode, NoticeAction};
 use crate::backend::SharedPdfBackend;
 use crate::command::{CommandId, CommandOutcome, CommandRequest};
 use crate::presenter::PresenterBackgroundEvent;
@@ -22,6 +23,12 @@ pub enum NavReason {
     History(HistoryOp),
     /// Navigation initiated from the PDF outline.
     Outline { title: String },
+    /// Navigation initiated by following a link annotation.
+    Link,
+    /// Navigation initiated from the annotations palette.
+    Annotation,
+    /// Jump to a named mark. Carries the mark letter.
+    Mark { name: char },
     /// Layout-change normalization moved the anchor page.
     LayoutNormalize,
 }
@@ -61,13 +68,19 @@ pub enum AppEvent {
 pub(crate) enum DocumentReloadReason {
     Manual,
     FileChanged,
+    /// Opens the file of a global mark, which is restored once the file is loaded.
+    Mark {
+        name: char,
+    },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct DocumentReloadRequest {
     pub(crate) reason: DocumentReloadReason,
     pub(crate) retry: bool,
     pub(crate) generation: u64,
+    /// File to open in place of the current document, which is reopened when unset.
+    pub(crate) path: Option<PathBuf>,
 }
 
 impl DocumentReloadRequest {
@@ -76,6 +89,7 @@ impl DocumentReloadRequest {
             reason,
             retry: false,
             generation: 0,
+            path: None,
         }
     }
 
@@ -84,9 +98,15 @@ impl DocumentReloadRequest {
             reason,
             retry: true,
             generation,
+            path: None,
         }
     }
 
+    pub(crate) fn with_path(mut self, path: PathBuf) -> Self {
+        self.path = Some(path);
+        self
+    }
+
     pub(crate) fn with_generation(mut self, generation: u64) -> Self {
         self.generation = generation;
         self
@@ -121,6 +141,14 @@ pub(crate) enum DomainEvent {
     App(AppEvent),
     ReloadDocument(DocumentReloadRequest),
     DocumentReloaded(DocumentReloadResult),
+    /// Outcome of an `export-image` run off the UI thread.
+    ImageExported(NoticeAction),
+    /// Outcome of a `yank-page` or `yank-pages` run off the UI thread, with the text still to
+    /// be copied over OSC 52 when no clipboard command took it.
+    PagesYanked {
+        notice: NoticeAction,
+        osc52_text: Option<String>,
+    },
     RenderComplete(RenderWorkerResult),
     EncodeComplete(PresenterBackgroundEvent),
     PrefetchTick,
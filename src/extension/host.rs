@@ -1,13 +1,28 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use crate::annotations::{
+    AnnotationsCommandPort, AnnotationsExtension, AnnotationsState, AnnotationsUiSnapshot,
+};
 use crate::app::AppState;
-use crate::backend::SharedPdfBackend;
+use crate::attachments::{
+    AttachmentsCommandPort, AttachmentsExtension, AttachmentsState, AttachmentsUiSnapshot,
+};
+use crate::backend::{PdfBackend, SharedPdfBackend};
+use crate::config::SearchPolicy;
 use crate::event::AppEvent;
 use crate::highlight::HighlightOverlaySnapshot;
 use crate::history::{HistoryCommandPort, HistoryExtension, HistoryState};
+use crate::info::{
+    DocumentInfoCommandPort, DocumentInfoExtension, DocumentInfoState, DocumentInfoView,
+};
 use crate::input::{AppInputEvent, InputHookResult};
+use crate::links::{LinkHintView, LinkHintsCommandPort, LinkHintsExtension, LinkHintsState};
+use crate::marks::{MarkStore, MarksCommandPort, MarksExtension, MarksState, MarksUiSnapshot};
 use crate::outline::{OutlineCommandPort, OutlineExtension, OutlineState, OutlineUiSnapshot};
+use crate::search::text_index::SharedTextIndex;
 use crate::search::{SearchCommandPort, SearchExtension, SearchRuntime, SearchUiSnapshot};
+use crate::selection::{SelectionCommandPort, SelectionExtension, SelectionState};
 
 use super::traits::Extension;
 
@@ -15,23 +30,41 @@ use super::traits::Extension;
 pub struct ExtensionUiSnapshot {
     pub search: SearchUiSnapshot,
     pub outline: OutlineUiSnapshot,
+    pub attachments: AttachmentsUiSnapshot,
+    pub annotations: AnnotationsUiSnapshot,
+    pub marks: MarksUiSnapshot,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct ExtensionRenderSnapshot {
     pub highlight_overlay: HighlightOverlaySnapshot,
+    pub link_hints: Vec<LinkHintView>,
+    pub document_info: Option<DocumentInfoView>,
+    pub document_title: Option<String>,
 }
 
 pub(crate) struct ExtensionCommandPorts<'a> {
     pub search: SearchCommandPort<'a>,
     pub history: HistoryCommandPort<'a>,
     pub outline: OutlineCommandPort<'a>,
+    pub links: LinkHintsCommandPort<'a>,
+    pub info: DocumentInfoCommandPort<'a>,
+    pub attachments: AttachmentsCommandPort<'a>,
+    pub annotations: AnnotationsCommandPort<'a>,
+    pub marks: MarksCommandPort<'a>,
+    pub selection: SelectionCommandPort<'a>,
 }
 
 pub struct ExtensionHost {
     search: SearchRuntime,
     history: HistoryState,
     outline: OutlineState,
+    links: LinkHintsState,
+    info: DocumentInfoState,
+    attachments: AttachmentsState,
+    annotations: AnnotationsState,
+    marks: MarksState,
+    selection: SelectionState,
 }
 
 impl ExtensionHost {
@@ -40,6 +73,12 @@ impl ExtensionHost {
             search: SearchExtension::init_state(),
             history: HistoryExtension::init_state(),
             outline: OutlineExtension::init_state(),
+            links: LinkHintsExtension::init_state(),
+            info: DocumentInfoExtension::init_state(),
+            attachments: AttachmentsExtension::init_state(),
+            annotations: AnnotationsExtension::init_state(),
+            marks: MarksExtension::init_state(),
+            selection: SelectionExtension::init_state(),
         }
     }
 
@@ -48,6 +87,12 @@ impl ExtensionHost {
             search: SearchCommandPort::new(&mut self.search),
             history: HistoryCommandPort::new(&mut self.history),
             outline: OutlineCommandPort::new(&mut self.outline),
+            links: LinkHintsCommandPort::new(&mut self.links),
+            info: DocumentInfoCommandPort::new(&mut self.info),
+            attachments: AttachmentsCommandPort::new(&mut self.attachments),
+            annotations: AnnotationsCommandPort::new(&mut self.annotations),
+            marks: MarksCommandPort::new(&mut self.marks),
+            selection: SelectionCommandPort::new(&mut self.selection),
         }
     }
 
@@ -55,7 +100,45 @@ impl ExtensionHost {
         &self.search
     }
 
+    pub(crate) fn attach_text_index(&mut self, text_index: SharedTextIndex) {
+        self.search.attach_text_index(text_index);
+    }
+
+    pub(crate) fn configure_search(&mut self, policy: SearchPolicy) {
+        self.search.set_folding(policy.folding);
+        self.search.set_incremental(policy.incremental);
+    }
+
+    pub(crate) fn attach_mark_store(&mut self, store: MarkStore) {
+        self.marks.attach_store(store);
+    }
+
+    pub(crate) fn mark_document(&mut self, pdf: &dyn PdfBackend, name: char) -> Option<PathBuf> {
+        self.marks.document_of(pdf, name)
+    }
+
     pub fn handle_input(&mut self, event: AppInputEvent, app: &mut AppState) -> InputHookResult {
+        // Runs first so the flash is dismissed even when a later hook consumes the key.
+        AnnotationsExtension::handle_input(&mut self.annotations, event, app);
+
+        // An open info overlay is dismissed by whatever key comes next.
+        let info_result = DocumentInfoExtension::handle_input(&mut self.info, event, app);
+        if info_result != InputHookResult::Ignored {
+            return info_result;
+        }
+
+        // Active link hints own the keyboard until a label is chosen or the mode is canceled.
+        let links_result = LinkHintsExtension::handle_input(&mut self.links, event, app);
+        if links_result != InputHookResult::Ignored {
+            return links_result;
+        }
+
+        // After `m` or `'` the next key names the mark instead of running its binding.
+        let marks_result = MarksExtension::handle_input(&mut self.marks, event, app);
+        if marks_result != InputHookResult::Ignored {
+            return marks_result;
+        }
+
         let search_result = SearchExtension::handle_input(&mut self.search, event, app);
         if search_result != InputHookResult::Ignored {
             return search_result;
@@ -73,6 +156,8 @@ impl ExtensionHost {
         SearchExtension::handle_event(&mut self.search, event, app);
         HistoryExtension::handle_event(&mut self.history, event, app);
         OutlineExtension::handle_event(&mut self.outline, event, app);
+        LinkHintsExtension::handle_event(&mut self.links, event, app);
+        SelectionExtension::handle_event(&mut self.selection, event, app);
     }
 
     pub fn drain_background(&mut self, app: &mut AppState) -> bool {
@@ -82,13 +167,21 @@ impl ExtensionHost {
     }
 
     pub fn on_document_opened(&mut self, pdf: SharedPdfBackend) {
+        self.info.load_title(pdf.as_ref());
+        self.marks.open_document(pdf.path());
         self.search.prewarm(pdf);
     }
 
     pub fn on_document_reloaded(&mut self, app: &mut AppState, pdf: SharedPdfBackend) {
+        self.marks.open_document(pdf.path());
         SearchExtension::on_document_reloaded(&mut self.search, app, Arc::clone(&pdf));
         HistoryExtension::on_document_reloaded(&mut self.history, app, Arc::clone(&pdf));
-        OutlineExtension::on_document_reloaded(&mut self.outline, app, pdf);
+        OutlineExtension::on_document_reloaded(&mut self.outline, app, Arc::clone(&pdf));
+        LinkHintsExtension::on_document_reloaded(&mut self.links, app, Arc::clone(&pdf));
+        AttachmentsExtension::on_document_reloaded(&mut self.attachments, app, Arc::clone(&pdf));
+        AnnotationsExtension::on_document_reloaded(&mut self.annotations, app, Arc::clone(&pdf));
+        SelectionExtension::on_document_reloaded(&mut self.selection, app, Arc::clone(&pdf));
+        DocumentInfoExtension::on_document_reloaded(&mut self.info, app, pdf);
     }
 
     pub fn on_visible_pages_changed(
@@ -112,6 +205,21 @@ impl ExtensionHost {
         {
             segments.push(segment);
         }
+        if let Some(segment) = LinkHintsExtension::status_bar_segment(&self.links, app)
+            && !segment.is_empty()
+        {
+            segments.push(segment);
+        }
+        if let Some(segment) = MarksExtension::status_bar_segment(&self.marks, app)
+            && !segment.is_empty()
+        {
+            segments.push(segment);
+        }
+        if let Some(segment) = SelectionExtension::status_bar_segment(&self.selection, app)
+            && !segment.is_empty()
+        {
+            segments.push(segment);
+        }
         segments
     }
 
@@ -119,14 +227,30 @@ impl ExtensionHost {
         ExtensionUiSnapshot {
             search: self.search.ui_snapshot(),
             outline: self.outline.ui_snapshot(),
+            attachments: self.attachments.ui_snapshot(),
+            annotations: self.annotations.ui_snapshot(),
+            marks: self.marks.ui_snapshot(),
         }
     }
 
     pub fn render_snapshot(&self, visible_pages: [Option<usize>; 2]) -> ExtensionRenderSnapshot {
+        let mut highlight_spans = self
+            .search
+            .highlight_overlay_for_visible_pages(visible_pages)
+            .spans;
+        highlight_spans.extend(
+            self.annotations
+                .highlight_spans_for_visible_pages(visible_pages),
+        );
+        highlight_spans.extend(
+            self.selection
+                .highlight_spans_for_visible_pages(visible_pages),
+        );
         ExtensionRenderSnapshot {
-            highlight_overlay: self
-                .search
-                .highlight_overlay_for_visible_pages(visible_pages),
+            highlight_overlay: HighlightOverlaySnapshot::new(highlight_spans),
+            link_hints: self.links.hint_views(visible_pages),
+            document_info: self.info.view().cloned(),
+            document_title: self.info.title().map(str::to_owned),
         }
     }
 }
@@ -142,7 +266,7 @@ mod tests {
     use std::path::{Path, PathBuf};
     use std::sync::Arc;
 
-    use crate::backend::{PdfBackend, RgbaFrame, SharedPdfBackend, TextPage};
+    use crate::backend::{PdfBackend, RgbaFrame, SharedPdfBackend, TextLayout, TextPage};
     use crate::command::{CommandOutcome, SearchMatcherKind};
     use crate::event::{AppEvent, NavReason, PageGotoKind};
 
@@ -192,6 +316,7 @@ mod tests {
                 height_pt: 792.0,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
@@ -1,8 +1,13 @@
 mod host;
 mod traits;
 
+pub use crate::annotations::AnnotationsPaletteProvider;
+pub use crate::attachments::AttachmentsPaletteProvider;
 pub use crate::history::HistoryPaletteProvider;
+pub use crate::marks::MarksPaletteProvider;
 pub use crate::outline::OutlinePaletteProvider;
-pub use crate::search::{SearchPaletteProvider, SearchResultsPaletteProvider};
+pub use crate::search::{
+    HighlightLayersPaletteProvider, SearchPaletteProvider, SearchResultsPaletteProvider,
+};
 pub use host::{ExtensionHost, ExtensionUiSnapshot};
 pub use traits::Extension;
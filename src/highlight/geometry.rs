@@ -1,5 +1,72 @@
+use ratatui::layout::Rect;
+
 use crate::backend::{PdfRect, TextGlyph};
 
+/// Pixel region of a rendered page that a presenter slot shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageCrop {
+    pub page: usize,
+    pub frame_width_px: u32,
+    pub frame_height_px: u32,
+    pub x_px: u32,
+    pub y_px: u32,
+    pub width_px: u32,
+    pub height_px: u32,
+}
+
+/// A page crop together with the terminal cells it is drawn into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageCellPlacement {
+    pub crop: PageCrop,
+    pub area: Rect,
+}
+
+impl PageCellPlacement {
+    /// Maps a page-space rect to the cells it covers, clipped to the visible crop.
+    pub fn page_rect_to_cells(
+        &self,
+        rect: PdfRect,
+        (width_pt, height_pt): (f32, f32),
+    ) -> Option<Rect> {
+        let crop = self.crop;
+        if width_pt <= 0.0
+            || height_pt <= 0.0
+            || crop.width_px == 0
+            || crop.height_px == 0
+            || self.area.width == 0
+            || self.area.height == 0
+        {
+            return None;
+        }
+
+        let to_px_x = crop.frame_width_px as f32 / width_pt;
+        let to_px_y = crop.frame_height_px as f32 / height_pt;
+        let to_cell_x = f32::from(self.area.width) / crop.width_px as f32;
+        let to_cell_y = f32::from(self.area.height) / crop.height_px as f32;
+        let cell_x = |pt: f32| (pt * to_px_x - crop.x_px as f32) * to_cell_x;
+        let cell_y = |pt: f32| (pt * to_px_y - crop.y_px as f32) * to_cell_y;
+
+        let x0 = cell_x(rect.x0.min(rect.x1)).floor().max(0.0);
+        let y0 = cell_y(rect.y0.min(rect.y1)).floor().max(0.0);
+        let x1 = cell_x(rect.x0.max(rect.x1))
+            .ceil()
+            .min(f32::from(self.area.width));
+        let y1 = cell_y(rect.y0.max(rect.y1))
+            .ceil()
+            .min(f32::from(self.area.height));
+        if x1 <= x0 || y1 <= y0 {
+            return None;
+        }
+
+        Some(Rect::new(
+            self.area.x + x0 as u16,
+            self.area.y + y0 as u16,
+            (x1 - x0) as u16,
+            (y1 - y0) as u16,
+        ))
+    }
+}
+
 pub fn merge_text_glyph_rects(glyphs: &[TextGlyph]) -> Vec<PdfRect> {
     let glyphs: Vec<HighlightGlyph> = glyphs
         .iter()
@@ -149,7 +216,9 @@ enum RectExtent {
 
 #[cfg(test)]
 mod tests {
-    use super::merge_text_glyph_rects;
+    use super::{PageCellPlacement, PageCrop, merge_text_glyph_rects};
+    use ratatui::layout::Rect;
+
     use crate::backend::{PdfRect, TextGlyph};
 
     #[test]
@@ -266,6 +335,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn page_rect_maps_through_crop_into_cells() {
+        let placement = PageCellPlacement {
+            crop: PageCrop {
+                page: 0,
+                frame_width_px: 200,
+                frame_height_px: 400,
+                x_px: 40,
+                y_px: 100,
+                width_px: 100,
+                height_px: 200,
+            },
+            area: Rect::new(5, 2, 10, 10),
+        };
+
+        let rect = PdfRect {
+            x0: 30.0,
+            y0: 60.0,
+            x1: 45.0,
+            y1: 70.0,
+        };
+        assert_eq!(
+            placement.page_rect_to_cells(rect, (100.0, 200.0)),
+            Some(Rect::new(7, 3, 3, 1))
+        );
+
+        let hidden = PdfRect {
+            x0: 0.0,
+            y0: 0.0,
+            x1: 10.0,
+            y1: 10.0,
+        };
+        assert_eq!(placement.page_rect_to_cells(hidden, (100.0, 200.0)), None);
+    }
+
     fn glyph(ch: char, x0: f32, y0: f32, x1: f32, y1: f32) -> TextGlyph {
         TextGlyph {
             ch,
@@ -8,6 +8,11 @@ pub mod geometry;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HighlightSource {
     Search,
+    Annotation,
+    /// A search pinned as a named highlight layer.
+    Layer,
+    /// The visual mode caret or selection.
+    Selection,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -21,6 +26,37 @@ impl HighlightStyle {
         fill_rgba: [255, 196, 79, 96],
         priority: 0,
     };
+
+    pub const ANNOTATION_FLASH: Self = Self {
+        fill_rgba: [79, 166, 255, 112],
+        priority: 1,
+    };
+
+    pub const SELECTION: Self = Self {
+        fill_rgba: [120, 150, 255, 112],
+        priority: 2,
+    };
+
+    pub const CARET: Self = Self {
+        fill_rgba: [255, 255, 255, 160],
+        priority: 2,
+    };
+
+    /// Fills handed to pinned search layers, in order.
+    pub const LAYER_FILLS: [Self; 5] = [
+        Self::layer([102, 204, 102, 96]),
+        Self::layer([255, 105, 180, 96]),
+        Self::layer([64, 200, 224, 96]),
+        Self::layer([160, 110, 255, 96]),
+        Self::layer([240, 80, 80, 96]),
+    ];
+
+    const fn layer(fill_rgba: [u8; 4]) -> Self {
+        Self {
+            fill_rgba,
+            priority: 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
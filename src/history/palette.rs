@@ -1,3 +1,4 @@
+use crate::backend::PageLabels;
 use crate::command::Command;
 use crate::error::AppResult;
 use crate::input::shortcut::format_shortcut_key;
@@ -46,9 +47,10 @@ impl PaletteProvider for HistoryPaletteProvider {
             _ => "",
         };
         let parsed = parse_seed(seed, ctx.app.current_page);
+        let labels = &ctx.app.page_labels;
         let query = ctx.input.trim().to_ascii_lowercase();
         if query.is_empty() {
-            return Ok(build_history_candidates(parsed));
+            return Ok(build_history_candidates(parsed, labels));
         }
 
         let mut index_matches = Vec::new();
@@ -57,9 +59,9 @@ impl PaletteProvider for HistoryPaletteProvider {
 
         for entry in parsed.into_iter() {
             let idx = entry.display_index;
-            let page_1indexed = entry.page + 1;
-            let view = HistoryEntryView::new(idx, page_1indexed, entry.reason.as_str());
-            let bucket = view.match_bucket(idx, page_1indexed, &query);
+            let page = entry_page_text(&entry, labels);
+            let view = HistoryEntryView::new(idx, &page, entry.reason.as_str());
+            let bucket = view.match_bucket(idx, &page, &query);
             let candidate = view.into_candidate(&entry);
             match bucket {
                 Some(HistoryMatchBucket::Index) => index_matches.push(candidate),
@@ -116,16 +118,24 @@ impl PaletteProvider for HistoryPaletteProvider {
     }
 }
 
-fn build_history_candidates(entries: Vec<SeedEntry>) -> Vec<PaletteCandidate> {
+fn build_history_candidates(entries: Vec<SeedEntry>, labels: &PageLabels) -> Vec<PaletteCandidate> {
     entries
         .into_iter()
         .map(|entry| {
-            HistoryEntryView::new(entry.display_index, entry.page + 1, entry.reason.as_str())
+            let page = entry_page_text(&entry, labels);
+            HistoryEntryView::new(entry.display_index, &page, entry.reason.as_str())
                 .into_candidate(&entry)
         })
         .collect()
 }
 
+fn entry_page_text(entry: &SeedEntry, labels: &PageLabels) -> String {
+    match labels.distinct(entry.page) {
+        Some(label) => format!("p.{label}"),
+        None => page_text(entry.page + 1),
+    }
+}
+
 #[derive(Debug, Clone)]
 struct HistoryEntryView {
     reason: HistoryReasonLabel,
@@ -135,11 +145,11 @@ struct HistoryEntryView {
 }
 
 impl HistoryEntryView {
-    fn new(idx: isize, page_1indexed: usize, reason: &str) -> Self {
+    fn new(idx: isize, page: &str, reason: &str) -> Self {
         let reason = HistoryReasonLabel::parse(reason);
-        let left = reason.left_parts(idx, page_1indexed);
-        let right = vec![PaletteTextPart::secondary(page_text(page_1indexed))];
-        let search_texts = reason.search_texts(idx, page_1indexed);
+        let left = reason.left_parts(idx, page);
+        let right = vec![PaletteTextPart::secondary(page)];
+        let search_texts = reason.search_texts(idx, page);
         Self {
             reason,
             left,
@@ -164,13 +174,8 @@ impl HistoryEntryView {
         }
     }
 
-    fn match_bucket(
-        &self,
-        idx: isize,
-        page_1indexed: usize,
-        query: &str,
-    ) -> Option<HistoryMatchBucket> {
-        self.reason.match_bucket(idx, page_1indexed, query)
+    fn match_bucket(&self, idx: isize, page: &str, query: &str) -> Option<HistoryMatchBucket> {
+        self.reason.match_bucket(idx, page, query)
     }
 }
 
@@ -247,7 +252,7 @@ impl HistoryReasonLabel {
         }
     }
 
-    fn left_parts(&self, idx: isize, page_1indexed: usize) -> Vec<PaletteTextPart> {
+    fn left_parts(&self, idx: isize, page: &str) -> Vec<PaletteTextPart> {
         let mut parts = Vec::with_capacity(4);
         parts.push(PaletteTextPart::primary(idx.to_string()));
         parts.push(PaletteTextPart::primary("  "));
@@ -265,69 +270,57 @@ impl HistoryReasonLabel {
                 parts.push(PaletteTextPart::primary(label.clone()));
             }
             HistoryReasonLabel::PageOnly => {
-                parts.push(PaletteTextPart::primary(page_text(page_1indexed)));
+                parts.push(PaletteTextPart::primary(page));
             }
         }
 
         parts
     }
 
-    fn search_texts(&self, idx: isize, page_1indexed: usize) -> Vec<PaletteSearchText> {
+    fn search_texts(&self, idx: isize, page: &str) -> Vec<PaletteSearchText> {
         let mut texts = Vec::with_capacity(3);
         texts.push(PaletteSearchText::new(idx.to_string()));
 
         match self {
             HistoryReasonLabel::Search(query) => {
                 texts.push(PaletteSearchText::new(format!("/{query}")));
-                texts.push(PaletteSearchText::new(page_text(page_1indexed)));
+                texts.push(PaletteSearchText::new(page));
             }
             HistoryReasonLabel::Outline(title) => {
                 texts.push(PaletteSearchText::new(format!("#{title}")));
-                texts.push(PaletteSearchText::new(page_text(page_1indexed)));
+                texts.push(PaletteSearchText::new(page));
             }
             HistoryReasonLabel::Goto(label) => {
                 texts.push(PaletteSearchText::new(label.clone()));
-                texts.push(PaletteSearchText::new(page_text(page_1indexed)));
+                texts.push(PaletteSearchText::new(page));
             }
             HistoryReasonLabel::PageOnly => {
-                texts.push(PaletteSearchText::new(page_text(page_1indexed)));
+                texts.push(PaletteSearchText::new(page));
             }
         }
 
         texts
     }
 
-    fn display_text(&self, page_1indexed: usize) -> String {
+    fn display_text(&self, page: &str) -> String {
         match self {
             HistoryReasonLabel::Search(query) => format!("/{query}"),
             HistoryReasonLabel::Outline(title) => format!("#{title}"),
             HistoryReasonLabel::Goto(label) => label.clone(),
-            HistoryReasonLabel::PageOnly => page_text(page_1indexed),
+            HistoryReasonLabel::PageOnly => page.to_string(),
         }
     }
 
-    fn match_bucket(
-        &self,
-        idx: isize,
-        page_1indexed: usize,
-        query: &str,
-    ) -> Option<HistoryMatchBucket> {
+    fn match_bucket(&self, idx: isize, page: &str, query: &str) -> Option<HistoryMatchBucket> {
         if idx.to_string().contains(query) {
             return Some(HistoryMatchBucket::Index);
         }
 
-        if self
-            .display_text(page_1indexed)
-            .to_ascii_lowercase()
-            .contains(query)
-        {
+        if self.display_text(page).to_ascii_lowercase().contains(query) {
             return Some(HistoryMatchBucket::Reason);
         }
 
-        if page_text(page_1indexed)
-            .to_ascii_lowercase()
-            .contains(query)
-        {
+        if page.to_ascii_lowercase().contains(query) {
             return Some(HistoryMatchBucket::Page);
         }
 
@@ -345,7 +338,7 @@ fn history_parts(
     Vec<PaletteTextPart>,
     Vec<PaletteSearchText>,
 ) {
-    let view = HistoryEntryView::new(idx, page_1indexed, reason);
+    let view = HistoryEntryView::new(idx, &page_text(page_1indexed), reason);
     (view.left, view.right, view.search_texts)
 }
 
@@ -371,12 +364,12 @@ fn history_match_bucket(
     reason: &str,
     query: &str,
 ) -> Option<HistoryMatchBucket> {
-    HistoryReasonLabel::parse(reason).match_bucket(idx, page_1indexed, query)
+    HistoryReasonLabel::parse(reason).match_bucket(idx, &page_text(page_1indexed), query)
 }
 
 #[cfg(test)]
 fn history_reason_display_text(page_1indexed: usize, reason: &str) -> String {
-    HistoryReasonLabel::parse(reason).display_text(page_1indexed)
+    HistoryReasonLabel::parse(reason).display_text(&page_text(page_1indexed))
 }
 
 struct SeedEntry {
@@ -462,6 +455,7 @@ fn parse_entry(item: &str) -> Option<SeedEntry> {
 
 #[cfg(test)]
 mod tests {
+    use crate::backend::PageLabels;
     use crate::palette::PaletteTextTone;
     use crate::{
         extension::ExtensionUiSnapshot,
@@ -653,6 +647,38 @@ mod tests {
         assert_eq!(labels, vec!["4", "3", "2", "1", "0", "-1"]);
     }
 
+    #[test]
+    fn list_shows_page_labels_that_differ_from_page_numbers() {
+        let provider = HistoryPaletteProvider;
+        let app = PaletteAppSnapshot {
+            current_page: 1,
+            page_labels: PageLabels::new(vec!["i".to_string(), "ii".to_string(), "3".to_string()]),
+            ..PaletteAppSnapshot::default()
+        };
+        let extensions = ExtensionUiSnapshot::default();
+        let payload = PaletteOpenPayload::HistorySeed("b:2|c:1".to_string());
+        let mut ctx = PaletteContext {
+            app,
+            extensions: &extensions,
+            kind: crate::palette::PaletteKind::History,
+            input: "",
+            open_payload: Some(&payload),
+        };
+
+        let items = provider.list(&ctx).expect("history list should build");
+        let pages: Vec<_> = items
+            .iter()
+            .map(|item| item.right[0].text.as_str())
+            .collect();
+        assert_eq!(pages, vec!["p.ii", "p.3"]);
+        assert_eq!(items[0].payload, PalettePayload::Opaque("2".to_string()));
+
+        ctx.input = "ii";
+        let items = provider.list(&ctx).expect("history list should build");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].left[2].text, "p.ii");
+    }
+
     #[test]
     fn match_bucket_prefers_index_before_reason_before_page() {
         assert_eq!(
@@ -272,6 +272,9 @@ fn format_reason(reason: &NavReason) -> String {
             HistoryOp::Goto => "History:goto".to_string(),
         },
         NavReason::Outline { title } => format!("Outline:~{}", encode_seed_component(title)),
+        NavReason::Link => "Link".to_string(),
+        NavReason::Annotation => "Annotation".to_string(),
+        NavReason::Mark { name } => format!("Mark:{name}"),
         NavReason::LayoutNormalize => "LayoutNormalize".to_string(),
     }
 }
@@ -299,9 +302,12 @@ enum RecordPolicy {
 
 fn record_policy(reason: &NavReason) -> RecordPolicy {
     match reason {
-        NavReason::PageGoto(_) | NavReason::Search { .. } | NavReason::Outline { .. } => {
-            RecordPolicy::Record
-        }
+        NavReason::PageGoto(_)
+        | NavReason::Search { .. }
+        | NavReason::Outline { .. }
+        | NavReason::Link
+        | NavReason::Annotation
+        | NavReason::Mark { .. } => RecordPolicy::Record,
         NavReason::Step | NavReason::LayoutNormalize => RecordPolicy::SkipAndClearForward,
         NavReason::History(_) => RecordPolicy::SkipAndKeepStacks,
     }
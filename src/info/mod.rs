@@ -0,0 +1,34 @@
+pub mod state;
+
+use crate::app::AppState;
+use crate::backend::SharedPdfBackend;
+use crate::extension::Extension;
+use crate::input::{AppInputEvent, InputHookResult};
+pub use state::{DocumentInfoCommandPort, DocumentInfoState, DocumentInfoView};
+
+pub struct DocumentInfoExtension;
+
+impl Extension for DocumentInfoExtension {
+    type State = DocumentInfoState;
+
+    fn init_state() -> Self::State {
+        DocumentInfoState::default()
+    }
+
+    fn handle_input(
+        state: &mut Self::State,
+        event: AppInputEvent,
+        app: &mut AppState,
+    ) -> InputHookResult {
+        let _ = app;
+        match event {
+            AppInputEvent::Key(key) => state.handle_key(key),
+        }
+    }
+
+    fn on_document_reloaded(state: &mut Self::State, app: &mut AppState, pdf: SharedPdfBackend) {
+        let _ = app;
+        state.close();
+        state.load_title(pdf.as_ref());
+    }
+}
@@ -0,0 +1,241 @@
+use crossterm::event::{KeyEvent, KeyEventKind};
+
+use crate::app::NoticeAction;
+use crate::backend::{PdfBackend, PdfMetadata};
+use crate::command::CommandOutcome;
+use crate::error::AppResult;
+use crate::input::InputHookResult;
+
+const PAGE_SIZE_TOLERANCE_PT: i32 = 2;
+const NAMED_PAGE_SIZES: [(&str, i32, i32); 6] = [
+    ("A3", 842, 1191),
+    ("A4", 595, 842),
+    ("A5", 420, 595),
+    ("Letter", 612, 792),
+    ("Legal", 612, 1008),
+    ("Tabloid", 792, 1224),
+];
+
+/// Labeled rows for the document info overlay, captured when the overlay opens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentInfoView {
+    pub rows: Vec<(&'static str, String)>,
+}
+
+#[derive(Debug, Default)]
+pub struct DocumentInfoState {
+    overlay: Option<DocumentInfoView>,
+    title: Option<String>,
+}
+
+pub struct DocumentInfoCommandPort<'a> {
+    state: &'a mut DocumentInfoState,
+}
+
+impl<'a> DocumentInfoCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut DocumentInfoState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn show(
+        &mut self,
+        pdf: &dyn PdfBackend,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.show(pdf)
+    }
+}
+
+impl DocumentInfoState {
+    pub fn is_open(&self) -> bool {
+        self.overlay.is_some()
+    }
+
+    pub fn view(&self) -> Option<&DocumentInfoView> {
+        self.overlay.as_ref()
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Caches the document title for the status bar; unreadable metadata just leaves it unset.
+    pub fn load_title(&mut self, pdf: &dyn PdfBackend) {
+        self.title = pdf.metadata().ok().and_then(|metadata| metadata.title);
+    }
+
+    pub fn show(&mut self, pdf: &dyn PdfBackend) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let metadata = pdf.metadata()?;
+        self.title = metadata.title.clone();
+        self.overlay = Some(DocumentInfoView {
+            rows: document_info_rows(pdf, metadata),
+        });
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    pub fn close(&mut self) {
+        self.overlay = None;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InputHookResult {
+        if !self.is_open() {
+            return InputHookResult::Ignored;
+        }
+        if key.kind != KeyEventKind::Release {
+            self.close();
+        }
+        InputHookResult::Consumed
+    }
+}
+
+fn document_info_rows(pdf: &dyn PdfBackend, metadata: PdfMetadata) -> Vec<(&'static str, String)> {
+    let file_name = pdf
+        .path()
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(str::to_owned)
+        .unwrap_or_else(|| pdf.path().display().to_string());
+    let yes_no = |flag: bool| if flag { "yes" } else { "no" }.to_string();
+
+    let mut rows = vec![("File", file_name)];
+    let optional = [
+        ("Title", metadata.title),
+        ("Author", metadata.author),
+        ("Subject", metadata.subject),
+        ("Keywords", metadata.keywords),
+        ("Creator", metadata.creator),
+        ("Producer", metadata.producer),
+        ("Created", metadata.created.map(|date| date.to_string())),
+        ("Modified", metadata.modified.map(|date| date.to_string())),
+        ("PDF version", metadata.version),
+    ];
+    rows.extend(
+        optional
+            .into_iter()
+            .filter_map(|(label, value)| Some((label, value?))),
+    );
+    rows.push(("Pages", pdf.page_count().to_string()));
+    if let Some(summary) = page_size_summary(pdf) {
+        rows.push(("Page size", summary));
+    }
+    rows.push(("Encrypted", yes_no(metadata.encrypted)));
+    rows.push(("Tagged", yes_no(metadata.tagged)));
+    rows
+}
+
+/// Describes the most common page size, noting how many other sizes the document mixes in.
+fn page_size_summary(pdf: &dyn PdfBackend) -> Option<String> {
+    let mut sizes: Vec<((i32, i32), usize)> = Vec::new();
+    for page in 0..pdf.page_count() {
+        let Ok((width, height)) = pdf.page_dimensions(page) else {
+            continue;
+        };
+        let size = (width.round() as i32, height.round() as i32);
+        match sizes.iter_mut().find(|(known, _)| *known == size) {
+            Some((_, count)) => *count += 1,
+            None => sizes.push((size, 1)),
+        }
+    }
+    // Stable sort keeps the earliest page's size first among equally common ones.
+    sizes.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let ((width, height), _) = *sizes.first()?;
+    let mut summary = format!("{width} x {height} pt");
+    if let Some(name) = page_size_name(width, height) {
+        summary.push_str(&format!(" ({name})"));
+    }
+    match sizes.len() {
+        1 => {}
+        2 => summary.push_str(", 1 other size"),
+        count => summary.push_str(&format!(", {} other sizes", count - 1)),
+    }
+    Some(summary)
+}
+
+fn page_size_name(width: i32, height: i32) -> Option<String> {
+    let near = |left: i32, right: i32| (left - right).abs() <= PAGE_SIZE_TOLERANCE_PT;
+    NAMED_PAGE_SIZES.iter().find_map(|&(name, short, long)| {
+        if near(width, short) && near(height, long) {
+            Some(name.to_string())
+        } else if near(width, long) && near(height, short) {
+            Some(format!("{name} landscape"))
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::backend::PdfMetadata;
+    use crate::backend::test_support::TextStubPdf;
+    use crate::command::CommandOutcome;
+    use crate::input::InputHookResult;
+
+    use super::{DocumentInfoState, page_size_summary};
+
+    fn info_pdf(sizes: Vec<(f32, f32)>) -> TextStubPdf {
+        TextStubPdf::blank(sizes.len())
+            .with_path("/tmp/report.pdf")
+            .with_page_sizes(&sizes)
+            .with_metadata(PdfMetadata {
+                title: Some("Quarterly Report".to_string()),
+                version: Some("1.7".to_string()),
+                tagged: true,
+                ..PdfMetadata::default()
+            })
+    }
+
+    #[test]
+    fn show_lists_present_fields_and_any_key_closes() {
+        let pdf = info_pdf(vec![(595.3, 841.9); 3]);
+        let mut state = DocumentInfoState::default();
+
+        let (outcome, _) = state.show(&pdf).expect("show should succeed");
+        assert_eq!(outcome, CommandOutcome::Applied);
+        assert_eq!(state.title(), Some("Quarterly Report"));
+        let rows = &state.view().expect("overlay should be open").rows;
+        assert_eq!(
+            rows.iter().map(|(label, _)| *label).collect::<Vec<_>>(),
+            vec![
+                "File",
+                "Title",
+                "PDF version",
+                "Pages",
+                "Page size",
+                "Encrypted",
+                "Tagged"
+            ]
+        );
+        assert!(rows.contains(&("Tagged", "yes".to_string())));
+
+        assert_eq!(
+            state.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+            InputHookResult::Consumed
+        );
+        assert!(!state.is_open());
+        assert_eq!(
+            state.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+            InputHookResult::Ignored
+        );
+    }
+
+    #[test]
+    fn page_size_summary_names_common_sizes_and_counts_mixed_ones() {
+        assert_eq!(
+            page_size_summary(&info_pdf(vec![(595.0, 842.0); 2])),
+            Some("595 x 842 pt (A4)".to_string())
+        );
+        assert_eq!(
+            page_size_summary(&info_pdf(vec![
+                (300.0, 200.0),
+                (792.0, 612.0),
+                (792.0, 612.0),
+                (500.0, 500.0),
+            ])),
+            Some("792 x 612 pt (Letter landscape), 2 other sizes".to_string())
+        );
+        assert_eq!(page_size_summary(&info_pdf(Vec::new())), None);
+    }
+}
@@ -46,7 +46,15 @@ impl InputHistoryService {
         match kind {
             PaletteKind::Command => Some(self.command_snapshot()),
             PaletteKind::Search => Some(self.search_snapshot()),
-            PaletteKind::SearchResults | PaletteKind::History | PaletteKind::Outline => None,
+            PaletteKind::SearchResults
+            | PaletteKind::History
+            | PaletteKind::Outline
+            | PaletteKind::Attachments
+            | PaletteKind::Annotations
+            | PaletteKind::Marks
+            | PaletteKind::HighlightLayers
+            | PaletteKind::Export
+            | PaletteKind::Password => None,
         }
     }
 
@@ -671,6 +671,12 @@ fn command_stops_followup_reprocessing(command: &Command) -> bool {
             | Command::OpenSearchResults
             | Command::OpenHistory
             | Command::OpenOutline
+            | Command::OpenAttachments { .. }
+            | Command::OpenAnnotations
+            | Command::OpenMarks
+            | Command::OpenHighlightLayers
+            | Command::SetMark { name: None }
+            | Command::GotoMark { name: None }
     )
 }
 
@@ -1,22 +1,29 @@
 pub mod app;
 pub mod backend;
 pub(crate) mod cache;
+pub(crate) mod clipboard;
 pub mod config;
 pub mod error;
 pub mod metrics;
 pub mod perf;
 pub mod presenter;
 
+mod annotations;
+mod attachments;
 mod command;
 pub(crate) mod condition;
 mod event;
 mod extension;
 mod highlight;
 mod history;
+mod info;
 mod input;
+mod links;
+mod marks;
 mod outline;
 mod palette;
 mod render;
 mod search;
+mod selection;
 mod ui;
 mod work;
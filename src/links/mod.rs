@@ -0,0 +1,48 @@
+pub mod state;
+
+use crate::app::AppState;
+use crate::event::AppEvent;
+use crate::extension::Extension;
+use crate::input::{AppInputEvent, InputHookResult};
+pub use state::{LinkHintView, LinkHintsCommandPort, LinkHintsState};
+
+pub struct LinkHintsExtension;
+
+impl Extension for LinkHintsExtension {
+    type State = LinkHintsState;
+
+    fn init_state() -> Self::State {
+        LinkHintsState::default()
+    }
+
+    fn handle_input(
+        state: &mut Self::State,
+        event: AppInputEvent,
+        app: &mut AppState,
+    ) -> InputHookResult {
+        let _ = app;
+        match event {
+            AppInputEvent::Key(key) => state.handle_key(key),
+        }
+    }
+
+    fn handle_event(state: &mut Self::State, event: &AppEvent, app: &mut AppState) {
+        let _ = app;
+        if matches!(event, AppEvent::PageChanged { .. }) {
+            state.cancel();
+        }
+    }
+
+    fn on_document_reloaded(
+        state: &mut Self::State,
+        app: &mut AppState,
+        pdf: crate::backend::SharedPdfBackend,
+    ) {
+        let _ = (app, pdf);
+        state.cancel();
+    }
+
+    fn status_bar_segment(state: &Self::State, _app: &AppState) -> Option<String> {
+        state.status_bar_segment()
+    }
+}
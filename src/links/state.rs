@@ -0,0 +1,324 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::app::{AppState, NoticeAction};
+use crate::backend::{PdfBackend, PdfLinkTarget, PdfRect};
+use crate::command::{Command, CommandOutcome};
+use crate::error::{AppError, AppResult};
+use crate::input::InputHookResult;
+
+const HINT_ALPHABET: [char; 9] = ['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'];
+
+#[derive(Debug, Clone, PartialEq)]
+struct LinkHint {
+    label: String,
+    page: usize,
+    rect: PdfRect,
+    target: PdfLinkTarget,
+}
+
+/// A hint label still reachable from the typed prefix, ready to draw over its link rect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkHintView {
+    pub page: usize,
+    pub rect: PdfRect,
+    pub label: String,
+    pub typed_len: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct LinkHintsState {
+    hints: Vec<LinkHint>,
+    typed: String,
+}
+
+pub struct LinkHintsCommandPort<'a> {
+    state: &'a mut LinkHintsState,
+}
+
+impl<'a> LinkHintsCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut LinkHintsState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn show(
+        &mut self,
+        app: &AppState,
+        pdf: &dyn PdfBackend,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.show(app, pdf)
+    }
+
+    pub(crate) fn goto(
+        &mut self,
+        app: &mut AppState,
+        page_count: usize,
+        page: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.goto(app, page_count, page)
+    }
+}
+
+impl LinkHintsState {
+    pub fn is_active(&self) -> bool {
+        !self.hints.is_empty()
+    }
+
+    pub fn show(
+        &mut self,
+        app: &AppState,
+        pdf: &dyn PdfBackend,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let mut links = Vec::new();
+        for page in app
+            .visible_page_slots(pdf.page_count())
+            .existing_pages()
+            .into_iter()
+            .flatten()
+        {
+            links.extend(
+                pdf.extract_links(page)?
+                    .into_iter()
+                    .map(|link| (page, link)),
+            );
+        }
+        if links.is_empty() {
+            self.cancel();
+            return Ok((
+                CommandOutcome::Noop,
+                NoticeAction::warning("no links on visible pages"),
+            ));
+        }
+
+        links.sort_by(|(left_page, left), (right_page, right)| {
+            left_page
+                .cmp(right_page)
+                .then(left.rect.y0.total_cmp(&right.rect.y0))
+                .then(left.rect.x0.total_cmp(&right.rect.x0))
+        });
+        let labels = hint_labels(links.len());
+        self.hints = links
+            .into_iter()
+            .zip(labels)
+            .map(|((page, link), label)| LinkHint {
+                label,
+                page,
+                rect: link.rect,
+                target: link.target,
+            })
+            .collect();
+        self.typed.clear();
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    pub fn goto(
+        &mut self,
+        app: &mut AppState,
+        page_count: usize,
+        page: usize,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        if page >= page_count {
+            return Err(AppError::page_out_of_range(
+                page.saturating_add(1),
+                page_count,
+            ));
+        }
+
+        let target = app.normalize_page_for_layout(page, page_count);
+        app.current_page = target;
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    pub fn cancel(&mut self) {
+        self.hints.clear();
+        self.typed.clear();
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InputHookResult {
+        if !self.is_active() {
+            return InputHookResult::Ignored;
+        }
+        if key.kind == KeyEventKind::Release {
+            return InputHookResult::Consumed;
+        }
+
+        let plain = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Backspace => {
+                self.typed.pop();
+                InputHookResult::Consumed
+            }
+            KeyCode::Char(ch) if plain && HINT_ALPHABET.contains(&ch.to_ascii_lowercase()) => {
+                self.typed.push(ch.to_ascii_lowercase());
+                self.narrow()
+            }
+            _ => {
+                self.cancel();
+                InputHookResult::Consumed
+            }
+        }
+    }
+
+    pub fn hint_views(&self, visible_pages: [Option<usize>; 2]) -> Vec<LinkHintView> {
+        self.hints
+            .iter()
+            .filter(|hint| visible_pages.contains(&Some(hint.page)))
+            .filter(|hint| hint.label.starts_with(&self.typed))
+            .map(|hint| LinkHintView {
+                page: hint.page,
+                rect: hint.rect,
+                label: hint.label.clone(),
+                typed_len: self.typed.len(),
+            })
+            .collect()
+    }
+
+    pub fn status_bar_segment(&self) -> Option<String> {
+        self.is_active()
+            .then(|| format!("LINK {}", self.typed).trim_end().to_string())
+    }
+
+    fn narrow(&mut self) -> InputHookResult {
+        let Some(chosen) = self.hints.iter().position(|hint| hint.label == self.typed) else {
+            if !self
+                .hints
+                .iter()
+                .any(|hint| hint.label.starts_with(&self.typed))
+            {
+                self.cancel();
+            }
+            return InputHookResult::Consumed;
+        };
+
+        let hint = self.hints.swap_remove(chosen);
+        self.cancel();
+        InputHookResult::EmitCommand(match hint.target {
+            PdfLinkTarget::Page(page) => Command::LinkGoto { page },
+            PdfLinkTarget::Uri(uri) => Command::OpenLinkUri { uri },
+        })
+    }
+}
+
+/// Assigns equal-length labels so no label is a prefix of another.
+fn hint_labels(count: usize) -> Vec<String> {
+    let base = HINT_ALPHABET.len();
+    let mut width = 1;
+    while base.pow(width) < count {
+        width += 1;
+    }
+
+    (0..count)
+        .map(|index| {
+            let mut label = vec![HINT_ALPHABET[0]; width as usize];
+            let mut rest = index;
+            for slot in label.iter_mut().rev() {
+                *slot = HINT_ALPHABET[rest % base];
+                rest /= base;
+            }
+            label.into_iter().collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::app::AppState;
+    use crate::backend::test_support::TextStubPdf;
+    use crate::backend::{PdfLink, PdfLinkTarget, PdfRect};
+    use crate::command::{Command, CommandOutcome};
+    use crate::input::InputHookResult;
+
+    use super::{LinkHintsState, hint_labels};
+
+    /// Two 100 pt square pages with `links` on the first.
+    fn link_pdf(links: Vec<PdfLink>) -> TextStubPdf {
+        TextStubPdf::blank(2)
+            .with_path("links.pdf")
+            .with_page_sizes(&[(100.0, 100.0), (100.0, 100.0)])
+            .with_links(0, links)
+    }
+
+    fn rect(y0: f32) -> PdfRect {
+        PdfRect {
+            x0: 10.0,
+            y0,
+            x1: 40.0,
+            y1: y0 + 10.0,
+        }
+    }
+
+    fn key(ch: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn hint_labels_share_one_width() {
+        assert_eq!(hint_labels(3), vec!["a", "s", "d"]);
+        let labels = hint_labels(10);
+        assert_eq!(labels.len(), 10);
+        assert!(labels.iter().all(|label| label.len() == 2));
+        assert_eq!(labels[0], "aa");
+        assert_eq!(labels[9], "sa");
+    }
+
+    #[test]
+    fn typing_a_label_emits_the_link_command_in_reading_order() {
+        let pdf = link_pdf(vec![
+            PdfLink {
+                rect: rect(60.0),
+                target: PdfLinkTarget::Uri("https://example.com".to_string()),
+            },
+            PdfLink {
+                rect: rect(20.0),
+                target: PdfLinkTarget::Page(1),
+            },
+        ]);
+        let app = AppState::default();
+        let mut state = LinkHintsState::default();
+
+        let (outcome, _) = state.show(&app, &pdf).expect("show should succeed");
+        assert_eq!(outcome, CommandOutcome::Applied);
+        let views = state.hint_views([Some(0), None]);
+        assert_eq!(views.len(), 2);
+        assert_eq!(views[0].label, "a");
+        assert_eq!(views[0].rect, rect(20.0));
+
+        assert_eq!(
+            state.handle_key(key('s')),
+            InputHookResult::EmitCommand(Command::OpenLinkUri {
+                uri: "https://example.com".to_string()
+            })
+        );
+        assert!(!state.is_active());
+    }
+
+    #[test]
+    fn unknown_keys_cancel_hints_and_empty_pages_warn() {
+        let pdf = link_pdf(vec![PdfLink {
+            rect: rect(20.0),
+            target: PdfLinkTarget::Page(1),
+        }]);
+        let mut state = LinkHintsState::default();
+        state
+            .show(&AppState::default(), &pdf)
+            .expect("show should succeed");
+
+        assert_eq!(
+            state.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            InputHookResult::Consumed
+        );
+        assert!(!state.is_active());
+        assert_eq!(state.handle_key(key('a')), InputHookResult::Ignored);
+
+        let app = AppState {
+            current_page: 1,
+            ..AppState::default()
+        };
+        let (outcome, _) = state.show(&app, &pdf).expect("show should succeed");
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert!(!state.is_active());
+    }
+}
@@ -3,8 +3,6 @@ mod cli;
 #[cfg(not(test))]
 use pvf::app::App;
 #[cfg(not(test))]
-use pvf::backend::open_default_backend;
-#[cfg(not(test))]
 use pvf::error::AppResult;
 #[cfg(not(test))]
 use pvf::presenter::PresenterKind;
@@ -12,7 +10,28 @@ use pvf::presenter::PresenterKind;
 #[cfg(not(test))]
 #[tokio::main(flavor = "multi_thread")]
 async fn main() {
-    if let Err(err) = run().await {
+    let options = match cli::parse() {
+        cli::CliAction::View(options) => *options,
+        cli::CliAction::Cache(command) => return exit_on_error(cli::run_cache_command(command)),
+        cli::CliAction::Document(command) => {
+            let result = cli::run_document_command(
+                command,
+                &mut std::io::stdout().lock(),
+                |path, password| pvf::backend::open_default_backend_with_password(path, password),
+            );
+            if let Err((failure, err)) = result {
+                eprintln!("{err}");
+                std::process::exit(failure.exit_code());
+            }
+            return;
+        }
+    };
+    exit_on_error(run(options).await);
+}
+
+#[cfg(not(test))]
+fn exit_on_error(result: AppResult<()>) {
+    if let Err(err) = result {
         eprintln!("{err}");
         std::process::exit(1);
     }
@@ -22,11 +41,14 @@ async fn main() {
 fn main() {}
 
 #[cfg(not(test))]
-async fn run() -> AppResult<()> {
-    let options = cli::parse();
-
-    let pdf = open_default_backend(&options.pdf_path)?;
+async fn run(options: cli::CliOptions) -> AppResult<()> {
+    let password = options.password()?;
+    let explicit_view = options.options.view.clone();
     let app_options = options.config.load_options()?.merge(options.options);
     let mut app = App::new_with_options(PresenterKind::RatatuiImage, app_options)?;
-    app.run(pdf).await
+    app.enable_disk_cache();
+    app.enable_persistent_marks();
+    app.remember_reading_positions(&explicit_view);
+    app.open_and_run(&options.pdf_path, password.as_deref())
+        .await
 }
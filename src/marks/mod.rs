@@ -0,0 +1,35 @@
+pub mod palette;
+pub mod state;
+pub mod store;
+
+use crate::app::AppState;
+use crate::extension::Extension;
+use crate::input::{AppInputEvent, InputHookResult};
+pub use palette::MarksPaletteProvider;
+pub use state::{MarksCommandPort, MarksState, MarksUiSnapshot};
+pub use store::{MarkStore, marks_path};
+
+pub struct MarksExtension;
+
+impl Extension for MarksExtension {
+    type State = MarksState;
+
+    fn init_state() -> Self::State {
+        MarksState::default()
+    }
+
+    fn handle_input(
+        state: &mut Self::State,
+        event: AppInputEvent,
+        app: &mut AppState,
+    ) -> InputHookResult {
+        let _ = app;
+        match event {
+            AppInputEvent::Key(key) => state.handle_key(key),
+        }
+    }
+
+    fn status_bar_segment(state: &Self::State, _app: &AppState) -> Option<String> {
+        state.status_bar_segment()
+    }
+}
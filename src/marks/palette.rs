@@ -0,0 +1,177 @@
+use crate::backend::PageLabels;
+use crate::command::Command;
+use crate::error::AppResult;
+use crate::input::shortcut::format_shortcut_key;
+use crate::palette::{
+    PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PalettePayload,
+    PalettePostAction, PaletteProvider, PaletteSearchText, PaletteSubmitEffect, PaletteTextPart,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkPaletteEntry {
+    pub name: char,
+    pub page: usize,
+    pub zoom_percent: u32,
+    /// File name of the document a global mark was set in, when it is not the open one.
+    pub file: Option<String>,
+}
+
+pub struct MarksPaletteProvider;
+
+impl PaletteProvider for MarksPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::Marks
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Marks".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::FilterCandidates
+    }
+
+    fn list(&self, ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok(ctx
+            .extensions
+            .marks
+            .entries
+            .iter()
+            .map(|entry| mark_candidate(entry, &ctx.app.page_labels))
+            .collect())
+    }
+
+    fn on_submit(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        let Some(name) = selected.and_then(|candidate| decode_payload(&candidate.payload)) else {
+            return Ok(PaletteSubmitEffect::Close);
+        };
+
+        Ok(PaletteSubmitEffect::Dispatch {
+            command: Command::GotoMark { name: Some(name) },
+            history_record: None,
+            next: PalettePostAction::Close,
+        })
+    }
+
+    fn assistive_text(
+        &self,
+        ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        if ctx.extensions.marks.entries.is_empty() {
+            return Some("No marks set".to_string());
+        }
+
+        let enter = format_shortcut_key(crate::input::shortcut::ShortcutKey::key(
+            crossterm::event::KeyCode::Enter,
+        ));
+        Some(format!("{enter} jump to mark"))
+    }
+}
+
+fn mark_candidate(entry: &MarkPaletteEntry, labels: &PageLabels) -> PaletteCandidate {
+    let page = match labels.distinct(entry.page) {
+        Some(label) => format!("p.{label}"),
+        None => format!("p.{}", entry.page + 1),
+    };
+
+    let mut details = vec![format!("{}%", entry.zoom_percent)];
+    let mut search_texts = vec![
+        PaletteSearchText::new(entry.name.to_string()),
+        PaletteSearchText::new(page.clone()),
+        PaletteSearchText::new(format!("page {}", entry.page + 1)),
+    ];
+    if let Some(file) = &entry.file {
+        details.push(file.clone());
+        search_texts.push(PaletteSearchText::new(file.clone()));
+    }
+
+    PaletteCandidate {
+        id: format!("mark-{}", entry.name),
+        left: vec![
+            PaletteTextPart::primary(entry.name.to_string()),
+            PaletteTextPart::primary("  "),
+            PaletteTextPart::primary(page),
+        ],
+        right: vec![PaletteTextPart::secondary(details.join(" · "))],
+        search_texts,
+        payload: PalettePayload::Opaque(entry.name.to_string()),
+    }
+}
+
+fn decode_payload(payload: &PalettePayload) -> Option<char> {
+    let PalettePayload::Opaque(payload) = payload else {
+        return None;
+    };
+    let mut chars = payload.chars();
+    let name = chars.next()?;
+    chars.next().is_none().then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::PageLabels;
+    use crate::command::Command;
+    use crate::extension::ExtensionUiSnapshot;
+    use crate::palette::{
+        PaletteAppSnapshot, PaletteContext, PaletteKind, PaletteProvider, PaletteSubmitEffect,
+    };
+
+    use super::{MarkPaletteEntry, MarksPaletteProvider};
+
+    #[test]
+    fn list_shows_page_zoom_and_file_and_submit_jumps_to_the_mark() {
+        let provider = MarksPaletteProvider;
+        let extensions = ExtensionUiSnapshot {
+            marks: crate::marks::MarksUiSnapshot {
+                entries: vec![
+                    MarkPaletteEntry {
+                        name: 'd',
+                        page: 1,
+                        zoom_percent: 100,
+                        file: None,
+                    },
+                    MarkPaletteEntry {
+                        name: 'R',
+                        page: 0,
+                        zoom_percent: 150,
+                        file: Some("refs.pdf".to_string()),
+                    },
+                ]
+                .into(),
+            },
+            ..ExtensionUiSnapshot::default()
+        };
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot {
+                page_labels: PageLabels::new(["i", "ii"].map(str::to_string).to_vec()),
+                ..PaletteAppSnapshot::default()
+            },
+            extensions: &extensions,
+            kind: PaletteKind::Marks,
+            input: "",
+            open_payload: None,
+        };
+
+        let items = provider.list(&ctx).expect("marks list should build");
+        assert_eq!(items[0].left[0].text, "d");
+        assert_eq!(items[0].left[2].text, "p.ii");
+        assert_eq!(items[0].right[0].text, "100%");
+        assert_eq!(items[1].right[0].text, "150% · refs.pdf");
+
+        let effect = provider
+            .on_submit(&ctx, Some(&items[1]))
+            .expect("submit should succeed");
+        assert!(matches!(
+            effect,
+            PaletteSubmitEffect::Dispatch {
+                command: Command::GotoMark { name: Some('R') },
+                ..
+            }
+        ));
+    }
+}
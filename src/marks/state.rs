@@ -0,0 +1,402 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::app::scale::{ZOOM_MAX, ZOOM_MIN};
+use crate::app::{AppState, NoticeAction, PaletteRequest};
+use crate::backend::PdfBackend;
+use crate::command::{Command, CommandOutcome};
+use crate::error::{AppError, AppResult};
+use crate::input::InputHookResult;
+use crate::palette::PaletteKind;
+
+use super::palette::MarkPaletteEntry;
+use super::store::{Mark, MarkStore, document_key, is_mark_name, upsert_mark};
+
+/// Which command the next letter completes after a bare `set-mark` or `goto-mark`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingMark {
+    Set,
+    Goto,
+}
+
+#[derive(Debug, Default)]
+pub struct MarksState {
+    marks: Vec<Mark>,
+    store: Option<MarkStore>,
+    /// Canonical path of the open document, which owns the lowercase marks in view.
+    document: PathBuf,
+    entries: Arc<[MarkPaletteEntry]>,
+    pending: Option<PendingMark>,
+}
+
+pub struct MarksCommandPort<'a> {
+    state: &'a mut MarksState,
+}
+
+impl<'a> MarksCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut MarksState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn set(
+        &mut self,
+        app: &AppState,
+        pdf: &dyn PdfBackend,
+        name: Option<char>,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.set(app, pdf, name)
+    }
+
+    pub(crate) fn goto(
+        &mut self,
+        app: &mut AppState,
+        pdf: &dyn PdfBackend,
+        name: Option<char>,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.goto(app, pdf, name)
+    }
+
+    pub(crate) fn open_palette(&mut self, pdf: &dyn PdfBackend) -> PaletteRequest {
+        self.state.open_palette(pdf)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarksUiSnapshot {
+    pub entries: Arc<[MarkPaletteEntry]>,
+}
+
+impl MarksState {
+    /// Loads the saved marks and keeps every later change in `store`.
+    pub fn attach_store(&mut self, store: MarkStore) {
+        self.marks = store.load();
+        self.store = Some(store);
+        self.rebuild_entries();
+    }
+
+    pub fn open_document(&mut self, path: &Path) {
+        self.document = document_key(path);
+        self.rebuild_entries();
+    }
+
+    /// Stores the current page, zoom, and pan under `name`, or waits for the letter when no
+    /// name is given.
+    pub fn set(
+        &mut self,
+        app: &AppState,
+        pdf: &dyn PdfBackend,
+        name: Option<char>,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let Some(name) = name else {
+            self.pending = Some(PendingMark::Set);
+            return Ok((CommandOutcome::Applied, NoticeAction::Clear));
+        };
+        validate_name(name)?;
+        self.sync_document(pdf);
+
+        let mark = Mark {
+            name,
+            path: self.document.clone(),
+            page: app.current_page,
+            zoom: app.zoom,
+            pan_x: app.pan_x,
+            pan_y: app.pan_y,
+        };
+        let notice = match &self.store {
+            Some(store) => match store.save(mark.clone()) {
+                Ok(marks) => {
+                    self.marks = marks;
+                    NoticeAction::info(format!("set mark {name}"))
+                }
+                Err(err) => {
+                    upsert_mark(&mut self.marks, mark);
+                    NoticeAction::warning(format!("set mark {name} for this session only: {err}"))
+                }
+            },
+            None => {
+                upsert_mark(&mut self.marks, mark);
+                NoticeAction::info(format!("set mark {name}"))
+            }
+        };
+        self.rebuild_entries();
+        Ok((CommandOutcome::Applied, notice))
+    }
+
+    /// Restores the page, zoom, and pan stored under `name`, or waits for the letter when no
+    /// name is given.
+    pub fn goto(
+        &mut self,
+        app: &mut AppState,
+        pdf: &dyn PdfBackend,
+        name: Option<char>,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let Some(name) = name else {
+            self.pending = Some(PendingMark::Goto);
+            return Ok((CommandOutcome::Applied, NoticeAction::Clear));
+        };
+        validate_name(name)?;
+        self.sync_document(pdf);
+        self.reload();
+
+        let Some(mark) = self.find(name) else {
+            return Ok((
+                CommandOutcome::Noop,
+                NoticeAction::warning(format!("mark {name} is not set")),
+            ));
+        };
+        if mark.path != self.document {
+            // The loop opens the file first; see `document_of`.
+            return Ok((
+                CommandOutcome::Noop,
+                NoticeAction::warning(format!("mark {name} is in {}", mark.path.display())),
+            ));
+        }
+        let page_count = pdf.page_count();
+        if mark.page >= page_count {
+            return Err(AppError::page_out_of_range(
+                mark.page.saturating_add(1),
+                page_count,
+            ));
+        }
+
+        app.current_page = app.normalize_page_for_layout(mark.page, page_count);
+        if mark.zoom.is_finite() {
+            app.zoom = mark.zoom.clamp(ZOOM_MIN, ZOOM_MAX);
+        }
+        app.pan_x = mark.pan_x;
+        app.pan_y = mark.pan_y;
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    /// File of the global mark `name` when it lies outside the open document, which has to be
+    /// opened before the mark can be restored.
+    pub fn document_of(&mut self, pdf: &dyn PdfBackend, name: char) -> Option<PathBuf> {
+        self.sync_document(pdf);
+        self.reload();
+        self.find(name)
+            .filter(|mark| mark.path != self.document)
+            .map(|mark| mark.path.clone())
+    }
+
+    pub fn open_palette(&mut self, pdf: &dyn PdfBackend) -> PaletteRequest {
+        self.sync_document(pdf);
+        self.reload();
+        PaletteRequest::Open {
+            kind: PaletteKind::Marks,
+            payload: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> InputHookResult {
+        let Some(pending) = self.pending else {
+            return InputHookResult::Ignored;
+        };
+        if key.kind == KeyEventKind::Release {
+            return InputHookResult::Consumed;
+        }
+
+        self.pending = None;
+        let plain = !key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char(name) if plain && is_mark_name(name) => {
+                InputHookResult::EmitCommand(match pending {
+                    PendingMark::Set => Command::SetMark { name: Some(name) },
+                    PendingMark::Goto => Command::GotoMark { name: Some(name) },
+                })
+            }
+            // Any other key, Esc included, just abandons the mark.
+            _ => InputHookResult::Consumed,
+        }
+    }
+
+    pub fn status_bar_segment(&self) -> Option<String> {
+        self.pending.map(|pending| match pending {
+            PendingMark::Set => "SET MARK".to_string(),
+            PendingMark::Goto => "GOTO MARK".to_string(),
+        })
+    }
+
+    pub fn ui_snapshot(&self) -> MarksUiSnapshot {
+        MarksUiSnapshot {
+            entries: Arc::clone(&self.entries),
+        }
+    }
+
+    fn find(&self, name: char) -> Option<&Mark> {
+        self.marks
+            .iter()
+            .find(|mark| mark.name == name && (mark.is_global() || mark.path == self.document))
+    }
+
+    fn sync_document(&mut self, pdf: &dyn PdfBackend) {
+        if self.document.as_os_str().is_empty() {
+            self.open_document(pdf.path());
+        }
+    }
+
+    /// Picks up marks other pvf processes saved since the last look.
+    fn reload(&mut self) {
+        if let Some(store) = &self.store {
+            self.marks = store.load();
+            self.rebuild_entries();
+        }
+    }
+
+    fn rebuild_entries(&mut self) {
+        let mut entries = self
+            .marks
+            .iter()
+            .filter(|mark| mark.is_global() || mark.path == self.document)
+            .map(|mark| MarkPaletteEntry {
+                name: mark.name,
+                page: mark.page,
+                zoom_percent: (mark.zoom * 100.0).round() as u32,
+                file: (mark.path != self.document).then(|| {
+                    mark.path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| mark.path.display().to_string())
+                }),
+            })
+            .collect::<Vec<_>>();
+        // Local marks first, each group in letter order.
+        entries.sort_by_key(|entry| (entry.name.is_ascii_uppercase(), entry.name));
+        self.entries = entries.into();
+    }
+}
+
+fn validate_name(name: char) -> AppResult<()> {
+    if is_mark_name(name) {
+        return Ok(());
+    }
+    Err(AppError::invalid_argument(
+        "mark name must be a letter a-z or A-Z",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::app::{AppState, NoticeAction};
+    use crate::backend::test_support::TextStubPdf;
+    use crate::command::{Command, CommandOutcome};
+    use crate::input::InputHookResult;
+
+    use super::MarksState;
+
+    fn stub_pdf(path: &str) -> TextStubPdf {
+        TextStubPdf::blank(20).with_path(path)
+    }
+
+    fn key(ch: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn local_marks_restore_page_zoom_and_pan_of_their_document_only() {
+        let mut marks = MarksState::default();
+        let pdf = stub_pdf("/docs/spec.pdf");
+        let other = stub_pdf("/docs/notes.pdf");
+        let mut app = AppState {
+            current_page: 7,
+            zoom: 2.0,
+            pan_x: 40,
+            pan_y: -12,
+            ..AppState::default()
+        };
+
+        marks
+            .set(&app, &pdf, Some('d'))
+            .expect("set-mark should succeed");
+        app.current_page = 1;
+        app.zoom = 1.0;
+        app.pan_x = 0;
+        app.pan_y = 0;
+
+        let (outcome, _) = marks
+            .goto(&mut app, &pdf, Some('d'))
+            .expect("goto-mark should succeed");
+        assert_eq!(outcome, CommandOutcome::Applied);
+        assert_eq!(
+            (app.current_page, app.zoom, app.pan_x, app.pan_y),
+            (7, 2.0, 40, -12)
+        );
+
+        let mut elsewhere = MarksState {
+            marks: marks.marks.clone(),
+            ..MarksState::default()
+        };
+        let (outcome, notice) = elsewhere
+            .goto(&mut app, &other, Some('d'))
+            .expect("goto-mark should succeed");
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert_eq!(notice, NoticeAction::warning("mark d is not set"));
+    }
+
+    #[test]
+    fn global_marks_are_listed_everywhere_but_only_jump_within_their_document() {
+        let mut marks = MarksState::default();
+        let pdf = stub_pdf("/docs/spec.pdf");
+        let mut app = AppState {
+            current_page: 3,
+            ..AppState::default()
+        };
+        marks
+            .set(&app, &pdf, Some('T'))
+            .expect("set-mark should succeed");
+        marks
+            .set(&app, &pdf, Some('t'))
+            .expect("set-mark should succeed");
+
+        marks.open_document(Path::new("/docs/notes.pdf"));
+        let entries = marks.ui_snapshot().entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, 'T');
+        assert_eq!(entries[0].file.as_deref(), Some("spec.pdf"));
+
+        let (outcome, notice) = marks
+            .goto(&mut app, &stub_pdf("/docs/notes.pdf"), Some('T'))
+            .expect("goto-mark should succeed");
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert_eq!(notice, NoticeAction::warning("mark T is in /docs/spec.pdf"));
+    }
+
+    #[test]
+    fn bare_commands_wait_for_the_mark_letter() {
+        let mut marks = MarksState::default();
+        let pdf = stub_pdf("/docs/spec.pdf");
+        let mut app = AppState::default();
+
+        assert_eq!(marks.handle_key(key('a')), InputHookResult::Ignored);
+        marks.set(&app, &pdf, None).expect("set-mark should arm");
+        assert_eq!(marks.status_bar_segment().as_deref(), Some("SET MARK"));
+        assert_eq!(
+            marks.handle_key(key('a')),
+            InputHookResult::EmitCommand(Command::SetMark { name: Some('a') })
+        );
+
+        marks
+            .goto(&mut app, &pdf, None)
+            .expect("goto-mark should arm");
+        assert_eq!(
+            marks.handle_key(key('Q')),
+            InputHookResult::EmitCommand(Command::GotoMark { name: Some('Q') })
+        );
+
+        marks
+            .goto(&mut app, &pdf, None)
+            .expect("goto-mark should arm");
+        assert_eq!(
+            marks.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            InputHookResult::Consumed
+        );
+        assert_eq!(marks.status_bar_segment(), None);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::default_state_dir;
+use crate::error::{AppError, AppResult};
+
+/// Name of the marks file below the pvf state directory.
+pub const MARKS_FILE: &str = "marks.json";
+
+/// A named view position. Lowercase marks belong to the document they were set in; uppercase
+/// marks are global, one per letter, and remember the document along with the position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mark {
+    pub name: char,
+    pub path: PathBuf,
+    pub page: usize,
+    pub zoom: f32,
+    pub pan_x: i32,
+    pub pan_y: i32,
+}
+
+impl Mark {
+    pub fn is_global(&self) -> bool {
+        self.name.is_ascii_uppercase()
+    }
+
+    /// Whether setting `other` replaces this mark.
+    fn shares_slot(&self, other: &Mark) -> bool {
+        self.name == other.name && (self.is_global() || self.path == other.path)
+    }
+}
+
+pub fn is_mark_name(name: char) -> bool {
+    name.is_ascii_alphabetic()
+}
+
+/// Canonical form of a document path, so marks survive opening a file through another path.
+pub fn document_key(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Marks of all documents, kept in one small JSON file.
+///
+/// Every write rereads the file first, so pvf processes setting marks in different documents
+/// keep each other's marks.
+#[derive(Debug, Clone)]
+pub struct MarkStore {
+    file: PathBuf,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SavedMarks {
+    marks: Vec<Mark>,
+}
+
+impl MarkStore {
+    pub fn new(file: impl Into<PathBuf>) -> Self {
+        Self { file: file.into() }
+    }
+
+    /// Saved marks; a missing or unreadable file counts as empty.
+    pub fn load(&self) -> Vec<Mark> {
+        fs::read(&self.file)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<SavedMarks>(&bytes).ok())
+            .map(|saved| saved.marks)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|mark| is_mark_name(mark.name))
+            .collect()
+    }
+
+    /// Stores `mark`, replacing the one in its slot, and returns all saved marks.
+    pub fn save(&self, mark: Mark) -> AppResult<Vec<Mark>> {
+        let mut marks = self.load();
+        // Local marks of deleted documents can never be reached again.
+        marks.retain(|saved| {
+            !saved.shares_slot(&mark) && (saved.is_global() || saved.path.exists())
+        });
+        marks.push(mark);
+        self.write(&SavedMarks { marks })
+    }
+
+    fn write(&self, saved: &SavedMarks) -> AppResult<Vec<Mark>> {
+        let context = || format!("writing marks {}", self.file.display());
+        let encoded = serde_json::to_vec(saved)
+            .map_err(|source| AppError::io_with_context(io::Error::other(source), context()))?;
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|source| AppError::io_with_context(source, context()))?;
+        }
+        let partial = self.file.with_extension("partial");
+        let written = File::create(&partial)
+            .and_then(|mut file| file.write_all(&encoded))
+            .and_then(|()| fs::rename(&partial, &self.file));
+        if let Err(source) = written {
+            let _ = fs::remove_file(&partial);
+            return Err(AppError::io_with_context(source, context()));
+        }
+        Ok(saved.marks.clone())
+    }
+}
+
+/// File holding the saved marks.
+pub fn marks_path() -> Option<PathBuf> {
+    default_state_dir().map(|dir| dir.join(MARKS_FILE))
+}
+
+/// Replaces the mark in `mark`'s slot, or adds it.
+pub(super) fn upsert_mark(marks: &mut Vec<Mark>, mark: Mark) {
+    marks.retain(|existing| !existing.shares_slot(&mark));
+    marks.push(mark);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{Mark, MarkStore};
+    use crate::backend::test_support::unique_temp_path;
+
+    fn mark(name: char, path: &str, page: usize) -> Mark {
+        Mark {
+            name,
+            path: PathBuf::from(path),
+            page,
+            zoom: 1.25,
+            pan_x: 3,
+            pan_y: -7,
+        }
+    }
+
+    #[test]
+    fn saving_replaces_local_marks_per_document_and_global_marks_everywhere() {
+        let dir = unique_temp_path("marks_store");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let document = dir.join("a.pdf");
+        let other = dir.join("b.pdf");
+        fs::write(&document, b"").expect("document should be written");
+        fs::write(&other, b"").expect("document should be written");
+        let document = document.to_str().expect("utf-8 path");
+        let other = other.to_str().expect("utf-8 path");
+        let store = MarkStore::new(dir.join("state").join("marks.json"));
+
+        store
+            .save(mark('a', document, 1))
+            .expect("save should work");
+        store.save(mark('a', other, 2)).expect("save should work");
+        store
+            .save(mark('A', document, 3))
+            .expect("save should work");
+        store
+            .save(mark('a', document, 4))
+            .expect("save should work");
+        let marks = store.save(mark('A', other, 5)).expect("save should work");
+
+        assert_eq!(marks, store.load());
+        assert_eq!(
+            marks,
+            vec![
+                mark('a', other, 2),
+                mark('a', document, 4),
+                mark('A', other, 5),
+            ]
+        );
+
+        fs::remove_dir_all(&dir).expect("temp dir should be removed");
+    }
+
+    #[test]
+    fn local_marks_of_missing_documents_are_dropped_on_save() {
+        let dir = unique_temp_path("marks_store_gone");
+        fs::create_dir_all(&dir).expect("temp dir should be created");
+        let document = dir.join("kept.pdf");
+        fs::write(&document, b"").expect("document should be written");
+        let document = document.to_str().expect("utf-8 path");
+        let store = MarkStore::new(dir.join("marks.json"));
+
+        store
+            .save(mark('b', "/nonexistent/gone.pdf", 0))
+            .expect("save should work");
+        store
+            .save(mark('G', "/nonexistent/gone.pdf", 0))
+            .expect("save should work");
+        let marks = store
+            .save(mark('c', document, 1))
+            .expect("save should work");
+
+        assert_eq!(
+            marks,
+            vec![
+                mark('G', "/nonexistent/gone.pdf", 0),
+                mark('c', document, 1)
+            ]
+        );
+
+        fs::remove_dir_all(&dir).expect("temp dir should be removed");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::backend::PageLabels;
 use crate::command::Command;
 use crate::error::AppResult;
 use crate::input::shortcut::format_shortcut_key;
@@ -36,13 +37,14 @@ impl PaletteProvider for OutlinePaletteProvider {
 
     fn list(&self, ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
         let query = ctx.input.trim().to_lowercase();
+        let labels = &ctx.app.page_labels;
 
         let mut text_matches = Vec::new();
         let mut page_text_matches = Vec::new();
         let mut unfiltered = Vec::new();
 
         for (index, entry) in ctx.extensions.outline.entries.iter().enumerate() {
-            let candidate = outline_candidate(index, entry);
+            let candidate = outline_candidate(index, entry, labels);
             if query.is_empty() {
                 unfiltered.push(candidate);
                 continue;
@@ -52,7 +54,8 @@ impl PaletteProvider for OutlinePaletteProvider {
             // That makes inputs like `p.1` behave like normal text and match `p.10` / `p.123`.
             if text_contains(&entry.title, &query) {
                 text_matches.push((entry.page, index, candidate));
-            } else if text_contains(&format_outline_page_detail(entry.page), &query)
+            } else if text_contains(&outline_page_text(entry.page, labels), &query)
+                || text_contains(&format_outline_page_detail(entry.page), &query)
                 || text_contains(&format!("page {}", entry.page + 1), &query)
                 || text_contains(&(entry.page + 1).to_string(), &query)
             {
@@ -116,7 +119,21 @@ fn encode_payload(page: usize, title: &str) -> String {
     format!("{page}{PAYLOAD_SEP}{title}")
 }
 
-fn outline_candidate(index: usize, entry: &OutlinePaletteEntry) -> PaletteCandidate {
+fn outline_candidate(
+    index: usize,
+    entry: &OutlinePaletteEntry,
+    labels: &PageLabels,
+) -> PaletteCandidate {
+    let mut search_texts = vec![
+        PaletteSearchText::new(entry.title.clone()),
+        PaletteSearchText::new(format!("page {}", entry.page + 1)),
+        PaletteSearchText::new(format_outline_page_detail(entry.page)),
+        PaletteSearchText::new((entry.page + 1).to_string()),
+    ];
+    if let Some(label) = labels.distinct(entry.page) {
+        search_texts.push(PaletteSearchText::new(format!("p.{label}")));
+    }
+
     PaletteCandidate {
         id: format!("outline-{index}"),
         left: vec![PaletteTextPart::primary(format!(
@@ -124,15 +141,10 @@ fn outline_candidate(index: usize, entry: &OutlinePaletteEntry) -> PaletteCandid
             "  ".repeat(entry.depth),
             entry.title
         ))],
-        right: vec![PaletteTextPart::secondary(format_outline_page_detail(
-            entry.page,
+        right: vec![PaletteTextPart::secondary(outline_page_text(
+            entry.page, labels,
         ))],
-        search_texts: vec![
-            PaletteSearchText::new(entry.title.clone()),
-            PaletteSearchText::new(format!("page {}", entry.page + 1)),
-            PaletteSearchText::new(format_outline_page_detail(entry.page)),
-            PaletteSearchText::new((entry.page + 1).to_string()),
-        ],
+        search_texts,
         payload: PalettePayload::Opaque(encode_payload(entry.page, &entry.title)),
     }
 }
@@ -141,6 +153,14 @@ fn format_outline_page_detail(page: usize) -> String {
     format!("p.{}", page + 1)
 }
 
+/// Shows the page label in place of the page number when the document defines a distinct one.
+fn outline_page_text(page: usize, labels: &PageLabels) -> String {
+    match labels.distinct(page) {
+        Some(label) => format!("p.{label}"),
+        None => format_outline_page_detail(page),
+    }
+}
+
 fn text_contains(text: &str, query: &str) -> bool {
     text.to_lowercase().contains(query)
 }
@@ -156,6 +176,7 @@ fn decode_payload(payload: &PalettePayload) -> Option<(usize, String)> {
 #[cfg(test)]
 mod tests {
     use crate::{
+        backend::PageLabels,
         extension::ExtensionUiSnapshot,
         palette::{
             PaletteAppSnapshot, PaletteContext, PaletteKind, PalettePayload, PaletteProvider,
@@ -303,6 +324,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_shows_page_labels_and_matches_them_as_page_text() {
+        let provider = OutlinePaletteProvider;
+        let entries = vec![
+            OutlinePaletteEntry {
+                title: "Preface".to_string(),
+                page: 1,
+                depth: 0,
+            },
+            OutlinePaletteEntry {
+                title: "Introduction".to_string(),
+                page: 2,
+                depth: 0,
+            },
+        ];
+        let extensions = ExtensionUiSnapshot {
+            outline: crate::outline::OutlineUiSnapshot {
+                entries: entries.into(),
+            },
+            ..ExtensionUiSnapshot::default()
+        };
+        let labels = ["i", "ii", "3"].map(str::to_string);
+        let mut ctx = PaletteContext {
+            app: PaletteAppSnapshot {
+                page_labels: PageLabels::new(labels.to_vec()),
+                ..PaletteAppSnapshot::default()
+            },
+            extensions: &extensions,
+            kind: PaletteKind::Outline,
+            input: "",
+            open_payload: None,
+        };
+
+        let items = provider.list(&ctx).expect("outline list should build");
+        assert_eq!(items[0].right[0].text, "p.ii");
+        assert_eq!(items[1].right[0].text, "p.3");
+
+        ctx.input = "p.ii";
+        let items = provider.list(&ctx).expect("outline list should build");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].left[0].text.trim(), "Preface");
+    }
+
     #[test]
     fn list_matches_unicode_titles_case_insensitively() {
         let provider = OutlinePaletteProvider;
@@ -138,7 +138,9 @@ mod tests {
     use std::path::{Path, PathBuf};
     use std::sync::Arc;
 
-    use crate::backend::{OutlineNode, PdfBackend, RgbaFrame, SharedPdfBackend, TextPage};
+    use crate::backend::{
+        OutlineNode, PdfBackend, RgbaFrame, SharedPdfBackend, TextLayout, TextPage,
+    };
 
     use super::OutlineState;
 
@@ -178,6 +180,7 @@ mod tests {
                 height_pt: 1.0,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
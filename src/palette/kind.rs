@@ -5,6 +5,12 @@ pub enum PaletteKind {
     SearchResults,
     History,
     Outline,
+    Attachments,
+    Annotations,
+    Marks,
+    HighlightLayers,
+    Export,
+    Password,
 }
 
 impl PaletteKind {
@@ -15,6 +21,12 @@ impl PaletteKind {
             Self::SearchResults => "search-results",
             Self::History => "history",
             Self::Outline => "outline",
+            Self::Attachments => "attachments",
+            Self::Annotations => "annotations",
+            Self::Marks => "marks",
+            Self::HighlightLayers => "highlight-layers",
+            Self::Export => "export",
+            Self::Password => "password",
         }
     }
 
@@ -25,6 +37,12 @@ impl PaletteKind {
             "search-results" => Some(Self::SearchResults),
             "history" => Some(Self::History),
             "outline" => Some(Self::Outline),
+            "attachments" => Some(Self::Attachments),
+            "annotations" => Some(Self::Annotations),
+            "marks" => Some(Self::Marks),
+            "highlight-layers" => Some(Self::HighlightLayers),
+            "export" => Some(Self::Export),
+            // The password palette is only opened by the document unlock prompt.
             _ => None,
         }
     }
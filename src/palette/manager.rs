@@ -1,6 +1,7 @@
 use tui_input::{Input, InputRequest};
 
 use crate::app::AppState;
+use crate::command::Command;
 use crate::error::AppResult;
 use crate::extension::ExtensionUiSnapshot;
 use crate::input::InputHistorySnapshot;
@@ -14,6 +15,8 @@ use super::types::{
     PaletteOpenPayload, PaletteSubmitAction, PaletteTabEffect, PaletteView,
 };
 
+const MASKED_INPUT_CHAR: &str = "*";
+
 #[derive(Debug)]
 struct PaletteSession {
     id: u64,
@@ -153,6 +156,11 @@ impl PaletteManager {
         self.active.as_ref().map(|session| session.kind)
     }
 
+    /// Returns the raw input of the active palette, including masked input.
+    pub fn active_input(&self) -> Option<&str> {
+        self.active.as_ref().map(|session| session.input.value())
+    }
+
     pub fn active_input_is_empty(&self) -> bool {
         self.active
             .as_ref()
@@ -201,6 +209,44 @@ impl PaletteManager {
         }))
     }
 
+    /// Command the active provider asks for after its input or selection changed.
+    pub fn change_command(
+        &self,
+        registry: &PaletteRegistry,
+        app: &AppState,
+        extensions: &ExtensionUiSnapshot,
+    ) -> Option<Command> {
+        let session = self.active.as_ref()?;
+        let provider = registry.get(session.kind);
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot::from(app),
+            extensions,
+            kind: session.kind,
+            input: session.input.value(),
+            open_payload: session.payload.as_ref(),
+        };
+        provider.on_change(&ctx, selected_candidate(session))
+    }
+
+    /// Command the active provider asks for when the palette is dismissed.
+    pub fn cancel_command(
+        &self,
+        registry: &PaletteRegistry,
+        app: &AppState,
+        extensions: &ExtensionUiSnapshot,
+    ) -> Option<Command> {
+        let session = self.active.as_ref()?;
+        let provider = registry.get(session.kind);
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot::from(app),
+            extensions,
+            kind: session.kind,
+            input: session.input.value(),
+            open_payload: session.payload.as_ref(),
+        };
+        provider.on_cancel(&ctx)
+    }
+
     pub fn complete(
         &mut self,
         registry: &PaletteRegistry,
@@ -314,11 +360,22 @@ impl PaletteManager {
                 });
             }
         }
+        let (input, cursor) = if session.input_mode == PaletteInputMode::Masked {
+            (
+                MASKED_INPUT_CHAR.repeat(session.input.value().chars().count()),
+                session.input.cursor(),
+            )
+        } else {
+            (
+                session.input.value().to_string(),
+                session.input.visual_cursor(),
+            )
+        };
         Some(PaletteView {
             title: session.title.clone(),
             kind: session.kind,
-            input: session.input.value().to_string(),
-            cursor: session.input.visual_cursor(),
+            input,
+            cursor,
             assistive_text: session.assistive_text.clone(),
             selected_idx: session.selected,
             items,
@@ -383,7 +440,7 @@ impl PaletteManager {
     ) -> Vec<usize> {
         match input_mode {
             PaletteInputMode::FilterCandidates => self.matcher.select(input, candidates),
-            PaletteInputMode::FreeText | PaletteInputMode::Custom => {
+            PaletteInputMode::FreeText | PaletteInputMode::Custom | PaletteInputMode::Masked => {
                 (0..candidates.len()).collect()
             }
         }
@@ -550,6 +607,36 @@ mod tests {
         assert_eq!(filtered_view.input, "p");
     }
 
+    #[test]
+    fn password_palette_masks_input_in_view_but_keeps_raw_value() {
+        let registry = PaletteRegistry::default();
+        let mut manager = PaletteManager::default();
+        let app = AppState::default();
+        let extensions = ExtensionUiSnapshot::default();
+
+        manager
+            .open(
+                &registry,
+                &app,
+                &extensions,
+                PaletteKind::Password,
+                None,
+                None,
+            )
+            .expect("password palette should open");
+        manager
+            .insert_text(&registry, &app, &extensions, "pässword")
+            .expect("typing should succeed");
+        manager
+            .edit_input(&registry, &app, &extensions, InputRequest::GoToPrevChar)
+            .expect("cursor movement should succeed");
+
+        let view = manager.view().expect("palette should be visible");
+        assert_eq!(view.input, "********");
+        assert_eq!(view.cursor, 7);
+        assert_eq!(manager.active_input(), Some("pässword"));
+    }
+
     #[test]
     fn palette_operations_report_noop_when_state_does_not_change() {
         let registry = PaletteRegistry::default();
@@ -0,0 +1,134 @@
+use crate::command::{ExportScope, parse_command_text};
+use crate::error::AppResult;
+use crate::input::shortcut::{
+    ShortcutKey, format_shortcut_alternatives_tight, format_shortcut_key,
+};
+use crate::palette::{
+    PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PalettePayload,
+    PalettePostAction, PaletteProvider, PaletteSearchText, PaletteSubmitEffect, PaletteTextPart,
+};
+
+/// Picks what `export-image` renders; the input takes the optional `[dpi] [path]` that follow.
+pub struct ExportPaletteProvider;
+
+impl PaletteProvider for ExportPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::Export
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Export Image".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::FreeText
+    }
+
+    fn list(&self, _ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok([
+            (ExportScope::Page, "Current page"),
+            (ExportScope::Spread, "Pages on screen"),
+            (ExportScope::View, "Zoomed-in view"),
+        ]
+        .into_iter()
+        .map(|(scope, label)| PaletteCandidate {
+            id: scope.as_str().to_string(),
+            left: vec![PaletteTextPart::primary(label)],
+            right: vec![PaletteTextPart::secondary(scope.as_str())],
+            search_texts: vec![PaletteSearchText::new(scope.as_str())],
+            payload: PalettePayload::Opaque(scope.as_str().to_string()),
+        })
+        .collect())
+    }
+
+    fn on_submit(
+        &self,
+        ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        let scope = selected
+            .and_then(|candidate| match &candidate.payload {
+                PalettePayload::Opaque(scope) => ExportScope::parse(scope),
+                PalettePayload::None => None,
+            })
+            .unwrap_or(ExportScope::Page);
+        let command = parse_command_text(&format!(
+            "export-image {} {}",
+            scope.as_str(),
+            ctx.input.trim()
+        ))?;
+
+        Ok(PaletteSubmitEffect::Dispatch {
+            command,
+            history_record: None,
+            next: PalettePostAction::Close,
+        })
+    }
+
+    fn assistive_text(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        let enter = format_shortcut_key(ShortcutKey::key(crossterm::event::KeyCode::Enter));
+        let scope =
+            format_shortcut_alternatives_tight(&[ShortcutKey::ctrl('p'), ShortcutKey::ctrl('n')]);
+        Some(format!("[dpi] [path]   {enter} export   {scope} scope"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::{Command, ExportScope};
+    use crate::extension::ExtensionUiSnapshot;
+    use crate::palette::{
+        PaletteAppSnapshot, PaletteContext, PaletteKind, PaletteProvider, PaletteSubmitEffect,
+    };
+
+    use super::ExportPaletteProvider;
+
+    fn submit(input: &str, selected: usize) -> PaletteSubmitEffect {
+        let extensions = ExtensionUiSnapshot::default();
+        let ctx = PaletteContext {
+            app: PaletteAppSnapshot::default(),
+            extensions: &extensions,
+            kind: PaletteKind::Export,
+            input,
+            open_payload: None,
+        };
+        let candidates = ExportPaletteProvider
+            .list(&ctx)
+            .expect("list should succeed");
+        ExportPaletteProvider
+            .on_submit(&ctx, candidates.get(selected))
+            .expect("submit should succeed")
+    }
+
+    #[test]
+    fn selected_scope_and_typed_dpi_become_the_export_command() {
+        let PaletteSubmitEffect::Dispatch { command, .. } = submit("300 shots/{page}.png", 2)
+        else {
+            panic!("submit should dispatch");
+        };
+        assert_eq!(
+            command,
+            Command::ExportImage {
+                scope: ExportScope::View,
+                dpi: Some(300),
+                path: Some("shots/{page}.png".to_string()),
+            }
+        );
+
+        let PaletteSubmitEffect::Dispatch { command, .. } = submit("", 1) else {
+            panic!("submit should dispatch");
+        };
+        assert_eq!(
+            command,
+            Command::ExportImage {
+                scope: ExportScope::Spread,
+                dpi: None,
+                path: None,
+            }
+        );
+    }
+}
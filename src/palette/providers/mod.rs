@@ -1,3 +1,7 @@
 mod command;
+mod export;
+mod password;
 
 pub use command::CommandPaletteProvider;
+pub use export::ExportPaletteProvider;
+pub use password::PasswordPaletteProvider;
@@ -0,0 +1,95 @@
+use crate::error::AppResult;
+use crate::palette::{
+    PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PaletteOpenPayload,
+    PaletteProvider, PaletteSubmitEffect,
+};
+
+/// Prompts for the password of an encrypted document.
+///
+/// The input is read back by the unlock prompt before the palette closes, so submitting only
+/// closes the session.
+pub struct PasswordPaletteProvider;
+
+impl PaletteProvider for PasswordPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::Password
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Password".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::Masked
+    }
+
+    fn list(&self, _ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok(Vec::new())
+    }
+
+    fn on_submit(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        Ok(PaletteSubmitEffect::Close)
+    }
+
+    fn assistive_text(
+        &self,
+        ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        let incorrect = matches!(
+            ctx.open_payload,
+            Some(PaletteOpenPayload::PasswordPrompt { incorrect: true })
+        );
+        Some(if incorrect {
+            "Incorrect password, try again (Esc to quit)".to_string()
+        } else {
+            "Document is encrypted (Esc to quit)".to_string()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extension::ExtensionUiSnapshot;
+    use crate::palette::{
+        PaletteAppSnapshot, PaletteContext, PaletteKind, PaletteOpenPayload, PaletteProvider,
+    };
+
+    use super::PasswordPaletteProvider;
+
+    fn context<'a>(
+        extensions: &'a ExtensionUiSnapshot,
+        payload: Option<&'a PaletteOpenPayload>,
+    ) -> PaletteContext<'a> {
+        PaletteContext {
+            app: PaletteAppSnapshot::default(),
+            extensions,
+            kind: PaletteKind::Password,
+            input: "secret",
+            open_payload: payload,
+        }
+    }
+
+    #[test]
+    fn assistive_text_reports_incorrect_password_on_retry() {
+        let extensions = ExtensionUiSnapshot::default();
+        let retry = PaletteOpenPayload::PasswordPrompt { incorrect: true };
+
+        let first = PasswordPaletteProvider.assistive_text(&context(&extensions, None), None);
+        let second =
+            PasswordPaletteProvider.assistive_text(&context(&extensions, Some(&retry)), None);
+
+        assert!(first.is_some_and(|text| text.starts_with("Document is encrypted")));
+        assert!(second.is_some_and(|text| text.starts_with("Incorrect password")));
+    }
+
+    #[test]
+    fn initial_input_is_empty_for_password_prompt() {
+        let payload = PaletteOpenPayload::PasswordPrompt { incorrect: true };
+        assert_eq!(PasswordPaletteProvider.initial_input(Some(&payload)), "");
+    }
+}
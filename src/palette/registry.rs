@@ -1,10 +1,12 @@
+use crate::command::Command;
 use crate::error::AppResult;
 use crate::extension::{
-    HistoryPaletteProvider, OutlinePaletteProvider, SearchPaletteProvider,
+    AnnotationsPaletteProvider, AttachmentsPaletteProvider, HighlightLayersPaletteProvider,
+    HistoryPaletteProvider, MarksPaletteProvider, OutlinePaletteProvider, SearchPaletteProvider,
     SearchResultsPaletteProvider,
 };
 
-use super::providers::CommandPaletteProvider;
+use super::providers::{CommandPaletteProvider, ExportPaletteProvider, PasswordPaletteProvider};
 use super::{
     PaletteCandidate, PaletteContext, PaletteInputMode, PaletteKind, PaletteProvider,
     PaletteSubmitEffect, PaletteTabEffect,
@@ -16,6 +18,12 @@ pub struct PaletteRegistry {
     search_results: SearchResultsPaletteProvider,
     history: HistoryPaletteProvider,
     outline: OutlinePaletteProvider,
+    attachments: AttachmentsPaletteProvider,
+    annotations: AnnotationsPaletteProvider,
+    marks: MarksPaletteProvider,
+    highlight_layers: HighlightLayersPaletteProvider,
+    export: ExportPaletteProvider,
+    password: PasswordPaletteProvider,
 }
 
 pub enum PaletteProviderRef<'a> {
@@ -24,6 +32,12 @@ pub enum PaletteProviderRef<'a> {
     SearchResults(&'a SearchResultsPaletteProvider),
     History(&'a HistoryPaletteProvider),
     Outline(&'a OutlinePaletteProvider),
+    Attachments(&'a AttachmentsPaletteProvider),
+    Annotations(&'a AnnotationsPaletteProvider),
+    Marks(&'a MarksPaletteProvider),
+    HighlightLayers(&'a HighlightLayersPaletteProvider),
+    Export(&'a ExportPaletteProvider),
+    Password(&'a PasswordPaletteProvider),
 }
 
 impl Default for PaletteRegistry {
@@ -34,6 +48,12 @@ impl Default for PaletteRegistry {
             search_results: SearchResultsPaletteProvider,
             history: HistoryPaletteProvider,
             outline: OutlinePaletteProvider,
+            attachments: AttachmentsPaletteProvider,
+            annotations: AnnotationsPaletteProvider,
+            marks: MarksPaletteProvider,
+            highlight_layers: HighlightLayersPaletteProvider,
+            export: ExportPaletteProvider,
+            password: PasswordPaletteProvider,
         }
     }
 }
@@ -46,6 +66,14 @@ impl PaletteRegistry {
             PaletteKind::SearchResults => PaletteProviderRef::SearchResults(&self.search_results),
             PaletteKind::History => PaletteProviderRef::History(&self.history),
             PaletteKind::Outline => PaletteProviderRef::Outline(&self.outline),
+            PaletteKind::Attachments => PaletteProviderRef::Attachments(&self.attachments),
+            PaletteKind::Annotations => PaletteProviderRef::Annotations(&self.annotations),
+            PaletteKind::Marks => PaletteProviderRef::Marks(&self.marks),
+            PaletteKind::HighlightLayers => {
+                PaletteProviderRef::HighlightLayers(&self.highlight_layers)
+            }
+            PaletteKind::Export => PaletteProviderRef::Export(&self.export),
+            PaletteKind::Password => PaletteProviderRef::Password(&self.password),
         }
     }
 }
@@ -58,6 +86,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.kind(),
             Self::History(provider) => provider.kind(),
             Self::Outline(provider) => provider.kind(),
+            Self::Attachments(provider) => provider.kind(),
+            Self::Annotations(provider) => provider.kind(),
+            Self::Marks(provider) => provider.kind(),
+            Self::HighlightLayers(provider) => provider.kind(),
+            Self::Export(provider) => provider.kind(),
+            Self::Password(provider) => provider.kind(),
         }
     }
 
@@ -68,6 +102,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.title(ctx),
             Self::History(provider) => provider.title(ctx),
             Self::Outline(provider) => provider.title(ctx),
+            Self::Attachments(provider) => provider.title(ctx),
+            Self::Annotations(provider) => provider.title(ctx),
+            Self::Marks(provider) => provider.title(ctx),
+            Self::HighlightLayers(provider) => provider.title(ctx),
+            Self::Export(provider) => provider.title(ctx),
+            Self::Password(provider) => provider.title(ctx),
         }
     }
 
@@ -78,6 +118,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.input_mode(),
             Self::History(provider) => provider.input_mode(),
             Self::Outline(provider) => provider.input_mode(),
+            Self::Attachments(provider) => provider.input_mode(),
+            Self::Annotations(provider) => provider.input_mode(),
+            Self::Marks(provider) => provider.input_mode(),
+            Self::HighlightLayers(provider) => provider.input_mode(),
+            Self::Export(provider) => provider.input_mode(),
+            Self::Password(provider) => provider.input_mode(),
         }
     }
 
@@ -88,6 +134,48 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.list(ctx),
             Self::History(provider) => provider.list(ctx),
             Self::Outline(provider) => provider.list(ctx),
+            Self::Attachments(provider) => provider.list(ctx),
+            Self::Annotations(provider) => provider.list(ctx),
+            Self::Marks(provider) => provider.list(ctx),
+            Self::HighlightLayers(provider) => provider.list(ctx),
+            Self::Export(provider) => provider.list(ctx),
+            Self::Password(provider) => provider.list(ctx),
+        }
+    }
+
+    pub fn on_change(
+        &self,
+        ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> Option<Command> {
+        match self {
+            Self::Command(provider) => provider.on_change(ctx, selected),
+            Self::Search(provider) => provider.on_change(ctx, selected),
+            Self::SearchResults(provider) => provider.on_change(ctx, selected),
+            Self::History(provider) => provider.on_change(ctx, selected),
+            Self::Outline(provider) => provider.on_change(ctx, selected),
+            Self::Attachments(provider) => provider.on_change(ctx, selected),
+            Self::Annotations(provider) => provider.on_change(ctx, selected),
+            Self::Marks(provider) => provider.on_change(ctx, selected),
+            Self::HighlightLayers(provider) => provider.on_change(ctx, selected),
+            Self::Export(provider) => provider.on_change(ctx, selected),
+            Self::Password(provider) => provider.on_change(ctx, selected),
+        }
+    }
+
+    pub fn on_cancel(&self, ctx: &PaletteContext<'_>) -> Option<Command> {
+        match self {
+            Self::Command(provider) => provider.on_cancel(ctx),
+            Self::Search(provider) => provider.on_cancel(ctx),
+            Self::SearchResults(provider) => provider.on_cancel(ctx),
+            Self::History(provider) => provider.on_cancel(ctx),
+            Self::Outline(provider) => provider.on_cancel(ctx),
+            Self::Attachments(provider) => provider.on_cancel(ctx),
+            Self::Annotations(provider) => provider.on_cancel(ctx),
+            Self::Marks(provider) => provider.on_cancel(ctx),
+            Self::HighlightLayers(provider) => provider.on_cancel(ctx),
+            Self::Export(provider) => provider.on_cancel(ctx),
+            Self::Password(provider) => provider.on_cancel(ctx),
         }
     }
 
@@ -102,6 +190,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.on_tab(ctx, selected),
             Self::History(provider) => provider.on_tab(ctx, selected),
             Self::Outline(provider) => provider.on_tab(ctx, selected),
+            Self::Attachments(provider) => provider.on_tab(ctx, selected),
+            Self::Annotations(provider) => provider.on_tab(ctx, selected),
+            Self::Marks(provider) => provider.on_tab(ctx, selected),
+            Self::HighlightLayers(provider) => provider.on_tab(ctx, selected),
+            Self::Export(provider) => provider.on_tab(ctx, selected),
+            Self::Password(provider) => provider.on_tab(ctx, selected),
         }
     }
 
@@ -116,6 +210,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.on_submit(ctx, selected),
             Self::History(provider) => provider.on_submit(ctx, selected),
             Self::Outline(provider) => provider.on_submit(ctx, selected),
+            Self::Attachments(provider) => provider.on_submit(ctx, selected),
+            Self::Annotations(provider) => provider.on_submit(ctx, selected),
+            Self::Marks(provider) => provider.on_submit(ctx, selected),
+            Self::HighlightLayers(provider) => provider.on_submit(ctx, selected),
+            Self::Export(provider) => provider.on_submit(ctx, selected),
+            Self::Password(provider) => provider.on_submit(ctx, selected),
         }
     }
 
@@ -130,6 +230,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.assistive_text(ctx, selected),
             Self::History(provider) => provider.assistive_text(ctx, selected),
             Self::Outline(provider) => provider.assistive_text(ctx, selected),
+            Self::Attachments(provider) => provider.assistive_text(ctx, selected),
+            Self::Annotations(provider) => provider.assistive_text(ctx, selected),
+            Self::Marks(provider) => provider.assistive_text(ctx, selected),
+            Self::HighlightLayers(provider) => provider.assistive_text(ctx, selected),
+            Self::Export(provider) => provider.assistive_text(ctx, selected),
+            Self::Password(provider) => provider.assistive_text(ctx, selected),
         }
     }
 
@@ -140,6 +246,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.reset_selection_on_input_change(),
             Self::History(provider) => provider.reset_selection_on_input_change(),
             Self::Outline(provider) => provider.reset_selection_on_input_change(),
+            Self::Attachments(provider) => provider.reset_selection_on_input_change(),
+            Self::Annotations(provider) => provider.reset_selection_on_input_change(),
+            Self::Marks(provider) => provider.reset_selection_on_input_change(),
+            Self::HighlightLayers(provider) => provider.reset_selection_on_input_change(),
+            Self::Export(provider) => provider.reset_selection_on_input_change(),
+            Self::Password(provider) => provider.reset_selection_on_input_change(),
         }
     }
 
@@ -154,6 +266,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.initial_selected_candidate(ctx, candidates),
             Self::History(provider) => provider.initial_selected_candidate(ctx, candidates),
             Self::Outline(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::Attachments(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::Annotations(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::Marks(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::HighlightLayers(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::Export(provider) => provider.initial_selected_candidate(ctx, candidates),
+            Self::Password(provider) => provider.initial_selected_candidate(ctx, candidates),
         }
     }
 
@@ -164,6 +282,12 @@ impl<'a> PaletteProviderRef<'a> {
             Self::SearchResults(provider) => provider.initial_input(open_payload),
             Self::History(provider) => provider.initial_input(open_payload),
             Self::Outline(provider) => provider.initial_input(open_payload),
+            Self::Attachments(provider) => provider.initial_input(open_payload),
+            Self::Annotations(provider) => provider.initial_input(open_payload),
+            Self::Marks(provider) => provider.initial_input(open_payload),
+            Self::HighlightLayers(provider) => provider.initial_input(open_payload),
+            Self::Export(provider) => provider.initial_input(open_payload),
+            Self::Password(provider) => provider.initial_input(open_payload),
         }
     }
 }
@@ -207,6 +331,23 @@ mod tests {
             registry.get(PaletteKind::Outline).kind(),
             PaletteKind::Outline
         );
+        assert_eq!(
+            registry.get(PaletteKind::Attachments).kind(),
+            PaletteKind::Attachments
+        );
+        assert_eq!(
+            registry.get(PaletteKind::Annotations).kind(),
+            PaletteKind::Annotations
+        );
+        assert_eq!(registry.get(PaletteKind::Marks).kind(), PaletteKind::Marks);
+        assert_eq!(
+            registry.get(PaletteKind::Export).kind(),
+            PaletteKind::Export
+        );
+        assert_eq!(
+            registry.get(PaletteKind::Password).kind(),
+            PaletteKind::Password
+        );
         assert!(!registry.get(PaletteKind::Command).title(&ctx).is_empty());
     }
 }
@@ -1,5 +1,6 @@
 use super::kind::PaletteKind;
 use crate::app::{AppState, Mode, PageLayoutMode, SpreadCoverPolicy};
+use crate::backend::PageLabels;
 use crate::command::{Command, SearchMatcherKind};
 use crate::error::AppResult;
 use crate::extension::ExtensionUiSnapshot;
@@ -10,6 +11,8 @@ pub enum PaletteInputMode {
     FilterCandidates,
     FreeText,
     Custom,
+    /// Free text that is never shown verbatim, e.g. a document password.
+    Masked,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -110,11 +113,19 @@ pub enum PaletteOpenPayload {
     CommandInput(String),
     HistorySeed(String),
     OutlineQuery(String),
+    AttachmentsQuery(String),
+    AnnotationsQuery(String),
+    MarksQuery(String),
+    HighlightLayersQuery(String),
+    ExportInput(String),
     SearchResultsQuery(String),
     Search {
         query: String,
         matcher: SearchMatcherKind,
     },
+    PasswordPrompt {
+        incorrect: bool,
+    },
 }
 
 impl PaletteOpenPayload {
@@ -123,8 +134,14 @@ impl PaletteOpenPayload {
             Self::CommandInput(input) => Some(input.as_str()),
             Self::HistorySeed(_) => None,
             Self::OutlineQuery(query) => Some(query.as_str()),
+            Self::AttachmentsQuery(query) => Some(query.as_str()),
+            Self::AnnotationsQuery(query) => Some(query.as_str()),
+            Self::MarksQuery(query) => Some(query.as_str()),
+            Self::HighlightLayersQuery(query) => Some(query.as_str()),
+            Self::ExportInput(input) => Some(input.as_str()),
             Self::SearchResultsQuery(query) => Some(query.as_str()),
             Self::Search { query, .. } => Some(query.as_str()),
+            Self::PasswordPrompt { .. } => None,
         }
     }
 }
@@ -161,12 +178,13 @@ pub enum PaletteTabEffect {
     },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PaletteAppSnapshot {
     pub current_page: usize,
     pub mode: Mode,
     pub page_layout_mode: PageLayoutMode,
     pub spread_cover_policy: SpreadCoverPolicy,
+    pub page_labels: PageLabels,
 }
 
 impl Default for PaletteAppSnapshot {
@@ -176,6 +194,7 @@ impl Default for PaletteAppSnapshot {
             mode: Mode::Normal,
             page_layout_mode: PageLayoutMode::default(),
             spread_cover_policy: SpreadCoverPolicy::default(),
+            page_labels: PageLabels::default(),
         }
     }
 }
@@ -187,6 +206,7 @@ impl From<&AppState> for PaletteAppSnapshot {
             mode: app.mode,
             page_layout_mode: app.page_layout_mode,
             spread_cover_policy: app.spread_cover_policy,
+            page_labels: app.page_labels.clone(),
         }
     }
 }
@@ -223,6 +243,21 @@ pub trait PaletteProvider: Send + Sync {
     ) -> Option<String> {
         None
     }
+    /// Returns a command to run after the input or the selected candidate changed.
+    ///
+    /// Defaults to `None`. Providers that preview their result while the palette is open
+    /// override it.
+    fn on_change(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<Command> {
+        None
+    }
+    /// Returns a command to run when the palette is closed without submitting.
+    fn on_cancel(&self, _ctx: &PaletteContext<'_>) -> Option<Command> {
+        None
+    }
     /// Returns whether a changed input should reset the selected candidate to the first item.
     ///
     /// Defaults to `false` so providers that do not filter or reorder candidates by input keep
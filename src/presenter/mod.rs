@@ -1,3 +1,5 @@
+use ::ratatui::layout::Rect;
+
 mod encode;
 mod factory;
 mod image_ops;
@@ -17,3 +19,22 @@ pub use traits::{
     PresenterRenderOutcome, PresenterRenderSlot, PresenterRuntimeInfo, PresenterSlot,
     PresenterSlotOutcome, Viewport,
 };
+
+/// Cells a `width_px` x `height_px` frame occupies once the presenter fits it into `area`.
+pub(crate) fn fitted_image_area(
+    width_px: u32,
+    height_px: u32,
+    cell_px: (u16, u16),
+    area: Rect,
+    horizontal_align: PresenterHorizontalAlign,
+    allow_upscale: bool,
+) -> Rect {
+    ratatui::aligned_fit_area(
+        width_px,
+        height_px,
+        cell_px,
+        area,
+        horizontal_align,
+        allow_upscale,
+    )
+}
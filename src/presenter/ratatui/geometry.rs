@@ -19,7 +19,7 @@ pub(super) fn centered_fit_area(
     )
 }
 
-pub(super) fn aligned_fit_area(
+pub(in crate::presenter) fn aligned_fit_area(
     image_width_px: u32,
     image_height_px: u32,
     font_size: (u16, u16),
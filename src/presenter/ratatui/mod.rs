@@ -2,6 +2,8 @@ use ratatui::Frame;
 
 mod draw;
 mod geometry;
+
+pub(super) use geometry::aligned_fit_area;
 use ratatui::layout::Rect;
 use ratatui::widgets::Clear;
 use ratatui_image::picker::Picker;
@@ -0,0 +1,543 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flume::{Receiver, Sender, TrySendError};
+use lru::LruCache;
+
+use crate::backend::RgbaFrame;
+use crate::error::{AppError, AppResult};
+use crate::render::cache::RenderedPageKey;
+
+/// Name of the render cache directory below the pvf cache directory.
+pub const RENDER_CACHE_DIR: &str = "renders";
+
+const FRAME_MAGIC: &[u8; 8] = b"PVFRGBA1";
+const FRAME_HEADER_LEN: usize = FRAME_MAGIC.len() + 8;
+const FRAME_EXTENSION: &str = "frame";
+const PARTIAL_EXTENSION: &str = "partial";
+/// Frames waiting to be written; renders finishing while the queue is full are not persisted.
+const WRITE_QUEUE_DEPTH: usize = 16;
+/// Deflate cannot expand its input by more than this, which bounds the frame a file can hold.
+const MAX_DEFLATE_RATIO: usize = 1032;
+
+/// Disk-backed render cache tier behind the in-memory L1 cache.
+///
+/// Lookups run on the render workers. Frames are compressed and written by a background thread
+/// so that persisting a render never stalls the event loop. The lock only guards the index of
+/// cached frames; file reads, writes and deletions all happen outside it.
+#[derive(Debug, Clone)]
+pub struct DiskRenderCache {
+    store: Arc<Mutex<DiskRenderStore>>,
+    writes: Sender<(RenderedPageKey, RgbaFrame)>,
+}
+
+impl DiskRenderCache {
+    pub fn open(dir: impl Into<PathBuf>, budget_bytes: u64) -> AppResult<Self> {
+        let store = Arc::new(Mutex::new(DiskRenderStore::open(dir, budget_bytes)?));
+        let (writes, pending) = flume::bounded(WRITE_QUEUE_DEPTH);
+        // The writer gets the index but not the sender, so it stops once the last handle drops.
+        let writer_store = Arc::clone(&store);
+        std::thread::Builder::new()
+            .name("pvf-disk-cache".to_string())
+            .spawn(move || disk_cache_writer_main(&writer_store, pending))
+            .map_err(|source| AppError::io_with_context(source, "starting disk cache writer"))?;
+        Ok(Self { store, writes })
+    }
+
+    pub fn contains(&self, key: &RenderedPageKey) -> bool {
+        self.lock().contains(key)
+    }
+
+    pub fn load(&self, key: &RenderedPageKey) -> Option<RgbaFrame> {
+        let path = self.lock().touch(key)?;
+        let frame = fs::read(&path)
+            .ok()
+            .and_then(|bytes| decode_frame(&bytes).ok());
+        let Some(frame) = frame else {
+            // Another pvf process may have evicted the file, or it is damaged; either way it
+            // must not be offered again.
+            if self.lock().forget(key) {
+                let _ = fs::remove_file(&path);
+            }
+            return None;
+        };
+        if let Ok(file) = File::options().write(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(frame)
+    }
+
+    /// Queues `frame` to be persisted; returns false when it was dropped instead.
+    pub fn store(&self, key: RenderedPageKey, frame: RgbaFrame) -> bool {
+        match self.writes.try_send((key, frame)) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => false,
+        }
+    }
+
+    #[cfg(test)]
+    fn insert(&self, key: RenderedPageKey, frame: &RgbaFrame) -> AppResult<bool> {
+        let encoded = encode_frame(frame)?;
+        insert_encoded(&self.store, key, &encoded)
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    #[cfg(test)]
+    fn disk_bytes(&self) -> u64 {
+        self.lock().disk_bytes
+    }
+
+    fn lock(&self) -> MutexGuard<'_, DiskRenderStore> {
+        lock_store(&self.store)
+    }
+}
+
+fn lock_store(store: &Mutex<DiskRenderStore>) -> MutexGuard<'_, DiskRenderStore> {
+    // The critical sections only do bookkeeping, so a poisoned index is still usable.
+    store
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn disk_cache_writer_main(
+    store: &Mutex<DiskRenderStore>,
+    pending: Receiver<(RenderedPageKey, RgbaFrame)>,
+) {
+    while let Ok((key, frame)) = pending.recv() {
+        if lock_store(store).contains(&key) {
+            continue;
+        }
+        let Ok(encoded) = encode_frame(&frame) else {
+            continue;
+        };
+        let _ = insert_encoded(store, key, &encoded);
+    }
+}
+
+fn insert_encoded(
+    store: &Mutex<DiskRenderStore>,
+    key: RenderedPageKey,
+    encoded: &[u8],
+) -> AppResult<bool> {
+    let bytes = encoded.len() as u64;
+    let Some((path, evicted)) = lock_store(store).reserve(&key, bytes) else {
+        return Ok(false);
+    };
+    for evicted in evicted {
+        let _ = fs::remove_file(evicted);
+    }
+
+    let partial = path.with_extension(PARTIAL_EXTENSION);
+    let written = File::create(&partial)
+        .and_then(|mut file| file.write_all(encoded))
+        .and_then(|()| fs::rename(&partial, &path));
+    if let Err(source) = written {
+        let _ = fs::remove_file(&partial);
+        lock_store(store).release(bytes);
+        return Err(AppError::io_with_context(
+            source,
+            format!("writing cached frame {}", path.display()),
+        ));
+    }
+    lock_store(store).commit(key, bytes);
+    Ok(true)
+}
+
+/// Index of the frames in the cache directory, one compressed file each, evicted least recently
+/// used first.
+///
+/// Recency survives restarts through file modification times, which a hit refreshes. Methods
+/// only update the bookkeeping and hand back the paths the caller has to read, write or delete.
+#[derive(Debug)]
+struct DiskRenderStore {
+    dir: PathBuf,
+    entries: LruCache<RenderedPageKey, u64>,
+    /// Bytes of the indexed frames plus those reserved for frames being written.
+    disk_bytes: u64,
+    budget_bytes: u64,
+}
+
+impl DiskRenderStore {
+    fn open(dir: impl Into<PathBuf>, budget_bytes: u64) -> AppResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|source| {
+            AppError::io_with_context(
+                source,
+                format!("creating cache directory {}", dir.display()),
+            )
+        })?;
+        let listing = fs::read_dir(&dir).map_err(|source| {
+            AppError::io_with_context(source, format!("reading cache directory {}", dir.display()))
+        })?;
+
+        let mut found = Vec::new();
+        for entry in listing.flatten() {
+            let path = entry.path();
+            let Some(key) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(parse_file_name)
+            else {
+                // Leftovers of writes interrupted by a crash are never completed.
+                if path.extension().is_some_and(|ext| ext == PARTIAL_EXTENSION) {
+                    let _ = fs::remove_file(&path);
+                }
+                continue;
+            };
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let used_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            found.push((used_at, key, metadata.len()));
+        }
+        found.sort_by_key(|(used_at, _, _)| *used_at);
+
+        let mut store = Self {
+            dir,
+            entries: LruCache::unbounded(),
+            disk_bytes: 0,
+            budget_bytes,
+        };
+        for (_, key, bytes) in found {
+            store.entries.put(key, bytes);
+            store.disk_bytes = store.disk_bytes.saturating_add(bytes);
+        }
+        for path in store.evict_to(budget_bytes) {
+            let _ = fs::remove_file(path);
+        }
+        Ok(store)
+    }
+
+    fn contains(&self, key: &RenderedPageKey) -> bool {
+        self.entries.contains(key)
+    }
+
+    /// Marks `key` as just used and returns the file holding it.
+    fn touch(&mut self, key: &RenderedPageKey) -> Option<PathBuf> {
+        self.entries.get(key)?;
+        Some(self.path_for(key))
+    }
+
+    /// Drops `key` from the index, returning whether it was there.
+    fn forget(&mut self, key: &RenderedPageKey) -> bool {
+        let Some(bytes) = self.entries.pop(key) else {
+            return false;
+        };
+        self.disk_bytes = self.disk_bytes.saturating_sub(bytes);
+        true
+    }
+
+    /// Makes room for `bytes` more and reserves them. Returns the path to write `key` to and
+    /// the files of the frames evicted for it, or `None` when the frame exceeds the budget.
+    fn reserve(&mut self, key: &RenderedPageKey, bytes: u64) -> Option<(PathBuf, Vec<PathBuf>)> {
+        if bytes > self.budget_bytes {
+            return None;
+        }
+        // The old file is replaced by the rename, so only its bytes are given back.
+        self.forget(key);
+        let evicted = self.evict_to(self.budget_bytes - bytes);
+        self.disk_bytes = self.disk_bytes.saturating_add(bytes);
+        Some((self.path_for(key), evicted))
+    }
+
+    /// Indexes `key` once its file, reserved for with `reserve`, is in place.
+    fn commit(&mut self, key: RenderedPageKey, bytes: u64) {
+        if let Some(replaced) = self.entries.put(key, bytes) {
+            self.disk_bytes = self.disk_bytes.saturating_sub(replaced);
+        }
+    }
+
+    /// Gives back a reservation whose file could not be written.
+    fn release(&mut self, bytes: u64) {
+        self.disk_bytes = self.disk_bytes.saturating_sub(bytes);
+    }
+
+    fn evict_to(&mut self, limit_bytes: u64) -> Vec<PathBuf> {
+        let mut evicted = Vec::new();
+        while self.disk_bytes > limit_bytes {
+            let Some((key, bytes)) = self.entries.pop_lru() else {
+                break;
+            };
+            self.disk_bytes = self.disk_bytes.saturating_sub(bytes);
+            evicted.push(self.path_for(&key));
+        }
+        evicted
+    }
+
+    fn path_for(&self, key: &RenderedPageKey) -> PathBuf {
+        self.dir.join(file_name(key))
+    }
+}
+
+/// Summary of a cleared render cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClearedRenderCache {
+    pub frames: usize,
+    pub bytes: u64,
+}
+
+/// Deletes every cached frame in `dir`; a missing directory counts as already empty.
+pub fn clear_render_cache_dir(dir: &Path) -> AppResult<ClearedRenderCache> {
+    let listing = match fs::read_dir(dir) {
+        Ok(listing) => listing,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(ClearedRenderCache::default());
+        }
+        Err(source) => {
+            return Err(AppError::io_with_context(
+                source,
+                format!("reading cache directory {}", dir.display()),
+            ));
+        }
+    };
+
+    let mut cleared = ClearedRenderCache::default();
+    for entry in listing.flatten() {
+        let path = entry.path();
+        let cached = path
+            .extension()
+            .is_some_and(|ext| ext == FRAME_EXTENSION || ext == PARTIAL_EXTENSION);
+        if !cached {
+            continue;
+        }
+        let bytes = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        fs::remove_file(&path).map_err(|source| {
+            AppError::io_with_context(source, format!("removing {}", path.display()))
+        })?;
+        if path.extension().is_some_and(|ext| ext == FRAME_EXTENSION) {
+            cleared.frames += 1;
+        }
+        cleared.bytes = cleared.bytes.saturating_add(bytes);
+    }
+    Ok(cleared)
+}
+
+fn file_name(key: &RenderedPageKey) -> String {
+    format!(
+        "{:016x}-p{}-s{}-l{}.{FRAME_EXTENSION}",
+        key.doc_id, key.page, key.scale_milli, key.layout_tag
+    )
+}
+
+fn parse_file_name(name: &str) -> Option<RenderedPageKey> {
+    let stem = name.strip_suffix(FRAME_EXTENSION)?.strip_suffix('.')?;
+    let mut parts = stem.split('-');
+    let doc_id = u64::from_str_radix(parts.next()?, 16).ok()?;
+    let page = parts.next()?.strip_prefix('p')?.parse().ok()?;
+    let scale_milli = parts.next()?.strip_prefix('s')?.parse().ok()?;
+    let layout_tag = parts.next()?.strip_prefix('l')?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(RenderedPageKey {
+        doc_id,
+        page,
+        scale_milli,
+        layout_tag,
+    })
+}
+
+fn encode_frame(frame: &RgbaFrame) -> AppResult<Vec<u8>> {
+    let mut encoded = Vec::with_capacity(FRAME_HEADER_LEN + frame.byte_len() / 4);
+    encoded.extend_from_slice(FRAME_MAGIC);
+    encoded.extend_from_slice(&frame.width.to_le_bytes());
+    encoded.extend_from_slice(&frame.height.to_le_bytes());
+    let mut encoder = DeflateEncoder::new(encoded, Compression::fast());
+    encoder
+        .write_all(&frame.pixels)
+        .and_then(|()| encoder.finish())
+        .map_err(|source| AppError::io_with_context(source, "compressing rendered frame"))
+}
+
+fn decode_frame(bytes: &[u8]) -> AppResult<RgbaFrame> {
+    let invalid = || AppError::invalid_argument("cached frame is damaged");
+    let header = bytes.get(..FRAME_HEADER_LEN).ok_or_else(invalid)?;
+    if &header[..FRAME_MAGIC.len()] != FRAME_MAGIC {
+        return Err(invalid());
+    }
+    let width = u32::from_le_bytes(header[8..12].try_into().map_err(|_| invalid())?);
+    let height = u32::from_le_bytes(header[12..16].try_into().map_err(|_| invalid())?);
+    let payload = &bytes[FRAME_HEADER_LEN..];
+    let expected = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(4))
+        .filter(|&expected| expected / MAX_DEFLATE_RATIO <= payload.len())
+        .ok_or_else(invalid)?;
+
+    let mut pixels = Vec::with_capacity(expected);
+    DeflateDecoder::new(payload)
+        .take(expected as u64 + 1)
+        .read_to_end(&mut pixels)
+        .map_err(|_| invalid())?;
+    if pixels.len() != expected {
+        return Err(invalid());
+    }
+    Ok(RgbaFrame {
+        width,
+        height,
+        pixels: pixels.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use super::{
+        DiskRenderCache, FRAME_MAGIC, clear_render_cache_dir, encode_frame, file_name,
+        parse_file_name,
+    };
+    use crate::backend::RgbaFrame;
+    use crate::backend::test_support::unique_temp_path;
+    use crate::render::cache::RenderedPageKey;
+
+    fn frame(width: u32, height: u32, seed: u8) -> RgbaFrame {
+        let pixels = (0..width * height * 4)
+            .map(|idx| (idx as u8).wrapping_mul(seed))
+            .collect::<Vec<_>>();
+        RgbaFrame {
+            width,
+            height,
+            pixels: pixels.into(),
+        }
+    }
+
+    #[test]
+    fn file_names_round_trip_page_keys() {
+        let page = RenderedPageKey::with_layout(0xfeed, 12, 1.5, 3);
+
+        assert_eq!(parse_file_name(&file_name(&page)), Some(page));
+        assert_eq!(parse_file_name("notes.txt"), None);
+    }
+
+    #[test]
+    fn stored_frames_survive_reopening() {
+        let dir = unique_temp_path("disk_cache_reopen");
+        let key = RenderedPageKey::new(7, 0, 1.0);
+        let original = frame(16, 8, 3);
+
+        let store = DiskRenderCache::open(&dir, 1 << 20).expect("store should open");
+        assert!(
+            store
+                .insert(key, &original)
+                .expect("frame should be written")
+        );
+        drop(store);
+
+        let reopened = DiskRenderCache::open(&dir, 1 << 20).expect("store should reopen");
+        let loaded = reopened.load(&key).expect("frame should be cached");
+        assert_eq!((loaded.width, loaded.height), (16, 8));
+        assert_eq!(&*loaded.pixels, &*original.pixels);
+        assert!(reopened.load(&RenderedPageKey::new(7, 1, 1.0)).is_none());
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+
+    #[test]
+    fn insert_evicts_least_recently_used_frames_over_budget() {
+        let dir = unique_temp_path("disk_cache_evict");
+        let keys = [0, 1, 2].map(|page| RenderedPageKey::new(7, page, 1.0));
+        let store = DiskRenderCache::open(&dir, u64::MAX).expect("store should open");
+        assert!(store.insert(keys[0], &frame(64, 64, 5)).expect("write"));
+        let one_frame = store.disk_bytes();
+        drop(store);
+
+        let store =
+            DiskRenderCache::open(&dir, one_frame * 2 + one_frame / 2).expect("store should open");
+        assert!(store.insert(keys[1], &frame(64, 64, 5)).expect("write"));
+        assert!(store.load(&keys[0]).is_some());
+        assert!(store.insert(keys[2], &frame(64, 64, 5)).expect("write"));
+
+        assert!(store.contains(&keys[0]));
+        assert!(!store.contains(&keys[1]));
+        assert!(store.contains(&keys[2]));
+        assert_eq!(store.len(), 2);
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+
+    #[test]
+    fn damaged_frames_are_dropped_on_load() {
+        let dir = unique_temp_path("disk_cache_damaged");
+        let key = RenderedPageKey::new(7, 0, 1.0);
+        let store = DiskRenderCache::open(&dir, 1 << 20).expect("store should open");
+        assert!(store.insert(key, &frame(8, 8, 1)).expect("write"));
+        fs::write(dir.join(file_name(&key)), b"PVFRGBA1garbage").expect("overwrite frame");
+
+        assert!(store.load(&key).is_none());
+        assert!(!store.contains(&key));
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+
+    #[test]
+    fn frames_claiming_more_pixels_than_the_file_can_hold_are_misses() {
+        let dir = unique_temp_path("disk_cache_oversized");
+        let key = RenderedPageKey::new(7, 0, 1.0);
+        let store = DiskRenderCache::open(&dir, 1 << 20).expect("store should open");
+        assert!(store.insert(key, &frame(8, 8, 1)).expect("write"));
+        let mut forged = encode_frame(&frame(8, 8, 1)).expect("encode");
+        forged[FRAME_MAGIC.len()..FRAME_MAGIC.len() + 8].copy_from_slice(&[0xff; 8]);
+        fs::write(dir.join(file_name(&key)), forged).expect("overwrite frame");
+
+        assert!(store.load(&key).is_none());
+        assert!(!store.contains(&key));
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+
+    #[test]
+    fn writer_thread_exits_when_the_last_handle_drops() {
+        let dir = unique_temp_path("disk_cache_writer_exit");
+        let store = DiskRenderCache::open(&dir, 1 << 20).expect("store should open");
+        let index = Arc::downgrade(&store.store);
+        drop(store);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while index.upgrade().is_some() {
+            assert!(
+                Instant::now() < deadline,
+                "writer thread kept the index alive"
+            );
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+
+    #[test]
+    fn clear_removes_cached_frames_only() {
+        let dir = unique_temp_path("disk_cache_clear");
+        let store = DiskRenderCache::open(&dir, 1 << 20).expect("store should open");
+        assert!(
+            store
+                .insert(RenderedPageKey::new(7, 0, 1.0), &frame(8, 8, 1))
+                .expect("write")
+        );
+        fs::write(dir.join("keep.txt"), b"unrelated").expect("write unrelated file");
+
+        let cleared = clear_render_cache_dir(&dir).expect("cache should clear");
+
+        assert_eq!(cleared.frames, 1);
+        assert_eq!(cleared.bytes, store.disk_bytes());
+        assert!(dir.join("keep.txt").exists());
+        assert_eq!(
+            clear_render_cache_dir(&dir.join("missing")).expect("missing dir is empty"),
+            Default::default()
+        );
+
+        fs::remove_dir_all(&dir).expect("cache dir should be removed");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod cache;
+pub mod disk_cache;
 pub mod prefetch;
 pub mod scheduler;
 pub mod worker;
@@ -10,6 +10,7 @@ use tokio::task::JoinHandle;
 use crate::backend::{RgbaFrame, SharedPdfBackend};
 use crate::error::{AppError, AppResult};
 use crate::render::cache::RenderedPageKey;
+use crate::render::disk_cache::DiskRenderCache;
 use crate::render::scheduler::RenderTask;
 use crate::work::WorkClass;
 
@@ -28,6 +29,8 @@ pub(crate) struct RenderWorkerResult {
     pub(crate) class: WorkClass,
     pub(crate) generation: u64,
     pub(crate) result: AppResult<RgbaFrame>,
+    /// Set when the frame was read from the L3 cache instead of rendered.
+    pub(crate) from_l3: bool,
     pub(crate) queue_wait: Duration,
     pub(crate) elapsed: Duration,
 }
@@ -39,6 +42,8 @@ pub(crate) struct RenderResultEvent {
     pub(crate) class: WorkClass,
     pub(crate) generation: u64,
     pub(crate) result: AppResult<RgbaFrame>,
+    /// Set when the frame was read from the L3 cache instead of rendered.
+    pub(crate) from_l3: bool,
     pub(crate) queue_wait: Duration,
     pub(crate) elapsed: Duration,
 }
@@ -96,7 +101,13 @@ struct InFlightTask {
 }
 
 impl RenderWorker {
-    pub(crate) fn spawn(pdf: SharedPdfBackend, worker_threads: usize) -> Self {
+    /// Spawns `worker_threads` workers for `pdf`. With `l3_cache`, workers look a page up there
+    /// before rendering it.
+    pub(crate) fn spawn(
+        pdf: SharedPdfBackend,
+        worker_threads: usize,
+        l3_cache: Option<DiskRenderCache>,
+    ) -> Self {
         let (request_tx, request_rx) = flume::unbounded();
         let (result_tx, result_rx) = unbounded_channel();
         let runtime = RenderWorkerRuntime::new();
@@ -106,8 +117,9 @@ impl RenderWorker {
             let request_rx = request_rx.clone();
             let pdf = Arc::clone(&pdf);
             let result_tx = result_tx.clone();
-            let worker =
-                runtime.spawn_blocking(move || render_worker_main(pdf, request_rx, result_tx));
+            let l3_cache = l3_cache.clone();
+            let worker = runtime
+                .spawn_blocking(move || render_worker_main(pdf, l3_cache, request_rx, result_tx));
             workers.push(worker);
         }
 
@@ -248,6 +260,7 @@ impl RenderWorker {
             class: result.class,
             generation: result.generation,
             result: result.result,
+            from_l3: result.from_l3,
             queue_wait: result.queue_wait,
             elapsed: result.elapsed,
         })
@@ -293,6 +306,7 @@ impl Drop for RenderWorker {
 
 fn render_worker_main(
     doc: SharedPdfBackend,
+    l3_cache: Option<DiskRenderCache>,
     request_rx: Receiver<RenderWorkerRequest>,
     result_tx: UnboundedSender<RenderResultEvent>,
 ) {
@@ -313,7 +327,14 @@ fn render_worker_main(
             } => {
                 let key = RenderedPageKey::new(task.doc_id, task.page, task.scale);
                 let started = Instant::now();
-                let result = if doc.doc_id() != task.doc_id {
+                let persisted = l3_cache
+                    .as_ref()
+                    .filter(|_| doc.doc_id() == task.doc_id)
+                    .and_then(|l3_cache| l3_cache.load(&key));
+                let from_l3 = persisted.is_some();
+                let result = if let Some(frame) = persisted {
+                    Ok(frame)
+                } else if doc.doc_id() != task.doc_id {
                     Err(AppError::invalid_argument(
                         "render task does not match active document",
                     ))
@@ -329,6 +350,7 @@ fn render_worker_main(
                     class: task.class,
                     generation: task.generation,
                     result,
+                    from_l3,
                     queue_wait: started.saturating_duration_since(enqueued_at),
                     elapsed: started.elapsed(),
                 };
@@ -461,7 +483,7 @@ mod tests {
 
     fn spawn_worker(doc: Arc<PdfDoc>, worker_threads: usize) -> RenderWorker {
         let doc: SharedPdfBackend = doc;
-        RenderWorker::spawn(doc, worker_threads)
+        RenderWorker::spawn(doc, worker_threads, None)
     }
 
     fn drain_render_results(worker: &mut RenderWorker) -> Vec<RenderedPageKey> {
@@ -0,0 +1,500 @@
+use std::sync::Mutex;
+
+use crate::backend::{TextGlyph, TextPage};
+
+use super::engine::SearchOccurrence;
+use super::matcher::{SearchFolding, SearchMatcher, locate_occurrences, prepare_contains_query};
+
+/// A parsed boolean query.
+///
+/// Terms and quoted phrases match like case-insensitive substring search. Juxtaposed terms and
+/// `AND` require all of them on the page, `OR` either, `NOT` the absence, and `a NEAR/n b`
+/// both within `n` words of each other. `NEAR` binds tightest, then `NOT`, `AND`, and `OR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryNode {
+    Term(String),
+    And(Vec<QueryNode>),
+    Or(Vec<QueryNode>),
+    Not(Box<QueryNode>),
+    Near {
+        left: String,
+        right: String,
+        distance: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Term(String),
+    Phrase(String),
+    And,
+    Or,
+    Not,
+    Near(usize),
+    Open,
+    Close,
+}
+
+/// Why `query` is not a valid boolean query, as one line for a notice.
+pub(crate) fn boolean_query_error(query: &str) -> Option<String> {
+    parse_query(query).err()
+}
+
+fn parse_query(query: &str) -> Result<QueryNode, String> {
+    let tokens = tokenize(query)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+    };
+    let node = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(match token {
+            Token::Close => "unmatched )".to_string(),
+            _ => "unexpected operator".to_string(),
+        });
+    }
+    if !has_positive_term(&node) {
+        return Err("query needs a term that is not negated".to_string());
+    }
+    Ok(node)
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch == '(' || ch == ')' {
+            chars.next();
+            tokens.push(if ch == '(' { Token::Open } else { Token::Close });
+        } else if ch == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(ch) => phrase.push(ch),
+                    None => return Err("unclosed quote".to_string()),
+                }
+            }
+            if phrase.trim().is_empty() {
+                return Err("empty phrase".to_string());
+            }
+            tokens.push(Token::Phrase(phrase));
+        } else {
+            let mut word = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || matches!(ch, '(' | ')' | '"') {
+                    break;
+                }
+                word.push(ch);
+                chars.next();
+            }
+            tokens.push(keyword_token(word)?);
+        }
+    }
+    Ok(tokens)
+}
+
+/// Operators are recognized only in upper case, so "and" or "near" can still be searched for.
+fn keyword_token(word: String) -> Result<Token, String> {
+    match word.as_str() {
+        "AND" => Ok(Token::And),
+        "OR" => Ok(Token::Or),
+        "NOT" => Ok(Token::Not),
+        _ => match word.strip_prefix("NEAR/") {
+            Some(distance) => distance
+                .parse::<usize>()
+                .ok()
+                .filter(|distance| *distance > 0)
+                .map(Token::Near)
+                .ok_or_else(|| format!("{word} needs a positive word distance")),
+            None => Ok(Token::Term(word)),
+        },
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<QueryNode, String> {
+        let mut operands = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            operands.push(self.parse_and()?);
+        }
+        Ok(collapse(operands, QueryNode::Or))
+    }
+
+    fn parse_and(&mut self) -> Result<QueryNode, String> {
+        let mut operands = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.next();
+                }
+                Some(Token::Term(_) | Token::Phrase(_) | Token::Not | Token::Open) => {}
+                _ => break,
+            }
+            operands.push(self.parse_unary()?);
+        }
+        Ok(collapse(operands, QueryNode::And))
+    }
+
+    fn parse_unary(&mut self) -> Result<QueryNode, String> {
+        if self.peek() == Some(&Token::Not) {
+            self.next();
+            return Ok(QueryNode::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_near()
+    }
+
+    fn parse_near(&mut self) -> Result<QueryNode, String> {
+        let left = self.parse_primary()?;
+        let Some(&Token::Near(distance)) = self.peek() else {
+            return Ok(left);
+        };
+        self.next();
+        let right = self.parse_primary()?;
+        let near = match (left, right) {
+            (QueryNode::Term(left), QueryNode::Term(right)) => QueryNode::Near {
+                left,
+                right,
+                distance,
+            },
+            _ => return Err("NEAR joins two words or phrases".to_string()),
+        };
+        if matches!(self.peek(), Some(Token::Near(_))) {
+            return Err("NEAR joins two words or phrases".to_string());
+        }
+        Ok(near)
+    }
+
+    fn parse_primary(&mut self) -> Result<QueryNode, String> {
+        match self.next() {
+            Some(Token::Term(term) | Token::Phrase(term)) => Ok(QueryNode::Term(term.clone())),
+            Some(Token::Open) => {
+                let node = self.parse_or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(node),
+                    _ => Err("missing )".to_string()),
+                }
+            }
+            Some(Token::Close) => Err("unmatched )".to_string()),
+            Some(Token::And | Token::Or | Token::Near(_)) => {
+                Err("operator needs a term on both sides".to_string())
+            }
+            // `parse_unary` takes every other NOT, so this one follows a NEAR.
+            Some(Token::Not) => Err("NEAR joins two words or phrases".to_string()),
+            None => Err("query ends before a term".to_string()),
+        }
+    }
+}
+
+fn collapse(mut operands: Vec<QueryNode>, group: fn(Vec<QueryNode>) -> QueryNode) -> QueryNode {
+    if operands.len() == 1 {
+        operands.remove(0)
+    } else {
+        group(operands)
+    }
+}
+
+fn has_positive_term(node: &QueryNode) -> bool {
+    match node {
+        QueryNode::Term(_) | QueryNode::Near { .. } => true,
+        QueryNode::And(operands) | QueryNode::Or(operands) => {
+            operands.iter().any(has_positive_term)
+        }
+        QueryNode::Not(_) => false,
+    }
+}
+
+/// Matches boolean and proximity queries page by page; see [`QueryNode`] for the syntax.
+///
+/// A page is a hit when the query holds on it, and every term that made it hold is
+/// highlighted.
+#[derive(Debug)]
+pub(crate) struct BooleanMatcher {
+    folding: SearchFolding,
+    /// The last parsed query; one job asks for the same query on every page.
+    parsed: Mutex<Option<(String, Option<QueryNode>)>>,
+}
+
+impl BooleanMatcher {
+    pub(crate) fn new(folding: SearchFolding) -> Self {
+        Self {
+            folding,
+            parsed: Mutex::new(None),
+        }
+    }
+
+    fn query(&self, query: &str) -> Option<QueryNode> {
+        let mut parsed = self
+            .parsed
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some((cached_query, node)) = parsed.as_ref()
+            && cached_query == query
+        {
+            return node.clone();
+        }
+        let node = parse_query(query).ok();
+        *parsed = Some((query.to_string(), node.clone()));
+        node
+    }
+
+    fn locate_term(&self, glyphs: &[TextGlyph], term: &str) -> Vec<SearchOccurrence> {
+        let prepared = prepare_contains_query(term, false, self.folding);
+        locate_occurrences(glyphs, &prepared, false, self.folding)
+    }
+
+    /// Occurrences that satisfy `node`, or `None` when it does not hold on the page.
+    fn evaluate(&self, node: &QueryNode, page: &PageWords<'_>) -> Option<Vec<SearchOccurrence>> {
+        match node {
+            QueryNode::Term(term) => {
+                let occurrences = self.locate_term(page.glyphs, term);
+                (!occurrences.is_empty()).then_some(occurrences)
+            }
+            QueryNode::And(operands) => {
+                let mut occurrences = Vec::new();
+                for operand in operands {
+                    occurrences.extend(self.evaluate(operand, page)?);
+                }
+                Some(occurrences)
+            }
+            QueryNode::Or(operands) => {
+                let mut held = false;
+                let mut occurrences = Vec::new();
+                for operand in operands {
+                    if let Some(found) = self.evaluate(operand, page) {
+                        held = true;
+                        occurrences.extend(found);
+                    }
+                }
+                held.then_some(occurrences)
+            }
+            QueryNode::Not(operand) => self.evaluate(operand, page).is_none().then(Vec::new),
+            QueryNode::Near {
+                left,
+                right,
+                distance,
+            } => {
+                let lefts = self.locate_term(page.glyphs, left);
+                let rights = self.locate_term(page.glyphs, right);
+                let mut occurrences = Vec::new();
+                for left in &lefts {
+                    let near = rights
+                        .iter()
+                        .filter(|right| page.word_distance(left, right) <= *distance)
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if !near.is_empty() {
+                        occurrences.push(left.clone());
+                        occurrences.extend(near);
+                    }
+                }
+                (!occurrences.is_empty()).then_some(occurrences)
+            }
+        }
+    }
+}
+
+impl SearchMatcher for BooleanMatcher {
+    fn prepare_query(&self, raw_query: &str) -> String {
+        raw_query.trim().to_string()
+    }
+
+    fn locate_matches(&self, page: &TextPage, prepared_query: &str) -> Vec<SearchOccurrence> {
+        let Some(node) = self.query(prepared_query) else {
+            return Vec::new();
+        };
+        let words = PageWords::new(&page.glyphs);
+        let mut occurrences = self.evaluate(&node, &words).unwrap_or_default();
+        occurrences.sort_by_key(|occurrence| (occurrence.match_start, occurrence.match_end));
+        occurrences.dedup_by_key(|occurrence| (occurrence.match_start, occurrence.match_end));
+        occurrences
+    }
+
+    fn reports_pages(&self) -> bool {
+        true
+    }
+}
+
+/// Word numbers of a page's glyphs, for measuring `NEAR` distances.
+struct PageWords<'a> {
+    glyphs: &'a [TextGlyph],
+    word_of_glyph: Vec<usize>,
+}
+
+impl<'a> PageWords<'a> {
+    fn new(glyphs: &'a [TextGlyph]) -> Self {
+        let mut word = 0;
+        let mut in_word = false;
+        let word_of_glyph = glyphs
+            .iter()
+            .map(|glyph| {
+                let is_word = !glyph.ch.is_whitespace();
+                if is_word && !in_word {
+                    word += 1;
+                }
+                in_word = is_word;
+                word
+            })
+            .collect();
+        Self {
+            glyphs,
+            word_of_glyph,
+        }
+    }
+
+    /// How many words on from `a` the nearer end of `b` is; adjacent words are 1 apart.
+    fn word_distance(&self, a: &SearchOccurrence, b: &SearchOccurrence) -> usize {
+        let (first, second) = if a.match_start <= b.match_start {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let first_end = self.word_of_glyph[first.match_end];
+        let second_start = self.word_of_glyph[second.match_start];
+        second_start.saturating_sub(first_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BooleanMatcher, QueryNode, boolean_query_error, parse_query};
+    use crate::backend::{PdfRect, TextGlyph, TextLayout, TextPage};
+    use crate::search::matcher::{SearchFolding, SearchMatcher};
+
+    fn term(text: &str) -> QueryNode {
+        QueryNode::Term(text.to_string())
+    }
+
+    #[test]
+    fn parse_query_applies_operator_precedence() {
+        assert_eq!(
+            parse_query("timeout retry OR \"connection reset\" NOT flaky"),
+            Ok(QueryNode::Or(vec![
+                QueryNode::And(vec![term("timeout"), term("retry")]),
+                QueryNode::And(vec![
+                    term("connection reset"),
+                    QueryNode::Not(Box::new(term("flaky"))),
+                ]),
+            ]))
+        );
+        assert_eq!(
+            parse_query("(timeout OR deadline) AND retry NEAR/5 backoff"),
+            Ok(QueryNode::And(vec![
+                QueryNode::Or(vec![term("timeout"), term("deadline")]),
+                QueryNode::Near {
+                    left: "retry".to_string(),
+                    right: "backoff".to_string(),
+                    distance: 5,
+                },
+            ]))
+        );
+        assert_eq!(
+            parse_query("and or near"),
+            Ok(QueryNode::And(vec![term("and"), term("or"), term("near"),]))
+        );
+    }
+
+    #[test]
+    fn boolean_query_error_explains_invalid_queries() {
+        assert_eq!(boolean_query_error("timeout AND retry"), None);
+        assert_eq!(
+            boolean_query_error("\"timeout").as_deref(),
+            Some("unclosed quote")
+        );
+        assert_eq!(
+            boolean_query_error("(timeout OR retry").as_deref(),
+            Some("missing )")
+        );
+        assert_eq!(
+            boolean_query_error("timeout AND").as_deref(),
+            Some("query ends before a term")
+        );
+        assert_eq!(
+            boolean_query_error("timeout NEAR/x retry").as_deref(),
+            Some("NEAR/x needs a positive word distance")
+        );
+        assert_eq!(
+            boolean_query_error("(a OR b) NEAR/2 c").as_deref(),
+            Some("NEAR joins two words or phrases")
+        );
+        assert_eq!(
+            boolean_query_error("NOT flaky").as_deref(),
+            Some("query needs a term that is not negated")
+        );
+    }
+
+    #[test]
+    fn locate_matches_highlights_every_contributing_term() {
+        let page = text_page("the retry after a timeout; retry again later");
+        let matcher = BooleanMatcher::new(SearchFolding::default());
+        let ranges = |query: &str| {
+            matcher
+                .locate_matches(&page, &matcher.prepare_query(query))
+                .iter()
+                .map(|occurrence| (occurrence.match_start, occurrence.match_end))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ranges("timeout AND retry"),
+            vec![(4, 8), (18, 24), (27, 31)]
+        );
+        assert_eq!(ranges("timeout OR missing"), vec![(18, 24)]);
+        assert_eq!(ranges("timeout AND missing"), Vec::new());
+        assert_eq!(ranges("timeout NOT missing"), vec![(18, 24)]);
+        assert_eq!(ranges("timeout NOT retry"), Vec::new());
+        assert_eq!(ranges("\"retry again\""), vec![(27, 37)]);
+        // "timeout" is one word before the second "retry" and three after the first.
+        assert_eq!(ranges("timeout NEAR/1 retry"), vec![(18, 24), (27, 31)]);
+        assert_eq!(
+            ranges("timeout NEAR/3 retry"),
+            vec![(4, 8), (18, 24), (27, 31)]
+        );
+        assert_eq!(ranges("the NEAR/2 later"), Vec::new());
+    }
+
+    fn text_page(text: &str) -> TextPage {
+        let glyphs = text
+            .chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                let x0 = index as f32 * 10.0;
+                TextGlyph {
+                    ch,
+                    bbox: Some(PdfRect {
+                        x0,
+                        y0: 20.0,
+                        x1: x0 + 8.0,
+                        y1: 32.0,
+                    }),
+                }
+            })
+            .collect();
+        TextPage {
+            width_pt: 500.0,
+            height_pt: 100.0,
+            glyphs,
+            dropped_glyphs: 0,
+            layout: TextLayout::default(),
+        }
+    }
+}
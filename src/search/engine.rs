@@ -1,4 +1,6 @@
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::runtime::{Builder, Handle, Runtime};
 use tokio::sync::mpsc::{
@@ -10,6 +12,7 @@ use crate::backend::{PdfRect, SharedPdfBackend, TextPage};
 use crate::error::{AppError, AppResult};
 
 use super::matcher::SearchMatcher;
+use super::text_index::SharedTextIndex;
 use super::worker::{
     GeometryJob, GeometryPriority, PrewarmJob, SearchJob, WorkerRequest, worker_main,
 };
@@ -20,6 +23,8 @@ pub struct SearchSnapshot {
     pub scanned_pages: usize,
     pub total_pages: usize,
     pub hit_pages: usize,
+    /// Time since the scan started, for the scan rate.
+    pub elapsed: Duration,
     pub done: bool,
 }
 
@@ -54,10 +59,20 @@ pub struct SearchOccurrence {
     pub snippet_match_end: Option<usize>,
 }
 
+/// One snippet for a whole page hit, with every occurrence in it marked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageSnippet {
+    pub text: String,
+    /// Byte ranges into `text`.
+    pub matches: Vec<Range<usize>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SearchPageHit {
     pub page: usize,
     pub occurrences: Vec<SearchOccurrence>,
+    /// Set when the matcher reports pages instead of single occurrences.
+    pub summary: Option<PageSnippet>,
 }
 
 pub struct SearchEngine {
@@ -110,10 +125,15 @@ impl Default for SearchEngine {
 
 impl SearchEngine {
     pub fn new() -> Self {
+        Self::with_text_index(None)
+    }
+
+    /// Engine whose worker reads and extends `text_index` before extracting page text.
+    pub(crate) fn with_text_index(text_index: Option<SharedTextIndex>) -> Self {
         let (request_tx, request_rx) = unbounded_channel();
         let (event_tx, event_rx) = unbounded_channel();
         let runtime = SearchWorkerRuntime::new();
-        let worker = runtime.spawn_blocking(move || worker_main(request_rx, event_tx));
+        let worker = runtime.spawn_blocking(move || worker_main(request_rx, event_tx, text_index));
 
         Self {
             request_tx,
@@ -186,6 +206,20 @@ impl SearchEngine {
             }));
     }
 
+    /// Finds matches on `pages` alone under a fresh generation, ahead of background work.
+    pub fn preview(
+        &mut self,
+        pdf: SharedPdfBackend,
+        query: impl Into<String>,
+        matcher: Arc<dyn SearchMatcher>,
+        pages: Vec<usize>,
+    ) -> u64 {
+        self.next_generation = self.next_generation.saturating_add(1);
+        let generation = self.next_generation;
+        self.resolve_geometry(pdf, generation, query, matcher, pages, true);
+        generation
+    }
+
     pub fn drain_events(&mut self) -> Vec<SearchEvent> {
         let mut drained = Vec::new();
 
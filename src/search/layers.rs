@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::backend::SharedPdfBackend;
+use crate::command::SearchMatcherKind;
+use crate::error::{AppError, AppResult};
+use crate::highlight::{HighlightSource, HighlightSpan, HighlightStyle};
+
+use super::engine::{SearchEngine, SearchEvent, SearchPageHit};
+use super::matcher::{SearchFolding, matcher_for_kind};
+use super::text_index::SharedTextIndex;
+
+const LAYER_COLOR_NAMES: [&str; HighlightStyle::LAYER_FILLS.len()] =
+    ["green", "pink", "cyan", "purple", "red"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighlightLayerEntry {
+    /// 1-based position, accepted by the layer commands in place of the name.
+    pub number: usize,
+    pub name: String,
+    pub query: String,
+    pub color: &'static str,
+    pub visible: bool,
+    /// Pages with a match, or `None` while the layer is being computed.
+    pub hit_pages: Option<usize>,
+}
+
+struct HighlightLayer {
+    id: u64,
+    name: String,
+    query: String,
+    matcher: SearchMatcherKind,
+    folding: SearchFolding,
+    color: usize,
+    visible: bool,
+    hits: Vec<SearchPageHit>,
+    /// Set until the worker has searched the current document for the layer.
+    stale: bool,
+}
+
+/// Searches pinned as named highlight layers.
+///
+/// Layers are searched one at a time on a worker of their own, so computing them never
+/// cancels the active search.
+#[derive(Default)]
+pub(crate) struct HighlightLayers {
+    layers: Vec<HighlightLayer>,
+    next_id: u64,
+    engine: Option<SearchEngine>,
+    pdf: Option<SharedPdfBackend>,
+    /// Layer id and generation of the query the worker is running.
+    running: Option<(u64, u64)>,
+}
+
+pub(crate) struct PinnedSearch {
+    pub name: String,
+    pub query: String,
+    pub matcher: SearchMatcherKind,
+    pub folding: SearchFolding,
+    /// Hits of a finished search; `None` has the layer searched again.
+    pub hits: Option<Vec<SearchPageHit>>,
+}
+
+impl HighlightLayers {
+    /// Adds a layer, or replaces the query of the layer that already has the name.
+    pub fn pin(&mut self, pinned: PinnedSearch, pdf: SharedPdfBackend) {
+        self.pdf = Some(pdf);
+        let stale = pinned.hits.is_none();
+        let hits = pinned.hits.unwrap_or_default();
+        if let Some(layer) = self
+            .layers
+            .iter_mut()
+            .find(|layer| layer.name == pinned.name)
+        {
+            if self.running.is_some_and(|(id, _)| id == layer.id) {
+                self.running = None;
+            }
+            layer.query = pinned.query;
+            layer.matcher = pinned.matcher;
+            layer.folding = pinned.folding;
+            layer.visible = true;
+            layer.hits = hits;
+            layer.stale = stale;
+            return;
+        }
+
+        self.next_id += 1;
+        let color = self.unused_color();
+        self.layers.push(HighlightLayer {
+            id: self.next_id,
+            name: pinned.name,
+            query: pinned.query,
+            matcher: pinned.matcher,
+            folding: pinned.folding,
+            color,
+            visible: true,
+            hits,
+            stale,
+        });
+    }
+
+    /// Shows a hidden layer or hides a shown one, returning its name and new visibility.
+    pub fn toggle(&mut self, selector: &str) -> AppResult<(String, bool)> {
+        let index = self.find(selector)?;
+        let layer = &mut self.layers[index];
+        layer.visible = !layer.visible;
+        Ok((layer.name.clone(), layer.visible))
+    }
+
+    pub fn remove(&mut self, selector: &str) -> AppResult<String> {
+        let index = self.find(selector)?;
+        let layer = self.layers.remove(index);
+        if self.running.is_some_and(|(id, _)| id == layer.id) {
+            self.running = None;
+        }
+        Ok(layer.name)
+    }
+
+    /// Searches every layer again in `pdf`, keeping the old matches up until the new ones arrive.
+    pub fn recompute(&mut self, pdf: SharedPdfBackend) {
+        // The worker keeps pages of the previous document, so a fresh one is started on demand.
+        self.engine = None;
+        self.running = None;
+        self.pdf = Some(pdf);
+        for layer in &mut self.layers {
+            layer.stale = true;
+        }
+    }
+
+    /// Collects finished layer searches and starts the next one; returns whether a layer changed.
+    pub fn on_background(&mut self, text_index: Option<&SharedTextIndex>) -> bool {
+        let mut changed = false;
+        let events = self
+            .engine
+            .as_mut()
+            .map(SearchEngine::drain_events)
+            .unwrap_or_default();
+        for event in events {
+            let SearchEvent::Completed {
+                generation, hits, ..
+            } = event
+            else {
+                continue;
+            };
+            let Some((id, running_generation)) = self.running else {
+                continue;
+            };
+            if generation != running_generation {
+                continue;
+            }
+            self.running = None;
+            if let Some(layer) = self.layers.iter_mut().find(|layer| layer.id == id) {
+                layer.hits = hits;
+                layer.stale = false;
+                changed = true;
+            }
+        }
+        self.start_next(text_index);
+        changed
+    }
+
+    fn start_next(&mut self, text_index: Option<&SharedTextIndex>) {
+        if self.running.is_some() {
+            return;
+        }
+        let Some(pdf) = self.pdf.clone() else {
+            return;
+        };
+        while let Some(layer) = self.layers.iter_mut().find(|layer| layer.stale) {
+            let engine = self
+                .engine
+                .get_or_insert_with(|| SearchEngine::with_text_index(text_index.cloned()));
+            let matcher = matcher_for_kind(layer.matcher, layer.folding);
+            match engine.submit(Arc::clone(&pdf), layer.query.clone(), matcher) {
+                Ok(generation) => {
+                    self.running = Some((layer.id, generation));
+                    return;
+                }
+                Err(_) => {
+                    layer.hits.clear();
+                    layer.stale = false;
+                }
+            }
+        }
+    }
+
+    pub fn entries(&self) -> Arc<[HighlightLayerEntry]> {
+        self.layers
+            .iter()
+            .enumerate()
+            .map(|(index, layer)| HighlightLayerEntry {
+                number: index + 1,
+                name: layer.name.clone(),
+                query: layer.query.clone(),
+                color: LAYER_COLOR_NAMES[layer.color],
+                visible: layer.visible,
+                hit_pages: (!layer.stale).then_some(layer.hits.len()),
+            })
+            .collect()
+    }
+
+    /// Highlights of the shown layers, earlier layers drawn first.
+    pub fn spans(&self, visible_pages: [Option<usize>; 2]) -> Vec<HighlightSpan> {
+        self.layers
+            .iter()
+            .filter(|layer| layer.visible)
+            .flat_map(|layer| {
+                let style = HighlightStyle::LAYER_FILLS[layer.color];
+                layer
+                    .hits
+                    .iter()
+                    .filter(|hit| visible_pages.contains(&Some(hit.page)))
+                    .flat_map(move |hit| {
+                        hit.occurrences.iter().filter_map(move |occurrence| {
+                            (!occurrence.rects.is_empty()).then_some(HighlightSpan {
+                                source: HighlightSource::Layer,
+                                page: hit.page,
+                                rects: occurrence.rects.clone(),
+                                style,
+                            })
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    /// Resolves a layer name, or failing that a 1-based layer number.
+    fn find(&self, selector: &str) -> AppResult<usize> {
+        let selector = selector.trim();
+        if let Some(index) = self.layers.iter().position(|layer| layer.name == selector) {
+            return Ok(index);
+        }
+        selector
+            .parse::<usize>()
+            .ok()
+            .filter(|number| (1..=self.layers.len()).contains(number))
+            .map(|number| number - 1)
+            .ok_or_else(|| AppError::invalid_argument(format!("no highlight layer '{selector}'")))
+    }
+
+    /// First color no layer uses, cycling through the palette once every color is taken.
+    fn unused_color(&self) -> usize {
+        let colors = HighlightStyle::LAYER_FILLS.len();
+        (0..colors)
+            .find(|color| self.layers.iter().all(|layer| layer.color != *color))
+            .unwrap_or(self.layers.len() % colors)
+    }
+}
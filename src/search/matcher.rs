@@ -1,53 +1,165 @@
-use std::sync::Arc;
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
 
-use crate::backend::{TextGlyph, TextPage};
+use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::backend::{PdfRect, TextGlyph, TextPage};
 use crate::command::SearchMatcherKind;
 use crate::highlight::geometry::merge_text_glyph_rects;
 
-use super::engine::SearchOccurrence;
+use super::boolean::BooleanMatcher;
+use super::engine::{PageSnippet, SearchOccurrence};
 
 pub trait SearchMatcher: Send + Sync {
     fn prepare_query(&self, raw_query: &str) -> String;
     fn locate_matches(&self, page: &TextPage, prepared_query: &str) -> Vec<SearchOccurrence>;
+    /// Whether results are listed once per page rather than once per occurrence.
+    fn reports_pages(&self) -> bool {
+        false
+    }
+}
+
+/// Differences between the query and the page text that substring search ignores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchFolding {
+    /// NFKC compatibility forms: ligatures such as "ﬃ", superscripts, circled and full-width
+    /// characters.
+    pub compatibility: bool,
+    /// Accents and other combining marks, so "resume" finds "résumé".
+    pub diacritics: bool,
+    /// Full-width Latin and half-width kana; already covered by `compatibility`.
+    pub width: bool,
+    /// A hyphen at the end of a line, so a word split across lines is found whole.
+    pub hyphenation: bool,
+}
+
+impl SearchFolding {
+    /// Matches the text exactly as extracted, apart from case.
+    pub const NONE: Self = Self {
+        compatibility: false,
+        diacritics: false,
+        width: false,
+        hyphenation: false,
+    };
+}
+
+impl Default for SearchFolding {
+    fn default() -> Self {
+        Self {
+            compatibility: true,
+            diacritics: true,
+            width: true,
+            hyphenation: true,
+        }
+    }
 }
 
-pub fn matcher_for_kind(kind: SearchMatcherKind) -> Arc<dyn SearchMatcher> {
-    Arc::new(ContainsMatcher {
-        case_sensitive: kind == SearchMatcherKind::ContainsSensitive,
-    })
+pub fn matcher_for_kind(kind: SearchMatcherKind, folding: SearchFolding) -> Arc<dyn SearchMatcher> {
+    match kind {
+        SearchMatcherKind::ContainsInsensitive | SearchMatcherKind::ContainsSensitive => {
+            Arc::new(ContainsMatcher {
+                case_sensitive: kind == SearchMatcherKind::ContainsSensitive,
+                folding,
+            })
+        }
+        SearchMatcherKind::Regex => Arc::new(RegexMatcher::default()),
+        SearchMatcherKind::Boolean => Arc::new(BooleanMatcher::new(folding)),
+    }
+}
+
+/// Why `pattern` is not a valid regex search, as one line for a notice.
+pub(crate) fn regex_pattern_error(pattern: &str) -> Option<String> {
+    let err = Regex::new(pattern).err()?.to_string();
+    // Syntax errors span several lines with a caret diagram; the last one names the problem.
+    let message = err.lines().last().unwrap_or_default().trim();
+    Some(message.trim_start_matches("error: ").to_string())
 }
 
 #[derive(Debug)]
 struct ContainsMatcher {
     case_sensitive: bool,
+    folding: SearchFolding,
 }
 
 impl SearchMatcher for ContainsMatcher {
     fn prepare_query(&self, raw_query: &str) -> String {
-        prepare_contains_query(raw_query, self.case_sensitive)
+        prepare_contains_query(raw_query, self.case_sensitive, self.folding)
     }
 
     fn locate_matches(&self, page: &TextPage, prepared_query: &str) -> Vec<SearchOccurrence> {
-        locate_occurrences(&page.glyphs, prepared_query, self.case_sensitive)
+        locate_occurrences(
+            &page.glyphs,
+            prepared_query,
+            self.case_sensitive,
+            self.folding,
+        )
     }
 }
 
-pub(crate) fn prepare_contains_query(raw_query: &str, case_sensitive: bool) -> String {
-    normalize_text_for_search(raw_query, case_sensitive, false)
+/// Matches a regular expression against the page text, case-sensitively unless the pattern
+/// opts out with `(?i)`. The text is not folded, so patterns see it as extracted.
+#[derive(Debug, Default)]
+struct RegexMatcher {
+    /// The last compiled pattern; one job asks for the same pattern on every page.
+    compiled: Mutex<Option<(String, Option<Regex>)>>,
+}
+
+impl RegexMatcher {
+    fn regex(&self, pattern: &str) -> Option<Regex> {
+        let mut compiled = self
+            .compiled
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some((cached_pattern, regex)) = compiled.as_ref()
+            && cached_pattern == pattern
+        {
+            return regex.clone();
+        }
+        let regex = Regex::new(pattern).ok();
+        *compiled = Some((pattern.to_string(), regex.clone()));
+        regex
+    }
+}
+
+impl SearchMatcher for RegexMatcher {
+    fn prepare_query(&self, raw_query: &str) -> String {
+        raw_query.to_string()
+    }
+
+    fn locate_matches(&self, page: &TextPage, prepared_query: &str) -> Vec<SearchOccurrence> {
+        if prepared_query.is_empty() {
+            return Vec::new();
+        }
+        let Some(regex) = self.regex(prepared_query) else {
+            return Vec::new();
+        };
+        locate_regex_occurrences(&page.glyphs, &regex)
+    }
+}
+
+pub(crate) fn prepare_contains_query(
+    raw_query: &str,
+    case_sensitive: bool,
+    folding: SearchFolding,
+) -> String {
+    normalize_text_for_search(raw_query, case_sensitive, false, folding)
 }
 
 pub(crate) fn locate_occurrences(
     glyphs: &[TextGlyph],
     prepared_query: &str,
     case_sensitive: bool,
+    folding: SearchFolding,
 ) -> Vec<SearchOccurrence> {
     let occurrences =
-        locate_occurrences_with_strategy(glyphs, prepared_query, case_sensitive, false);
+        locate_occurrences_with_strategy(glyphs, prepared_query, case_sensitive, false, folding);
     if !occurrences.is_empty() {
         return occurrences;
     }
 
-    locate_occurrences_with_strategy(glyphs, prepared_query, case_sensitive, true)
+    locate_occurrences_with_strategy(glyphs, prepared_query, case_sensitive, true, folding)
 }
 
 pub(crate) fn occurrence_highlight_unavailable(
@@ -74,6 +186,78 @@ pub(crate) fn apply_hit_snippet(occurrence: &mut SearchOccurrence, glyphs: &[Tex
     occurrence.snippet_match_end = snippet.match_end;
 }
 
+/// Glyphs of context shown on each side of a match in result snippets.
+const SNIPPET_CONTEXT_CHARS: usize = 16;
+
+/// Joins the context of each occurrence on a page into one snippet, merging occurrences whose
+/// context overlaps.
+pub(crate) fn build_page_snippet(
+    glyphs: &[TextGlyph],
+    occurrences: &[SearchOccurrence],
+) -> PageSnippet {
+    // Enough to fill a palette row; the page's occurrences are all highlighted on the page.
+    const MAX_WINDOWS: usize = 4;
+
+    let mut matches = occurrences
+        .iter()
+        .filter(|occurrence| {
+            occurrence.match_start < glyphs.len() && occurrence.match_start <= occurrence.match_end
+        })
+        .map(|occurrence| occurrence.match_start..occurrence.match_end.min(glyphs.len() - 1) + 1)
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|range| range.start);
+
+    let mut windows: Vec<(Range<usize>, Vec<Range<usize>>)> = Vec::new();
+    for range in matches {
+        let context = range.start.saturating_sub(SNIPPET_CONTEXT_CHARS)
+            ..(range.end + SNIPPET_CONTEXT_CHARS).min(glyphs.len());
+        if let Some((window, ranges)) = windows.last_mut()
+            && context.start <= window.end
+        {
+            window.end = window.end.max(context.end);
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        } else if windows.len() == MAX_WINDOWS {
+            break;
+        } else {
+            windows.push((context, vec![range]));
+        }
+    }
+
+    let chars = |range: Range<usize>| glyphs[range].iter().map(|glyph| glyph.ch);
+    let mut text = String::new();
+    let mut snippet_matches = Vec::new();
+    for (index, (window, ranges)) in windows.iter().enumerate() {
+        if index > 0 {
+            text.push_str(" … ");
+        } else if window.start > 0 {
+            text.push('…');
+        }
+        let mut cursor = window.start;
+        for range in ranges {
+            text.extend(chars(cursor..range.start));
+            let start = text.len();
+            text.extend(chars(range.clone()));
+            snippet_matches.push(start..text.len());
+            cursor = range.end;
+        }
+        text.extend(chars(cursor..window.end));
+    }
+    if windows
+        .last()
+        .is_some_and(|(window, _)| window.end < glyphs.len())
+    {
+        text.push('…');
+    }
+
+    PageSnippet {
+        text,
+        matches: snippet_matches,
+    }
+}
+
 struct SnippetPresentation {
     text: String,
     match_start: Option<usize>,
@@ -85,8 +269,6 @@ fn build_hit_snippet(
     match_start: usize,
     match_end: usize,
 ) -> SnippetPresentation {
-    const CONTEXT_CHARS: usize = 16;
-
     if glyphs.is_empty() || match_start >= glyphs.len() || match_end < match_start {
         return SnippetPresentation {
             text: String::new(),
@@ -96,9 +278,9 @@ fn build_hit_snippet(
     }
 
     let match_end = match_end.min(glyphs.len() - 1);
-    let context_start = match_start.saturating_sub(CONTEXT_CHARS);
+    let context_start = match_start.saturating_sub(SNIPPET_CONTEXT_CHARS);
     let context_end = match_end
-        .saturating_add(CONTEXT_CHARS)
+        .saturating_add(SNIPPET_CONTEXT_CHARS)
         .saturating_add(1)
         .min(glyphs.len());
 
@@ -162,18 +344,21 @@ fn locate_occurrences_with_strategy(
     prepared_query: &str,
     case_sensitive: bool,
     ignore_whitespace: bool,
+    folding: SearchFolding,
 ) -> Vec<SearchOccurrence> {
     if prepared_query.is_empty() {
         return Vec::new();
     }
 
     let (search_text, char_map) =
-        normalize_glyphs_for_search(glyphs, case_sensitive, ignore_whitespace);
+        normalize_glyphs_for_search(glyphs, case_sensitive, ignore_whitespace, folding);
     if search_text.is_empty() {
         return Vec::new();
     }
 
-    let query_text = normalize_text_for_search(prepared_query, true, ignore_whitespace);
+    // The prepared query is folded already; this only drops whitespace when ignoring it.
+    let query_text =
+        normalize_text_for_search(prepared_query, true, ignore_whitespace, SearchFolding::NONE);
     if query_text.is_empty() || query_text.len() > search_text.len() {
         return Vec::new();
     }
@@ -201,6 +386,20 @@ fn locate_occurrences_with_strategy(
         );
         let match_char_start =
             match_char_start.expect("str::find returned a non-character-boundary offset");
+        let match_end_byte = match_byte + query_text.len();
+        // Decomposed text keeps accents as separate marks; "cafe" must not end inside "café".
+        if !folding.diacritics
+            && search_text[match_end_byte..]
+                .chars()
+                .next()
+                .is_some_and(is_combining_mark)
+        {
+            cursor_byte = char_byte_offsets
+                .get(match_char_start + 1)
+                .copied()
+                .unwrap_or(search_text.len() + 1);
+            continue;
+        }
         let glyph_start = char_map[match_char_start];
         let glyph_end = char_map[match_char_start + query_char_len - 1];
         let rects = merge_text_glyph_rects(&glyphs[glyph_start..=glyph_end]);
@@ -212,25 +411,68 @@ fn locate_occurrences_with_strategy(
             snippet_match_start: None,
             snippet_match_end: None,
         });
-        cursor_byte = match_byte + query_text.len();
+        cursor_byte = match_end_byte;
     }
 
     occurrences
 }
 
+fn locate_regex_occurrences(glyphs: &[TextGlyph], regex: &Regex) -> Vec<SearchOccurrence> {
+    let (search_text, char_map) =
+        normalize_glyphs_for_search(glyphs, true, false, SearchFolding::NONE);
+    if search_text.is_empty() {
+        return Vec::new();
+    }
+
+    let char_byte_offsets: Vec<usize> = search_text
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .collect();
+    regex
+        .find_iter(&search_text)
+        .filter(|found| !found.is_empty())
+        .map(|found| {
+            // Regex matches always start and end on character boundaries.
+            let char_start = char_byte_offsets.partition_point(|&offset| offset < found.start());
+            let char_end = char_byte_offsets.partition_point(|&offset| offset < found.end());
+            let glyph_start = char_map[char_start];
+            let glyph_end = char_map[char_end - 1];
+            SearchOccurrence {
+                match_start: glyph_start,
+                match_end: glyph_end,
+                rects: merge_text_glyph_rects(&glyphs[glyph_start..=glyph_end]),
+                snippet: String::new(),
+                snippet_match_start: None,
+                snippet_match_end: None,
+            }
+        })
+        .collect()
+}
+
 fn normalize_glyphs_for_search(
     glyphs: &[TextGlyph],
     case_sensitive: bool,
     ignore_whitespace: bool,
+    folding: SearchFolding,
 ) -> (String, Vec<usize>) {
     let mut search_text = String::new();
     let mut char_map = Vec::new();
+    let joined = if folding.hyphenation {
+        line_break_hyphens(glyphs)
+    } else {
+        Vec::new()
+    };
 
     for (glyph_index, glyph) in glyphs.iter().enumerate() {
         if ignore_whitespace && glyph.ch.is_whitespace() {
             continue;
         }
-        push_normalized_chars(glyph.ch, case_sensitive, |normalized| {
+        if joined.get(glyph_index).copied().unwrap_or(false) {
+            continue;
+        }
+        // Every char folded out of a glyph maps back to it, so ligature matches cover the
+        // whole ligature glyph.
+        push_normalized_chars(glyph.ch, case_sensitive, folding, |normalized| {
             if !ignore_whitespace || !normalized.is_whitespace() {
                 search_text.push(normalized);
                 char_map.push(glyph_index);
@@ -241,23 +483,85 @@ fn normalize_glyphs_for_search(
     (search_text, char_map)
 }
 
-fn push_normalized_chars(ch: char, case_sensitive: bool, mut push: impl FnMut(char)) {
-    if case_sensitive {
-        push(ch);
-    } else {
-        for normalized in ch.to_lowercase() {
-            push(normalized);
+/// Marks line-end hyphens between two letters, and the whitespace after them, as left out of
+/// the search text.
+fn line_break_hyphens(glyphs: &[TextGlyph]) -> Vec<bool> {
+    let mut joined = vec![false; glyphs.len()];
+    for (index, glyph) in glyphs.iter().enumerate() {
+        if !matches!(glyph.ch, '-' | '\u{00AD}' | '\u{2010}') {
+            continue;
+        }
+        let Some(before) = index.checked_sub(1).map(|before| &glyphs[before]) else {
+            continue;
+        };
+        let Some(next) = (index + 1..glyphs.len()).find(|&next| !glyphs[next].ch.is_whitespace())
+        else {
+            continue;
+        };
+        if !before.ch.is_alphabetic() || !glyphs[next].ch.is_alphabetic() {
+            continue;
+        }
+        if let (Some(hyphen), Some(next_bbox)) = (glyph.bbox, glyphs[next].bbox)
+            && starts_next_line(hyphen, next_bbox)
+        {
+            joined[index..next].fill(true);
         }
     }
+    joined
+}
+
+/// Whether text continues at `next` on a new line below `hyphen` rather than on its line.
+fn starts_next_line(hyphen: PdfRect, next: PdfRect) -> bool {
+    let shares_band = next.y0 < hyphen.y1 && hyphen.y0 < next.y1;
+    next.x0 < hyphen.x0 && !shares_band
 }
 
-fn normalize_text_for_search(text: &str, case_sensitive: bool, ignore_whitespace: bool) -> String {
+fn push_normalized_chars(
+    ch: char,
+    case_sensitive: bool,
+    folding: SearchFolding,
+    mut push: impl FnMut(char),
+) {
+    let mut push_folded = |part: char| {
+        if folding.diacritics && is_combining_mark(part) {
+            return;
+        }
+        if case_sensitive {
+            push(part);
+        } else {
+            for normalized in part.to_lowercase() {
+                push(normalized);
+            }
+        }
+    };
+
+    // Query and page text are folded one char at a time, into decomposed form, so they agree
+    // however the PDF encoded an accented letter.
+    if folding.compatibility || (folding.width && is_width_variant(ch)) {
+        ch.nfkd().for_each(&mut push_folded);
+    } else if folding.diacritics {
+        ch.nfd().for_each(&mut push_folded);
+    } else {
+        push_folded(ch);
+    }
+}
+
+fn is_width_variant(ch: char) -> bool {
+    matches!(ch, '\u{3000}' | '\u{FF01}'..='\u{FFEE}')
+}
+
+fn normalize_text_for_search(
+    text: &str,
+    case_sensitive: bool,
+    ignore_whitespace: bool,
+    folding: SearchFolding,
+) -> String {
     let mut normalized_text = String::with_capacity(text.len());
     for ch in text.chars() {
         if ignore_whitespace && ch.is_whitespace() {
             continue;
         }
-        push_normalized_chars(ch, case_sensitive, |normalized| {
+        push_normalized_chars(ch, case_sensitive, folding, |normalized| {
             if !ignore_whitespace || !normalized.is_whitespace() {
                 normalized_text.push(normalized);
             }
@@ -268,8 +572,12 @@ fn normalize_text_for_search(text: &str, case_sensitive: bool, ignore_whitespace
 
 #[cfg(test)]
 mod tests {
-    use super::{apply_hit_snippet, locate_occurrences, normalize_text_for_search};
-    use crate::backend::{PdfRect, TextGlyph};
+    use super::{
+        SearchFolding, apply_hit_snippet, build_page_snippet, locate_occurrences, matcher_for_kind,
+        normalize_text_for_search, prepare_contains_query, regex_pattern_error,
+    };
+    use crate::backend::{PdfRect, TextGlyph, TextLayout, TextPage};
+    use crate::command::SearchMatcherKind;
     use crate::search::engine::SearchOccurrence;
 
     #[test]
@@ -291,7 +599,7 @@ mod tests {
             glyph('r', 140.0, 20.0, 148.0, 32.0),
         ];
 
-        let occurrences = locate_occurrences(&glyphs, "foobar", false);
+        let occurrences = locate_occurrences(&glyphs, "foobar", false, SearchFolding::default());
 
         assert_eq!(occurrences.len(), 1);
         assert_eq!(occurrences[0].match_start, 0);
@@ -309,7 +617,7 @@ mod tests {
             glyph('r', 60.0, 20.0, 68.0, 32.0),
         ];
 
-        let occurrences = locate_occurrences(&glyphs, "foo bar", false);
+        let occurrences = locate_occurrences(&glyphs, "foo bar", false, SearchFolding::default());
 
         assert_eq!(occurrences.len(), 1);
         assert_eq!(occurrences[0].match_start, 0);
@@ -325,7 +633,7 @@ mod tests {
             glyph('β', 40.0, 20.0, 48.0, 32.0),
         ];
 
-        let occurrences = locate_occurrences(&glyphs, "βa", true);
+        let occurrences = locate_occurrences(&glyphs, "βa", true, SearchFolding::default());
 
         assert_eq!(occurrences.len(), 1);
         assert_eq!(occurrences[0].match_start, 1);
@@ -334,14 +642,92 @@ mod tests {
 
     #[test]
     fn normalize_text_for_search_preserves_search_semantics() {
-        assert_eq!(normalize_text_for_search("İ", false, false), "i\u{307}");
-        assert_eq!(normalize_text_for_search("İ", true, false), "İ");
+        let none = SearchFolding::NONE;
+        assert_eq!(
+            normalize_text_for_search("İ", false, false, none),
+            "i\u{307}"
+        );
+        assert_eq!(normalize_text_for_search("İ", true, false, none), "İ");
         assert_eq!(
-            normalize_text_for_search("A \tİ\nB", false, true),
+            normalize_text_for_search("A \tİ\nB", false, true, none),
             "ai\u{307}b"
         );
     }
 
+    #[test]
+    fn normalize_text_for_search_folds_compatibility_diacritics_and_width() {
+        let folding = SearchFolding::default();
+        assert_eq!(
+            normalize_text_for_search("Oﬃce Résumé ＡＢＣ１ ｶﾀ", false, false, folding),
+            "office resume abc1 カタ"
+        );
+        assert_eq!(
+            normalize_text_for_search(
+                "ﬁ Ｘ é",
+                true,
+                false,
+                SearchFolding {
+                    width: true,
+                    ..SearchFolding::NONE
+                }
+            ),
+            "ﬁ X é"
+        );
+    }
+
+    #[test]
+    fn locate_occurrences_maps_folded_matches_to_original_glyphs() {
+        let glyphs = glyphs_on_line("The oﬃce résumé, ＰＤＦ", 20.0);
+        let folding = SearchFolding::default();
+
+        let office = locate_occurrences(&glyphs, "office", false, folding);
+        assert_eq!((office[0].match_start, office[0].match_end), (4, 7));
+        let resume = locate_occurrences(&glyphs, "resume", false, folding);
+        assert_eq!((resume[0].match_start, resume[0].match_end), (9, 14));
+        let pdf = locate_occurrences(&glyphs, "pdf", false, folding);
+        assert_eq!((pdf[0].match_start, pdf[0].match_end), (17, 19));
+
+        assert!(locate_occurrences(&glyphs, "office", false, SearchFolding::NONE).is_empty());
+    }
+
+    #[test]
+    fn locate_occurrences_keeps_accents_apart_when_diacritics_are_not_folded() {
+        let folding = SearchFolding {
+            diacritics: false,
+            ..SearchFolding::default()
+        };
+        let glyphs = glyphs_on_line("cafe\u{301} café", 20.0);
+
+        let query = prepare_contains_query("Café", false, folding);
+        let accented = locate_occurrences(&glyphs, &query, false, folding);
+        assert_eq!(
+            accented
+                .iter()
+                .map(|occurrence| (occurrence.match_start, occurrence.match_end))
+                .collect::<Vec<_>>(),
+            vec![(0, 4), (6, 9)]
+        );
+        assert!(locate_occurrences(&glyphs, "cafe", false, folding).is_empty());
+    }
+
+    #[test]
+    fn locate_occurrences_joins_words_hyphenated_across_lines() {
+        let mut glyphs = glyphs_on_line("a hyphen-", 20.0);
+        glyphs.push(glyph(' ', 100.0, 20.0, 104.0, 32.0));
+        glyphs.extend(glyphs_on_line("ated well-known", 40.0));
+
+        let joined = locate_occurrences(&glyphs, "hyphenated", false, SearchFolding::default());
+        assert_eq!(joined.len(), 1);
+        assert_eq!((joined[0].match_start, joined[0].match_end), (2, 13));
+        assert_eq!(joined[0].rects.len(), 2);
+
+        // Hyphens inside a line stay part of the text.
+        assert!(
+            locate_occurrences(&glyphs, "wellknown", false, SearchFolding::default()).is_empty()
+        );
+        assert!(locate_occurrences(&glyphs, "hyphenated", false, SearchFolding::NONE).is_empty());
+    }
+
     #[test]
     fn apply_hit_snippet_uses_original_glyph_boundaries_after_case_fold_expansion() {
         let glyphs = vec![
@@ -369,6 +755,89 @@ mod tests {
         assert_eq!(occurrence.snippet_match_end, Some('İ'.len_utf8()));
     }
 
+    #[test]
+    fn build_page_snippet_marks_every_occurrence_and_elides_between_them() {
+        let text = format!("timeout{} retry", " x".repeat(30));
+        let glyphs = glyphs_on_line(&text, 20.0);
+        let occurrence = |match_start, match_end| SearchOccurrence {
+            match_start,
+            match_end,
+            rects: Vec::new(),
+            snippet: String::new(),
+            snippet_match_start: None,
+            snippet_match_end: None,
+        };
+
+        let snippet = build_page_snippet(&glyphs, &[occurrence(68, 72), occurrence(0, 6)]);
+
+        assert_eq!(
+            snippet.text,
+            "timeout x x x x x x x x … x x x x x x x x retry"
+        );
+        let matched = snippet
+            .matches
+            .iter()
+            .map(|range| &snippet.text[range.clone()])
+            .collect::<Vec<_>>();
+        assert_eq!(matched, vec!["timeout", "retry"]);
+
+        let close = build_page_snippet(&glyphs, &[occurrence(0, 6), occurrence(2, 3)]);
+        assert_eq!(close.matches, vec![0..7]);
+        assert!(close.text.ends_with('…'));
+    }
+
+    #[test]
+    fn regex_matcher_maps_matches_back_to_glyph_ranges() {
+        let glyphs = "REQ-12 and REQ-7, req-9 ÅREQ-300"
+            .chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                let x0 = index as f32 * 10.0;
+                glyph(ch, x0, 20.0, x0 + 8.0, 32.0)
+            })
+            .collect::<Vec<_>>();
+        let page = TextPage {
+            width_pt: 400.0,
+            height_pt: 100.0,
+            glyphs,
+            dropped_glyphs: 0,
+            layout: TextLayout::default(),
+        };
+        let matcher = matcher_for_kind(SearchMatcherKind::Regex, SearchFolding::default());
+        let query = matcher.prepare_query("REQ-[0-9]+");
+
+        let ranges = matcher
+            .locate_matches(&page, &query)
+            .iter()
+            .map(|occurrence| (occurrence.match_start, occurrence.match_end))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![(0, 5), (11, 15), (25, 31)]);
+
+        let insensitive = matcher.locate_matches(&page, "(?i)req-9");
+        assert_eq!(insensitive.len(), 1);
+        assert_eq!(insensitive[0].match_start, 18);
+        assert_eq!(insensitive[0].rects.len(), 1);
+    }
+
+    #[test]
+    fn regex_pattern_error_reports_one_line() {
+        assert_eq!(regex_pattern_error("ISO \\d{4,5}"), None);
+        assert_eq!(
+            regex_pattern_error("REQ-[0-9").as_deref(),
+            Some("unclosed character class")
+        );
+    }
+
+    fn glyphs_on_line(text: &str, y0: f32) -> Vec<TextGlyph> {
+        text.chars()
+            .enumerate()
+            .map(|(index, ch)| {
+                let x0 = 10.0 + index as f32 * 10.0;
+                glyph(ch, x0, y0, x0 + 8.0, y0 + 12.0)
+            })
+            .collect()
+    }
+
     fn glyph(ch: char, x0: f32, y0: f32, x1: f32, y1: f32) -> TextGlyph {
         TextGlyph {
             ch,
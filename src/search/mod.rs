@@ -1,12 +1,16 @@
+mod boolean;
 pub mod engine;
+pub mod layers;
 pub mod matcher;
 pub mod palette;
 pub mod state;
+pub(crate) mod text_index;
 pub(crate) mod worker;
 
 use crate::app::AppState;
 use crate::event::AppEvent;
 use crate::extension::Extension;
+pub use palette::HighlightLayersPaletteProvider;
 pub use palette::SearchPaletteProvider;
 pub use palette::SearchResultsPaletteProvider;
 pub use state::{SearchCommandPort, SearchRuntime, SearchUiSnapshot};
@@ -11,10 +11,12 @@ use crate::palette::{
     PaletteTextPart,
 };
 
+use super::layers::HighlightLayerEntry;
 use super::state::SearchPaletteEntry;
 
 pub struct SearchPaletteProvider;
 pub struct SearchResultsPaletteProvider;
+pub struct HighlightLayersPaletteProvider;
 
 impl PaletteProvider for SearchPaletteProvider {
     fn kind(&self) -> PaletteKind {
@@ -78,6 +80,26 @@ impl PaletteProvider for SearchPaletteProvider {
                     SearchMatcherKind::ContainsSensitive.id().to_string(),
                 ),
             },
+            PaletteCandidate {
+                id: SearchMatcherKind::Regex.id().to_string(),
+                left: vec![PaletteTextPart::primary("Regular expression")],
+                right: Vec::new(),
+                search_texts: vec![
+                    PaletteSearchText::new("regular expression"),
+                    PaletteSearchText::new(SearchMatcherKind::Regex.id()),
+                ],
+                payload: PalettePayload::Opaque(SearchMatcherKind::Regex.id().to_string()),
+            },
+            PaletteCandidate {
+                id: SearchMatcherKind::Boolean.id().to_string(),
+                left: vec![PaletteTextPart::primary("Boolean (AND, OR, NOT, NEAR/n)")],
+                right: Vec::new(),
+                search_texts: vec![
+                    PaletteSearchText::new("boolean"),
+                    PaletteSearchText::new("proximity near"),
+                ],
+                payload: PalettePayload::Opaque(SearchMatcherKind::Boolean.id().to_string()),
+            },
         ])
     }
 
@@ -94,23 +116,31 @@ impl PaletteProvider for SearchPaletteProvider {
             });
         }
 
-        let matcher = selected
-            .and_then(|c| match &c.payload {
-                PalettePayload::Opaque(id) => SearchMatcherKind::parse(id),
-                PalettePayload::None => None,
-            })
-            .unwrap_or(SearchMatcherKind::ContainsInsensitive);
-
         Ok(PaletteSubmitEffect::Dispatch {
             command: Command::SubmitSearch {
                 query: query.to_string(),
-                matcher,
+                matcher: selected_matcher(selected),
             },
             history_record: Some(InputHistoryRecord::SearchQuery(query.to_string())),
             next: PalettePostAction::Close,
         })
     }
 
+    fn on_change(
+        &self,
+        ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> Option<Command> {
+        Some(Command::PreviewSearch {
+            query: ctx.input.trim().to_string(),
+            matcher: selected_matcher(selected),
+        })
+    }
+
+    fn on_cancel(&self, _ctx: &PaletteContext<'_>) -> Option<Command> {
+        Some(Command::EndSearchPreview)
+    }
+
     fn assistive_text(
         &self,
         _ctx: &PaletteContext<'_>,
@@ -235,6 +265,105 @@ impl PaletteProvider for SearchResultsPaletteProvider {
     }
 }
 
+impl PaletteProvider for HighlightLayersPaletteProvider {
+    fn kind(&self) -> PaletteKind {
+        PaletteKind::HighlightLayers
+    }
+
+    fn title(&self, _ctx: &PaletteContext<'_>) -> String {
+        "Highlight Layers".to_string()
+    }
+
+    fn input_mode(&self) -> PaletteInputMode {
+        PaletteInputMode::FilterCandidates
+    }
+
+    fn list(&self, ctx: &PaletteContext<'_>) -> AppResult<Vec<PaletteCandidate>> {
+        Ok(ctx
+            .extensions
+            .search
+            .layers
+            .iter()
+            .map(layer_candidate)
+            .collect())
+    }
+
+    fn on_submit(
+        &self,
+        _ctx: &PaletteContext<'_>,
+        selected: Option<&PaletteCandidate>,
+    ) -> AppResult<PaletteSubmitEffect> {
+        let Some(PalettePayload::Opaque(name)) = selected.map(|candidate| &candidate.payload)
+        else {
+            return Ok(PaletteSubmitEffect::Close);
+        };
+
+        Ok(PaletteSubmitEffect::Dispatch {
+            command: Command::ToggleHighlightLayer {
+                layer: name.clone(),
+            },
+            history_record: None,
+            next: PalettePostAction::Close,
+        })
+    }
+
+    fn assistive_text(
+        &self,
+        ctx: &PaletteContext<'_>,
+        _selected: Option<&PaletteCandidate>,
+    ) -> Option<String> {
+        if ctx.extensions.search.layers.is_empty() {
+            return Some("No pinned searches".to_string());
+        }
+
+        let enter = format_shortcut_key(ShortcutKey::key(crossterm::event::KeyCode::Enter));
+        Some(format!("{enter} show/hide layer"))
+    }
+}
+
+fn layer_candidate(entry: &HighlightLayerEntry) -> PaletteCandidate {
+    let mut details = vec![entry.color.to_string()];
+    details.push(match entry.hit_pages {
+        Some(1) => "1 page".to_string(),
+        Some(pages) => format!("{pages} pages"),
+        None => "searching".to_string(),
+    });
+    if !entry.visible {
+        details.push("hidden".to_string());
+    }
+
+    let mut search_texts = vec![
+        PaletteSearchText::new(entry.number.to_string()),
+        PaletteSearchText::new(entry.name.clone()),
+        PaletteSearchText::new(entry.color),
+    ];
+    let mut left = vec![
+        PaletteTextPart::secondary(format!("{}  ", entry.number)),
+        PaletteTextPart::primary(entry.name.clone()),
+    ];
+    if entry.query != entry.name {
+        left.push(PaletteTextPart::secondary(format!("  {}", entry.query)));
+        search_texts.push(PaletteSearchText::new(entry.query.clone()));
+    }
+
+    PaletteCandidate {
+        id: format!("layer-{}", entry.name),
+        left,
+        right: vec![PaletteTextPart::secondary(details.join(" · "))],
+        search_texts,
+        payload: PalettePayload::Opaque(entry.name.clone()),
+    }
+}
+
+fn selected_matcher(selected: Option<&PaletteCandidate>) -> SearchMatcherKind {
+    selected
+        .and_then(|c| match &c.payload {
+            PalettePayload::Opaque(id) => SearchMatcherKind::parse(id),
+            PalettePayload::None => None,
+        })
+        .unwrap_or(SearchMatcherKind::ContainsInsensitive)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SearchResultMatchBucket {
     Index,
@@ -266,35 +395,33 @@ fn build_result_candidates(entries: &[SearchPaletteEntry]) -> Vec<PaletteCandida
 
 fn snippet_parts(entry: &SearchPaletteEntry) -> Vec<PaletteTextPart> {
     let snippet = entry.snippet.as_str();
-    let (Some(start), Some(end)) = (entry.snippet_match_start, entry.snippet_match_end) else {
-        return vec![PaletteTextPart::primary(snippet)];
-    };
-    if start >= end || end > snippet.len() {
-        return vec![PaletteTextPart::primary(snippet)];
+    let mut parts = Vec::new();
+    let mut cursor = 0;
+    for range in &entry.snippet_matches {
+        let (start, end) = (range.start, range.end);
+        if start < cursor || start >= end || end > snippet.len() {
+            return vec![PaletteTextPart::primary(snippet)];
+        }
+        if !snippet.is_char_boundary(start) || !snippet.is_char_boundary(end) {
+            return vec![PaletteTextPart::primary(snippet)];
+        }
+
+        let before = &snippet[cursor..start];
+        if !before.is_empty() {
+            parts.push(PaletteTextPart::secondary(before));
+        }
+        parts.push(PaletteTextPart::highlight(&snippet[start..end]));
+        cursor = end;
     }
-    if !snippet.is_char_boundary(start) || !snippet.is_char_boundary(end) {
+    if parts.is_empty() {
         return vec![PaletteTextPart::primary(snippet)];
     }
 
-    let before = &snippet[..start];
-    let matched = &snippet[start..end];
-    let after = &snippet[end..];
-
-    let mut parts = Vec::new();
-    if !before.is_empty() {
-        parts.push(PaletteTextPart::secondary(before));
-    }
-    if !matched.is_empty() {
-        parts.push(PaletteTextPart::highlight(matched));
-    }
+    let after = &snippet[cursor..];
     if !after.is_empty() {
         parts.push(PaletteTextPart::secondary(after));
     }
-    if parts.is_empty() {
-        vec![PaletteTextPart::primary(snippet)]
-    } else {
-        parts
-    }
+    parts
 }
 
 fn result_candidate(entry: &SearchPaletteEntry) -> PaletteCandidate {
@@ -398,8 +525,7 @@ mod tests {
                     index: 1,
                     page: 4,
                     snippet: "…foo needle bar…".to_string(),
-                    snippet_match_start: Some(7),
-                    snippet_match_end: Some(13),
+                    snippet_matches: std::iter::once(7..13).collect(),
                 }]
                 .into(),
                 ..Default::default()
@@ -439,15 +565,13 @@ mod tests {
                         index: 1,
                         page: 8,
                         snippet: "other".to_string(),
-                        snippet_match_start: None,
-                        snippet_match_end: None,
+                        snippet_matches: Vec::new(),
                     },
                     SearchPaletteEntry {
                         index: 2,
                         page: 5,
                         snippet: "right".to_string(),
-                        snippet_match_start: None,
-                        snippet_match_end: None,
+                        snippet_matches: Vec::new(),
                     },
                 ]
                 .into(),
@@ -486,15 +610,13 @@ mod tests {
                         index: 1,
                         page: 0,
                         snippet: "cover".to_string(),
-                        snippet_match_start: None,
-                        snippet_match_end: None,
+                        snippet_matches: Vec::new(),
                     },
                     SearchPaletteEntry {
                         index: 2,
                         page: 1,
                         snippet: "next".to_string(),
-                        snippet_match_start: None,
-                        snippet_match_end: None,
+                        snippet_matches: Vec::new(),
                     },
                 ]
                 .into(),
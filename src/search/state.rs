@@ -1,19 +1,37 @@
+use std::iter;
+use std::mem;
+use std::ops::Range;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::app::{AppState, NoticeAction, PaletteRequest};
+use crate::app::{AppState, Mode, NoticeAction, PaletteRequest};
 use crate::backend::SharedPdfBackend;
 use crate::command::{CommandOutcome, SearchMatcherKind};
 use crate::error::AppResult;
 use crate::highlight::{HighlightOverlaySnapshot, HighlightSource, HighlightSpan, HighlightStyle};
 use crate::palette::{PaletteKind, PaletteOpenPayload};
 
+use super::boolean::boolean_query_error;
 use super::engine::{SearchEngine, SearchEvent, SearchPageHit};
-use super::matcher::matcher_for_kind;
+use super::layers::{HighlightLayerEntry, HighlightLayers, PinnedSearch};
+use super::matcher::{SearchFolding, matcher_for_kind, regex_pattern_error};
+use super::text_index::{SharedTextIndex, lock_text_index};
 
-#[derive(Default)]
 pub struct SearchRuntime {
     state: SearchState,
     engine: SearchEngine,
+    text_index: Option<SharedTextIndex>,
+    /// Document last handed to the worker, whose index is discarded when a reload changes the
+    /// same file.
+    document: Option<(PathBuf, u64)>,
+    layers: HighlightLayers,
+}
+
+impl Default for SearchRuntime {
+    fn default() -> Self {
+        Self::with_text_index(None, SearchFolding::default(), true)
+    }
 }
 
 pub struct SearchCommandPort<'a> {
@@ -39,10 +57,50 @@ impl<'a> SearchCommandPort<'a> {
         self.runtime.submit(app, pdf, query, matcher)
     }
 
+    pub(crate) fn preview(
+        &mut self,
+        app: &mut AppState,
+        pdf: SharedPdfBackend,
+        query: String,
+        matcher: SearchMatcherKind,
+    ) -> (CommandOutcome, NoticeAction) {
+        self.runtime.state.preview(app, pdf, query, matcher)
+    }
+
+    pub(crate) fn end_preview(&mut self, app: &mut AppState) -> (CommandOutcome, NoticeAction) {
+        self.runtime.state.end_preview(app)
+    }
+
     pub(crate) fn open_results_palette(&mut self) -> Option<PaletteRequest> {
         self.runtime.open_results_palette()
     }
 
+    pub(crate) fn pin(
+        &mut self,
+        pdf: SharedPdfBackend,
+        name: Option<String>,
+    ) -> (CommandOutcome, NoticeAction) {
+        self.runtime.pin(pdf, name)
+    }
+
+    pub(crate) fn open_layers_palette(&mut self) -> PaletteRequest {
+        self.runtime.open_layers_palette()
+    }
+
+    pub(crate) fn toggle_layer(
+        &mut self,
+        layer: &str,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.runtime.toggle_layer(layer)
+    }
+
+    pub(crate) fn remove_layer(
+        &mut self,
+        layer: &str,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.runtime.remove_layer(layer)
+    }
+
     pub(crate) fn goto_result(
         &mut self,
         app: &mut AppState,
@@ -69,9 +127,48 @@ impl<'a> SearchCommandPort<'a> {
 pub struct SearchUiSnapshot {
     pub active: bool,
     pub results_entries: Arc<[SearchPaletteEntry]>,
+    pub layers: Arc<[HighlightLayerEntry]>,
 }
 
 impl SearchRuntime {
+    fn with_text_index(
+        text_index: Option<SharedTextIndex>,
+        folding: SearchFolding,
+        incremental: bool,
+    ) -> Self {
+        Self {
+            state: SearchState {
+                folding,
+                incremental,
+                ..SearchState::default()
+            },
+            engine: SearchEngine::with_text_index(text_index.clone()),
+            text_index,
+            document: None,
+            layers: HighlightLayers::default(),
+        }
+    }
+
+    /// Persists extracted page text in `text_index` and reuses it across sessions.
+    pub(crate) fn attach_text_index(&mut self, text_index: SharedTextIndex) {
+        *self = self.rebuilt(Some(text_index));
+    }
+
+    /// Fresh runtime with the same settings, for a new text index or document.
+    fn rebuilt(&self, text_index: Option<SharedTextIndex>) -> Self {
+        Self::with_text_index(text_index, self.state.folding, self.state.incremental)
+    }
+
+    /// Sets which text differences later searches ignore.
+    pub(crate) fn set_folding(&mut self, folding: SearchFolding) {
+        self.state.folding = folding;
+    }
+
+    /// Sets whether the search palette previews matches while typing.
+    pub(crate) fn set_incremental(&mut self, incremental: bool) {
+        self.state.incremental = incremental;
+    }
+
     pub fn open_palette(&mut self) -> PaletteRequest {
         self.state.open_palette()
     }
@@ -91,6 +188,63 @@ impl SearchRuntime {
         self.state.open_results_palette()
     }
 
+    /// Keeps the submitted search highlighted as a layer named `name`, or after its query.
+    pub fn pin(
+        &mut self,
+        pdf: SharedPdfBackend,
+        name: Option<String>,
+    ) -> (CommandOutcome, NoticeAction) {
+        if self.state.query.is_empty() {
+            return (
+                CommandOutcome::Noop,
+                NoticeAction::warning("no search to pin"),
+            );
+        }
+        let name = name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| self.state.query.clone());
+        self.layers.pin(
+            PinnedSearch {
+                name: name.clone(),
+                query: self.state.query.clone(),
+                matcher: self.state.matcher,
+                folding: self.state.folding,
+                hits: (!self.state.in_progress).then(|| self.state.hits.clone()),
+            },
+            pdf,
+        );
+        self.layers.on_background(self.text_index.as_ref());
+        (
+            CommandOutcome::Applied,
+            NoticeAction::info(format!("pinned layer {name}")),
+        )
+    }
+
+    pub fn open_layers_palette(&mut self) -> PaletteRequest {
+        PaletteRequest::Open {
+            kind: PaletteKind::HighlightLayers,
+            payload: None,
+        }
+    }
+
+    pub fn toggle_layer(&mut self, layer: &str) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let (name, visible) = self.layers.toggle(layer)?;
+        let state = if visible { "shown" } else { "hidden" };
+        Ok((
+            CommandOutcome::Applied,
+            NoticeAction::info(format!("layer {name} {state}")),
+        ))
+    }
+
+    pub fn remove_layer(&mut self, layer: &str) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let name = self.layers.remove(layer)?;
+        Ok((
+            CommandOutcome::Applied,
+            NoticeAction::info(format!("removed layer {name}")),
+        ))
+    }
+
     pub fn goto_result(
         &mut self,
         app: &mut AppState,
@@ -113,10 +267,13 @@ impl SearchRuntime {
     }
 
     pub fn on_background(&mut self, app: &mut AppState) -> bool {
-        self.state.on_background(app, &mut self.engine)
+        let search_changed = self.state.on_background(app, &mut self.engine);
+        let layers_changed = self.layers.on_background(self.text_index.as_ref());
+        search_changed || layers_changed
     }
 
     pub fn prewarm(&mut self, pdf: SharedPdfBackend) {
+        self.document = Some((pdf.path().to_path_buf(), pdf.doc_id()));
         self.engine.prewarm(pdf);
     }
 
@@ -129,7 +286,7 @@ impl SearchRuntime {
             return;
         }
         let pages = self.state.geometry_priority_pages(visible_pages, false);
-        let matcher = matcher_for_kind(self.state.matcher);
+        let matcher = matcher_for_kind(self.state.matcher, self.state.folding);
         self.engine.resolve_geometry(
             pdf,
             self.state.generation,
@@ -164,14 +321,21 @@ impl SearchRuntime {
         &self,
         visible_pages: [Option<usize>; 2],
     ) -> HighlightOverlaySnapshot {
-        self.state
-            .highlight_overlay_for_visible_pages(visible_pages)
+        // Pinned layers go first so the active search is drawn over them.
+        let mut spans = self.layers.spans(visible_pages);
+        spans.extend(
+            self.state
+                .highlight_overlay_for_visible_pages(visible_pages)
+                .spans,
+        );
+        HighlightOverlaySnapshot::new(spans)
     }
 
     pub fn ui_snapshot(&self) -> SearchUiSnapshot {
         SearchUiSnapshot {
             active: self.is_active(),
             results_entries: self.palette_entries(),
+            layers: self.layers.entries(),
         }
     }
 
@@ -179,12 +343,27 @@ impl SearchRuntime {
         let active_search = self
             .is_active()
             .then(|| (self.query().to_string(), self.matcher()));
-        *self = Self::default();
+        let mut layers = mem::take(&mut self.layers);
+        let text_index = self.text_index.take();
+        if let Some(index) = &text_index
+            && let Some((old_path, old_doc_id)) = &self.document
+            && old_path == pdf.path()
+            && *old_doc_id != pdf.doc_id()
+        {
+            lock_text_index(index).remove_doc(*old_doc_id);
+        }
+        *self = self.rebuilt(text_index);
         self.prewarm(Arc::clone(&pdf));
+        layers.recompute(Arc::clone(&pdf));
+        self.layers = layers;
+        self.layers.on_background(self.text_index.as_ref());
         if let Some((query, matcher)) = active_search
             && let Err(err) = self.submit(app, Arc::clone(&pdf), query, matcher)
         {
-            *self = Self::default();
+            let layers = mem::take(&mut self.layers);
+            let text_index = self.text_index.take();
+            *self = self.rebuilt(text_index);
+            self.layers = layers;
             self.prewarm(pdf);
             app.set_warning_notice(format!("Could not restore search after reload: {err}"));
         }
@@ -196,14 +375,15 @@ pub struct SearchPaletteEntry {
     pub index: usize,
     pub page: usize,
     pub snippet: String,
-    pub snippet_match_start: Option<usize>,
-    pub snippet_match_end: Option<usize>,
+    /// Byte ranges of the matched text in `snippet`.
+    pub snippet_matches: Vec<Range<usize>>,
 }
 
 #[derive(Clone)]
 pub struct SearchState {
     query: String,
     matcher: SearchMatcherKind,
+    folding: SearchFolding,
     generation: u64,
     in_progress: bool,
     /// Number of scanned pages observed so far while scanning.
@@ -213,6 +393,8 @@ pub struct SearchState {
     /// Number of matched pages observed so far while scanning.
     /// This is progress-oriented and may change before completion.
     hit_pages_progress: usize,
+    /// Pages scanned per second so far, once the worker has reported a scanned page.
+    scan_rate: Option<f64>,
     /// Final matched page list. Occurrence rects may be filled after completion by geometry events.
     hits: Vec<SearchPageHit>,
     /// Final matched occurrences for the results palette, ordered by page/match order.
@@ -220,6 +402,23 @@ pub struct SearchState {
     current_hit: Option<usize>,
     last_error: Option<String>,
     active_pdf: Option<SharedPdfBackend>,
+    incremental: bool,
+    /// Matches of the query being typed in the search palette. While set, they replace the
+    /// submitted search's highlights.
+    preview: Option<SearchPreview>,
+}
+
+#[derive(Clone)]
+struct SearchPreview {
+    query: String,
+    matcher: SearchMatcherKind,
+    pdf: SharedPdfBackend,
+    /// When the query goes to the worker; `None` once sent or when there is nothing to search.
+    due: Option<Instant>,
+    generation: Option<u64>,
+    hits: Vec<SearchPageHit>,
+    /// Page shown when the preview started, restored if the palette is dismissed.
+    origin_page: usize,
 }
 
 impl Default for SearchState {
@@ -227,16 +426,20 @@ impl Default for SearchState {
         Self {
             query: String::new(),
             matcher: SearchMatcherKind::ContainsInsensitive,
+            folding: SearchFolding::default(),
             generation: 0,
             in_progress: false,
             scanned_pages_progress: 0,
             total_pages: 0,
             hit_pages_progress: 0,
+            scan_rate: None,
             hits: Vec::new(),
             palette_entries: Arc::from([]),
             current_hit: None,
             last_error: None,
             active_pdf: None,
+            incremental: true,
+            preview: None,
         }
     }
 }
@@ -244,6 +447,8 @@ impl Default for SearchState {
 impl SearchState {
     const HIGHLIGHT_UNAVAILABLE_NOTICE: &str = "some search highlights are unavailable";
     const PAGE_SKIPPED_NOTICE: &str = "some pages could not be searched";
+    /// Typing pause before the preview searches the visible pages.
+    const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(120);
 
     pub fn open_palette(&mut self) -> PaletteRequest {
         let payload = if self.query.is_empty() {
@@ -278,6 +483,7 @@ impl SearchState {
         query: String,
         matcher: SearchMatcherKind,
     ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.preview = None;
         let query = query.trim().to_string();
         if query.is_empty() {
             self.generation = search_engine.cancel(Arc::clone(&pdf))?;
@@ -286,8 +492,11 @@ impl SearchState {
             self.clear_results();
             return Ok((CommandOutcome::Noop, NoticeAction::Clear));
         }
+        if let Some(message) = query_error(&query, matcher) {
+            return Ok((CommandOutcome::Noop, NoticeAction::warning(message)));
+        }
 
-        let search_matcher = matcher_for_kind(matcher);
+        let search_matcher = matcher_for_kind(matcher, self.folding);
         let generation = search_engine.submit(Arc::clone(&pdf), query.clone(), search_matcher)?;
 
         self.query = query;
@@ -297,6 +506,7 @@ impl SearchState {
         self.scanned_pages_progress = 0;
         self.total_pages = pdf.page_count();
         self.hit_pages_progress = 0;
+        self.scan_rate = None;
         self.hits.clear();
         self.palette_entries = Arc::from([]);
         self.current_hit = None;
@@ -305,6 +515,106 @@ impl SearchState {
         Ok((CommandOutcome::Applied, NoticeAction::Clear))
     }
 
+    /// Schedules a search of the visible pages for `query`, replacing the pending one.
+    pub fn preview(
+        &mut self,
+        app: &AppState,
+        pdf: SharedPdfBackend,
+        query: String,
+        matcher: SearchMatcherKind,
+    ) -> (CommandOutcome, NoticeAction) {
+        if !self.incremental {
+            return (CommandOutcome::Noop, NoticeAction::Keep);
+        }
+        let query = query.trim().to_string();
+        let searchable = !query.is_empty() && query_error(&query, matcher).is_none();
+        let (origin_page, hits) = match self.preview.take() {
+            // Earlier matches stay up until the new ones arrive, so typing does not flicker.
+            Some(previous) if searchable => (previous.origin_page, previous.hits),
+            Some(previous) => (previous.origin_page, Vec::new()),
+            None => (app.current_page, Vec::new()),
+        };
+        self.preview = Some(SearchPreview {
+            query,
+            matcher,
+            pdf,
+            due: searchable.then(|| Instant::now() + Self::PREVIEW_DEBOUNCE),
+            generation: None,
+            hits,
+            origin_page,
+        });
+        (CommandOutcome::Applied, NoticeAction::Keep)
+    }
+
+    /// Drops the preview, bringing back the submitted search and the page it started on.
+    pub fn end_preview(&mut self, app: &mut AppState) -> (CommandOutcome, NoticeAction) {
+        let Some(preview) = self.preview.take() else {
+            return (CommandOutcome::Noop, NoticeAction::Keep);
+        };
+        app.current_page = preview.origin_page;
+        (CommandOutcome::Applied, NoticeAction::Keep)
+    }
+
+    /// Sends a preview whose debounce has elapsed to the worker.
+    fn flush_preview(&mut self, app: &AppState, search_engine: &mut SearchEngine) {
+        let Some(preview) = self.preview.as_mut() else {
+            return;
+        };
+        if preview.due.is_none_or(|due| Instant::now() < due) {
+            return;
+        }
+        preview.due = None;
+        let pages = app
+            .visible_page_slots(preview.pdf.page_count())
+            .existing_pages()
+            .into_iter()
+            .flatten()
+            .collect();
+        preview.generation = Some(search_engine.preview(
+            Arc::clone(&preview.pdf),
+            preview.query.clone(),
+            matcher_for_kind(preview.matcher, self.folding),
+            pages,
+        ));
+    }
+
+    /// Applies worker results meant for the preview and returns the other events.
+    fn apply_preview_events(
+        &mut self,
+        events: Vec<SearchEvent>,
+        changed: &mut bool,
+    ) -> Vec<SearchEvent> {
+        let Some(preview) = self.preview.as_mut() else {
+            return events;
+        };
+        let Some(preview_generation) = preview.generation else {
+            return events;
+        };
+        let mut rest = Vec::new();
+        for event in events {
+            match event {
+                SearchEvent::GeometryResolved {
+                    generation,
+                    page,
+                    occurrences,
+                    ..
+                } if generation == preview_generation => {
+                    preview.hits.retain(|hit| hit.page != page);
+                    if !occurrences.is_empty() {
+                        preview.hits.push(SearchPageHit {
+                            page,
+                            occurrences,
+                            summary: None,
+                        });
+                    }
+                    *changed = true;
+                }
+                event => rest.push(event),
+            }
+        }
+        rest
+    }
+
     pub fn next_hit(&mut self, app: &mut AppState) -> (CommandOutcome, NoticeAction) {
         self.move_hit(app, true)
     }
@@ -354,17 +664,24 @@ impl SearchState {
     }
 
     pub fn on_background(&mut self, app: &mut AppState, search_engine: &mut SearchEngine) -> bool {
+        let mut changed = false;
+        // A palette closed without `end_preview`, e.g. by opening another one, ends the preview.
+        if self.preview.is_some() && app.mode != Mode::Palette {
+            self.preview = None;
+            changed = true;
+        }
+        self.flush_preview(app, search_engine);
         let events = search_engine.drain_events();
+        let events = self.apply_preview_events(events, &mut changed);
         if events.is_empty() {
-            return false;
+            return changed;
         }
         // If search is inactive (e.g. canceled), drain pending worker events without
         // changing state/message. This avoids "search complete (0 hits)" flash after cancel.
         if self.query.is_empty() {
-            return false;
+            return changed;
         }
 
-        let mut changed = false;
         for event in events {
             match event {
                 SearchEvent::Snapshot(snapshot) => {
@@ -374,6 +691,9 @@ impl SearchState {
                     self.scanned_pages_progress = snapshot.scanned_pages;
                     self.total_pages = snapshot.total_pages;
                     self.hit_pages_progress = snapshot.hit_pages;
+                    let elapsed = snapshot.elapsed.as_secs_f64();
+                    self.scan_rate = (snapshot.scanned_pages > 0 && elapsed > 0.0)
+                        .then(|| snapshot.scanned_pages as f64 / elapsed);
                     self.in_progress = true;
                     changed = true;
                 }
@@ -395,7 +715,7 @@ impl SearchState {
                     if !pages.is_empty()
                         && let Some(pdf) = &self.active_pdf
                     {
-                        let matcher = matcher_for_kind(self.matcher);
+                        let matcher = matcher_for_kind(self.matcher, self.folding);
                         search_engine.resolve_geometry(
                             Arc::clone(pdf),
                             self.generation,
@@ -473,7 +793,20 @@ impl SearchState {
             ));
         }
 
-        Some(format!("SEARCH {} hits", self.hit_pages_progress))
+        let hits = format!("SEARCH {} hits", self.hit_pages_progress);
+        match self.scan_rate.filter(|_| self.in_progress) {
+            Some(rate) => {
+                let remaining = self.total_pages.saturating_sub(self.scanned_pages_progress);
+                let eta = (remaining as f64 / rate).ceil() as u64;
+                Some(format!(
+                    "{hits} {}/{} {rate:.0} pages/s {} left",
+                    self.scanned_pages_progress,
+                    self.total_pages,
+                    format_eta(eta)
+                ))
+            }
+            None => Some(hits),
+        }
     }
 
     pub fn palette_entries(&self) -> Arc<[SearchPaletteEntry]> {
@@ -558,26 +891,14 @@ impl SearchState {
         &self,
         visible_pages: [Option<usize>; 2],
     ) -> HighlightOverlaySnapshot {
+        if let Some(preview) = &self.preview {
+            return HighlightOverlaySnapshot::new(hit_spans(&preview.hits, visible_pages));
+        }
         if self.query.is_empty() {
             return HighlightOverlaySnapshot::default();
         }
 
-        let spans = self
-            .hits
-            .iter()
-            .filter(|hit| visible_pages.contains(&Some(hit.page)))
-            .flat_map(|hit| {
-                hit.occurrences.iter().filter_map(move |occurrence| {
-                    (!occurrence.rects.is_empty()).then_some(HighlightSpan {
-                        source: HighlightSource::Search,
-                        page: hit.page,
-                        rects: occurrence.rects.clone(),
-                        style: HighlightStyle::SEARCH_HIT,
-                    })
-                })
-            })
-            .collect();
-        HighlightOverlaySnapshot::new(spans)
+        HighlightOverlaySnapshot::new(hit_spans(&self.hits, visible_pages))
     }
 
     fn clear_results(&mut self) {
@@ -585,6 +906,7 @@ impl SearchState {
         self.scanned_pages_progress = 0;
         self.total_pages = 0;
         self.hit_pages_progress = 0;
+        self.scan_rate = None;
         self.hits.clear();
         self.palette_entries = Arc::from([]);
         self.current_hit = None;
@@ -593,6 +915,35 @@ impl SearchState {
     }
 }
 
+/// Why `query` cannot be searched with `matcher`, if it cannot.
+fn query_error(query: &str, matcher: SearchMatcherKind) -> Option<String> {
+    match matcher {
+        SearchMatcherKind::Regex => {
+            regex_pattern_error(query).map(|message| format!("invalid regex: {message}"))
+        }
+        SearchMatcherKind::Boolean => {
+            boolean_query_error(query).map(|message| format!("invalid query: {message}"))
+        }
+        SearchMatcherKind::ContainsInsensitive | SearchMatcherKind::ContainsSensitive => None,
+    }
+}
+
+fn hit_spans(hits: &[SearchPageHit], visible_pages: [Option<usize>; 2]) -> Vec<HighlightSpan> {
+    hits.iter()
+        .filter(|hit| visible_pages.contains(&Some(hit.page)))
+        .flat_map(|hit| {
+            hit.occurrences.iter().filter_map(move |occurrence| {
+                (!occurrence.rects.is_empty()).then_some(HighlightSpan {
+                    source: HighlightSource::Search,
+                    page: hit.page,
+                    rects: occurrence.rects.clone(),
+                    style: HighlightStyle::SEARCH_HIT,
+                })
+            })
+        })
+        .collect()
+}
+
 fn push_unique_page(pages: &mut Vec<usize>, page: usize) {
     if !pages.contains(&page) {
         pages.push(page);
@@ -602,16 +953,30 @@ fn push_unique_page(pages: &mut Vec<usize>, page: usize) {
 fn build_palette_entries(hits: &[SearchPageHit]) -> Arc<[SearchPaletteEntry]> {
     Arc::from(
         hits.iter()
-            .flat_map(|hit| {
+            .flat_map(|hit| -> Vec<SearchPaletteEntry> {
+                if let Some(summary) = &hit.summary {
+                    return vec![SearchPaletteEntry {
+                        index: 0,
+                        page: hit.page,
+                        snippet: summary.text.clone(),
+                        snippet_matches: summary.matches.clone(),
+                    }];
+                }
                 hit.occurrences
                     .iter()
-                    .map(move |occurrence| SearchPaletteEntry {
+                    .map(|occurrence| SearchPaletteEntry {
                         index: 0,
                         page: hit.page,
                         snippet: occurrence.snippet.clone(),
-                        snippet_match_start: occurrence.snippet_match_start,
-                        snippet_match_end: occurrence.snippet_match_end,
+                        snippet_matches: match (
+                            occurrence.snippet_match_start,
+                            occurrence.snippet_match_end,
+                        ) {
+                            (Some(start), Some(end)) => iter::once(start..end).collect(),
+                            _ => Vec::new(),
+                        },
                     })
+                    .collect()
             })
             .enumerate()
             .map(|(idx, mut entry)| {
@@ -622,21 +987,34 @@ fn build_palette_entries(hits: &[SearchPageHit]) -> Arc<[SearchPaletteEntry]> {
     )
 }
 
+fn format_eta(seconds: u64) -> String {
+    if seconds < 60 {
+        format!("{seconds}s")
+    } else {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::fs;
     use std::path::{Path, PathBuf};
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
-    use crate::app::{AppState, NoticeAction, NoticeLevel, PageLayoutMode, PaletteRequest};
-    use crate::backend::{PdfBackend, RgbaFrame, SharedPdfBackend, TextPage};
+    use crate::app::{AppState, Mode, NoticeAction, NoticeLevel, PageLayoutMode, PaletteRequest};
+    use crate::backend::test_support::{TextStubPdf, unique_temp_path};
+    use crate::backend::{PdfBackend, PdfRect, RgbaFrame, SharedPdfBackend, TextLayout, TextPage};
     use crate::command::{CommandOutcome, SearchMatcherKind};
+    use crate::highlight::HighlightSource;
     use crate::palette::{PaletteKind, PaletteOpenPayload};
-    use crate::search::engine::{SearchEngine, SearchPageHit};
+    use crate::search::engine::{PageSnippet, SearchEngine, SearchOccurrence, SearchPageHit};
+    use crate::search::text_index::{TextIndexStore, lock_text_index};
 
-    use super::SearchState;
+    use super::{SearchRuntime, SearchState, build_palette_entries};
 
     struct StubPdf {
         path: PathBuf,
+        doc_id: u64,
         page_count: usize,
     }
 
@@ -644,6 +1022,7 @@ mod tests {
         fn new(page_count: usize) -> Self {
             Self {
                 path: PathBuf::from("stub.pdf"),
+                doc_id: 9,
                 page_count,
             }
         }
@@ -655,7 +1034,7 @@ mod tests {
         }
 
         fn doc_id(&self) -> u64 {
-            9
+            self.doc_id
         }
 
         fn page_count(&self) -> usize {
@@ -679,6 +1058,7 @@ mod tests {
                 height_pt: 792.0,
                 glyphs: Vec::new(),
                 dropped_glyphs: 0,
+                layout: TextLayout::default(),
             })
         }
 
@@ -704,6 +1084,7 @@ mod tests {
                         .map(|ch| crate::backend::TextGlyph { ch, bbox: None })
                         .collect(),
                     dropped_glyphs: text.chars().count(),
+                    layout: TextLayout::default(),
                 },
             }
         }
@@ -769,6 +1150,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn submit_rejects_an_invalid_regex_without_starting_a_search() {
+        let mut state = SearchState::default();
+        let mut app = AppState::default();
+        let pdf = Arc::new(StubPdf::new(5)) as SharedPdfBackend;
+        let mut engine = SearchEngine::new();
+
+        let (outcome, notice) = state
+            .submit(
+                &mut app,
+                Arc::clone(&pdf),
+                &mut engine,
+                "REQ-(".to_string(),
+                SearchMatcherKind::Regex,
+            )
+            .expect("submit should succeed");
+
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert_eq!(
+            notice,
+            NoticeAction::warning("invalid regex: unclosed group")
+        );
+        assert!(!state.is_active());
+    }
+
+    #[test]
+    fn reload_of_changed_document_discards_its_text_index() {
+        let dir = unique_temp_path("search_reload_text_index");
+        let index = Arc::new(Mutex::new(
+            TextIndexStore::open(&dir, u64::MAX).expect("text index should open"),
+        ));
+        let page = StubPdf::new(1)
+            .extract_text_page(0)
+            .expect("stub text should extract");
+        lock_text_index(&index)
+            .store(9, 0, &page)
+            .expect("page text should be stored");
+        let mut runtime = SearchRuntime::default();
+        runtime.attach_text_index(Arc::clone(&index));
+        let mut app = AppState::default();
+
+        runtime.prewarm(Arc::new(StubPdf::new(0)));
+        runtime.on_document_reloaded(&mut app, Arc::new(StubPdf::new(0)));
+        assert!(lock_text_index(&index).contains(9, 0));
+
+        let changed = StubPdf {
+            doc_id: 10,
+            ..StubPdf::new(0)
+        };
+        runtime.on_document_reloaded(&mut app, Arc::new(changed));
+        assert!(!lock_text_index(&index).contains(9, 0));
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+
     #[test]
     fn open_palette_includes_query_and_matcher_in_payload() {
         let mut state = SearchState {
@@ -935,6 +1371,29 @@ mod tests {
         assert_eq!(state.status_bar_segment(), Some("SEARCH 2/3".to_string()));
     }
 
+    #[test]
+    fn status_bar_segment_shows_scan_rate_and_time_left_while_scanning() {
+        let mut state = SearchState {
+            query: "needle".to_string(),
+            in_progress: true,
+            scanned_pages_progress: 120,
+            total_pages: 9120,
+            hit_pages_progress: 3,
+            scan_rate: Some(80.0),
+            ..SearchState::default()
+        };
+        assert_eq!(
+            state.status_bar_segment(),
+            Some("SEARCH 3 hits 120/9120 80 pages/s 1m53s left".to_string())
+        );
+
+        state.in_progress = false;
+        assert_eq!(
+            state.status_bar_segment(),
+            Some("SEARCH 3 hits".to_string())
+        );
+    }
+
     #[test]
     fn on_background_ignores_synthetic_events_after_cancel() {
         let mut state = SearchState::default();
@@ -1003,6 +1462,167 @@ mod tests {
         assert_eq!(notice.message, SearchState::HIGHLIGHT_UNAVAILABLE_NOTICE);
     }
 
+    #[test]
+    fn preview_highlights_visible_matches_until_dismissed() {
+        let submitted = SearchPageHit {
+            page: 1,
+            occurrences: vec![SearchOccurrence {
+                match_start: 0,
+                match_end: 3,
+                rects: vec![PdfRect {
+                    x0: 0.0,
+                    y0: 0.0,
+                    x1: 1.0,
+                    y1: 1.0,
+                }],
+                snippet: "old".to_string(),
+                snippet_match_start: Some(0),
+                snippet_match_end: Some(3),
+            }],
+            summary: None,
+        };
+        let mut state = SearchState {
+            query: "old".to_string(),
+            hits: vec![submitted],
+            ..SearchState::default()
+        };
+        let mut app = AppState {
+            mode: Mode::Palette,
+            current_page: 1,
+            ..AppState::default()
+        };
+        let pdf = Arc::new(TextStubPdf::new(&[
+            "needle",
+            "a needle, another needle",
+            "needle",
+        ])) as SharedPdfBackend;
+        let mut engine = SearchEngine::new();
+        let submitted_overlay = state.highlight_overlay_for_visible_pages([Some(1), None]);
+
+        let (outcome, _) = state.preview(
+            &app,
+            Arc::clone(&pdf),
+            "needle".to_string(),
+            SearchMatcherKind::ContainsInsensitive,
+        );
+        assert_eq!(outcome, CommandOutcome::Applied);
+
+        let timeout = std::time::Duration::from_secs(3);
+        let start = std::time::Instant::now();
+        while state
+            .preview
+            .as_ref()
+            .is_some_and(|preview| preview.hits.is_empty())
+        {
+            let _ = state.on_background(&mut app, &mut engine);
+            assert!(start.elapsed() <= timeout, "timed out waiting for preview");
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let overlay = state.highlight_overlay_for_visible_pages([Some(1), None]);
+        assert_eq!(overlay.spans.len(), 2);
+        assert!(overlay.spans.iter().all(|span| span.page == 1));
+        assert_eq!(state.query, "old");
+
+        app.current_page = 2;
+        let (outcome, _) = state.end_preview(&mut app);
+        assert_eq!(outcome, CommandOutcome::Applied);
+        assert_eq!(app.current_page, 1);
+        assert_eq!(
+            state.highlight_overlay_for_visible_pages([Some(1), None]),
+            submitted_overlay
+        );
+    }
+
+    #[test]
+    fn preview_is_ignored_when_incremental_search_is_off() {
+        let mut state = SearchState {
+            incremental: false,
+            ..SearchState::default()
+        };
+        let app = AppState::default();
+        let pdf = Arc::new(StubPdf::new(1)) as SharedPdfBackend;
+
+        let (outcome, _) = state.preview(
+            &app,
+            pdf,
+            "needle".to_string(),
+            SearchMatcherKind::ContainsInsensitive,
+        );
+
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert!(state.preview.is_none());
+    }
+
+    fn wait_for_runtime(
+        runtime: &mut SearchRuntime,
+        app: &mut AppState,
+        mut done: impl FnMut(&SearchRuntime) -> bool,
+    ) {
+        let timeout = std::time::Duration::from_secs(3);
+        let start = std::time::Instant::now();
+        while !done(runtime) {
+            let _ = runtime.on_background(app);
+            assert!(start.elapsed() <= timeout, "timed out waiting for search");
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn pinned_layers_stay_highlighted_and_are_recomputed_on_reload() {
+        let mut app = AppState::default();
+        let pdf = Arc::new(TextStubPdf::new(&["input to output", "output"])) as SharedPdfBackend;
+        let mut runtime = SearchRuntime::default();
+        runtime
+            .submit(
+                &mut app,
+                Arc::clone(&pdf),
+                "input".to_string(),
+                SearchMatcherKind::ContainsInsensitive,
+            )
+            .expect("search should start");
+        wait_for_runtime(&mut runtime, &mut app, |runtime| !runtime.state.in_progress);
+        runtime.pin(Arc::clone(&pdf), None);
+        runtime
+            .submit(
+                &mut app,
+                Arc::clone(&pdf),
+                "output".to_string(),
+                SearchMatcherKind::ContainsInsensitive,
+            )
+            .expect("search should start");
+        wait_for_runtime(&mut runtime, &mut app, |runtime| !runtime.state.in_progress);
+
+        let sources = |runtime: &SearchRuntime, page: usize| {
+            runtime
+                .highlight_overlay_for_visible_pages([Some(page), None])
+                .spans
+                .iter()
+                .map(|span| span.source)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            sources(&runtime, 0),
+            vec![HighlightSource::Layer, HighlightSource::Search]
+        );
+
+        runtime.toggle_layer("input").expect("layer should exist");
+        assert_eq!(sources(&runtime, 0), vec![HighlightSource::Search]);
+        runtime.toggle_layer("1").expect("layer should exist");
+        assert!(runtime.remove_layer("2").is_err());
+
+        let reloaded = Arc::new(TextStubPdf::new(&["output", "input"])) as SharedPdfBackend;
+        runtime.on_document_reloaded(&mut app, reloaded);
+        wait_for_runtime(&mut runtime, &mut app, |runtime| {
+            runtime.ui_snapshot().layers[0].hit_pages.is_some() && !runtime.state.in_progress
+        });
+        assert_eq!(sources(&runtime, 0), vec![HighlightSource::Search]);
+        assert_eq!(sources(&runtime, 1), vec![HighlightSource::Layer]);
+
+        runtime.remove_layer("input").expect("layer should exist");
+        assert!(runtime.ui_snapshot().layers.is_empty());
+    }
+
     #[test]
     fn next_hit_keeps_active_error_notice_when_no_hits_exist() {
         let mut state = SearchState {
@@ -1027,9 +1647,58 @@ mod tests {
         SearchPageHit {
             page,
             occurrences: Vec::new(),
+            summary: None,
         }
     }
 
+    #[test]
+    fn build_palette_entries_lists_summarized_hits_once_per_page() {
+        let occurrence = |snippet: &str| SearchOccurrence {
+            match_start: 0,
+            match_end: 0,
+            rects: Vec::new(),
+            snippet: snippet.to_string(),
+            snippet_match_start: Some(0),
+            snippet_match_end: Some(1),
+        };
+        let hits = vec![
+            SearchPageHit {
+                page: 2,
+                occurrences: vec![occurrence("a"), occurrence("b")],
+                summary: None,
+            },
+            SearchPageHit {
+                page: 5,
+                occurrences: vec![occurrence("timeout"), occurrence("retry")],
+                summary: Some(PageSnippet {
+                    text: "timeout … retry".to_string(),
+                    matches: vec![0..7, 12..17],
+                }),
+            },
+        ];
+
+        let entries = build_palette_entries(&hits);
+
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| {
+                    let matches = entry
+                        .snippet_matches
+                        .iter()
+                        .map(|range| (range.start, range.end))
+                        .collect::<Vec<_>>();
+                    (entry.index, entry.page, matches)
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                (1, 2, vec![(0, 1)]),
+                (2, 2, vec![(0, 1)]),
+                (3, 5, vec![(0, 7), (12, 17)]),
+            ]
+        );
+    }
+
     #[test]
     fn next_hit_keeps_progress_notice_while_search_is_still_running() {
         let mut state = SearchState {
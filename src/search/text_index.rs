@@ -0,0 +1,736 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use flate2::Compression;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use lru::LruCache;
+
+use crate::backend::{
+    PdfRect, TextBlock, TextGlyph, TextLayout, TextLine, TextPage, TextWord, WritingDirection,
+};
+use crate::error::{AppError, AppResult};
+
+/// Name of the search text directory below the pvf cache directory.
+pub const TEXT_INDEX_DIR: &str = "text";
+
+const INDEX_MAGIC: &[u8; 8] = b"PVFTEXT1";
+const INDEX_EXTENSION: &str = "pages";
+const RECORD_HEADER_LEN: u64 = 8;
+
+/// Text index shared by every search worker of one process.
+pub type SharedTextIndex = Arc<Mutex<TextIndexStore>>;
+
+pub fn lock_text_index(index: &SharedTextIndex) -> MutexGuard<'_, TextIndexStore> {
+    index
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Extracted page text persisted per document, so that searches in a later session skip text
+/// extraction.
+///
+/// Each document is one append-only file of compressed page records named after its `doc_id`.
+/// Record offsets are read the first time a document is used; page text is decoded on demand.
+/// Whole documents are evicted least recently used first once the files outgrow the budget;
+/// recency survives restarts through file modification times.
+///
+/// Several pvf processes may share the directory: appends hold an exclusive lock on the file
+/// and first pick up records other processes appended since.
+#[derive(Debug)]
+pub struct TextIndexStore {
+    dir: PathBuf,
+    docs: HashMap<u64, DocTextIndex>,
+    /// Size of every document file, least recently used first.
+    sizes: LruCache<u64, u64>,
+    disk_bytes: u64,
+    budget_bytes: u64,
+    /// Documents whose index was discarded; late writes from a worker still scanning them are
+    /// dropped instead of recreating the file.
+    retired: HashSet<u64>,
+}
+
+impl TextIndexStore {
+    pub fn open(dir: impl Into<PathBuf>, budget_bytes: u64) -> AppResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|source| {
+            AppError::io_with_context(
+                source,
+                format!("creating cache directory {}", dir.display()),
+            )
+        })?;
+        let listing = fs::read_dir(&dir).map_err(|source| {
+            AppError::io_with_context(source, format!("reading cache directory {}", dir.display()))
+        })?;
+
+        let mut found = Vec::new();
+        for entry in listing.flatten() {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != INDEX_EXTENSION) {
+                continue;
+            }
+            let Some(doc_id) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| u64::from_str_radix(stem, 16).ok())
+            else {
+                continue;
+            };
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let used_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            found.push((used_at, doc_id, metadata.len()));
+        }
+        found.sort_by_key(|(used_at, _, _)| *used_at);
+
+        let mut store = Self {
+            dir,
+            docs: HashMap::new(),
+            sizes: LruCache::unbounded(),
+            disk_bytes: 0,
+            budget_bytes,
+            retired: HashSet::new(),
+        };
+        for (_, doc_id, bytes) in found {
+            store.sizes.put(doc_id, bytes);
+            store.disk_bytes = store.disk_bytes.saturating_add(bytes);
+        }
+        store.evict_to(budget_bytes, None);
+        Ok(store)
+    }
+
+    pub fn contains(&mut self, doc_id: u64, page: usize) -> bool {
+        self.doc(doc_id)
+            .is_some_and(|index| index.pages.contains_key(&page))
+    }
+
+    pub fn load(&mut self, doc_id: u64, page: usize) -> Option<TextPage> {
+        let index = self.doc(doc_id)?;
+        let record = *index.pages.get(&page)?;
+        let text_page = index.read(record).ok().and_then(|payload| {
+            let mut decoded = Vec::new();
+            DeflateDecoder::new(payload.as_slice())
+                .read_to_end(&mut decoded)
+                .ok()?;
+            decode_text_page(&decoded)
+        });
+        if text_page.is_none() {
+            // A damaged record is extracted again; the fresh copy is appended and wins.
+            index.pages.remove(&page);
+        }
+        text_page
+    }
+
+    pub fn store(&mut self, doc_id: u64, page: usize, text_page: &TextPage) -> AppResult<()> {
+        let Ok(page_tag) = u32::try_from(page) else {
+            return Ok(());
+        };
+        let budget_bytes = self.budget_bytes;
+        let Some(index) = self.doc(doc_id) else {
+            return Ok(());
+        };
+        if index.pages.contains_key(&page) {
+            return Ok(());
+        }
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+        let payload = encoder
+            .write_all(&encode_text_page(text_page))
+            .and_then(|()| encoder.finish())
+            .map_err(|source| AppError::io_with_context(source, "compressing page text"))?;
+        // A document that alone outgrows the budget keeps the pages it has.
+        if index.end + RECORD_HEADER_LEN + payload.len() as u64 > budget_bytes {
+            return Ok(());
+        }
+        let appended = index.append(page_tag, &payload).map_err(|source| {
+            AppError::io_with_context(source, format!("writing text index for page {}", page + 1))
+        });
+        let bytes = index.end;
+        self.resize(doc_id, bytes);
+        self.evict_to(self.budget_bytes, Some(doc_id));
+        appended
+    }
+
+    /// Deletes the index of `doc_id` and ignores it from now on.
+    pub fn remove_doc(&mut self, doc_id: u64) {
+        self.docs.remove(&doc_id);
+        self.retired.insert(doc_id);
+        if let Some(bytes) = self.sizes.pop(&doc_id) {
+            self.disk_bytes = self.disk_bytes.saturating_sub(bytes);
+        }
+        let _ = fs::remove_file(self.path_for(doc_id));
+    }
+
+    fn doc(&mut self, doc_id: u64) -> Option<&mut DocTextIndex> {
+        if self.retired.contains(&doc_id) {
+            return None;
+        }
+        if !self.docs.contains_key(&doc_id) {
+            let path = self.path_for(doc_id);
+            let index = DocTextIndex::open(&path).ok()?;
+            let _ = index.file.set_modified(SystemTime::now());
+            let bytes = index.end;
+            self.docs.insert(doc_id, index);
+            self.resize(doc_id, bytes);
+        }
+        self.sizes.promote(&doc_id);
+        self.docs.get_mut(&doc_id)
+    }
+
+    /// Records that the file of `doc_id` now holds `bytes`.
+    fn resize(&mut self, doc_id: u64, bytes: u64) {
+        let previous = self.sizes.put(doc_id, bytes).unwrap_or(0);
+        self.disk_bytes = self
+            .disk_bytes
+            .saturating_sub(previous)
+            .saturating_add(bytes);
+    }
+
+    /// Deletes least recently used documents other than `keep` until the files fit
+    /// `limit_bytes`.
+    fn evict_to(&mut self, limit_bytes: u64, keep: Option<u64>) {
+        while self.disk_bytes > limit_bytes {
+            let Some((doc_id, bytes)) = self.sizes.pop_lru() else {
+                break;
+            };
+            if Some(doc_id) == keep {
+                self.sizes.put(doc_id, bytes);
+                break;
+            }
+            self.disk_bytes = self.disk_bytes.saturating_sub(bytes);
+            self.docs.remove(&doc_id);
+            let _ = fs::remove_file(self.path_for(doc_id));
+        }
+    }
+
+    fn path_for(&self, doc_id: u64) -> PathBuf {
+        self.dir.join(format!("{doc_id:016x}.{INDEX_EXTENSION}"))
+    }
+}
+
+#[derive(Debug)]
+struct DocTextIndex {
+    file: File,
+    pages: HashMap<usize, PageRecord>,
+    end: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PageRecord {
+    offset: u64,
+    len: u32,
+}
+
+impl DocTextIndex {
+    fn open(path: &Path) -> io::Result<Self> {
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut index = Self {
+            file,
+            pages: HashMap::new(),
+            end: 0,
+        };
+        index.locked(Self::catch_up)?;
+        Ok(index)
+    }
+
+    /// Runs `op` holding the exclusive lock other pvf processes take to change the file.
+    fn locked<T>(&mut self, op: impl FnOnce(&mut Self) -> io::Result<T>) -> io::Result<T> {
+        self.file.lock()?;
+        let result = op(self);
+        let _ = self.file.unlock();
+        result
+    }
+
+    /// Indexes the records appended since the file was last read. Needs the file lock.
+    fn catch_up(&mut self) -> io::Result<()> {
+        let len = self.file.metadata()?.len();
+        if len < self.end {
+            // Another process started the file over.
+            self.pages.clear();
+            self.end = 0;
+        }
+        if self.end == 0 {
+            let mut magic = [0; INDEX_MAGIC.len()];
+            let readable = len >= INDEX_MAGIC.len() as u64
+                && self.file.seek(SeekFrom::Start(0)).is_ok()
+                && self.file.read_exact(&mut magic).is_ok()
+                && &magic == INDEX_MAGIC;
+            if !readable {
+                self.file.set_len(0)?;
+                self.file.seek(SeekFrom::Start(0))?;
+                self.file.write_all(INDEX_MAGIC)?;
+                self.end = INDEX_MAGIC.len() as u64;
+                return Ok(());
+            }
+            self.end = INDEX_MAGIC.len() as u64;
+        }
+
+        while self.end + RECORD_HEADER_LEN <= len {
+            let mut header = [0; RECORD_HEADER_LEN as usize];
+            self.file.seek(SeekFrom::Start(self.end))?;
+            self.file.read_exact(&mut header)?;
+            let page = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+            let record_len = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+            let record_end = self.end + RECORD_HEADER_LEN + u64::from(record_len);
+            if record_end > len {
+                break;
+            }
+            self.pages.insert(
+                page as usize,
+                PageRecord {
+                    offset: self.end + RECORD_HEADER_LEN,
+                    len: record_len,
+                },
+            );
+            self.end = record_end;
+        }
+        // Writers hold the lock, so a record cut short here was left by an interrupted write and
+        // is dropped so that appends start cleanly.
+        if self.end < len {
+            self.file.set_len(self.end)?;
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, record: PageRecord) -> io::Result<Vec<u8>> {
+        let mut payload = vec![0; record.len as usize];
+        self.file.seek(SeekFrom::Start(record.offset))?;
+        self.file.read_exact(&mut payload)?;
+        Ok(payload)
+    }
+
+    fn append(&mut self, page: u32, payload: &[u8]) -> io::Result<()> {
+        let len = u32::try_from(payload.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "page text too large"))?;
+        let mut record = Vec::with_capacity(RECORD_HEADER_LEN as usize + payload.len());
+        record.extend_from_slice(&page.to_le_bytes());
+        record.extend_from_slice(&len.to_le_bytes());
+        record.extend_from_slice(payload);
+
+        self.locked(|index| {
+            index.catch_up()?;
+            if index.pages.contains_key(&(page as usize)) {
+                return Ok(());
+            }
+            let written = index
+                .file
+                .seek(SeekFrom::Start(index.end))
+                .and_then(|_| index.file.write_all(&record));
+            if let Err(err) = written {
+                let _ = index.file.set_len(index.end);
+                return Err(err);
+            }
+            index.pages.insert(
+                page as usize,
+                PageRecord {
+                    offset: index.end + RECORD_HEADER_LEN,
+                    len,
+                },
+            );
+            index.end += record.len() as u64;
+            Ok(())
+        })
+    }
+}
+
+/// Summary of a cleared text index.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClearedTextIndex {
+    pub documents: usize,
+    pub bytes: u64,
+}
+
+/// Deletes every document index in `dir`; a missing directory counts as already empty.
+pub fn clear_text_index_dir(dir: &Path) -> AppResult<ClearedTextIndex> {
+    let listing = match fs::read_dir(dir) {
+        Ok(listing) => listing,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(ClearedTextIndex::default());
+        }
+        Err(source) => {
+            return Err(AppError::io_with_context(
+                source,
+                format!("reading cache directory {}", dir.display()),
+            ));
+        }
+    };
+
+    let mut cleared = ClearedTextIndex::default();
+    for entry in listing.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != INDEX_EXTENSION) {
+            continue;
+        }
+        let bytes = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        fs::remove_file(&path).map_err(|source| {
+            AppError::io_with_context(source, format!("removing {}", path.display()))
+        })?;
+        cleared.documents += 1;
+        cleared.bytes = cleared.bytes.saturating_add(bytes);
+    }
+    Ok(cleared)
+}
+
+fn encode_text_page(text_page: &TextPage) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32 + text_page.glyphs.len() * 21);
+    put_f32(&mut out, text_page.width_pt);
+    put_f32(&mut out, text_page.height_pt);
+    put_len(&mut out, text_page.dropped_glyphs);
+
+    put_len(&mut out, text_page.glyphs.len());
+    for glyph in &text_page.glyphs {
+        put_u32(&mut out, u32::from(glyph.ch));
+        put_rect(&mut out, glyph.bbox);
+    }
+
+    let layout = &text_page.layout;
+    put_len(&mut out, layout.words.len());
+    for word in &layout.words {
+        put_range(&mut out, &word.glyphs);
+        put_rect(&mut out, word.bbox);
+    }
+    put_len(&mut out, layout.lines.len());
+    for line in &layout.lines {
+        put_range(&mut out, &line.words);
+        put_rect(&mut out, line.bbox);
+        put_f32(&mut out, line.baseline);
+        put_f32(&mut out, line.font_size);
+        out.push(match line.direction {
+            WritingDirection::LeftToRight => 0,
+            WritingDirection::RightToLeft => 1,
+            WritingDirection::TopToBottom => 2,
+            WritingDirection::BottomToTop => 3,
+        });
+    }
+    put_len(&mut out, layout.blocks.len());
+    for block in &layout.blocks {
+        put_range(&mut out, &block.lines);
+        put_rect(&mut out, block.bbox);
+    }
+    out
+}
+
+fn decode_text_page(bytes: &[u8]) -> Option<TextPage> {
+    let mut reader = Reader { bytes };
+    let width_pt = reader.f32()?;
+    let height_pt = reader.f32()?;
+    let dropped_glyphs = reader.u32()? as usize;
+
+    let glyphs = reader.list(|reader| {
+        Some(TextGlyph {
+            ch: char::from_u32(reader.u32()?)?,
+            bbox: reader.rect()?,
+        })
+    })?;
+    let words = reader.list(|reader| {
+        Some(TextWord {
+            glyphs: reader.range(glyphs.len())?,
+            bbox: reader.rect()?,
+        })
+    })?;
+    let lines = reader.list(|reader| {
+        Some(TextLine {
+            words: reader.range(words.len())?,
+            bbox: reader.rect()?,
+            baseline: reader.f32()?,
+            font_size: reader.f32()?,
+            direction: match reader.u8()? {
+                0 => WritingDirection::LeftToRight,
+                1 => WritingDirection::RightToLeft,
+                2 => WritingDirection::TopToBottom,
+                3 => WritingDirection::BottomToTop,
+                _ => return None,
+            },
+        })
+    })?;
+    let blocks = reader.list(|reader| {
+        Some(TextBlock {
+            lines: reader.range(lines.len())?,
+            bbox: reader.rect()?,
+        })
+    })?;
+    if !reader.bytes.is_empty() {
+        return None;
+    }
+
+    Some(TextPage {
+        width_pt,
+        height_pt,
+        glyphs,
+        dropped_glyphs,
+        layout: TextLayout {
+            words,
+            lines,
+            blocks,
+        },
+    })
+}
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_len(out: &mut Vec<u8>, value: usize) {
+    put_u32(out, u32::try_from(value).unwrap_or(u32::MAX));
+}
+
+fn put_f32(out: &mut Vec<u8>, value: f32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_range(out: &mut Vec<u8>, range: &Range<usize>) {
+    put_len(out, range.start);
+    put_len(out, range.end);
+}
+
+fn put_rect(out: &mut Vec<u8>, rect: Option<PdfRect>) {
+    match rect {
+        Some(rect) => {
+            out.push(1);
+            for value in [rect.x0, rect.y0, rect.x1, rect.y1] {
+                put_f32(out, value);
+            }
+        }
+        None => out.push(0),
+    }
+}
+
+/// Cursor over an encoded page; every read fails on truncated input so that a damaged record
+/// never yields ranges pointing outside the page.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, rest) = self.bytes.split_first_chunk::<N>()?;
+        self.bytes = rest;
+        Some(*head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|[value]| value)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.take().map(f32::from_le_bytes)
+    }
+
+    fn range(&mut self, bound: usize) -> Option<Range<usize>> {
+        let start = self.u32()? as usize;
+        let end = self.u32()? as usize;
+        (start <= end && end <= bound).then_some(start..end)
+    }
+
+    fn rect(&mut self) -> Option<Option<PdfRect>> {
+        match self.u8()? {
+            0 => Some(None),
+            1 => Some(Some(PdfRect {
+                x0: self.f32()?,
+                y0: self.f32()?,
+                x1: self.f32()?,
+                y1: self.f32()?,
+            })),
+            _ => None,
+        }
+    }
+
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Option<T>) -> Option<Vec<T>> {
+        let len = self.u32()? as usize;
+        // Every item takes at least one byte, which bounds allocations for damaged lengths.
+        let mut items = Vec::with_capacity(len.min(self.bytes.len()));
+        for _ in 0..len {
+            items.push(item(self)?);
+        }
+        Some(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{TextIndexStore, clear_text_index_dir, decode_text_page, encode_text_page};
+    use crate::backend::test_support::unique_temp_path;
+    use crate::backend::{
+        PdfRect, TextBlock, TextGlyph, TextLayout, TextLine, TextPage, TextWord, WritingDirection,
+    };
+
+    fn text_page() -> TextPage {
+        let rect = |x0: f32| PdfRect {
+            x0,
+            y0: 10.0,
+            x1: x0 + 5.0,
+            y1: 20.0,
+        };
+        TextPage {
+            width_pt: 612.0,
+            height_pt: 792.0,
+            glyphs: vec![
+                TextGlyph {
+                    ch: 'h',
+                    bbox: Some(rect(0.0)),
+                },
+                TextGlyph {
+                    ch: 'é',
+                    bbox: None,
+                },
+            ],
+            dropped_glyphs: 3,
+            layout: TextLayout {
+                words: vec![TextWord {
+                    glyphs: 0..2,
+                    bbox: Some(rect(0.0)),
+                }],
+                lines: vec![TextLine {
+                    words: 0..1,
+                    bbox: Some(rect(0.0)),
+                    baseline: 18.5,
+                    font_size: 10.0,
+                    direction: WritingDirection::RightToLeft,
+                }],
+                blocks: vec![TextBlock {
+                    lines: 0..1,
+                    bbox: None,
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn encoded_pages_round_trip_glyphs_and_layout() {
+        let page = text_page();
+        let encoded = encode_text_page(&page);
+
+        assert_eq!(decode_text_page(&encoded), Some(page));
+        assert_eq!(decode_text_page(&encoded[..encoded.len() - 1]), None);
+    }
+
+    #[test]
+    fn decoding_rejects_ranges_outside_the_page() {
+        let mut page = text_page();
+        page.layout.words[0].glyphs = 0..5;
+
+        assert_eq!(decode_text_page(&encode_text_page(&page)), None);
+    }
+
+    #[test]
+    fn stored_pages_survive_reopening_and_truncated_tails() {
+        let dir = unique_temp_path("text_index_reopen");
+        let page = text_page();
+
+        let mut store = TextIndexStore::open(&dir, u64::MAX).expect("store should open");
+        store.store(9, 0, &page).expect("page 0 should be written");
+        store.store(9, 4, &page).expect("page 4 should be written");
+        drop(store);
+
+        let path = dir.join(format!("{:016x}.pages", 9));
+        let len = fs::metadata(&path).expect("index file should exist").len();
+        let file = fs::File::options()
+            .write(true)
+            .open(&path)
+            .expect("index file should open");
+        file.set_len(len - 2).expect("index should be truncated");
+        drop(file);
+
+        let mut reopened = TextIndexStore::open(&dir, u64::MAX).expect("store should reopen");
+        assert_eq!(reopened.load(9, 0), Some(page.clone()));
+        assert!(!reopened.contains(9, 4));
+        reopened
+            .store(9, 4, &page)
+            .expect("page 4 should be rewritten");
+        assert_eq!(reopened.load(9, 4), Some(page));
+        assert!(reopened.load(10, 0).is_none());
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+
+    #[test]
+    fn least_recently_used_documents_are_evicted_over_budget() {
+        let dir = unique_temp_path("text_index_evict");
+        let path = |doc_id: u64| dir.join(format!("{doc_id:016x}.pages"));
+        let mut store = TextIndexStore::open(&dir, u64::MAX).expect("store should open");
+        store
+            .store(1, 0, &text_page())
+            .expect("page should be written");
+        let one_doc = fs::metadata(path(1))
+            .expect("index file should exist")
+            .len();
+        drop(store);
+
+        let mut store =
+            TextIndexStore::open(&dir, one_doc * 2 + one_doc / 2).expect("store should reopen");
+        store
+            .store(2, 0, &text_page())
+            .expect("page should be written");
+        assert!(store.contains(1, 0));
+        store
+            .store(3, 0, &text_page())
+            .expect("page should be written");
+
+        assert!(path(1).exists());
+        assert!(!path(2).exists());
+        assert!(path(3).exists());
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+
+    #[test]
+    fn appends_pick_up_records_written_by_another_process() {
+        let dir = unique_temp_path("text_index_shared");
+        let page = text_page();
+        let mut first = TextIndexStore::open(&dir, u64::MAX).expect("store should open");
+        let mut second = TextIndexStore::open(&dir, u64::MAX).expect("store should open");
+        assert!(!first.contains(9, 0));
+
+        second.store(9, 0, &page).expect("page 0 should be written");
+        first.store(9, 1, &page).expect("page 1 should be written");
+
+        assert_eq!(first.load(9, 0), Some(page.clone()));
+        let mut reopened = TextIndexStore::open(&dir, u64::MAX).expect("store should reopen");
+        assert_eq!(reopened.load(9, 0), Some(page.clone()));
+        assert_eq!(reopened.load(9, 1), Some(page));
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+
+    #[test]
+    fn removed_documents_are_deleted_and_not_recreated() {
+        let dir = unique_temp_path("text_index_remove");
+        let mut store = TextIndexStore::open(&dir, u64::MAX).expect("store should open");
+        store
+            .store(9, 0, &text_page())
+            .expect("page should be written");
+        store
+            .store(10, 0, &text_page())
+            .expect("page should be written");
+
+        store.remove_doc(9);
+        store
+            .store(9, 1, &text_page())
+            .expect("late write is ignored");
+
+        assert!(!dir.join(format!("{:016x}.pages", 9)).exists());
+        assert!(store.load(9, 0).is_none());
+        let cleared = clear_text_index_dir(&dir).expect("index should be cleared");
+        assert_eq!(cleared.documents, 1);
+        assert!(cleared.bytes > 0);
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+}
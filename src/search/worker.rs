@@ -1,14 +1,30 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::mem::size_of;
-use std::sync::Arc;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, error::TryRecvError};
 
-use crate::backend::{SharedPdfBackend, TextGlyph, TextPage};
+use crate::backend::{
+    PdfBackend, SharedPdfBackend, TextBlock, TextGlyph, TextLine, TextPage, TextWord,
+};
 use crate::cache::{BudgetedLruCache, CacheLimits, EvictionPolicy, InsertPolicy, OversizePolicy};
+use crate::error::AppResult;
 
 use super::engine::{SearchEvent, SearchPageHit, SearchSnapshot};
-use super::matcher::{SearchMatcher, apply_hit_snippet, occurrence_highlight_unavailable};
+use super::matcher::{
+    SearchMatcher, apply_hit_snippet, build_page_snippet, occurrence_highlight_unavailable,
+};
+use super::text_index::{SharedTextIndex, lock_text_index};
+
+/// Upper bound on the threads scanning pages for one query.
+const SEARCH_SCAN_THREADS: usize = 4;
+/// How often the scan checks for a newer query while no page finishes.
+const SEARCH_SCAN_POLL_INTERVAL: Duration = Duration::from_millis(20);
 
 #[derive(Clone)]
 pub(crate) struct SearchJob {
@@ -72,19 +88,25 @@ struct SearchPageCacheKey {
 
 struct SearchPageCache {
     pages: BudgetedLruCache<SearchPageCacheKey, Arc<TextPage>>,
+    /// Disk tier behind `pages`; pages of password-protected documents are never persisted.
+    text_index: Option<SharedTextIndex>,
 }
 
 impl SearchPageCache {
     const DEFAULT_MAX_ENTRIES: usize = 16_384;
     const DEFAULT_MEMORY_BUDGET_BYTES: usize = 16 * 1024 * 1024;
 
-    fn new() -> Self {
-        Self::with_limits(Self::DEFAULT_MAX_ENTRIES, Self::DEFAULT_MEMORY_BUDGET_BYTES)
+    fn new(text_index: Option<SharedTextIndex>) -> Self {
+        Self {
+            text_index,
+            ..Self::with_limits(Self::DEFAULT_MAX_ENTRIES, Self::DEFAULT_MEMORY_BUDGET_BYTES)
+        }
     }
 
     fn with_limits(max_entries: usize, memory_budget_bytes: usize) -> Self {
         Self {
             pages: BudgetedLruCache::new(CacheLimits::new(max_entries, memory_budget_bytes)),
+            text_index: None,
         }
     }
 
@@ -124,6 +146,27 @@ impl SearchPageCache {
             .inserted
     }
 
+    /// Text of `page` from the disk tier, extracting and persisting it on a miss. The result is
+    /// not added to the memory tier; callers pick the insert policy.
+    fn load_or_extract(&self, doc: &dyn PdfBackend, page: usize) -> AppResult<Arc<TextPage>> {
+        load_or_extract_page(self.text_index_for(doc), doc, page)
+    }
+
+    fn persists(&self, doc: &dyn PdfBackend) -> bool {
+        self.text_index_for(doc).is_some()
+    }
+
+    fn is_persisted(&self, doc: &dyn PdfBackend, page: usize) -> bool {
+        self.text_index_for(doc)
+            .is_some_and(|index| lock_text_index(index).contains(doc.doc_id(), page))
+    }
+
+    fn text_index_for(&self, doc: &dyn PdfBackend) -> Option<&SharedTextIndex> {
+        self.text_index
+            .as_ref()
+            .filter(|_| doc.password().is_none())
+    }
+
     #[cfg(test)]
     fn len(&self) -> usize {
         self.pages.len()
@@ -135,16 +178,40 @@ impl SearchPageCache {
     }
 }
 
+fn load_or_extract_page(
+    text_index: Option<&SharedTextIndex>,
+    doc: &dyn PdfBackend,
+    page: usize,
+) -> AppResult<Arc<TextPage>> {
+    let doc_id = doc.doc_id();
+    if let Some(text_page) = text_index.and_then(|index| lock_text_index(index).load(doc_id, page))
+    {
+        return Ok(Arc::new(text_page));
+    }
+
+    let text_page = doc.extract_text_page(page)?;
+    if let Some(index) = text_index {
+        let _ = lock_text_index(index).store(doc_id, page, &text_page);
+    }
+    Ok(Arc::new(text_page))
+}
+
 fn estimate_text_page_bytes(text_page: &TextPage) -> usize {
-    size_of::<TextPage>() + text_page.glyphs.capacity() * size_of::<TextGlyph>()
+    let layout = &text_page.layout;
+    size_of::<TextPage>()
+        + text_page.glyphs.capacity() * size_of::<TextGlyph>()
+        + layout.words.capacity() * size_of::<TextWord>()
+        + layout.lines.capacity() * size_of::<TextLine>()
+        + layout.blocks.capacity() * size_of::<TextBlock>()
 }
 
 pub(crate) fn worker_main(
     mut request_rx: UnboundedReceiver<WorkerRequest>,
     event_tx: UnboundedSender<SearchEvent>,
+    text_index: Option<SharedTextIndex>,
 ) {
     let mut pending = PendingWorkerWork::default();
-    let mut page_cache = SearchPageCache::new();
+    let mut page_cache = SearchPageCache::new(text_index);
     let mut prewarm_finished_doc_ids = HashSet::new();
 
     loop {
@@ -264,6 +331,7 @@ fn run_prewarm_job(
         return PrewarmControl::Finished;
     }
 
+    let mut memory_full = false;
     for page in 0..total_pages {
         match flush_requests(request_rx, pending) {
             WorkerControl::Continue => {
@@ -278,15 +346,20 @@ fn run_prewarm_job(
             }
             WorkerControl::Shutdown => return PrewarmControl::Shutdown,
         }
-        if page_cache.get(doc_id, page).is_some() {
+        if page_cache.get(doc_id, page).is_some()
+            || (memory_full && page_cache.is_persisted(doc.as_ref(), page))
+        {
             continue;
         }
-        if let Ok(text_page) = doc.extract_text_page(page) {
-            let text_page = Arc::new(text_page);
-            if !page_cache.try_insert_without_eviction(doc_id, page, text_page) {
-                prewarm_finished_doc_ids.insert(doc_id);
+        let Ok(text_page) = page_cache.load_or_extract(doc.as_ref(), page) else {
+            continue;
+        };
+        if !memory_full && !page_cache.try_insert_without_eviction(doc_id, page, text_page) {
+            // Past the memory budget, prewarm only keeps building the on-disk index.
+            if !page_cache.persists(doc.as_ref()) {
                 break;
             }
+            memory_full = true;
         }
     }
 
@@ -301,6 +374,7 @@ fn run_job(
     pending: &mut PendingWorkerWork,
     page_cache: &mut SearchPageCache,
 ) -> WorkerControl {
+    let started = Instant::now();
     let query = job.matcher.prepare_query(job.query.trim());
     if query.is_empty() {
         let snapshot = SearchSnapshot {
@@ -308,6 +382,7 @@ fn run_job(
             scanned_pages: 0,
             total_pages: 0,
             hit_pages: 0,
+            elapsed: started.elapsed(),
             done: true,
         };
         let _ = event_tx.send(SearchEvent::Snapshot(snapshot));
@@ -319,73 +394,194 @@ fn run_job(
         return WorkerControl::Continue;
     }
 
-    let doc = job.pdf;
-    let doc_id = doc.doc_id();
+    let doc = job.pdf.as_ref();
     let total_pages = doc.page_count();
+    let text_index = page_cache.text_index_for(doc).cloned();
+    let scan = PageScan {
+        doc,
+        matcher: job.matcher.as_ref(),
+        query: &query,
+        text_index: text_index.as_ref(),
+        page_cache: Mutex::new(page_cache),
+        next_page: AtomicUsize::new(0),
+        cancelled: AtomicBool::new(false),
+    };
+    let scan_threads = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(SEARCH_SCAN_THREADS)
+        .min(total_pages)
+        .max(1);
+
+    thread::scope(|scope| {
+        let (result_tx, result_rx) = mpsc::channel();
+        for _ in 0..scan_threads {
+            let result_tx = result_tx.clone();
+            let scan = &scan;
+            scope.spawn(move || scan.run(&result_tx));
+        }
+        drop(result_tx);
+
+        // Workers finish pages out of order; hits and skips are released in page order.
+        let mut finished = BTreeMap::new();
+        let mut next_in_order = 0;
+        let mut scanned_pages = 0;
+        let mut hit_pages = 0;
+        let mut hits = Vec::new();
+        let mut highlight_unavailable = false;
+        loop {
+            match flush_requests(request_rx, pending) {
+                WorkerControl::Continue if pending.query.is_none() => {}
+                control => {
+                    scan.cancelled.store(true, Ordering::Relaxed);
+                    return control;
+                }
+            }
+            // Highlights for the visible pages must not wait for the rest of the document; the
+            // scan threads stall on the page cache until the geometry job is done.
+            if let Some(geometry) = pending
+                .geometry
+                .take_if(|queued| queued.priority == GeometryPriority::High)
+            {
+                let mut page_cache = scan.lock_page_cache();
+                let control =
+                    run_geometry_job(geometry, request_rx, event_tx, pending, &mut page_cache);
+                if matches!(control, WorkerControl::Shutdown) {
+                    scan.cancelled.store(true, Ordering::Relaxed);
+                    return control;
+                }
+                continue;
+            }
 
-    let mut hits = Vec::new();
-    let mut highlight_unavailable = false;
-    for page in 0..total_pages {
-        match flush_requests(request_rx, pending) {
-            WorkerControl::Continue => {
-                if pending.query.is_some() {
-                    return WorkerControl::Continue;
+            let scanned = match result_rx.recv_timeout(SEARCH_SCAN_POLL_INTERVAL) {
+                Ok(scanned) => scanned,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+            scanned_pages += 1;
+            if matches!(&scanned.outcome, Ok(Some(_))) {
+                hit_pages += 1;
+            }
+            finished.insert(scanned.page, scanned);
+            while let Some(scanned) = finished.remove(&next_in_order) {
+                next_in_order += 1;
+                highlight_unavailable |= scanned.highlight_unavailable;
+                match scanned.outcome {
+                    Ok(Some(hit)) => hits.push(hit),
+                    Ok(None) => {}
+                    Err(message) => {
+                        let _ = event_tx.send(SearchEvent::PageSkipped {
+                            generation: job.generation,
+                            page: scanned.page,
+                            message,
+                        });
+                    }
                 }
             }
-            WorkerControl::Shutdown => return WorkerControl::Shutdown,
+
+            let snapshot = SearchSnapshot {
+                generation: job.generation,
+                scanned_pages,
+                total_pages,
+                hit_pages,
+                elapsed: started.elapsed(),
+                done: scanned_pages == total_pages,
+            };
+            let _ = event_tx.send(SearchEvent::Snapshot(snapshot));
         }
 
-        let text_page = match page_cache.get(doc_id, page) {
-            Some(text_page) => Ok(text_page),
-            None => doc.extract_text_page(page).map(|text_page| {
-                let text_page = Arc::new(text_page);
-                page_cache.try_insert_without_eviction(doc_id, page, Arc::clone(&text_page));
-                text_page
-            }),
-        };
+        let _ = event_tx.send(SearchEvent::Completed {
+            generation: job.generation,
+            hits,
+            highlight_unavailable,
+        });
+        WorkerControl::Continue
+    })
+}
 
-        let text_page = match text_page {
-            Ok(text_page) => Some(text_page),
-            Err(err) => {
-                let _ = event_tx.send(SearchEvent::PageSkipped {
-                    generation: job.generation,
-                    page,
-                    message: err.to_string(),
-                });
-                None
-            }
-        };
+/// Shared state of the threads scanning one query; each claims the next unscanned page.
+struct PageScan<'a> {
+    doc: &'a dyn PdfBackend,
+    matcher: &'a dyn SearchMatcher,
+    query: &'a str,
+    text_index: Option<&'a SharedTextIndex>,
+    page_cache: Mutex<&'a mut SearchPageCache>,
+    next_page: AtomicUsize,
+    cancelled: AtomicBool,
+}
 
-        if let Some(text_page) = text_page {
-            let mut occurrences = job.matcher.locate_matches(text_page.as_ref(), &query);
-            for occurrence in &mut occurrences {
-                if occurrence_highlight_unavailable(occurrence, &text_page.glyphs) {
-                    highlight_unavailable = true;
-                }
-                apply_hit_snippet(occurrence, &text_page.glyphs);
+struct ScannedPage {
+    page: usize,
+    /// The hit on the page, if any, or why its text could not be read.
+    outcome: Result<Option<SearchPageHit>, String>,
+    highlight_unavailable: bool,
+}
+
+impl<'a> PageScan<'a> {
+    fn run(&self, result_tx: &mpsc::Sender<ScannedPage>) {
+        let doc_id = self.doc.doc_id();
+        let total_pages = self.doc.page_count();
+        while !self.cancelled.load(Ordering::Relaxed) {
+            let page = self.next_page.fetch_add(1, Ordering::Relaxed);
+            if page >= total_pages {
+                break;
             }
-            if !occurrences.is_empty() {
-                hits.push(SearchPageHit { page, occurrences });
+            let cached = self.lock_page_cache().get(doc_id, page);
+            let text_page = match cached {
+                Some(text_page) => Ok(text_page),
+                // Extraction runs outside the lock so the other threads keep scanning.
+                None => {
+                    load_or_extract_page(self.text_index, self.doc, page).inspect(|text_page| {
+                        self.lock_page_cache().try_insert_without_eviction(
+                            doc_id,
+                            page,
+                            Arc::clone(text_page),
+                        );
+                    })
+                }
+            };
+            let scanned = match text_page {
+                Ok(text_page) => self.match_page(page, &text_page),
+                Err(err) => ScannedPage {
+                    page,
+                    outcome: Err(err.to_string()),
+                    highlight_unavailable: false,
+                },
+            };
+            if result_tx.send(scanned).is_err() {
+                break;
             }
         }
+    }
 
-        let scanned_pages = page + 1;
-        let snapshot = SearchSnapshot {
-            generation: job.generation,
-            scanned_pages,
-            total_pages,
-            hit_pages: hits.len(),
-            done: scanned_pages == total_pages,
-        };
-        let _ = event_tx.send(SearchEvent::Snapshot(snapshot));
+    fn match_page(&self, page: usize, text_page: &TextPage) -> ScannedPage {
+        let mut occurrences = self.matcher.locate_matches(text_page, self.query);
+        let mut highlight_unavailable = false;
+        for occurrence in &mut occurrences {
+            if occurrence_highlight_unavailable(occurrence, &text_page.glyphs) {
+                highlight_unavailable = true;
+            }
+            apply_hit_snippet(occurrence, &text_page.glyphs);
+        }
+        let hit = (!occurrences.is_empty()).then(|| SearchPageHit {
+            page,
+            summary: self
+                .matcher
+                .reports_pages()
+                .then(|| build_page_snippet(&text_page.glyphs, &occurrences)),
+            occurrences,
+        });
+        ScannedPage {
+            page,
+            outcome: Ok(hit),
+            highlight_unavailable,
+        }
     }
 
-    let _ = event_tx.send(SearchEvent::Completed {
-        generation: job.generation,
-        hits,
-        highlight_unavailable,
-    });
-    WorkerControl::Continue
+    fn lock_page_cache(&self) -> MutexGuard<'_, &'a mut SearchPageCache> {
+        self.page_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
 }
 
 fn run_geometry_job(
@@ -425,11 +621,9 @@ fn run_geometry_job(
 
         let text_page = match page_cache.get(doc_id, page) {
             Some(text_page) => Ok(text_page),
-            None => doc.extract_text_page(page).map(|text_page| {
-                let text_page = Arc::new(text_page);
-                page_cache.insert(doc_id, page, Arc::clone(&text_page));
-                text_page
-            }),
+            None => page_cache
+                .load_or_extract(doc.as_ref(), page)
+                .inspect(|text_page| page_cache.insert(doc_id, page, Arc::clone(text_page))),
         };
 
         let Ok(text_page) = text_page else {
@@ -485,28 +679,34 @@ fn flush_requests(
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
+    use std::fs;
     use std::path::{Path, PathBuf};
     use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     use tokio::sync::mpsc::unbounded_channel;
 
     use super::{
-        PendingWorkerWork, PrewarmControl, PrewarmJob, SearchJob, SearchPageCache, WorkerRequest,
-        estimate_text_page_bytes, run_job, run_prewarm_job,
+        GeometryJob, GeometryPriority, PendingWorkerWork, PrewarmControl, PrewarmJob, SearchJob,
+        SearchPageCache, WorkerRequest, estimate_text_page_bytes, run_job, run_prewarm_job,
     };
+    use crate::backend::test_support::unique_temp_path;
     use crate::backend::{
-        OutlineNode, PdfBackend, PdfRect, RgbaFrame, SharedPdfBackend, TextGlyph, TextPage,
+        OutlineNode, PdfBackend, PdfRect, RgbaFrame, SharedPdfBackend, TextGlyph, TextLayout,
+        TextPage,
     };
     use crate::command::SearchMatcherKind;
     use crate::error::{AppError, AppResult};
     use crate::search::engine::SearchEvent;
-    use crate::search::matcher::matcher_for_kind;
+    use crate::search::matcher::{SearchFolding, matcher_for_kind};
+    use crate::search::text_index::TextIndexStore;
 
     struct CountingTextPageStubPdf {
         path: PathBuf,
         doc_id: u64,
         pages: Vec<TextPage>,
         text_page_calls: Mutex<Vec<usize>>,
+        extract_delay: Duration,
     }
 
     impl CountingTextPageStubPdf {
@@ -517,9 +717,15 @@ mod tests {
                 doc_id,
                 pages,
                 text_page_calls: Mutex::new(vec![0; page_count]),
+                extract_delay: Duration::ZERO,
             }
         }
 
+        fn with_extract_delay(mut self, delay: Duration) -> Self {
+            self.extract_delay = delay;
+            self
+        }
+
         fn text_page_calls(&self) -> Vec<usize> {
             self.text_page_calls
                 .lock()
@@ -550,6 +756,7 @@ mod tests {
         }
 
         fn extract_text_page(&self, page: usize) -> AppResult<TextPage> {
+            std::thread::sleep(self.extract_delay);
             let mut calls = self
                 .text_page_calls
                 .lock()
@@ -779,6 +986,71 @@ mod tests {
         assert_eq!(pdf.text_page_calls(), vec![1, 1]);
     }
 
+    #[test]
+    fn prewarm_indexes_pages_past_memory_budget_for_later_searches() {
+        let first_page = text_page("alpha");
+        let budget = estimate_text_page_bytes(&first_page);
+        let pdf = Arc::new(CountingTextPageStubPdf::new(
+            304,
+            vec![first_page, text_page("beta gamma"), text_page("beta")],
+        ));
+        let dir = unique_temp_path("search_prewarm_text_index");
+        let text_index = Arc::new(Mutex::new(
+            TextIndexStore::open(&dir, u64::MAX).expect("text index should open"),
+        ));
+        let (_request_tx, request_rx) = unbounded_channel();
+        let mut request_rx = request_rx;
+        let mut pending = PendingWorkerWork::default();
+        let mut page_cache = SearchPageCache {
+            text_index: Some(Arc::clone(&text_index)),
+            ..SearchPageCache::with_limits(4, budget)
+        };
+
+        assert!(matches!(
+            run_prewarm_job(
+                PrewarmJob { pdf: pdf.clone() },
+                &mut request_rx,
+                &mut pending,
+                &mut page_cache,
+                &mut HashSet::new(),
+            ),
+            PrewarmControl::Finished
+        ));
+        assert_eq!(pdf.text_page_calls(), vec![1, 1, 1]);
+
+        // A later session starts with an empty memory cache but the same index.
+        let (event_tx, mut event_rx) = unbounded_channel();
+        let mut next_session = SearchPageCache::new(Some(text_index));
+        run_job(
+            SearchJob {
+                generation: 1,
+                pdf: pdf.clone(),
+                query: "beta".to_string(),
+                matcher: matcher_for_kind(
+                    SearchMatcherKind::ContainsInsensitive,
+                    SearchFolding::default(),
+                ),
+            },
+            &mut request_rx,
+            &event_tx,
+            &mut pending,
+            &mut next_session,
+        );
+
+        assert_eq!(pdf.text_page_calls(), vec![1, 1, 1]);
+        let hit_pages = std::iter::from_fn(|| event_rx.try_recv().ok())
+            .find_map(|event| match event {
+                SearchEvent::Completed { hits, .. } => {
+                    Some(hits.iter().map(|hit| hit.page).collect::<Vec<_>>())
+                }
+                _ => None,
+            })
+            .expect("search should complete");
+        assert_eq!(hit_pages, vec![1, 2]);
+
+        fs::remove_dir_all(&dir).expect("text index dir should be removed");
+    }
+
     #[test]
     fn interrupted_prewarm_can_resume_after_priority_work() {
         let pdf = Arc::new(CountingTextPageStubPdf::new(
@@ -796,7 +1068,10 @@ mod tests {
                 generation: 1,
                 pdf: pdf.clone(),
                 query: "alpha".to_string(),
-                matcher: matcher_for_kind(SearchMatcherKind::ContainsInsensitive),
+                matcher: matcher_for_kind(
+                    SearchMatcherKind::ContainsInsensitive,
+                    SearchFolding::default(),
+                ),
             }))
             .expect("query request should be queued");
 
@@ -846,7 +1121,10 @@ mod tests {
                 generation: 1,
                 pdf,
                 query: "beta".to_string(),
-                matcher: matcher_for_kind(SearchMatcherKind::ContainsInsensitive),
+                matcher: matcher_for_kind(
+                    SearchMatcherKind::ContainsInsensitive,
+                    SearchFolding::default(),
+                ),
             },
             &mut request_rx,
             &event_tx,
@@ -878,6 +1156,134 @@ mod tests {
         assert_eq!(completed[0].page, 2);
     }
 
+    #[test]
+    fn search_delivers_hits_in_page_order() {
+        let pages = (0..40)
+            .map(|page| text_page(if page % 3 == 0 { "needle" } else { "hay" }))
+            .collect();
+        let pdf = Arc::new(CountingTextPageStubPdf::new(7, pages)) as SharedPdfBackend;
+        let (_request_tx, mut request_rx) = unbounded_channel();
+        let (event_tx, mut event_rx) = unbounded_channel();
+        let mut pending = PendingWorkerWork::default();
+        let mut page_cache = SearchPageCache::with_limits(64, usize::MAX);
+
+        run_job(
+            search_job(1, pdf, "needle"),
+            &mut request_rx,
+            &event_tx,
+            &mut pending,
+            &mut page_cache,
+        );
+
+        let mut snapshots = Vec::new();
+        let mut hits = None;
+        while let Ok(event) = event_rx.try_recv() {
+            match event {
+                SearchEvent::Snapshot(snapshot) => snapshots.push(snapshot),
+                SearchEvent::Completed { hits: done, .. } => hits = Some(done),
+                _ => {}
+            }
+        }
+        let pages = hits
+            .expect("search should complete")
+            .iter()
+            .map(|hit| hit.page)
+            .collect::<Vec<_>>();
+        assert_eq!(pages, (0..40).step_by(3).collect::<Vec<_>>());
+        assert_eq!(snapshots.len(), 40);
+        assert!(
+            snapshots
+                .windows(2)
+                .all(|pair| pair[0].scanned_pages < pair[1].scanned_pages)
+        );
+        let last = snapshots.last().expect("scan should report progress");
+        assert!(last.done);
+        assert_eq!(last.hit_pages, 14);
+    }
+
+    #[test]
+    fn newer_query_cancels_running_search() {
+        let pdf = Arc::new(CountingTextPageStubPdf::new(
+            8,
+            vec![text_page("needle"), text_page("needle")],
+        )) as SharedPdfBackend;
+        let (request_tx, mut request_rx) = unbounded_channel();
+        let (event_tx, mut event_rx) = unbounded_channel();
+        let mut pending = PendingWorkerWork::default();
+        let mut page_cache = SearchPageCache::with_limits(4, usize::MAX);
+        request_tx
+            .send(WorkerRequest::Query(search_job(2, Arc::clone(&pdf), "hay")))
+            .expect("worker channel should be open");
+
+        let control = run_job(
+            search_job(1, pdf, "needle"),
+            &mut request_rx,
+            &event_tx,
+            &mut pending,
+            &mut page_cache,
+        );
+
+        assert!(matches!(control, super::WorkerControl::Continue));
+        assert_eq!(pending.query.as_ref().map(|job| job.generation), Some(2));
+        while let Ok(event) = event_rx.try_recv() {
+            assert!(!matches!(event, SearchEvent::Completed { .. }));
+        }
+    }
+
+    #[test]
+    fn visible_page_geometry_resolves_before_a_long_scan_finishes() {
+        let pages = (0..64).map(|_| text_page("hay")).collect();
+        let pdf = Arc::new(
+            CountingTextPageStubPdf::new(9, pages).with_extract_delay(Duration::from_millis(10)),
+        ) as SharedPdfBackend;
+        let (request_tx, mut request_rx) = unbounded_channel();
+        let (event_tx, mut event_rx) = unbounded_channel();
+        let mut pending = PendingWorkerWork::default();
+        let mut page_cache = SearchPageCache::with_limits(128, usize::MAX);
+        let search = search_job(1, Arc::clone(&pdf), "hay");
+        request_tx
+            .send(WorkerRequest::ResolveGeometry(GeometryJob {
+                generation: 2,
+                pdf,
+                query: "hay".to_string(),
+                matcher: Arc::clone(&search.matcher),
+                pages: vec![0],
+                priority: GeometryPriority::High,
+            }))
+            .expect("worker channel should be open");
+
+        run_job(
+            search,
+            &mut request_rx,
+            &event_tx,
+            &mut pending,
+            &mut page_cache,
+        );
+
+        let mut events = Vec::new();
+        while let Ok(event) = event_rx.try_recv() {
+            match event {
+                SearchEvent::GeometryResolved { generation, .. } => events.push(generation),
+                SearchEvent::Completed { generation, .. } => events.push(generation),
+                _ => {}
+            }
+        }
+        assert_eq!(events, vec![2, 1]);
+        assert!(pending.geometry.is_none());
+    }
+
+    fn search_job(generation: u64, pdf: SharedPdfBackend, query: &str) -> SearchJob {
+        SearchJob {
+            generation,
+            pdf,
+            query: query.to_string(),
+            matcher: matcher_for_kind(
+                SearchMatcherKind::ContainsInsensitive,
+                SearchFolding::default(),
+            ),
+        }
+    }
+
     fn text_page(text: &str) -> TextPage {
         TextPage {
             width_pt: 100.0,
@@ -891,6 +1297,7 @@ mod tests {
                 })
                 .collect(),
             dropped_glyphs: 0,
+            layout: TextLayout::default(),
         }
     }
 
@@ -0,0 +1,284 @@
+use std::ops::{Range, RangeInclusive};
+
+use crate::backend::{PdfRect, TextGlyph, TextPage};
+use crate::command::CaretMotion;
+use crate::highlight::geometry::merge_text_glyph_rects;
+
+/// A page's glyphs in reading order, as the visual mode caret walks them.
+///
+/// Caret positions index `cells`; whitespace is left out, so every position is on a glyph.
+pub(super) struct CaretText {
+    glyphs: Vec<TextGlyph>,
+    cells: Vec<CaretCell>,
+    /// Position ranges of each word.
+    words: Vec<Range<usize>>,
+    /// Position ranges of each line.
+    lines: Vec<Range<usize>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CaretCell {
+    glyph: usize,
+    word: usize,
+    line: usize,
+}
+
+impl CaretText {
+    pub(super) fn new(page: &TextPage) -> Self {
+        let mut text = Self {
+            glyphs: page.glyphs.clone(),
+            cells: Vec::new(),
+            words: Vec::new(),
+            lines: Vec::new(),
+        };
+        if page.layout.is_empty() {
+            text.push_stream_order(page);
+        } else {
+            for line in page
+                .layout
+                .blocks
+                .iter()
+                .flat_map(|block| page.layout.block_lines(block))
+            {
+                let words = page
+                    .layout
+                    .line_words(line)
+                    .iter()
+                    .map(|word| word.glyphs.clone());
+                text.push_line(words);
+            }
+        }
+        text
+    }
+
+    /// Lines split at newlines and words at whitespace, for pages without layout analysis.
+    fn push_stream_order(&mut self, page: &TextPage) {
+        let mut lines = vec![Vec::new()];
+        let mut word_start = None;
+        for (index, glyph) in page.glyphs.iter().enumerate() {
+            if !glyph.ch.is_whitespace() {
+                word_start.get_or_insert(index);
+                continue;
+            }
+            if let Some(start) = word_start.take() {
+                lines
+                    .last_mut()
+                    .expect("lines start non-empty")
+                    .push(start..index);
+            }
+            if glyph.ch == '\n' {
+                lines.push(Vec::new());
+            }
+        }
+        if let Some(start) = word_start {
+            lines
+                .last_mut()
+                .expect("lines start non-empty")
+                .push(start..page.glyphs.len());
+        }
+        for words in lines {
+            self.push_line(words.into_iter());
+        }
+    }
+
+    fn push_line(&mut self, words: impl Iterator<Item = Range<usize>>) {
+        let line_start = self.cells.len();
+        let line = self.lines.len();
+        for glyphs in words {
+            let word_start = self.cells.len();
+            let word = self.words.len();
+            self.cells.extend(
+                glyphs
+                    .filter(|&glyph| !self.glyphs[glyph].ch.is_whitespace())
+                    .map(|glyph| CaretCell { glyph, word, line }),
+            );
+            if self.cells.len() > word_start {
+                self.words.push(word_start..self.cells.len());
+            }
+        }
+        if self.cells.len() > line_start {
+            self.lines.push(line_start..self.cells.len());
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Where `motion` takes the caret from `position`; it stays put at the edges of the page.
+    pub(super) fn step(&self, position: usize, motion: CaretMotion) -> usize {
+        let cell = self.cells[position];
+        let word = &self.words[cell.word];
+        let line = &self.lines[cell.line];
+        match motion {
+            CaretMotion::Left => position.saturating_sub(1),
+            CaretMotion::Right => (position + 1).min(self.cells.len() - 1),
+            CaretMotion::WordForward => self
+                .words
+                .get(cell.word + 1)
+                .map_or(position, |next| next.start),
+            CaretMotion::WordBackward if position > word.start => word.start,
+            CaretMotion::WordBackward => cell
+                .word
+                .checked_sub(1)
+                .map_or(position, |previous| self.words[previous].start),
+            CaretMotion::WordEnd if position + 1 < word.end => word.end - 1,
+            CaretMotion::WordEnd => self
+                .words
+                .get(cell.word + 1)
+                .map_or(position, |next| next.end - 1),
+            CaretMotion::LineDown => self
+                .lines
+                .get(cell.line + 1)
+                .map_or(position, |next| self.same_column(position, next)),
+            CaretMotion::LineUp => cell.line.checked_sub(1).map_or(position, |previous| {
+                self.same_column(position, &self.lines[previous])
+            }),
+            CaretMotion::LineStart => line.start,
+            CaretMotion::LineEnd => line.end - 1,
+        }
+    }
+
+    /// Position in `line` nearest below or above `position`, by glyph center when both have
+    /// bounds and by column otherwise.
+    fn same_column(&self, position: usize, line: &Range<usize>) -> usize {
+        let column = position - self.lines[self.cells[position].line].start;
+        let fallback = (line.start + column).min(line.end - 1);
+        let Some(x) = self.center_x(position) else {
+            return fallback;
+        };
+        line.clone()
+            .filter_map(|candidate| {
+                self.center_x(candidate)
+                    .map(|candidate_x| (candidate, (candidate_x - x).abs()))
+            })
+            .min_by(|(_, left), (_, right)| left.total_cmp(right))
+            .map_or(fallback, |(candidate, _)| candidate)
+    }
+
+    fn center_x(&self, position: usize) -> Option<f32> {
+        let bbox = self.glyphs[self.cells[position].glyph].bbox?;
+        Some((bbox.x0 + bbox.x1) / 2.0)
+    }
+
+    /// Positions from the start of `from`'s line to the end of `to`'s line.
+    pub(super) fn whole_lines(&self, from: usize, to: usize) -> RangeInclusive<usize> {
+        let first = self.cells[from.min(to)].line;
+        let last = self.cells[from.max(to)].line;
+        self.lines[first].start..=self.lines[last].end - 1
+    }
+
+    /// Text of `positions`, with a space between words and a newline between lines.
+    pub(super) fn text(&self, positions: RangeInclusive<usize>) -> String {
+        let mut text = String::new();
+        let mut previous: Option<CaretCell> = None;
+        for cell in &self.cells[positions] {
+            if let Some(previous) = previous {
+                if previous.line != cell.line {
+                    text.push('\n');
+                } else if previous.word != cell.word {
+                    text.push(' ');
+                }
+            }
+            text.push(self.glyphs[cell.glyph].ch);
+            previous = Some(*cell);
+        }
+        text
+    }
+
+    /// Page-space rects covering `positions`, merged line by line.
+    pub(super) fn rects(&self, positions: RangeInclusive<usize>) -> Vec<PdfRect> {
+        let cells = &self.cells[positions];
+        cells
+            .chunk_by(|left, right| left.line == right.line)
+            .flat_map(|line| {
+                let glyphs: Vec<TextGlyph> = line
+                    .iter()
+                    .map(|cell| self.glyphs[cell.glyph].clone())
+                    .collect();
+                merge_text_glyph_rects(&glyphs)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::{PdfRect, TextGlyph, TextLayout, TextPage};
+    use crate::command::CaretMotion;
+
+    use super::CaretText;
+
+    fn stream_page(text: &str) -> TextPage {
+        let mut x = 0.0;
+        let mut y = 0.0;
+        let glyphs = text
+            .chars()
+            .map(|ch| {
+                if ch == '\n' {
+                    x = 0.0;
+                    y += 12.0;
+                    return TextGlyph { ch, bbox: None };
+                }
+                let bbox = PdfRect {
+                    x0: x,
+                    y0: y,
+                    x1: x + 6.0,
+                    y1: y + 10.0,
+                };
+                x += 6.0;
+                TextGlyph {
+                    ch,
+                    bbox: Some(bbox),
+                }
+            })
+            .collect();
+        TextPage {
+            width_pt: 612.0,
+            height_pt: 792.0,
+            glyphs,
+            dropped_glyphs: 0,
+            layout: TextLayout::default(),
+        }
+    }
+
+    fn walk(text: &CaretText, from: usize, motions: &[CaretMotion]) -> usize {
+        motions
+            .iter()
+            .fold(from, |position, motion| text.step(position, *motion))
+    }
+
+    #[test]
+    fn word_motions_follow_vim() {
+        let text = CaretText::new(&stream_page("alpha beta\ngamma"));
+
+        assert_eq!(walk(&text, 0, &[CaretMotion::WordForward]), 5);
+        assert_eq!(walk(&text, 0, &[CaretMotion::WordEnd]), 4);
+        assert_eq!(walk(&text, 4, &[CaretMotion::WordEnd]), 8);
+        assert_eq!(walk(&text, 7, &[CaretMotion::WordBackward]), 5);
+        assert_eq!(walk(&text, 5, &[CaretMotion::WordBackward]), 0);
+        assert_eq!(walk(&text, 5, &[CaretMotion::WordForward]), 9);
+        assert_eq!(walk(&text, 13, &[CaretMotion::WordForward]), 13);
+    }
+
+    #[test]
+    fn line_motions_keep_the_caret_column() {
+        let text = CaretText::new(&stream_page("alpha beta\ngamma"));
+
+        assert_eq!(walk(&text, 2, &[CaretMotion::LineDown]), 11);
+        assert_eq!(walk(&text, 8, &[CaretMotion::LineDown]), 13);
+        assert_eq!(walk(&text, 11, &[CaretMotion::LineUp]), 2);
+        assert_eq!(walk(&text, 6, &[CaretMotion::LineStart]), 0);
+        assert_eq!(walk(&text, 0, &[CaretMotion::LineEnd]), 8);
+        assert_eq!(walk(&text, 13, &[CaretMotion::Right]), 13);
+    }
+
+    #[test]
+    fn selected_text_keeps_word_and_line_breaks() {
+        let text = CaretText::new(&stream_page("alpha beta\ngamma"));
+
+        assert_eq!(text.text(2..=11), "pha beta\ngam");
+        assert_eq!(text.text(text.whole_lines(6, 6)), "alpha beta");
+        assert_eq!(text.rects(2..=11).len(), 2);
+    }
+}
@@ -0,0 +1,34 @@
+mod caret;
+pub mod state;
+
+use crate::app::AppState;
+use crate::backend::SharedPdfBackend;
+use crate::event::AppEvent;
+use crate::extension::Extension;
+pub use state::{SelectionCommandPort, SelectionState};
+
+pub struct SelectionExtension;
+
+impl Extension for SelectionExtension {
+    type State = SelectionState;
+
+    fn init_state() -> Self::State {
+        SelectionState::default()
+    }
+
+    fn handle_event(state: &mut Self::State, event: &AppEvent, app: &mut AppState) {
+        // The caret lives on one page, so leaving it ends the selection.
+        if matches!(event, AppEvent::PageChanged { .. }) {
+            state.exit(app);
+        }
+    }
+
+    fn on_document_reloaded(state: &mut Self::State, app: &mut AppState, pdf: SharedPdfBackend) {
+        let _ = pdf;
+        state.exit(app);
+    }
+
+    fn status_bar_segment(state: &Self::State, _app: &AppState) -> Option<String> {
+        state.status_bar_segment()
+    }
+}
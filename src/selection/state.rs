@@ -0,0 +1,243 @@
+use std::ops::RangeInclusive;
+
+use crate::app::{AppState, Mode, NoticeAction};
+use crate::backend::PdfBackend;
+use crate::command::{CaretMotion, CommandOutcome, SelectionKind};
+use crate::error::AppResult;
+use crate::highlight::{HighlightSource, HighlightSpan, HighlightStyle};
+
+use super::caret::CaretText;
+
+struct SelectionSession {
+    page: usize,
+    text: CaretText,
+    caret: usize,
+    /// Where the selection was started and how it extends to the caret.
+    anchor: Option<(usize, SelectionKind)>,
+}
+
+impl SelectionSession {
+    fn selected(&self) -> Option<RangeInclusive<usize>> {
+        let (anchor, kind) = self.anchor?;
+        Some(match kind {
+            SelectionKind::Char => anchor.min(self.caret)..=anchor.max(self.caret),
+            SelectionKind::Line => self.text.whole_lines(anchor, self.caret),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct SelectionState {
+    session: Option<SelectionSession>,
+}
+
+pub struct SelectionCommandPort<'a> {
+    state: &'a mut SelectionState,
+}
+
+impl<'a> SelectionCommandPort<'a> {
+    pub(crate) fn new(state: &'a mut SelectionState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn enter(
+        &mut self,
+        app: &mut AppState,
+        pdf: &dyn PdfBackend,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        self.state.enter(app, pdf)
+    }
+
+    pub(crate) fn move_caret(&mut self, motion: CaretMotion) -> (CommandOutcome, NoticeAction) {
+        self.state.move_caret(motion)
+    }
+
+    pub(crate) fn select(&mut self, kind: SelectionKind) -> (CommandOutcome, NoticeAction) {
+        self.state.select(kind)
+    }
+
+    pub(crate) fn exit(&mut self, app: &mut AppState) -> (CommandOutcome, NoticeAction) {
+        self.state.exit(app)
+    }
+
+    pub(crate) fn take_selected_text(&mut self, app: &mut AppState) -> Option<String> {
+        self.state.take_selected_text(app)
+    }
+}
+
+impl SelectionState {
+    /// Puts the caret on the first glyph of the current page and switches to visual mode.
+    pub fn enter(
+        &mut self,
+        app: &mut AppState,
+        pdf: &dyn PdfBackend,
+    ) -> AppResult<(CommandOutcome, NoticeAction)> {
+        let page = app.current_page;
+        let text = CaretText::new(&pdf.extract_text_page(page)?);
+        if text.is_empty() {
+            return Ok((
+                CommandOutcome::Noop,
+                NoticeAction::warning("no text on this page"),
+            ));
+        }
+
+        self.session = Some(SelectionSession {
+            page,
+            text,
+            caret: 0,
+            anchor: None,
+        });
+        app.mode = Mode::Visual;
+        Ok((CommandOutcome::Applied, NoticeAction::Clear))
+    }
+
+    pub fn move_caret(&mut self, motion: CaretMotion) -> (CommandOutcome, NoticeAction) {
+        let Some(session) = &mut self.session else {
+            return (CommandOutcome::Noop, NoticeAction::Clear);
+        };
+        let caret = session.text.step(session.caret, motion);
+        if caret == session.caret {
+            return (CommandOutcome::Noop, NoticeAction::Clear);
+        }
+        session.caret = caret;
+        (CommandOutcome::Applied, NoticeAction::Clear)
+    }
+
+    /// Starts a selection at the caret, switches it to `kind`, or drops it when it already is
+    /// one, the way `v` and `V` toggle in vim.
+    pub fn select(&mut self, kind: SelectionKind) -> (CommandOutcome, NoticeAction) {
+        let Some(session) = &mut self.session else {
+            return (CommandOutcome::Noop, NoticeAction::Clear);
+        };
+        session.anchor = match session.anchor {
+            Some((_, current)) if current == kind => None,
+            Some((anchor, _)) => Some((anchor, kind)),
+            None => Some((session.caret, kind)),
+        };
+        (CommandOutcome::Applied, NoticeAction::Clear)
+    }
+
+    pub fn exit(&mut self, app: &mut AppState) -> (CommandOutcome, NoticeAction) {
+        if self.session.take().is_none() && app.mode != Mode::Visual {
+            return (CommandOutcome::Noop, NoticeAction::Clear);
+        }
+        if app.mode == Mode::Visual {
+            app.mode = Mode::Normal;
+        }
+        (CommandOutcome::Applied, NoticeAction::Clear)
+    }
+
+    /// Text of the selection, leaving visual mode when there is one to hand out.
+    pub fn take_selected_text(&mut self, app: &mut AppState) -> Option<String> {
+        let session = self.session.as_ref()?;
+        let text = session.text.text(session.selected()?);
+        self.exit(app);
+        Some(text)
+    }
+
+    pub fn status_bar_segment(&self) -> Option<String> {
+        let session = self.session.as_ref()?;
+        let label = match session.anchor {
+            None => "CARET",
+            Some((_, SelectionKind::Char)) => "VISUAL",
+            Some((_, SelectionKind::Line)) => "VISUAL LINE",
+        };
+        Some(label.to_string())
+    }
+
+    /// The selection when there is one, otherwise the glyph under the caret.
+    pub fn highlight_spans_for_visible_pages(
+        &self,
+        visible_pages: [Option<usize>; 2],
+    ) -> Vec<HighlightSpan> {
+        let Some(session) = self
+            .session
+            .as_ref()
+            .filter(|session| visible_pages.contains(&Some(session.page)))
+        else {
+            return Vec::new();
+        };
+        let (positions, style) = match session.selected() {
+            Some(positions) => (positions, HighlightStyle::SELECTION),
+            None => (session.caret..=session.caret, HighlightStyle::CARET),
+        };
+        let rects = session.text.rects(positions);
+        if rects.is_empty() {
+            return Vec::new();
+        }
+        vec![HighlightSpan {
+            source: HighlightSource::Selection,
+            page: session.page,
+            rects,
+            style,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::{AppState, Mode, NoticeAction};
+    use crate::backend::test_support::TextStubPdf;
+    use crate::command::{CaretMotion, CommandOutcome, SelectionKind};
+    use crate::highlight::HighlightStyle;
+
+    use super::SelectionState;
+
+    #[test]
+    fn selection_is_yanked_and_leaves_visual_mode() {
+        let mut state = SelectionState::default();
+        let mut app = AppState::default();
+        state
+            .enter(&mut app, &TextStubPdf::new(&["copy this text"]))
+            .expect("enter should succeed");
+        assert_eq!(app.mode, Mode::Visual);
+        assert_eq!(state.status_bar_segment().as_deref(), Some("CARET"));
+        assert_eq!(
+            state.highlight_spans_for_visible_pages([Some(0), None])[0].style,
+            HighlightStyle::CARET
+        );
+        assert_eq!(state.take_selected_text(&mut app), None);
+
+        state.move_caret(CaretMotion::WordForward);
+        state.select(SelectionKind::Char);
+        state.move_caret(CaretMotion::WordEnd);
+        assert_eq!(state.status_bar_segment().as_deref(), Some("VISUAL"));
+        assert_eq!(state.take_selected_text(&mut app).as_deref(), Some("this"));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(state.status_bar_segment().is_none());
+    }
+
+    #[test]
+    fn line_selection_toggles_and_covers_whole_lines() {
+        let mut state = SelectionState::default();
+        let mut app = AppState::default();
+        state
+            .enter(&mut app, &TextStubPdf::new(&["copy this text"]))
+            .expect("enter should succeed");
+
+        state.move_caret(CaretMotion::WordForward);
+        state.select(SelectionKind::Line);
+        assert_eq!(state.status_bar_segment().as_deref(), Some("VISUAL LINE"));
+        state.select(SelectionKind::Line);
+        assert_eq!(state.status_bar_segment().as_deref(), Some("CARET"));
+        state.select(SelectionKind::Line);
+        assert_eq!(
+            state.take_selected_text(&mut app).as_deref(),
+            Some("copy this text")
+        );
+    }
+
+    #[test]
+    fn pages_without_text_stay_in_normal_mode() {
+        let mut state = SelectionState::default();
+        let mut app = AppState::default();
+
+        let (outcome, notice) = state
+            .enter(&mut app, &TextStubPdf::new(&["  "]))
+            .expect("enter should succeed");
+
+        assert_eq!(outcome, CommandOutcome::Noop);
+        assert_eq!(notice, NoticeAction::warning("no text on this page"));
+        assert_eq!(app.mode, Mode::Normal);
+    }
+}
@@ -3,7 +3,8 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Wrap};
 use unicode_width::UnicodeWidthStr;
 
-use crate::app::{Notice, NoticeLevel, PageLayoutMode, VisiblePageSlots};
+use crate::app::{FitMode, Notice, NoticeLevel, PageLayoutMode, PageViewMode, VisiblePageSlots};
+use crate::backend::PageLabels;
 
 use super::layout::UiLayout;
 use super::{border, error_text, primary_text, warning_text};
@@ -14,9 +15,15 @@ const MIN_FILENAME_ELISION_WIDTH: usize = 7;
 pub struct ChromeViewState {
     pub visible_pages: VisiblePageSlots,
     pub page_presentation: PageLayoutMode,
+    pub view_mode: PageViewMode,
+    pub fit_mode: FitMode,
     pub zoom: f32,
     pub debug_status_visible: bool,
     pub notice: Option<Notice>,
+    /// Replaces the file name in the status bar when `[view] status_title` is on.
+    pub document_title: Option<String>,
+    /// `/PageLabels` labels shown ahead of the physical page numbers when they differ.
+    pub page_labels: PageLabels,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -79,12 +86,19 @@ fn build_status_text(
     extension_status_segments: &[String],
     max_width: usize,
 ) -> String {
+    let file_name = chrome.document_title.as_deref().unwrap_or(file_name);
     let page_total = page_count.max(1);
-    let base = format!(
-        "{} | zoom {:.2}x",
-        format_page_segment(chrome, page_total),
-        chrome.zoom
-    );
+    // Zoom has no effect on reflowed text, so text view names the mode in its place.
+    let view = match chrome.view_mode {
+        PageViewMode::Image => match chrome.fit_mode {
+            FitMode::Page => format!("zoom {:.2}x", chrome.zoom),
+            FitMode::Width => format!("fit-width {:.2}x", chrome.zoom),
+            FitMode::Height => format!("fit-height {:.2}x", chrome.zoom),
+            FitMode::Actual => format!("actual-size {:.2}x", chrome.zoom),
+        },
+        PageViewMode::Text => "text view".to_string(),
+    };
+    let base = format!("{} | {view}", format_page_segment(chrome, page_total));
     let sep = " | ";
 
     if max_width == 0 {
@@ -131,13 +145,29 @@ fn build_status_text(
 
 fn format_page_segment(chrome: &ChromeViewState, page_total: usize) -> String {
     let slots = chrome.visible_pages;
+    let labels = &chrome.page_labels;
     let page_width = page_total.to_string().len();
     match chrome.page_presentation {
-        PageLayoutMode::Single => {
+        PageLayoutMode::Single | PageLayoutMode::Continuous => {
             let page_now = slots.anchor_page.saturating_add(1).min(page_total);
-            format!("p.{:>page_width$}/{:>page_width$}", page_now, page_total)
+            match labels.distinct(page_now - 1) {
+                Some(label) => format!("{label} ({page_now}/{page_total})"),
+                None => format!("p.{:>page_width$}/{:>page_width$}", page_now, page_total),
+            }
         }
         PageLayoutMode::Spread => match slots.trailing_page {
+            Some(trailing)
+                if labels.distinct(slots.anchor_page).is_some()
+                    || labels.distinct(trailing).is_some() =>
+            {
+                format!(
+                    "{}-{} ({}-{}/{page_total})",
+                    page_label_or_number(labels, slots.anchor_page),
+                    page_label_or_number(labels, trailing),
+                    slots.anchor_page + 1,
+                    trailing + 1,
+                )
+            }
             Some(trailing) => format!(
                 "pp.{:>page_width$}-{:>page_width$}/{:>page_width$}",
                 slots.anchor_page + 1,
@@ -153,6 +183,12 @@ fn format_page_segment(chrome: &ChromeViewState, page_total: usize) -> String {
     }
 }
 
+fn page_label_or_number(labels: &PageLabels, page: usize) -> String {
+    labels
+        .get(page)
+        .map_or_else(|| (page + 1).to_string(), str::to_string)
+}
+
 fn build_presenter_path_text(
     presenter_label: &str,
     graphics_protocol: Option<&str>,
@@ -176,10 +212,12 @@ fn stylize_status_line(text: &str) -> Line<'static> {
 
 fn stylize_notice_line(notice: &Notice, max_width: usize) -> Line<'static> {
     let label = match notice.level {
+        NoticeLevel::Info => "info",
         NoticeLevel::Warning => "notice",
         NoticeLevel::Error => "error",
     };
     let accent = match notice.level {
+        NoticeLevel::Info => primary_text(),
         NoticeLevel::Warning => warning_text(),
         NoticeLevel::Error => error_text(),
     };
@@ -278,7 +316,10 @@ fn elide_middle_by_width(input: &str, max_width: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::app::{AppState, Notice, NoticeLevel, PageLayoutMode, SpreadCoverPolicy};
+    use crate::app::{
+        AppState, FitMode, Notice, NoticeLevel, PageLayoutMode, PageViewMode, SpreadCoverPolicy,
+    };
+    use crate::backend::PageLabels;
 
     use super::{
         ChromeViewState, build_presenter_path_text, build_status_text, display_width,
@@ -289,9 +330,13 @@ mod tests {
         ChromeViewState {
             visible_pages: app.visible_page_slots(page_count),
             page_presentation: app.page_presentation_for_slots(app.visible_page_slots(page_count)),
+            view_mode: app.view_mode,
+            fit_mode: app.fit_mode,
             zoom: app.zoom,
             debug_status_visible: app.debug_status_visible,
             notice: app.notice.clone(),
+            document_title: None,
+            page_labels: app.page_labels.clone(),
         }
     }
 
@@ -307,6 +352,42 @@ mod tests {
         assert_eq!(text, "p. 3/10 | zoom 1.50x | sample.pdf");
     }
 
+    #[test]
+    fn build_status_text_prefers_document_title_over_file_name() {
+        let app = AppState::default();
+        let chrome = ChromeViewState {
+            document_title: Some("Quarterly Report".to_string()),
+            ..chrome_from_app(&app, 4)
+        };
+
+        let text = build_status_text(&chrome, "q3.pdf", 4, &[], 80);
+        assert_eq!(text, "p.1/4 | zoom 1.00x | Quarterly Report");
+    }
+
+    #[test]
+    fn build_status_text_names_explicit_fit_mode_with_zoom() {
+        let app = AppState {
+            fit_mode: FitMode::Width,
+            zoom: 1.25,
+            ..AppState::default()
+        };
+
+        let text = build_status_text(&chrome_from_app(&app, 4), "q3.pdf", 4, &[], 80);
+        assert_eq!(text, "p.1/4 | fit-width 1.25x | q3.pdf");
+    }
+
+    #[test]
+    fn build_status_text_names_text_view_instead_of_zoom() {
+        let app = AppState {
+            view_mode: PageViewMode::Text,
+            zoom: 2.0,
+            ..AppState::default()
+        };
+
+        let text = build_status_text(&chrome_from_app(&app, 4), "q3.pdf", 4, &[], 80);
+        assert_eq!(text, "p.1/4 | text view | q3.pdf");
+    }
+
     #[test]
     fn build_presenter_path_text_formats_presenter_with_proto() {
         let text = build_presenter_path_text("ratatui-image", Some("kitty"), 200);
@@ -471,4 +552,27 @@ mod tests {
         let text = build_status_text(&chrome_from_app(&app, 10), "sample.pdf", 10, &[], 120);
         assert_eq!(text, "p. 1/10 | zoom 1.00x | sample.pdf");
     }
+
+    #[test]
+    fn build_status_text_shows_page_labels_that_differ_from_page_numbers() {
+        let labels = ["i", "ii", "iii", "iv", "1", "2"].map(str::to_string);
+        let mut app = AppState {
+            current_page: 3,
+            page_labels: PageLabels::new(labels.to_vec()),
+            ..AppState::default()
+        };
+        let text = build_status_text(&chrome_from_app(&app, 320), "book.pdf", 320, &[], 120);
+        assert_eq!(text, "iv (4/320) | zoom 1.00x | book.pdf");
+
+        app.page_layout_mode = PageLayoutMode::Spread;
+        app.current_page = 4;
+        let text = build_status_text(&chrome_from_app(&app, 320), "book.pdf", 320, &[], 120);
+        assert_eq!(text, "1-2 (5-6/320) | zoom 1.00x | book.pdf");
+
+        app.page_layout_mode = PageLayoutMode::Single;
+        app.page_labels = PageLabels::new(vec!["1".to_string(), "2".to_string()]);
+        app.current_page = 1;
+        let text = build_status_text(&chrome_from_app(&app, 2), "book.pdf", 2, &[], 120);
+        assert_eq!(text, "p.2/2 | zoom 1.00x | book.pdf");
+    }
 }
@@ -113,6 +113,10 @@ const DEFAULT_SECTIONS: &[HelpSection] = &[
                 sources: &[HelpKeySource::ExactCommand("history-forward")],
                 description: "History forward",
             },
+            HelpRow {
+                sources: &[HelpKeySource::ExactCommand("link-hints")],
+                description: "Follow link",
+            },
         ],
     },
     HelpSection {
@@ -130,10 +134,22 @@ const DEFAULT_SECTIONS: &[HelpSection] = &[
                 sources: &[HelpKeySource::ExactCommand("zoom-reset")],
                 description: "Reset zoom",
             },
+            HelpRow {
+                sources: &[HelpKeySource::ExactCommand("fit-page")],
+                description: "Fit page",
+            },
+            HelpRow {
+                sources: &[HelpKeySource::ExactCommand("fit-width")],
+                description: "Fit width",
+            },
             HelpRow {
                 sources: &[HelpKeySource::ExactCommand("pan")],
                 description: "Pan",
             },
+            HelpRow {
+                sources: &[HelpKeySource::ExactCommand("text-view-toggle")],
+                description: "Toggle text view",
+            },
         ],
     },
     HelpSection {
@@ -164,6 +180,10 @@ const DEFAULT_SECTIONS: &[HelpSection] = &[
                 sources: &[HelpKeySource::ExactCommand("help")],
                 description: "Help",
             },
+            HelpRow {
+                sources: &[HelpKeySource::ExactCommand("visual-mode")],
+                description: "Select and copy text",
+            },
             HelpRow {
                 sources: &[HelpKeySource::ExactCommand("quit")],
                 description: "Quit",
@@ -7,8 +7,11 @@ mod theme;
 pub use chrome::{ChromeViewState, draw_chrome};
 pub use help::draw_help_overlay;
 pub use layout::split_layout;
-pub use overlay::{draw_error_overlay, draw_loading_overlay, draw_palette_overlay};
+pub use overlay::{
+    LinkHintLabel, draw_error_overlay, draw_info_overlay, draw_link_hints, draw_loading_overlay,
+    draw_palette_overlay,
+};
 pub(crate) use theme::{
-    border, error_text, heading_text, hit_highlight_text, primary_text, secondary_text,
-    warning_text,
+    border, error_text, heading_text, hit_highlight_text, link_hint_text, primary_text,
+    secondary_text, warning_text,
 };
@@ -9,7 +9,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::palette::{PaletteItemView, PaletteView};
 
 use super::layout::centered_rect;
-use super::{border, error_text, hit_highlight_text, primary_text, secondary_text};
+use super::{border, error_text, hit_highlight_text, link_hint_text, primary_text, secondary_text};
 
 const PALETTE_ITEM_DECORATION_WIDTH: usize = 3;
 const MIN_VISIBLE_SIDE_WIDTH: usize = 4;
@@ -71,6 +71,86 @@ pub fn draw_error_overlay(frame: &mut Frame<'_>, area: Rect, message: &str) {
     frame.render_widget(paragraph, inner);
 }
 
+pub fn draw_info_overlay(frame: &mut Frame<'_>, area: Rect, rows: &[(&str, String)]) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+
+    let popup_width = area.width.min(64);
+    let popup_height = area.height.min(rows.len() as u16 + 2);
+    let popup = centered_rect(area, popup_width, popup_height);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(" Document Info ")
+        .borders(Borders::ALL)
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .border_style(border());
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    if inner.width <= 2 || inner.height == 0 {
+        return;
+    }
+
+    let content_width = inner.width as usize - 2;
+    let label_width = rows
+        .iter()
+        .map(|(label, _)| UnicodeWidthStr::width(*label))
+        .max()
+        .unwrap_or(0);
+    let lines = rows
+        .iter()
+        .map(|(label, value)| {
+            let label = format!("{label:<label_width$}  ");
+            let value_width = content_width.saturating_sub(UnicodeWidthStr::width(label.as_str()));
+            Line::from(vec![
+                Span::styled(truncate_to_width(&label, content_width), secondary_text()),
+                Span::styled(truncate_with_ellipsis(value, value_width), primary_text()),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let content_area = Rect::new(inner.x + 1, inner.y, inner.width - 2, inner.height);
+    frame.render_widget(Paragraph::new(lines), content_area);
+}
+
+/// A link hint label anchored at the top-left cell of its link rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkHintLabel<'a> {
+    pub anchor: Rect,
+    pub label: &'a str,
+    pub typed_len: usize,
+}
+
+pub fn draw_link_hints(frame: &mut Frame<'_>, area: Rect, hints: &[LinkHintLabel<'_>]) {
+    for hint in hints {
+        let Some(label_area) = link_hint_area(area, hint) else {
+            continue;
+        };
+        let (typed, rest) = hint.label.split_at(hint.typed_len.min(hint.label.len()));
+        let line = Line::from(vec![
+            Span::styled(
+                typed.to_string(),
+                link_hint_text().add_modifier(Modifier::DIM),
+            ),
+            Span::styled(rest.to_string(), link_hint_text()),
+        ]);
+        frame.render_widget(Clear, label_area);
+        frame.render_widget(Paragraph::new(line), label_area);
+    }
+}
+
+fn link_hint_area(area: Rect, hint: &LinkHintLabel<'_>) -> Option<Rect> {
+    let width = UnicodeWidthStr::width(hint.label) as u16;
+    if width == 0 || !area.contains(hint.anchor.as_position()) {
+        return None;
+    }
+    // Keep labels whole near the right edge by sliding them back into the viewer.
+    let right = area.x.saturating_add(area.width);
+    let x = hint.anchor.x.min(right.saturating_sub(width)).max(area.x);
+    Some(Rect::new(x, hint.anchor.y, width.min(area.width), 1))
+}
+
 pub fn draw_palette_overlay(frame: &mut Frame<'_>, area: Rect, view: &PaletteView) {
     if area.width == 0 || area.height == 0 {
         return;
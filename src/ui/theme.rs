@@ -24,6 +24,13 @@ pub fn hit_highlight_text() -> Style {
     Style::default().bg(Color::Yellow)
 }
 
+pub fn link_hint_text() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::LightYellow)
+        .add_modifier(Modifier::BOLD)
+}
+
 pub fn error_text() -> Style {
     Style::default().fg(Color::LightRed)
 }